@@ -0,0 +1,38 @@
+//! 签名性能基准
+//!
+//! 对比同一秒内重复签名（复用缓存的 SignKey）与每次 KeyTime 都不同（每次重新派生 SignKey）的耗时。运行方式：
+//! ```bash
+//! cargo bench --bench sign
+//! ```
+
+use chrono::{Duration, TimeZone, Utc};
+use cos_rust_sdk::Auth;
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: i64 = 200_000;
+
+fn main() {
+    let auth = Auth::new("AKIDEXAMPLE", "secret");
+    let headers = HashMap::from([
+        ("Host".to_string(), "examplebucket-1250000000.cos.ap-beijing.myqcloud.com".to_string()),
+        ("Content-Type".to_string(), "text/plain".to_string()),
+        ("Content-Length".to_string(), "13".to_string()),
+    ]);
+    let params = HashMap::new();
+    let start = Utc.timestamp_opt(1700000000, 0).unwrap();
+
+    let run = |name: &str, key_time_step: i64| {
+        let begin = Instant::now();
+        for i in 0..ITERATIONS {
+            let end = start + Duration::hours(1) + Duration::seconds(i * key_time_step);
+            black_box(auth.sign("PUT", "/a.txt", &headers, &params, start, end).unwrap());
+        }
+        let per_sign = begin.elapsed().as_nanos() / ITERATIONS as u128;
+        println!("{:<28} {:>6} ns/sign", name, per_sign);
+    };
+
+    run("sign_key cached", 0);
+    run("sign_key derived every time", 1);
+}
@@ -154,7 +154,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("   📁 对象列表:");
                 for (i, object) in response.contents.iter().enumerate() {
                     println!("   {}. {} ({} 字节) - {}", 
-                        i + 1, object.key, object.size, object.last_modified);
+                        i + 1, object.key, object.size, object.last_modified_raw);
                 }
             }
         }
@@ -220,7 +220,7 @@ async fn test_format_detection(
 
 /// 为不同格式创建示例数据
 fn create_sample_data_for_format(filename: &str) -> Vec<u8> {
-    let extension = filename.split('.').last().unwrap_or("").to_lowercase();
+    let extension = filename.split('.').next_back().unwrap_or("").to_lowercase();
     
     match extension.as_str() {
         // 图片格式
@@ -0,0 +1,98 @@
+//! 表单上传示例
+//!
+//! 这个示例展示了如何生成 POST Object 表单字段，并模拟浏览器以 multipart/form-data 上传文件。
+//! 实际使用时，服务端只需把 `PostForm` 交给前端，由前端填入表单后直接提交到 COS。
+//!
+//! 运行示例：
+//! ```bash
+//! cargo run --example post_upload
+//! ```
+//!
+//! 注意：运行前请设置环境变量：
+//! - COS_SECRET_ID: 腾讯云 SecretId
+//! - COS_SECRET_KEY: 腾讯云 SecretKey
+//! - COS_REGION: 地域，如 ap-beijing
+//! - COS_BUCKET: 存储桶名称（包含 APPID）
+
+use cos_rust_sdk::{Config, CosClient, ObjectClient, PostForm, PostPolicy};
+use std::env;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let secret_id = env::var("COS_SECRET_ID")
+        .expect("Please set COS_SECRET_ID environment variable");
+    let secret_key = env::var("COS_SECRET_KEY")
+        .expect("Please set COS_SECRET_KEY environment variable");
+    let region = env::var("COS_REGION")
+        .expect("Please set COS_REGION environment variable");
+    let bucket = env::var("COS_BUCKET")
+        .expect("Please set COS_BUCKET environment variable");
+
+    println!("=== 腾讯云 COS 表单上传示例 ===");
+
+    let config = Config::new(&secret_id, &secret_key, &region, &bucket);
+    let object_client = ObjectClient::new(CosClient::new(config)?);
+
+    // 1. 服务端生成表单字段：只允许上传到 uploads/ 下，大小不超过 1MB 的文本文件
+    let policy = PostPolicy::new(Duration::from_secs(600))
+        .with_content_length_range(1, 1024 * 1024)
+        .with_content_type("text/plain")
+        .with_success_action_status(201);
+    let form = object_client.generate_post_form("uploads/", policy)?;
+
+    println!("表单地址: {}", form.url);
+    for (name, value) in &form.fields {
+        println!("  {} = {}", name, value);
+    }
+
+    // 2. 模拟浏览器提交表单，文件字段必须放在最后
+    let (content_type, body) = multipart_body(&form, "hello.txt", b"Hello from a POST form!");
+    let response = reqwest::Client::new()
+        .post(&form.url)
+        .header("Content-Type", content_type)
+        .body(body)
+        .send()
+        .await?;
+
+    println!();
+    println!("上传状态: {}", response.status());
+    if let Some(location) = response.headers().get("location") {
+        println!("对象地址: {}", location.to_str()?);
+    }
+    println!("{}", response.text().await?);
+
+    // 3. 清理上传的对象
+    object_client.delete_object("uploads/hello.txt").await?;
+    println!("已删除 uploads/hello.txt");
+
+    Ok(())
+}
+
+/// 构造 multipart/form-data 请求体，返回 Content-Type 与请求体
+fn multipart_body(form: &PostForm, file_name: &str, data: &[u8]) -> (String, Vec<u8>) {
+    let boundary = format!("----cos-rust-sdk-{}", chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0));
+    let mut body = Vec::new();
+
+    for (name, value) in &form.fields {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                boundary, name, value
+            )
+            .as_bytes(),
+        );
+    }
+
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: text/plain\r\n\r\n",
+            boundary, file_name
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+    (format!("multipart/form-data; boundary={}", boundary), body)
+}
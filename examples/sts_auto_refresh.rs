@@ -0,0 +1,80 @@
+//! 自动刷新 STS 临时密钥的长时间上传示例
+//!
+//! 本示例展示如何：
+//! 1. 用 `StsCredentialsProvider` 按策略模板获取只能写入 `uploads/` 前缀的临时密钥
+//! 2. 持续上传对象，临时密钥临近过期时自动刷新，无需重建客户端
+//! 3. 观察上传过程中临时密钥的多次轮换
+//!
+//! 临时密钥有效期为 15 分钟（STS 允许的最小值），刷新余量为 10 分钟，约每 5 分钟轮换一次。
+//!
+//! 运行示例：
+//! ```bash
+//! export COS_SECRET_ID="your-secret-id"
+//! export COS_SECRET_KEY="your-secret-key"
+//! export COS_REGION="ap-beijing"
+//! export COS_BUCKET="your-bucket-name-appid"
+//!
+//! cargo run --example sts_auto_refresh
+//! ```
+
+use cos_rust_sdk::sts::{GetCredentialsRequest, Policy, StsClient, StsCredentialsProvider};
+use cos_rust_sdk::{Config, CosClient, ObjectClient};
+use std::env;
+use std::time::Duration;
+
+/// 观察到的密钥轮换次数达到该值后结束
+const ROTATIONS: usize = 3;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let secret_id = env::var("COS_SECRET_ID").expect("请设置环境变量 COS_SECRET_ID");
+    let secret_key = env::var("COS_SECRET_KEY").expect("请设置环境变量 COS_SECRET_KEY");
+    let region = env::var("COS_REGION").unwrap_or_else(|_| "ap-beijing".to_string());
+    let bucket = env::var("COS_BUCKET").expect("请设置环境变量 COS_BUCKET");
+
+    // 1. 创建自动刷新临时密钥的提供者
+    let provider = StsCredentialsProvider::new(
+        StsClient::new(secret_id, secret_key, region.clone()),
+        GetCredentialsRequest {
+            name: Some("sts-auto-refresh-example".to_string()),
+            policy: Policy::allow_put_object(&bucket, Some("uploads/")),
+            duration_seconds: Some(900),
+        },
+    )
+    .with_refresh_margin(Duration::from_secs(600));
+
+    // 2. 客户端中的密钥由提供者管理
+    let config = Config::new("", "", &region, &bucket).with_credentials_provider(provider);
+    let cos_client = CosClient::new(config)?;
+    let object_client = ObjectClient::new(cos_client.clone());
+
+    // 3. 持续上传，直到观察到足够多次轮换
+    let mut current_id = String::new();
+    let mut rotations = 0;
+    let mut uploaded = 0;
+    while rotations < ROTATIONS {
+        let key = format!("uploads/sts-auto-refresh-{}.txt", uploaded);
+        object_client
+            .put_object(&key, format!("upload #{}", uploaded), Some("text/plain"))
+            .await?;
+        uploaded += 1;
+
+        let credentials = cos_client.credentials().await?;
+        if credentials.secret_id != current_id {
+            if !current_id.is_empty() {
+                rotations += 1;
+            }
+            println!(
+                "🔑 使用临时密钥 {}，过期时间: {:?}",
+                credentials.secret_id, credentials.expires_at
+            );
+            current_id = credentials.secret_id;
+        }
+        println!("✅ 上传成功: {}", key);
+
+        tokio::time::sleep(Duration::from_secs(30)).await;
+    }
+
+    println!("🎉 共上传 {} 个对象，临时密钥轮换 {} 次", uploaded, rotations);
+    Ok(())
+}
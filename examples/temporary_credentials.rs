@@ -0,0 +1,58 @@
+//! 使用 STS 临时密钥访问 COS 示例
+//!
+//! 本示例展示如何：
+//! 1. 用长期密钥获取只能读写 `tmp/` 前缀的临时密钥
+//! 2. 用临时密钥创建 COS 客户端，请求会携带 `x-cos-security-token`
+//! 3. 上传、下载并删除对象
+//!
+//! 运行示例：
+//! ```bash
+//! export COS_SECRET_ID="your-secret-id"
+//! export COS_SECRET_KEY="your-secret-key"
+//! export COS_REGION="ap-beijing"
+//! export COS_BUCKET="your-bucket-name-appid"
+//!
+//! cargo run --example temporary_credentials
+//! ```
+
+use cos_rust_sdk::sts::{GetCredentialsRequest, Policy, StsClient};
+use cos_rust_sdk::{Config, CosClient, ObjectClient};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let secret_id = env::var("COS_SECRET_ID").expect("请设置环境变量 COS_SECRET_ID");
+    let secret_key = env::var("COS_SECRET_KEY").expect("请设置环境变量 COS_SECRET_KEY");
+    let region = env::var("COS_REGION").unwrap_or_else(|_| "ap-beijing".to_string());
+    let bucket = env::var("COS_BUCKET").expect("请设置环境变量 COS_BUCKET");
+
+    // 1. 获取临时密钥
+    let sts_client = StsClient::new(secret_id, secret_key, region.clone());
+    let credentials = sts_client
+        .get_credentials(GetCredentialsRequest {
+            name: Some("temporary-credentials-example".to_string()),
+            policy: Policy::allow_read_write(&bucket, Some("tmp/")),
+            duration_seconds: Some(900),
+        })
+        .await?;
+    println!("✅ 获取临时密钥成功，过期时间: {:?}", credentials.expired_time);
+
+    // 2. 用临时密钥创建客户端
+    let config = Config::from_temporary_credentials(&credentials, region, bucket);
+    let object_client = ObjectClient::new(CosClient::new(config)?);
+
+    // 3. 在授权的前缀内读写对象
+    let key = "tmp/temporary-credentials-example.txt";
+    object_client
+        .put_object(key, "Hello from temporary credentials!", Some("text/plain"))
+        .await?;
+    println!("✅ 上传成功: {}", key);
+
+    let response = object_client.get_object(key).await?;
+    println!("✅ 下载成功: {}", String::from_utf8_lossy(&response.data));
+
+    object_client.delete_object(key).await?;
+    println!("✅ 删除成功: {}", key);
+
+    Ok(())
+}
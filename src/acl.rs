@@ -0,0 +1,328 @@
+//! 访问控制模块
+//!
+//! 提供存储桶与对象共用的 ACL 类型，以及对象级别的 ACL 读写接口
+
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use crate::object::{content_md5, ObjectClient};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+
+impl ObjectClient {
+    /// 获取对象ACL
+    pub async fn get_object_acl(&self, key: &str) -> Result<AccessControlPolicy> {
+        let mut params = HashMap::new();
+        params.insert("acl".to_string(), "".to_string());
+
+        let response = self.client.get(&self.object_path(key)?, params).await?;
+        let acl_response: AccessControlPolicy = CosClient::parse_xml(response).await?;
+
+        Ok(acl_response)
+    }
+
+    /// 设置对象ACL
+    pub async fn put_object_acl(&self, key: &str, acl: ObjectAcl) -> Result<()> {
+        self.put_object_acl_with_grants(key, Some(acl), AclGrants::default())
+            .await
+    }
+
+    /// 设置对象ACL，并向指定账号授权
+    pub async fn put_object_acl_with_grants(
+        &self,
+        key: &str,
+        acl: Option<ObjectAcl>,
+        grants: AclGrants,
+    ) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("acl".to_string(), "".to_string());
+
+        let mut headers = grants.to_headers();
+        if let Some(acl) = acl {
+            headers.insert("x-cos-acl".to_string(), acl.to_string());
+        }
+
+        let _response = self
+            .client
+            .put_with_headers(&self.object_path(key)?, params, headers, None::<&[u8]>)
+            .await?;
+        Ok(())
+    }
+
+    /// 以完整的访问控制策略设置对象ACL，会覆盖已有的全部授权
+    pub async fn put_object_acl_with_policy(&self, key: &str, policy: &AccessControlPolicy) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("acl".to_string(), "".to_string());
+
+        let xml_body = policy.to_xml()?;
+        let mut headers = HashMap::new();
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
+
+        let _response = self
+            .client
+            .put_with_headers(&self.object_path(key)?, params, headers, Some(xml_body))
+            .await?;
+        Ok(())
+    }
+}
+
+/// 对象ACL类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectAcl {
+    /// 继承存储桶的权限
+    Default,
+    Private,
+    PublicRead,
+}
+
+impl fmt::Display for ObjectAcl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ObjectAcl::Default => "default",
+            ObjectAcl::Private => "private",
+            ObjectAcl::PublicRead => "public-read",
+        };
+        f.write_str(s)
+    }
+}
+
+/// 通过 `x-cos-grant-*` 请求头向指定账号授权
+///
+/// 每一项为被授权者表达式，如 `id="qcs::cam::uin/100000000001:uin/100000000001"`，
+/// 可使用 [`AclGrants::uin`] 由主账号 UIN 生成。
+#[derive(Debug, Clone, Default)]
+pub struct AclGrants {
+    pub read: Vec<String>,
+    /// 仅存储桶支持
+    pub write: Vec<String>,
+    pub read_acp: Vec<String>,
+    pub write_acp: Vec<String>,
+    pub full_control: Vec<String>,
+}
+
+impl AclGrants {
+    /// 生成主账号 UIN 对应的被授权者表达式
+    pub fn uin(uin: &str) -> String {
+        format!("id=\"qcs::cam::uin/{}:uin/{}\"", uin, uin)
+    }
+
+    /// 授予主账号读权限
+    pub fn grant_read(mut self, uin: &str) -> Self {
+        self.read.push(Self::uin(uin));
+        self
+    }
+
+    /// 授予主账号完全控制权限
+    pub fn grant_full_control(mut self, uin: &str) -> Self {
+        self.full_control.push(Self::uin(uin));
+        self
+    }
+
+    /// 转换为请求头，多个被授权者以逗号分隔
+    pub(crate) fn to_headers(&self) -> HashMap<String, String> {
+        let grants = [
+            ("x-cos-grant-read", &self.read),
+            ("x-cos-grant-write", &self.write),
+            ("x-cos-grant-read-acp", &self.read_acp),
+            ("x-cos-grant-write-acp", &self.write_acp),
+            ("x-cos-grant-full-control", &self.full_control),
+        ];
+
+        grants
+            .into_iter()
+            .filter(|(_, grantees)| !grantees.is_empty())
+            .map(|(name, grantees)| (name.to_string(), grantees.join(",")))
+            .collect()
+    }
+}
+
+/// 访问控制策略，存储桶与对象 ACL 共用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "AccessControlPolicy")]
+pub struct AccessControlPolicy {
+    #[serde(rename = "Owner")]
+    pub owner: Owner,
+    #[serde(rename = "AccessControlList")]
+    pub access_control_list: AccessControlList,
+}
+
+impl AccessControlPolicy {
+    /// 序列化为请求体
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        quick_xml::se::to_string(self)
+            .map_err(|e| CosError::other(format!("Failed to serialize access control policy: {}", e)))
+    }
+}
+
+/// 所有者信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Owner {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "DisplayName", default)]
+    pub display_name: String,
+}
+
+/// 访问控制列表
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccessControlList {
+    #[serde(rename = "Grant", default)]
+    pub grants: Vec<Grant>,
+}
+
+/// 授权信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Grant {
+    #[serde(rename = "Grantee")]
+    pub grantee: Grantee,
+    /// 权限，如 `READ`、`WRITE`、`FULL_CONTROL`
+    #[serde(rename = "Permission")]
+    pub permission: String,
+}
+
+impl Grant {
+    /// 创建授权
+    pub fn new<S: Into<String>>(grantee: Grantee, permission: S) -> Self {
+        Self {
+            grantee,
+            permission: permission.into(),
+        }
+    }
+}
+
+/// 被授权者
+///
+/// 序列化时带上 `xsi:type` 属性，取值为 `CanonicalUser`（按 ID 授权）或 `Group`（按 URI 授权）。
+#[derive(Debug, Clone, Deserialize)]
+pub struct Grantee {
+    #[serde(rename = "@type")]
+    pub grantee_type: String,
+    #[serde(rename = "ID", default)]
+    pub id: String,
+    #[serde(rename = "DisplayName", default)]
+    pub display_name: String,
+    #[serde(rename = "URI", default)]
+    pub uri: String,
+}
+
+impl Grantee {
+    /// 主账号 UIN 对应的被授权者
+    pub fn uin(uin: &str) -> Self {
+        Self {
+            grantee_type: "CanonicalUser".to_string(),
+            id: format!("qcs::cam::uin/{}:uin/{}", uin, uin),
+            display_name: String::new(),
+            uri: String::new(),
+        }
+    }
+
+    /// 所有用户（匿名访问）
+    pub fn all_users() -> Self {
+        Self {
+            grantee_type: "Group".to_string(),
+            id: String::new(),
+            display_name: String::new(),
+            uri: "http://cam.qcloud.com/groups/global/AllUsers".to_string(),
+        }
+    }
+}
+
+impl Serialize for Grantee {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Grantee", 5)?;
+        state.serialize_field("@xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance")?;
+        state.serialize_field("@xsi:type", &self.grantee_type)?;
+        if !self.id.is_empty() {
+            state.serialize_field("ID", &self.id)?;
+        }
+        if !self.display_name.is_empty() {
+            state.serialize_field("DisplayName", &self.display_name)?;
+        }
+        if !self.uri.is_empty() {
+            state.serialize_field("URI", &self.uri)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grant_headers() {
+        let headers = AclGrants::default()
+            .grant_read("100000000001")
+            .grant_read("100000000002")
+            .grant_full_control("100000000003")
+            .to_headers();
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(
+            headers["x-cos-grant-read"],
+            "id=\"qcs::cam::uin/100000000001:uin/100000000001\",id=\"qcs::cam::uin/100000000002:uin/100000000002\""
+        );
+        assert_eq!(
+            headers["x-cos-grant-full-control"],
+            "id=\"qcs::cam::uin/100000000003:uin/100000000003\""
+        );
+    }
+
+    #[test]
+    fn test_parse_access_control_policy() {
+        let xml = r#"<AccessControlPolicy>
+            <Owner><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>qcs::cam::uin/100000000001:uin/100000000001</DisplayName></Owner>
+            <AccessControlList>
+                <Grant>
+                    <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="CanonicalUser">
+                        <ID>qcs::cam::uin/100000000001:uin/100000000001</ID>
+                    </Grantee>
+                    <Permission>FULL_CONTROL</Permission>
+                </Grant>
+                <Grant>
+                    <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Group">
+                        <URI>http://cam.qcloud.com/groups/global/AllUsers</URI>
+                    </Grantee>
+                    <Permission>READ</Permission>
+                </Grant>
+            </AccessControlList>
+        </AccessControlPolicy>"#;
+
+        let policy: AccessControlPolicy = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(policy.access_control_list.grants.len(), 2);
+        assert_eq!(policy.access_control_list.grants[1].permission, "READ");
+        assert_eq!(policy.access_control_list.grants[1].grantee.grantee_type, "Group");
+        assert_eq!(
+            policy.access_control_list.grants[1].grantee.uri,
+            "http://cam.qcloud.com/groups/global/AllUsers"
+        );
+    }
+
+    #[test]
+    fn test_serialize_access_control_policy() {
+        let policy = AccessControlPolicy {
+            owner: Owner {
+                id: "qcs::cam::uin/100000000001:uin/100000000001".to_string(),
+                display_name: String::new(),
+            },
+            access_control_list: AccessControlList {
+                grants: vec![
+                    Grant::new(Grantee::uin("100000000002"), "FULL_CONTROL"),
+                    Grant::new(Grantee::all_users(), "READ"),
+                ],
+            },
+        };
+        let xml = policy.to_xml().unwrap();
+        assert!(xml.contains(
+            "<Grantee xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:type=\"CanonicalUser\">\
+             <ID>qcs::cam::uin/100000000002:uin/100000000002</ID></Grantee>"
+        ));
+        assert!(xml.contains("<URI>http://cam.qcloud.com/groups/global/AllUsers</URI>"));
+
+        let parsed: AccessControlPolicy = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed.access_control_list.grants.len(), 2);
+        assert_eq!(parsed.access_control_list.grants[0].grantee.grantee_type, "CanonicalUser");
+        assert_eq!(parsed.access_control_list.grants[1].permission, "READ");
+    }
+}
@@ -6,16 +6,152 @@ use crate::error::{CosError, Result};
 use chrono::{DateTime, Utc};
 use hmac::{Hmac, Mac};
 use sha1::{Digest, Sha1};
-use std::collections::HashMap;
-use url::Url;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Arc, RwLock};
 
 type HmacSha1 = Hmac<Sha1>;
 
+/// 与官方 SDK 一致参与签名的标准请求头（小写）
+const SIGNED_HEADERS: &[&str] = &[
+    "cache-control",
+    "content-disposition",
+    "content-encoding",
+    "content-length",
+    "content-md5",
+    "content-type",
+    "expect",
+    "expires",
+    "host",
+    "if-match",
+    "if-modified-since",
+    "if-none-match",
+    "if-unmodified-since",
+    "origin",
+    "pic-operations",
+    "range",
+    "transfer-encoding",
+];
+
+/// 临时密钥的安全令牌使用的请求头与查询参数名称
+const SECURITY_TOKEN: &str = "x-cos-security-token";
+
+/// 请求头是否参与签名，名称不区分大小写
+///
+/// 除 [`SIGNED_HEADERS`] 外，所有 `x-cos-*` 与 `x-ci-*` 请求头都参与签名；`User-Agent` 等
+/// 可能被代理改写的请求头不参与签名。
+pub(crate) fn is_signed_header(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SIGNED_HEADERS.contains(&name.as_str()) || name.starts_with("x-cos-") || name.starts_with("x-ci-")
+}
+
+/// 规范化参与签名的请求头：名称转为小写，只有大小写不同的同名请求头合并为一个，
+/// 取值按字典序排序后以 `,` 连接，结果与 `HashMap` 的遍历顺序无关
+pub(crate) fn normalize_headers(headers: &HashMap<String, String>) -> BTreeMap<String, String> {
+    let mut grouped: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for (name, value) in headers {
+        grouped.entry(name.to_ascii_lowercase()).or_default().push(value);
+    }
+    grouped
+        .into_iter()
+        .map(|(name, mut values)| {
+            values.sort_unstable();
+            (name, values.join(","))
+        })
+        .collect()
+}
+
+/// 以查询参数表示的签名，用于预签名 URL
+///
+/// 与 [`Auth::sign`] 生成的 Authorization 取值相同，按 [`Display`](fmt::Display) 输出为
+/// `q-sign-algorithm=sha1&q-ak=...&q-signature=...`，可以直接追加到 URL 的查询字符串中。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresignedQuery {
+    /// `q-ak`
+    pub ak: String,
+    /// `q-sign-time`，格式为 `<开始时间戳>;<结束时间戳>`
+    pub sign_time: String,
+    /// `q-key-time`，与 `sign_time` 相同
+    pub key_time: String,
+    /// `q-header-list`，参与签名的请求头名称（小写，按字典序以 `;` 分隔）
+    pub header_list: String,
+    /// `q-url-param-list`，参与签名的查询参数名称（编码后小写，按字典序以 `;` 分隔）
+    pub url_param_list: String,
+    /// `q-signature`
+    pub signature: String,
+    /// 临时密钥的安全令牌，作为 `x-cos-security-token` 查询参数参与签名，输出时追加在签名参数之后
+    pub security_token: Option<String>,
+}
+
+impl PresignedQuery {
+    /// 按签名规定的顺序返回签名参数，不包括 [`security_token`](Self::security_token)
+    pub fn params(&self) -> [(&'static str, &str); 7] {
+        [
+            ("q-sign-algorithm", "sha1"),
+            ("q-ak", &self.ak),
+            ("q-sign-time", &self.sign_time),
+            ("q-key-time", &self.key_time),
+            ("q-header-list", &self.header_list),
+            ("q-url-param-list", &self.url_param_list),
+            ("q-signature", &self.signature),
+        ]
+    }
+}
+
+impl fmt::Display for PresignedQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.params().iter().enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            write!(f, "{}={}", name, value)?;
+        }
+        if let Some(token) = &self.security_token {
+            write!(f, "&{}={}", SECURITY_TOKEN, urlencoding::encode(token))?;
+        }
+        Ok(())
+    }
+}
+
+/// [`Auth::verify`] 校验通过的签名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedSignature {
+    /// 签名使用的 SecretId（`q-ak`）
+    pub ak: String,
+    /// 签名的生效时间
+    pub start_time: DateTime<Utc>,
+    /// 签名的过期时间
+    pub end_time: DateTime<Utc>,
+    /// 参与签名的请求头名称（小写），取自 `q-header-list`
+    pub header_list: Vec<String>,
+    /// 参与签名的查询参数名称（编码后小写），取自 `q-url-param-list`
+    pub url_param_list: Vec<String>,
+}
+
 /// 认证信息
+///
+/// 由 KeyTime 派生的 SignKey 会被缓存，同一秒内生成的签名只计算一次；缓存由 `Auth` 的所有副本共享，
+/// 修改 [`secret_key`](Self::secret_key) 后自动失效。
 #[derive(Debug, Clone)]
 pub struct Auth {
     pub secret_id: String,
     pub secret_key: String,
+    /// 临时密钥的安全令牌，设置后参与签名，见 [`with_security_token`](Self::with_security_token)
+    pub security_token: Option<String>,
+    sign_key: Arc<RwLock<Option<CachedSignKey>>>,
+}
+
+/// 最近一次派生的 SignKey 及派生时使用的 KeyTime 与密钥
+struct CachedSignKey {
+    key_time: String,
+    secret_key: String,
+    sign_key: String,
+}
+
+impl fmt::Debug for CachedSignKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedSignKey").field("key_time", &self.key_time).finish_non_exhaustive()
+    }
 }
 
 impl Auth {
@@ -24,10 +160,26 @@ impl Auth {
         Self {
             secret_id: secret_id.into(),
             secret_key: secret_key.into(),
+            security_token: None,
+            sign_key: Arc::default(),
         }
     }
 
+    /// 设置临时密钥的安全令牌
+    ///
+    /// [`sign`](Self::sign) 将令牌作为 `x-cos-security-token` 请求头签名，发送请求时必须携带该请求头；
+    /// [`presign`](Self::presign) 将令牌作为同名查询参数签名，并输出到预签名查询参数中。
+    pub fn with_security_token<S: Into<String>>(mut self, token: S) -> Self {
+        self.security_token = Some(token.into());
+        self
+    }
+
     /// 生成授权签名
+    ///
+    /// `uri` 为未编码的请求路径，如 `/dir/a b.txt`，与官方 SDK 一致，按原样参与签名。
+    /// 请求头名称不区分大小写，只有大小写不同的同名请求头按取值的字典序以 `,` 连接后参与签名，
+    /// 发送请求时应携带合并后的一个请求头。
+    /// 设置了安全令牌时，`x-cos-security-token` 请求头取令牌的值参与签名。
     pub fn sign(
         &self,
         method: &str,
@@ -37,11 +189,68 @@ impl Auth {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> Result<String> {
+        let Some(token) = &self.security_token else {
+            return Ok(self.compute(method, uri, headers, params, start_time, end_time)?.to_string());
+        };
+        let mut headers = headers.clone();
+        headers.retain(|name, _| !name.eq_ignore_ascii_case(SECURITY_TOKEN));
+        headers.insert(SECURITY_TOKEN.to_string(), token.clone());
+        Ok(self.compute(method, uri, &headers, params, start_time, end_time)?.to_string())
+    }
+
+    /// 生成从当前时间起 `valid_for` 内有效的预签名查询参数
+    ///
+    /// 参数含义与 [`sign`](Self::sign) 相同，`headers` 中的请求头参与签名，使用 URL 时必须携带相同的值。
+    /// 设置了安全令牌时，令牌作为 `x-cos-security-token` 查询参数参与签名。
+    pub fn presign(
+        &self,
+        method: &str,
+        uri: &str,
+        headers: &HashMap<String, String>,
+        params: &HashMap<String, String>,
+        valid_for: std::time::Duration,
+    ) -> Result<PresignedQuery> {
+        let valid_for = chrono::Duration::from_std(valid_for)
+            .map_err(|e| CosError::config(format!("Invalid expiration: {}", e)))?;
+        let start_time = Utc::now();
+        self.sign_query(method, uri, headers, params, start_time, start_time + valid_for)
+    }
+
+    /// 计算预签名查询参数，安全令牌作为查询参数参与签名
+    pub(crate) fn sign_query(
+        &self,
+        method: &str,
+        uri: &str,
+        headers: &HashMap<String, String>,
+        params: &HashMap<String, String>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<PresignedQuery> {
+        let Some(token) = &self.security_token else {
+            return self.compute(method, uri, headers, params, start_time, end_time);
+        };
+        let mut params = params.clone();
+        params.insert(SECURITY_TOKEN.to_string(), token.clone());
+        let mut query = self.compute(method, uri, headers, &params, start_time, end_time)?;
+        query.security_token = Some(token.clone());
+        Ok(query)
+    }
+
+    /// 按给定的请求头与查询参数计算签名，[`sign`](Self::sign) 与 [`sign_query`](Self::sign_query) 共用
+    fn compute(
+        &self,
+        method: &str,
+        uri: &str,
+        headers: &HashMap<String, String>,
+        params: &HashMap<String, String>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<PresignedQuery> {
         // 1. 生成 KeyTime
         let key_time = format!("{};{}", start_time.timestamp(), end_time.timestamp());
 
         // 2. 生成 SignKey
-        let sign_key = self.hmac_sha1(&key_time)?;
+        let sign_key = self.sign_key(&key_time)?;
 
         // 3. 生成 HttpString
         let http_string = self.build_http_string(method, uri, headers, params)?;
@@ -52,18 +261,126 @@ impl Auth {
         // 5. 生成 Signature
         let signature = self.hmac_sha1_with_key(&string_to_sign, &sign_key)?;
 
-        // 6. 生成 Authorization
-        let authorization = format!(
-            "q-sign-algorithm=sha1&q-ak={}&q-sign-time={}&q-key-time={}&q-header-list={}&q-url-param-list={}&q-signature={}",
-            self.secret_id,
+        Ok(PresignedQuery {
+            ak: self.secret_id.clone(),
+            sign_time: key_time.clone(),
             key_time,
-            key_time,
-            self.build_header_list(headers),
-            self.build_param_list(params),
-            signature
-        );
+            header_list: self.build_header_list(headers),
+            url_param_list: self.build_param_list(params),
+            signature,
+            security_token: None,
+        })
+    }
 
-        Ok(authorization)
+    /// 校验其他组件使用相同密钥生成的 Authorization
+    ///
+    /// 解析 `authorization` 中的 `q-*` 字段，检查 SecretId 与当前时间是否在签名有效期内，再按 `q-header-list`
+    /// 与 `q-url-param-list` 从 `headers`、`params` 中取出对应的值重新计算签名，并以常量时间比较。
+    /// `uri` 与 `params` 使用未编码的原文，含义与 [`sign`](Self::sign) 相同；未列入签名的请求头与查询参数被忽略，
+    /// 调用方可以根据返回的列表判断需要的字段是否受签名保护。
+    pub fn verify(
+        &self,
+        authorization: &str,
+        method: &str,
+        uri: &str,
+        headers: &HashMap<String, String>,
+        params: &HashMap<String, String>,
+    ) -> Result<VerifiedSignature> {
+        self.verify_at(authorization, method, uri, headers, params, Utc::now())
+    }
+
+    /// 以 `now` 为当前时间校验签名，见 [`verify`](Self::verify)
+    pub(crate) fn verify_at(
+        &self,
+        authorization: &str,
+        method: &str,
+        uri: &str,
+        headers: &HashMap<String, String>,
+        params: &HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedSignature> {
+        let fields: HashMap<&str, &str> = authorization
+            .trim()
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .collect();
+        let field = |name: &str| {
+            fields
+                .get(name)
+                .copied()
+                .ok_or_else(|| CosError::auth(format!("Authorization is missing {}", name)))
+        };
+        let algorithm = field("q-sign-algorithm")?;
+        if algorithm != "sha1" {
+            return Err(CosError::auth(format!("Unsupported signature algorithm {:?}", algorithm)));
+        }
+        let ak = field("q-ak")?;
+        if ak != self.secret_id {
+            return Err(CosError::auth(format!("Signature was made with a different SecretId {:?}", ak)));
+        }
+        let sign_time = field("q-sign-time")?;
+        let key_time = field("q-key-time")?;
+        let (start_time, end_time) = parse_sign_time(sign_time)?;
+        if now < start_time {
+            return Err(CosError::auth(format!("Signature is not valid until {}", start_time)));
+        }
+        if now > end_time {
+            return Err(CosError::auth(format!("Signature expired at {}", end_time)));
+        }
+        let signature = hex::decode(field("q-signature")?)
+            .map_err(|_| CosError::auth("Signature is not a hex string"))?;
+
+        let split = |list: &str| -> Vec<String> {
+            list.split(';').filter(|name| !name.is_empty()).map(str::to_string).collect()
+        };
+        let header_list = split(field("q-header-list")?);
+        let url_param_list = split(field("q-url-param-list")?);
+
+        let normalized = normalize_headers(headers);
+        let mut signed_headers = HashMap::new();
+        for name in &header_list {
+            let value = normalized
+                .get(name)
+                .ok_or_else(|| CosError::auth(format!("Signed header {} is missing from the request", name)))?;
+            signed_headers.insert(name.clone(), value.clone());
+        }
+        let encoded_params: HashMap<String, (&String, &String)> = params
+            .iter()
+            .map(|(k, v)| (urlencoding::encode(k).to_lowercase(), (k, v)))
+            .collect();
+        let mut signed_params = HashMap::new();
+        for name in &url_param_list {
+            let (key, value) = encoded_params
+                .get(name)
+                .ok_or_else(|| CosError::auth(format!("Signed parameter {} is missing from the request", name)))?;
+            signed_params.insert((*key).clone(), (*value).clone());
+        }
+
+        let http_string = self.build_http_string(method, uri, &signed_headers, &signed_params)?;
+        let string_to_sign = format!("sha1\n{}\n{}\n", sign_time, self.sha1(&http_string)?);
+        let sign_key = self.sign_key(key_time)?;
+        let mut mac = HmacSha1::new_from_slice(sign_key.as_bytes())
+            .map_err(|e| CosError::auth(format!("HMAC key error: {}", e)))?;
+        mac.update(string_to_sign.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| CosError::auth("Signature does not match"))?;
+
+        Ok(VerifiedSignature {
+            ak: ak.to_string(),
+            start_time,
+            end_time,
+            header_list,
+            url_param_list,
+        })
+    }
+
+    /// 为 POST Object 表单上传的策略签名
+    ///
+    /// `policy` 为策略的 JSON 原文，返回 `q-signature` 字段的值。
+    pub fn sign_post_policy(&self, policy: &str, key_time: &str) -> Result<String> {
+        let sign_key = self.sign_key(key_time)?;
+        let string_to_sign = self.sha1(policy)?;
+        self.hmac_sha1_with_key(&string_to_sign, &sign_key)
     }
 
     /// 构建 HTTP 字符串
@@ -75,61 +392,72 @@ impl Auth {
         params: &HashMap<String, String>,
     ) -> Result<String> {
         let method = method.to_lowercase();
-        let uri_path = self.encode_uri_path(uri)?;
         let params_string = self.build_params_string(params);
         let headers_string = self.build_headers_string(headers);
 
         Ok(format!(
             "{}\n{}\n{}\n{}\n",
-            method, uri_path, params_string, headers_string
+            method, uri, params_string, headers_string
         ))
     }
 
-    /// 编码 URI 路径
-    fn encode_uri_path(&self, uri: &str) -> Result<String> {
-        let url = Url::parse(&format!("http://example.com{}", uri))
-            .map_err(|e| CosError::other(format!("Invalid URI: {}", e)))?;
-        Ok(url.path().to_string())
-    }
-
     /// 构建参数字符串
+    ///
+    /// 参数名先编码再转为小写，`imageMogr2/thumbnail/200x` 这类包含 `/` 的参数名也能正确签名。
     fn build_params_string(&self, params: &HashMap<String, String>) -> String {
-        let mut sorted_params: Vec<_> = params.iter().collect();
-        sorted_params.sort_by_key(|(k, _)| k.to_lowercase());
+        let mut sorted_params: Vec<_> = params
+            .iter()
+            .map(|(k, v)| (urlencoding::encode(k).to_lowercase(), v))
+            .collect();
+        sorted_params.sort_by(|a, b| a.0.cmp(&b.0));
 
         sorted_params
             .iter()
-            .map(|(k, v)| format!("{}={}", k.to_lowercase(), urlencoding::encode(v)))
+            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
             .collect::<Vec<_>>()
             .join("&")
     }
 
-    /// 构建请求头字符串
+    /// 构建请求头字符串，请求头按 [`normalize_headers`] 规范化
     fn build_headers_string(&self, headers: &HashMap<String, String>) -> String {
-        let mut sorted_headers: Vec<_> = headers.iter().collect();
-        sorted_headers.sort_by_key(|(k, _)| k.to_lowercase());
-
-        sorted_headers
+        normalize_headers(headers)
             .iter()
-            .map(|(k, v)| format!("{}={}", k.to_lowercase(), urlencoding::encode(v)))
+            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
             .collect::<Vec<_>>()
             .join("&")
     }
 
     /// 构建请求头列表
     fn build_header_list(&self, headers: &HashMap<String, String>) -> String {
-        let mut header_keys: Vec<_> = headers.keys().map(|k| k.to_lowercase()).collect();
-        header_keys.sort();
-        header_keys.join(";")
+        normalize_headers(headers).into_keys().collect::<Vec<_>>().join(";")
     }
 
     /// 构建参数列表
     fn build_param_list(&self, params: &HashMap<String, String>) -> String {
-        let mut param_keys: Vec<_> = params.keys().map(|k| k.to_lowercase()).collect();
+        let mut param_keys: Vec<_> = params
+            .keys()
+            .map(|k| urlencoding::encode(k).to_lowercase())
+            .collect();
         param_keys.sort();
         param_keys.join(";")
     }
 
+    /// 由 KeyTime 派生 SignKey，KeyTime 与密钥都与缓存的相同时直接返回缓存的结果
+    fn sign_key(&self, key_time: &str) -> Result<String> {
+        if let Some(cached) = self.sign_key.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            if cached.key_time == key_time && cached.secret_key == self.secret_key {
+                return Ok(cached.sign_key.clone());
+            }
+        }
+        let sign_key = self.hmac_sha1(key_time)?;
+        *self.sign_key.write().unwrap_or_else(|e| e.into_inner()) = Some(CachedSignKey {
+            key_time: key_time.to_string(),
+            secret_key: self.secret_key.clone(),
+            sign_key: sign_key.clone(),
+        });
+        Ok(sign_key)
+    }
+
     /// HMAC-SHA1 签名
     fn hmac_sha1(&self, data: &str) -> Result<String> {
         self.hmac_sha1_with_key(data, &self.secret_key)
@@ -152,10 +480,25 @@ impl Auth {
     }
 }
 
+/// 解析 `<开始时间戳>;<结束时间戳>` 格式的签名时间
+fn parse_sign_time(sign_time: &str) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let invalid = || CosError::auth(format!("Invalid q-sign-time {:?}", sign_time));
+    let (start, end) = sign_time.split_once(';').ok_or_else(invalid)?;
+    let parse = |secs: &str| {
+        secs.parse()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .ok_or_else(invalid)
+    };
+    Ok((parse(start)?, parse(end)?))
+}
+
 /// URL 编码工具
+///
+/// 按 RFC 3986 编码，空格编码为 `%20` 而不是 `+`，与 COS 服务端计算签名的方式一致
 mod urlencoding {
     pub fn encode(input: &str) -> String {
-        url::form_urlencoded::byte_serialize(input.as_bytes()).collect()
+        ::urlencoding::encode(input).into_owned()
     }
 }
 
@@ -191,4 +534,326 @@ mod tests {
         let result = auth.build_params_string(&params);
         assert_eq!(result, "a=value1&b=value2");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_sign_post_policy() {
+        let auth = Auth::new("AKID", "secret");
+        let policy = r#"{"expiration":"2023-11-14T22:23:20.000Z","conditions":[]}"#;
+        assert_eq!(
+            auth.sign_post_policy(policy, "1700000000;1700000600").unwrap(),
+            "fa77c17ae315bd6f2939e23e7fa6c6c53d843ea8"
+        );
+    }
+
+    #[test]
+    fn test_sign_encodes_param_names() {
+        let auth = Auth::new("id", "key");
+        let mut params = HashMap::new();
+        params.insert("imageMogr2/thumbnail/200x".to_string(), "".to_string());
+        params.insert("versionId".to_string(), "v1".to_string());
+
+        assert_eq!(
+            auth.build_params_string(&params),
+            "imagemogr2%2fthumbnail%2f200x=&versionid=v1"
+        );
+        assert_eq!(auth.build_param_list(&params), "imagemogr2%2fthumbnail%2f200x;versionid");
+    }
+
+    #[test]
+    fn test_sign_response_override_params() {
+        let auth = Auth::new("id", "key");
+        let mut params = HashMap::new();
+        params.insert("response-content-disposition".to_string(), "attachment; filename=\"a b.txt\"".to_string());
+        params.insert("response-content-type".to_string(), "text/plain".to_string());
+
+        assert_eq!(
+            auth.build_params_string(&params),
+            "response-content-disposition=attachment%3B%20filename%3D%22a%20b.txt%22&response-content-type=text%2Fplain"
+        );
+        assert_eq!(
+            auth.build_param_list(&params),
+            "response-content-disposition;response-content-type"
+        );
+    }
+
+    /// 官方 SDK 签名算法（cos-python-sdk-v5 的 `CosS3Auth`，与 cos-nodejs-sdk-v5 的 `getAuth` 相同）
+    /// 对相同输入、固定签名时间计算的结果
+    const GET_VECTOR: &str = "q-sign-algorithm=sha1&q-ak=AKIDEXAMPLE&q-sign-time=1700000000;1700003600\
+        &q-key-time=1700000000;1700003600&q-header-list=host&q-url-param-list=response-content-type;versionid\
+        &q-signature=abb7631ee5936e94bbbb97922d258cb6a4de2bed";
+    const PUT_VECTOR: &str = "q-sign-algorithm=sha1&q-ak=AKIDEXAMPLE&q-sign-time=1557989151;1557996351\
+        &q-key-time=1557989151;1557996351&q-header-list=content-md5;content-type;host;x-cos-acl&q-url-param-list=\
+        &q-signature=01b2e741d7aebea189b3dc23a2b99d351157e539";
+    const MULTI_HEADER_VECTOR: &str = "q-sign-algorithm=sha1&q-ak=AKIDEXAMPLE&q-sign-time=1700000000;1700000900\
+        &q-key-time=1700000000;1700000900&q-header-list=content-length;content-md5;content-type;host;range\
+        ;x-cos-meta-author;x-cos-storage-class;x-cos-tagging&q-url-param-list=partnumber;uploadid\
+        &q-signature=30853b72715aaa85d34834a9e202417bb85b90ee";
+
+    /// 签名测试向量的输入与期望的 Authorization
+    struct SignVector {
+        method: &'static str,
+        uri: &'static str,
+        headers: HashMap<String, String>,
+        params: HashMap<String, String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        expected: &'static str,
+    }
+
+    fn sign_vectors() -> Vec<SignVector> {
+        let host = ("Host".to_string(), "examplebucket-1250000000.cos.ap-beijing.myqcloud.com".to_string());
+        let time = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+        vec![
+            SignVector {
+                method: "GET",
+                uri: "/dir/a b.txt",
+                headers: HashMap::from([host.clone()]),
+                params: HashMap::from([
+                    ("response-content-type".to_string(), "text/plain".to_string()),
+                    ("versionId".to_string(), "v1".to_string()),
+                ]),
+                start: time(1700000000),
+                end: time(1700003600),
+                expected: GET_VECTOR,
+            },
+            SignVector {
+                method: "PUT",
+                uri: "/exampleobject(腾讯云)",
+                headers: HashMap::from([
+                    host,
+                    ("Content-Type".to_string(), "text/plain".to_string()),
+                    ("Content-MD5".to_string(), "mQ/fVh815F3k6TAUm8m0eg==".to_string()),
+                    ("x-cos-acl".to_string(), "private".to_string()),
+                ]),
+                params: HashMap::new(),
+                start: time(1557989151),
+                end: time(1557996351),
+                expected: PUT_VECTOR,
+            },
+            SignVector {
+                method: "PUT",
+                uri: "/dir/report 2024.pdf",
+                headers: HashMap::from([
+                    ("Host".to_string(), "examplebucket-1250000000.cos.ap-guangzhou.myqcloud.com".to_string()),
+                    ("Content-Type".to_string(), "application/pdf".to_string()),
+                    ("Content-Length".to_string(), "13".to_string()),
+                    ("Content-MD5".to_string(), "pdA1tI8ov4PvK5hvyM2Mlg==".to_string()),
+                    ("x-cos-meta-author".to_string(), "张三 & co".to_string()),
+                    ("x-cos-storage-class".to_string(), "STANDARD_IA".to_string()),
+                    ("x-cos-tagging".to_string(), "team=storage&env=prod".to_string()),
+                    ("Range".to_string(), "bytes=0-9".to_string()),
+                ]),
+                params: HashMap::from([
+                    ("partNumber".to_string(), "1".to_string()),
+                    (
+                        "uploadId".to_string(),
+                        "1585130821cbb7df1d11846c073ad648e8f33b087cec2381df437acdc833cf654b9ecc6361".to_string(),
+                    ),
+                ]),
+                start: time(1700000000),
+                end: time(1700000900),
+                expected: MULTI_HEADER_VECTOR,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_sign_and_presign_match_official_sdk() {
+        let auth = Auth::new("AKIDEXAMPLE", "secret");
+        for v in sign_vectors() {
+            let authorization = auth.sign(v.method, v.uri, &v.headers, &v.params, v.start, v.end).unwrap();
+            assert_eq!(authorization, v.expected);
+
+            let query = auth.sign_query(v.method, v.uri, &v.headers, &v.params, v.start, v.end).unwrap();
+            assert_eq!(query.to_string(), v.expected);
+            assert_eq!(query.params()[0], ("q-sign-algorithm", "sha1"));
+            assert_eq!(query.params()[6], ("q-signature", &v.expected[v.expected.len() - 40..]));
+        }
+
+        let query = auth
+            .presign("GET", "/a.txt", &HashMap::new(), &HashMap::new(), std::time::Duration::from_secs(600))
+            .unwrap();
+        let (start, end) = query.sign_time.split_once(';').unwrap();
+        assert_eq!(end.parse::<i64>().unwrap() - start.parse::<i64>().unwrap(), 600);
+        assert_eq!(query.key_time, query.sign_time);
+        assert_eq!(query.header_list, "");
+    }
+
+    #[test]
+    fn test_header_names_are_case_insensitive() {
+        use rand::seq::SliceRandom;
+        use rand::Rng;
+
+        let auth = Auth::new("AKIDEXAMPLE", "secret");
+        let v = sign_vectors().pop().unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            // 随机改变名称的大小写与插入顺序
+            let mut entries: Vec<_> = v
+                .headers
+                .iter()
+                .map(|(name, value)| {
+                    let name: String = name
+                        .chars()
+                        .map(|c| if rng.gen() { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() })
+                        .collect();
+                    (name, value.clone())
+                })
+                .collect();
+            entries.shuffle(&mut rng);
+            let headers: HashMap<_, _> = entries.into_iter().collect();
+            let authorization = auth.sign(v.method, v.uri, &headers, &v.params, v.start, v.end).unwrap();
+            assert_eq!(authorization, v.expected, "{:?}", headers);
+        }
+
+        // 只有大小写不同的同名请求头按取值排序后以逗号连接
+        let merged = HashMap::from([
+            ("host".to_string(), "example.com".to_string()),
+            ("x-cos-meta-tag".to_string(), "a,b".to_string()),
+        ]);
+        let expected = auth.sign("PUT", "/a", &merged, &HashMap::new(), v.start, v.end).unwrap();
+        assert!(expected.contains("q-header-list=host;x-cos-meta-tag&"), "{}", expected);
+        for (first, second) in [("b", "a"), ("a", "b")] {
+            let headers = HashMap::from([
+                ("Host".to_string(), "example.com".to_string()),
+                ("X-Cos-Meta-Tag".to_string(), first.to_string()),
+                ("x-cos-meta-tag".to_string(), second.to_string()),
+            ]);
+            assert_eq!(auth.sign("PUT", "/a", &headers, &HashMap::new(), v.start, v.end).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_sign_key_cache() {
+        let cached = Auth::new("AKIDEXAMPLE", "secret");
+        for v in sign_vectors() {
+            for _ in 0..2 {
+                let authorization = cached.sign(v.method, v.uri, &v.headers, &v.params, v.start, v.end).unwrap();
+                assert_eq!(authorization, v.expected);
+            }
+        }
+
+        // 与每次重新派生 SignKey 的结果逐字节相同，包括 KeyTime 交替变化时
+        let v = sign_vectors().remove(1);
+        for i in 0..20 {
+            let end = v.end + chrono::Duration::seconds(i % 3);
+            let uncached = Auth::new("AKIDEXAMPLE", "secret");
+            assert_eq!(
+                cached.sign(v.method, v.uri, &v.headers, &v.params, v.start, end).unwrap(),
+                uncached.sign(v.method, v.uri, &v.headers, &v.params, v.start, end).unwrap()
+            );
+            assert_eq!(
+                cached.sign_post_policy("{}", "1;2").unwrap(),
+                uncached.sign_post_policy("{}", "1;2").unwrap()
+            );
+        }
+
+        // 副本共享缓存，修改密钥后缓存失效
+        let mut copy = cached.clone();
+        assert_eq!(copy.sign(v.method, v.uri, &v.headers, &v.params, v.start, v.end).unwrap(), v.expected);
+        copy.secret_key = "other".to_string();
+        let authorization = copy.sign(v.method, v.uri, &v.headers, &v.params, v.start, v.end).unwrap();
+        assert_ne!(authorization, v.expected);
+        assert_eq!(
+            authorization,
+            Auth::new("AKIDEXAMPLE", "other")
+                .sign(v.method, v.uri, &v.headers, &v.params, v.start, v.end)
+                .unwrap()
+        );
+        assert_eq!(cached.sign(v.method, v.uri, &v.headers, &v.params, v.start, v.end).unwrap(), v.expected);
+        let key_time = format!("{};{}", v.start.timestamp(), v.end.timestamp());
+        assert!(!format!("{:?}", cached).contains(&cached.sign_key(&key_time).unwrap()));
+    }
+
+    #[test]
+    fn test_verify() {
+        let auth = Auth::new("AKIDEXAMPLE", "secret");
+        for v in sign_vectors() {
+            let now = v.start + chrono::Duration::seconds(10);
+            let verified = auth.verify_at(v.expected, v.method, v.uri, &v.headers, &v.params, now).unwrap();
+            assert_eq!(verified.ak, "AKIDEXAMPLE");
+            assert_eq!((verified.start_time, verified.end_time), (v.start, v.end));
+            let mut header_list: Vec<_> = v.headers.keys().map(|name| name.to_lowercase()).collect();
+            header_list.sort();
+            assert_eq!(verified.header_list, header_list);
+            assert_eq!(verified.url_param_list.len(), v.params.len());
+        }
+
+        let v = sign_vectors().remove(1);
+        let now = v.start + chrono::Duration::seconds(10);
+        let verify = |headers: &HashMap<String, String>, now| {
+            auth.verify_at(v.expected, v.method, v.uri, headers, &v.params, now)
+                .map_err(|e| e.to_string())
+        };
+
+        // 未参与签名的请求头被忽略，名称不区分大小写
+        let mut headers: HashMap<_, _> = v.headers.iter().map(|(k, v)| (k.to_uppercase(), v.clone())).collect();
+        headers.insert("User-Agent".to_string(), "curl".to_string());
+        assert!(verify(&headers, now).is_ok());
+
+        headers.insert("CONTENT-TYPE".to_string(), "text/html".to_string());
+        assert!(verify(&headers, now).unwrap_err().contains("does not match"));
+        headers.remove("CONTENT-TYPE");
+        assert!(verify(&headers, now).unwrap_err().contains("content-type is missing"));
+        assert!(verify(&v.headers, v.end + chrono::Duration::seconds(1)).unwrap_err().contains("expired"));
+        assert!(verify(&v.headers, v.start - chrono::Duration::seconds(1)).unwrap_err().contains("not valid until"));
+
+        let err = Auth::new("AKIDOTHER", "secret")
+            .verify_at(v.expected, v.method, v.uri, &v.headers, &v.params, now)
+            .unwrap_err();
+        assert!(err.to_string().contains("different SecretId"), "{}", err);
+        let err = Auth::new("AKIDEXAMPLE", "other")
+            .verify_at(v.expected, v.method, v.uri, &v.headers, &v.params, now)
+            .unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{}", err);
+        let tampered = v.expected.replace("q-sign-algorithm=sha1", "q-sign-algorithm=md5");
+        assert!(auth.verify_at(&tampered, v.method, v.uri, &v.headers, &v.params, now).is_err());
+        assert!(auth.verify_at(v.expected, "GET", v.uri, &v.headers, &v.params, now).is_err());
+
+        // 预签名 URL 中的安全令牌作为查询参数校验
+        let get = sign_vectors().remove(0);
+        let mut params = get.params.clone();
+        params.insert("x-cos-security-token".to_string(), "tmp-token/abc+==".to_string());
+        let verified = auth
+            .verify_at(TOKEN_QUERY_VECTOR, get.method, get.uri, &get.headers, &params, get.start)
+            .unwrap();
+        assert!(verified.url_param_list.contains(&"x-cos-security-token".to_string()));
+
+        // 按当前时间签名与校验
+        let authorization = auth
+            .presign("GET", "/a.txt", &v.headers, &HashMap::new(), std::time::Duration::from_secs(60))
+            .unwrap()
+            .to_string();
+        assert!(auth.verify(&authorization, "GET", "/a.txt", &v.headers, &HashMap::new()).is_ok());
+    }
+
+    /// 官方 SDK 签名算法对携带安全令牌的请求计算的结果：请求头签名时令牌作为请求头，预签名时作为查询参数
+    const TOKEN_HEADER_VECTOR: &str = "q-sign-algorithm=sha1&q-ak=AKIDEXAMPLE&q-sign-time=1557989151;1557996351\
+        &q-key-time=1557989151;1557996351&q-header-list=content-md5;content-type;host;x-cos-acl;x-cos-security-token\
+        &q-url-param-list=&q-signature=5212a6780dc3fd355e654645a819ec73d4b90473";
+    const TOKEN_QUERY_VECTOR: &str = "q-sign-algorithm=sha1&q-ak=AKIDEXAMPLE&q-sign-time=1700000000;1700003600\
+        &q-key-time=1700000000;1700003600&q-header-list=host\
+        &q-url-param-list=response-content-type;versionid;x-cos-security-token\
+        &q-signature=1d428c6ca538bd9caf28c5572868465e2209e119&x-cos-security-token=tmp-token%2Fabc%2B%3D%3D";
+
+    #[test]
+    fn test_sign_with_security_token() {
+        let auth = Auth::new("AKIDEXAMPLE", "secret").with_security_token("tmp-token/abc+==");
+        let vectors = sign_vectors();
+
+        // 请求头中已有的令牌被替换为认证信息中的令牌
+        let put = &vectors[1];
+        let mut headers = put.headers.clone();
+        let authorization = auth.sign(put.method, put.uri, &headers, &put.params, put.start, put.end).unwrap();
+        assert_eq!(authorization, TOKEN_HEADER_VECTOR);
+        headers.insert("X-Cos-Security-Token".to_string(), "stale".to_string());
+        let authorization = auth.sign(put.method, put.uri, &headers, &put.params, put.start, put.end).unwrap();
+        assert_eq!(authorization, TOKEN_HEADER_VECTOR);
+
+        let get = &vectors[0];
+        let query = auth.sign_query(get.method, get.uri, &get.headers, &get.params, get.start, get.end).unwrap();
+        assert_eq!(query.security_token.as_deref(), Some("tmp-token/abc+=="));
+        assert_eq!(query.to_string(), TOKEN_QUERY_VECTOR);
+        assert!(!query.params().iter().any(|(name, _)| *name == SECURITY_TOKEN));
+    }
+}
@@ -2,15 +2,34 @@
 //!
 //! 提供存储桶的创建、删除、列表等管理功能
 
+pub use crate::acl::{AccessControlList, AccessControlPolicy, Grant, Grantee, Owner};
+use crate::acl::AclGrants;
 use crate::client::CosClient;
 use crate::error::{CosError, Result};
-use serde::Deserialize;
+use crate::lifecycle::{LifecycleConfig, LifecycleObject, PlannedAction};
+use crate::multipart::{
+    AbortReport, AbortedUpload, FailedAbort, ListMultipartUploadsOptions, ListMultipartUploadsResponse,
+};
+use crate::policy::BucketPolicy;
+use crate::object::{content_md5, header_str, parse_http_date, request_id, ObjectClient, StorageClass};
+use crate::tagging::{validate_tags, Tag, TagSet, Tagging, MAX_BUCKET_TAGS};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use chrono::{DateTime, Utc};
+use futures::future;
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+/// 自动分页列出对象时每页请求的对象数量，为服务端允许的最大值
+const LIST_PAGE_SIZE: u32 = 1000;
 
 /// 存储桶操作客户端
 #[derive(Debug, Clone)]
 pub struct BucketClient {
-    client: CosClient,
+    pub(crate) client: CosClient,
 }
 
 impl BucketClient {
@@ -21,14 +40,53 @@ impl BucketClient {
 
     /// 创建存储桶
     pub async fn create_bucket(&self, acl: Option<BucketAcl>) -> Result<()> {
+        self.create_bucket_with_options(CreateBucketOptions {
+            acl,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// 按选项创建存储桶
+    ///
+    /// 存储桶已存在时返回 [`CosError::BucketAlreadyExists`]。
+    pub async fn create_bucket_with_options(&self, options: CreateBucketOptions) -> Result<()> {
         let params = HashMap::new();
-        let mut headers = HashMap::new();
+        let mut headers = options.grants.to_headers();
         
-        if let Some(acl) = acl {
+        if let Some(acl) = options.acl {
             headers.insert("x-cos-acl".to_string(), acl.to_string());
         }
+
+        let body = if options.maz {
+            let config = CreateBucketConfiguration {
+                bucket_az_config: "MAZ".to_string(),
+            };
+            Some(quick_xml::se::to_string(&config).map_err(|e| {
+                CosError::other(format!("Failed to serialize create bucket configuration: {}", e))
+            })?)
+        } else {
+            None
+        };
         
-        let _response = self.client.put("/", params, None::<&[u8]>).await?;
+        let response = self
+            .client
+            .request_raw(Method::PUT, "/", params, headers, body)
+            .await?;
+        if response.status() == StatusCode::CONFLICT {
+            let request_id = request_id(response.headers());
+            let body = response.text().await?;
+            let owned_by_you = body.contains("BucketAlreadyOwnedByYou");
+            if owned_by_you || body.contains("BucketAlreadyExists") {
+                return Err(CosError::BucketAlreadyExists {
+                    bucket: self.client.config().bucket.clone(),
+                    owned_by_you,
+                });
+            }
+            return Err(CosError::from_response(StatusCode::CONFLICT, body, request_id));
+        }
+
+        CosClient::error_for_status(response).await?;
         Ok(())
     }
 
@@ -40,28 +98,54 @@ impl BucketClient {
     }
 
     /// 检查存储桶是否存在
+    ///
+    /// 仅在服务端返回 404 时返回 `false`，权限不足、服务端错误和网络故障都会作为错误返回。
     pub async fn bucket_exists(&self) -> Result<bool> {
-        let params = HashMap::new();
-        match self.client.head("/", params).await {
+        match self.head_bucket().await {
             Ok(_) => Ok(true),
-            Err(CosError::Server { .. }) => Ok(false),
+            Err(CosError::NoSuchBucket { .. }) => Ok(false),
             Err(e) => Err(e),
         }
     }
 
+    /// 获取存储桶的元信息
+    ///
+    /// 存储桶不存在时返回 [`CosError::NoSuchBucket`]，没有权限时返回 [`CosError::AccessDenied`]，
+    /// 两者都带有服务端返回的存储桶地域（如有），可用于判断地域配置是否正确。
+    pub async fn head_bucket(&self) -> Result<HeadBucketResponse> {
+        let response = self
+            .client
+            .request_raw(Method::HEAD, "/", HashMap::new(), HashMap::new(), None::<&[u8]>)
+            .await?;
+        let region = header_str(response.headers(), "x-cos-bucket-region").map(|s| s.to_string());
+        let bucket = self.client.config().bucket.clone();
+        match response.status() {
+            StatusCode::NOT_FOUND => return Err(CosError::NoSuchBucket { bucket, region }),
+            StatusCode::FORBIDDEN => {
+                return Err(CosError::AccessDenied {
+                    bucket,
+                    region,
+                    request_id: request_id(response.headers()),
+                })
+            }
+            _ => {}
+        }
+
+        let response = CosClient::error_for_status(response).await?;
+        Ok(HeadBucketResponse {
+            region,
+            request_id: request_id(response.headers()),
+            headers: response.headers().clone(),
+        })
+    }
+
     /// 获取存储桶位置
     pub async fn get_bucket_location(&self) -> Result<String> {
         let mut params = HashMap::new();
         params.insert("location".to_string(), "".to_string());
         
         let response = self.client.get("/", params).await?;
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        let location_response: LocationResponse = quick_xml::de::from_str(&response_text)
-            .map_err(|e| CosError::other(format!("Failed to parse location response: {}", e)))?;
+        let location_response: LocationResponse = CosClient::parse_xml(response).await?;
         
         Ok(location_response.location_constraint)
     }
@@ -72,6 +156,7 @@ impl BucketClient {
         options: Option<ListObjectsOptions>,
     ) -> Result<ListObjectsResponse> {
         let mut params = HashMap::new();
+        let timeout = options.as_ref().and_then(|o| o.timeout);
         
         if let Some(opts) = options {
             if let Some(prefix) = opts.prefix {
@@ -86,16 +171,22 @@ impl BucketClient {
             if let Some(max_keys) = opts.max_keys {
                 params.insert("max-keys".to_string(), max_keys.to_string());
             }
+            if let Some(encoding_type) = opts.encoding_type {
+                params.insert("encoding-type".to_string(), encoding_type);
+            }
         }
+        let url_encoded = params.get("encoding-type").is_some_and(|e| e == "url");
         
-        let response = self.client.get("/", params).await?;
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        let list_response: ListObjectsResponse = quick_xml::de::from_str(&response_text)
-            .map_err(|e| CosError::other(format!("Failed to parse list objects response: {}", e)))?;
+        let response = self.client.with_timeout(timeout).get("/", params).await?;
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+        let mut list_response: ListObjectsResponse = CosClient::parse_xml(response).await?;
+        list_response.request_id = request_id;
+        list_response.headers = headers;
+        list_response.parse_last_modified();
+        if url_encoded {
+            list_response.decode_keys();
+        }
         
         Ok(list_response)
     }
@@ -106,6 +197,7 @@ impl BucketClient {
         options: Option<ListObjectsV2Options>,
     ) -> Result<ListObjectsV2Response> {
         let mut params = HashMap::new();
+        let timeout = options.as_ref().and_then(|o| o.timeout);
         params.insert("list-type".to_string(), "2".to_string());
         
         if let Some(opts) = options {
@@ -124,46 +216,361 @@ impl BucketClient {
             if let Some(start_after) = opts.start_after {
                 params.insert("start-after".to_string(), start_after);
             }
+            if opts.fetch_owner {
+                params.insert("fetch-owner".to_string(), "true".to_string());
+            }
+            if let Some(encoding_type) = opts.encoding_type {
+                params.insert("encoding-type".to_string(), encoding_type);
+            }
         }
+        let url_encoded = params.get("encoding-type").is_some_and(|e| e == "url");
         
-        let response = self.client.get("/", params).await?;
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        let list_response: ListObjectsV2Response = quick_xml::de::from_str(&response_text)
-            .map_err(|e| CosError::other(format!("Failed to parse list objects v2 response: {}", e)))?;
+        let response = self.client.with_timeout(timeout).get("/", params).await?;
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+        let mut list_response: ListObjectsV2Response = CosClient::parse_xml(response).await?;
+        list_response.request_id = request_id;
+        list_response.headers = headers;
+        list_response.parse_last_modified();
+        if url_encoded {
+            list_response.decode_keys();
+        }
         
         Ok(list_response)
     }
 
+    /// 以流的形式列出指定前缀下的全部对象，自动处理分页
+    ///
+    /// 每页最多 1000 个对象，只有当前页的对象被消费完后才会请求下一页，
+    /// 适用于对象数量很大的存储桶。分页过程中的错误会作为流的元素返回，之后流结束。
+    /// 请求使用 `encoding-type=url`，返回的对象键已经解码。
+    pub fn list_all_objects(&self, prefix: Option<&str>) -> impl Stream<Item = Result<ObjectInfo>> + '_ {
+        let prefix = prefix.map(|p| p.to_string());
+
+        // 状态：(下一页的 continuation token, 是否已经取完)
+        stream::try_unfold((None::<String>, false), move |(token, done)| {
+            let prefix = prefix.clone();
+            async move {
+                if done {
+                    return Ok::<_, CosError>(None);
+                }
+
+                let page = self
+                    .list_objects_v2(Some(ListObjectsV2Options {
+                        prefix,
+                        continuation_token: token,
+                        max_keys: Some(LIST_PAGE_SIZE),
+                        encoding_type: Some("url".to_string()),
+                        ..Default::default()
+                    }))
+                    .await?;
+
+                let next_token = page.next_continuation_token;
+                let done = !page.is_truncated || next_token.is_empty();
+                Ok(Some((
+                    stream::iter(page.contents.into_iter().map(Ok)),
+                    (Some(next_token), done),
+                )))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// 列出指定前缀下的全部对象并收集到 `Vec` 中
+    ///
+    /// `max_items` 为安全上限，对象数量超过上限时返回错误而不是继续占用内存。
+    pub async fn collect_all(&self, prefix: Option<&str>, max_items: Option<usize>) -> Result<Vec<ObjectInfo>> {
+        let stream = self.list_all_objects(prefix);
+        futures::pin_mut!(stream);
+
+        let mut objects = Vec::new();
+        while let Some(object) = stream.try_next().await? {
+            if max_items.is_some_and(|max| objects.len() >= max) {
+                return Err(CosError::client(
+                    "TooManyObjects".to_string(),
+                    format!("Listing exceeded the limit of {} objects", objects.len()),
+                ));
+            }
+            objects.push(object);
+        }
+        Ok(objects)
+    }
+
+    /// 列出存储桶中对象的所有版本及删除标记
+    pub async fn list_object_versions(
+        &self,
+        options: Option<ListObjectVersionsOptions>,
+    ) -> Result<ListObjectVersionsResponse> {
+        let mut params = HashMap::new();
+        let timeout = options.as_ref().and_then(|o| o.timeout);
+        params.insert("versions".to_string(), "".to_string());
+
+        if let Some(opts) = options {
+            if let Some(prefix) = opts.prefix {
+                params.insert("prefix".to_string(), prefix);
+            }
+            if let Some(delimiter) = opts.delimiter {
+                params.insert("delimiter".to_string(), delimiter);
+            }
+            if let Some(key_marker) = opts.key_marker {
+                params.insert("key-marker".to_string(), key_marker);
+            }
+            if let Some(version_id_marker) = opts.version_id_marker {
+                params.insert("version-id-marker".to_string(), version_id_marker);
+            }
+            if let Some(max_keys) = opts.max_keys {
+                params.insert("max-keys".to_string(), max_keys.to_string());
+            }
+        }
+
+        let response = self.client.with_timeout(timeout).get("/", params).await?;
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+        let mut list_response: ListObjectVersionsResponse = CosClient::parse_xml(response).await?;
+        list_response.request_id = request_id;
+        list_response.headers = headers;
+        list_response.parse_last_modified();
+
+        Ok(list_response)
+    }
+
+    /// 列出存储桶中进行中的分块上传
+    pub async fn list_multipart_uploads(
+        &self,
+        options: Option<ListMultipartUploadsOptions>,
+    ) -> Result<ListMultipartUploadsResponse> {
+        let mut params = HashMap::new();
+        params.insert("uploads".to_string(), "".to_string());
+
+        if let Some(opts) = options {
+            if let Some(prefix) = opts.prefix {
+                params.insert("prefix".to_string(), prefix);
+            }
+            if let Some(delimiter) = opts.delimiter {
+                params.insert("delimiter".to_string(), delimiter);
+            }
+            if let Some(key_marker) = opts.key_marker {
+                params.insert("key-marker".to_string(), key_marker);
+            }
+            if let Some(upload_id_marker) = opts.upload_id_marker {
+                params.insert("upload-id-marker".to_string(), upload_id_marker);
+            }
+            if let Some(max_uploads) = opts.max_uploads {
+                params.insert("max-uploads".to_string(), max_uploads.to_string());
+            }
+        }
+
+        let response = self.client.get("/", params).await?;
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+        let mut list_response: ListMultipartUploadsResponse = CosClient::parse_xml(response).await?;
+        list_response.request_id = request_id;
+        list_response.headers = headers;
+
+        Ok(list_response)
+    }
+
+    /// 终止初始化时间早于 `age` 之前的所有分块上传
+    ///
+    /// 会分页遍历全部进行中的分块上传，单个终止失败不会中断清理，失败信息记录在报告中。
+    /// 初始化时间无法解析的分块上传不会被终止，同样作为失败记录在报告中。
+    pub async fn abort_multipart_uploads_older_than(
+        &self,
+        age: chrono::Duration,
+        prefix: Option<&str>,
+    ) -> Result<AbortReport> {
+        let cutoff = Utc::now() - age;
+        let object_client = ObjectClient::new(self.client.clone());
+        let mut report = AbortReport::default();
+        let mut key_marker = None;
+        let mut upload_id_marker = None;
+
+        loop {
+            let page = self
+                .list_multipart_uploads(Some(ListMultipartUploadsOptions {
+                    prefix: prefix.map(|p| p.to_string()),
+                    key_marker: key_marker.take(),
+                    upload_id_marker: upload_id_marker.take(),
+                    ..Default::default()
+                }))
+                .await?;
+
+            for upload in page.uploads {
+                match initiated_before(&upload.initiated, cutoff) {
+                    Some(true) => {}
+                    Some(false) => continue,
+                    None => {
+                        log::warn!(
+                            "Skipping upload {} of '{}' with unparsable Initiated '{}'",
+                            upload.upload_id, upload.key, upload.initiated
+                        );
+                        report.failed.push(FailedAbort {
+                            error: CosError::other(format!("Unparsable Initiated time '{}'", upload.initiated)),
+                            key: upload.key,
+                            upload_id: upload.upload_id,
+                        });
+                        continue;
+                    }
+                }
+
+                match object_client.abort_multipart_upload(&upload.key, &upload.upload_id).await {
+                    Ok(()) => report.aborted.push(AbortedUpload {
+                        key: upload.key,
+                        upload_id: upload.upload_id,
+                    }),
+                    Err(error) => report.failed.push(FailedAbort {
+                        key: upload.key,
+                        upload_id: upload.upload_id,
+                        error,
+                    }),
+                }
+            }
+
+            if !page.is_truncated || page.next_key_marker.is_empty() {
+                break;
+            }
+            key_marker = Some(page.next_key_marker);
+            upload_id_marker = Some(page.next_upload_id_marker);
+        }
+
+        Ok(report)
+    }
+
     /// 获取存储桶ACL
-    pub async fn get_bucket_acl(&self) -> Result<BucketAclResponse> {
+    pub async fn get_bucket_acl(&self) -> Result<AccessControlPolicy> {
         let mut params = HashMap::new();
         params.insert("acl".to_string(), "".to_string());
         
         let response = self.client.get("/", params).await?;
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        let acl_response: BucketAclResponse = quick_xml::de::from_str(&response_text)
-            .map_err(|e| CosError::other(format!("Failed to parse ACL response: {}", e)))?;
+        let acl_response: AccessControlPolicy = CosClient::parse_xml(response).await?;
         
         Ok(acl_response)
     }
 
     /// 设置存储桶ACL
     pub async fn put_bucket_acl(&self, acl: BucketAcl) -> Result<()> {
+        self.put_bucket_acl_with_grants(Some(acl), AclGrants::default()).await
+    }
+
+    /// 设置存储桶ACL，并通过 `x-cos-grant-*` 请求头向指定账号授权
+    pub async fn put_bucket_acl_with_grants(&self, acl: Option<BucketAcl>, grants: AclGrants) -> Result<()> {
         let mut params = HashMap::new();
         params.insert("acl".to_string(), "".to_string());
         
-        let mut headers = HashMap::new();
-        headers.insert("x-cos-acl".to_string(), acl.to_string());
+        let mut headers = grants.to_headers();
+        if let Some(acl) = acl {
+            headers.insert("x-cos-acl".to_string(), acl.to_string());
+        }
         
-        let _response = self.client.put("/", params, None::<&[u8]>).await?;
+        let _response = self.client.put_with_headers("/", params, headers, None::<&[u8]>).await?;
+        Ok(())
+    }
+
+    /// 以完整的访问控制策略设置存储桶ACL，会覆盖已有的全部授权
+    pub async fn put_bucket_acl_with_policy(&self, policy: &AccessControlPolicy) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("acl".to_string(), "".to_string());
+
+        let xml_body = policy.to_xml()?;
+        let mut headers = HashMap::new();
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
+
+        let _response = self.client.put_with_headers("/", params, headers, Some(xml_body)).await?;
+        Ok(())
+    }
+
+    /// 设置存储桶策略，会覆盖已有的策略
+    pub async fn put_bucket_policy(&self, policy: &BucketPolicy) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("policy".to_string(), "".to_string());
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+
+        let body = serde_json::to_vec(policy)?;
+        self.client.put_with_headers("/", params, headers, Some(body)).await?;
+        Ok(())
+    }
+
+    /// 获取存储桶策略
+    ///
+    /// 存储桶未设置策略时返回 [`CosError::NoSuchPolicy`]。
+    pub async fn get_bucket_policy(&self) -> Result<BucketPolicy> {
+        let mut params = HashMap::new();
+        params.insert("policy".to_string(), "".to_string());
+
+        let response = self
+            .client
+            .request_raw(Method::GET, "/", params, HashMap::new(), None::<&[u8]>)
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            let request_id = request_id(response.headers());
+            let body = response.text().await?;
+            if body.contains("NoSuchPolicy") {
+                return Err(CosError::NoSuchPolicy {
+                    bucket: self.client.config().bucket.clone(),
+                });
+            }
+            return Err(CosError::from_response(StatusCode::NOT_FOUND, body, request_id));
+        }
+
+        let response = CosClient::error_for_status(response).await?;
+        let response_text = response.text().await?;
+        let policy: BucketPolicy = serde_json::from_str(&response_text)?;
+        Ok(policy)
+    }
+
+    /// 删除存储桶策略
+    pub async fn delete_bucket_policy(&self) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("policy".to_string(), "".to_string());
+
+        self.client.delete("/", params).await?;
+        Ok(())
+    }
+
+    /// 设置存储桶标签，会覆盖存储桶已有的全部标签
+    pub async fn put_bucket_tagging(&self, tags: &[(String, String)]) -> Result<()> {
+        let tags: Vec<Tag> = tags.iter().map(|(k, v)| Tag::new(k.as_str(), v.as_str())).collect();
+        validate_tags(&tags, MAX_BUCKET_TAGS)?;
+
+        let tagging = Tagging {
+            tag_set: TagSet { tags },
+        };
+        let xml_body = quick_xml::se::to_string(&tagging)
+            .map_err(|e| CosError::other(format!("Failed to serialize tagging: {}", e)))?;
+        let mut headers = HashMap::new();
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
+
+        self.client.put_with_headers("/", tagging_params(), headers, Some(xml_body)).await?;
+        Ok(())
+    }
+
+    /// 获取存储桶标签
+    ///
+    /// 存储桶未设置标签时服务端返回 404 NoSuchTagSet，此时返回空列表。
+    pub async fn get_bucket_tagging(&self) -> Result<Vec<Tag>> {
+        let response = self
+            .client
+            .request_raw(Method::GET, "/", tagging_params(), HashMap::new(), None::<&[u8]>)
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            let request_id = request_id(response.headers());
+            let body = response.text().await?;
+            if body.contains("NoSuchTagSet") {
+                return Ok(Vec::new());
+            }
+            return Err(CosError::from_response(StatusCode::NOT_FOUND, body, request_id));
+        }
+
+        let response = CosClient::error_for_status(response).await?;
+        let tagging: Tagging = CosClient::parse_xml(response).await?;
+
+        Ok(tagging.tag_set.tags)
+    }
+
+    /// 删除存储桶标签
+    pub async fn delete_bucket_tagging(&self) -> Result<()> {
+        self.client.delete("/", tagging_params()).await?;
         Ok(())
     }
 
@@ -173,18 +580,79 @@ impl BucketClient {
         params.insert("versioning".to_string(), "".to_string());
         
         let response = self.client.get("/", params).await?;
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        let versioning_response: VersioningResponse = quick_xml::de::from_str(&response_text)
-            .map_err(|e| CosError::other(format!("Failed to parse versioning response: {}", e)))?;
+        let versioning_response: VersioningResponse = CosClient::parse_xml(response).await?;
         
         Ok(versioning_response)
     }
+
+    /// 开启或暂停存储桶全球加速
+    ///
+    /// 开启后可通过 [`Config::with_accelerate`](crate::Config::with_accelerate) 使用加速域名访问。
+    pub async fn put_bucket_accelerate(&self, enabled: bool) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("accelerate".to_string(), "".to_string());
+        let config = AccelerateConfiguration {
+            status: if enabled { "Enabled" } else { "Suspended" }.to_string(),
+            accelerate_type: Some("COS".to_string()),
+        };
+        let xml_body = quick_xml::se::to_string(&config)
+            .map_err(|e| CosError::other(format!("Failed to serialize accelerate configuration: {}", e)))?;
+
+        self.client.put("/", params, Some(xml_body)).await?;
+        Ok(())
+    }
+
+    /// 获取存储桶全球加速是否开启，从未配置时返回 `false`
+    pub async fn get_bucket_accelerate(&self) -> Result<bool> {
+        let mut params = HashMap::new();
+        params.insert("accelerate".to_string(), "".to_string());
+
+        let response = self.client.get("/", params).await?;
+        let config: AccelerateConfiguration = CosClient::parse_xml(response).await?;
+        Ok(config.status == "Enabled")
+    }
+
+    /// 获取存储桶生命周期配置
+    pub async fn get_bucket_lifecycle(&self) -> Result<LifecycleConfig> {
+        let mut params = HashMap::new();
+        params.insert("lifecycle".to_string(), "".to_string());
+
+        let response = self.client.get("/", params).await?;
+        let lifecycle: LifecycleConfig = CosClient::parse_xml(response).await?;
+
+        Ok(lifecycle)
+    }
+
+    /// 使用存储桶当前的生命周期配置，模拟指定前缀下对象在 `as_of` 时刻的动作
+    pub async fn simulate_lifecycle(
+        &self,
+        prefix: Option<&str>,
+        as_of: DateTime<Utc>,
+    ) -> Result<impl Stream<Item = Result<PlannedAction>> + '_> {
+        let config = self.get_bucket_lifecycle().await?;
+        Ok(self.simulate_lifecycle_with(config, prefix, as_of))
+    }
+
+    /// 使用给定的生命周期配置，模拟指定前缀下对象在 `as_of` 时刻的动作
+    ///
+    /// 对象列表通过 [`list_all_objects`](Self::list_all_objects) 按页拉取，结果以流的形式逐个返回，
+    /// 适用于对象数量很大的前缀。
+    pub fn simulate_lifecycle_with(
+        &self,
+        config: LifecycleConfig,
+        prefix: Option<&str>,
+        as_of: DateTime<Utc>,
+    ) -> impl Stream<Item = Result<PlannedAction>> + '_ {
+        self.list_all_objects(prefix).try_filter_map(move |object| {
+            let action = LifecycleObject::try_from(&object).map(|object| config.plan(&object, as_of));
+            future::ready(action)
+        })
+    }
 }
 
+/// 存储桶ACL响应
+pub type BucketAclResponse = AccessControlPolicy;
+
 /// 存储桶ACL类型
 #[derive(Debug, Clone, Copy)]
 pub enum BucketAcl {
@@ -194,17 +662,37 @@ pub enum BucketAcl {
     AuthenticatedRead,
 }
 
-impl ToString for BucketAcl {
-    fn to_string(&self) -> String {
-        match self {
-            BucketAcl::Private => "private".to_string(),
-            BucketAcl::PublicRead => "public-read".to_string(),
-            BucketAcl::PublicReadWrite => "public-read-write".to_string(),
-            BucketAcl::AuthenticatedRead => "authenticated-read".to_string(),
-        }
+impl fmt::Display for BucketAcl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            BucketAcl::Private => "private",
+            BucketAcl::PublicRead => "public-read",
+            BucketAcl::PublicReadWrite => "public-read-write",
+            BucketAcl::AuthenticatedRead => "authenticated-read",
+        };
+        f.write_str(s)
     }
 }
 
+/// 创建存储桶选项
+#[derive(Debug, Clone, Default)]
+pub struct CreateBucketOptions {
+    /// 预设 ACL
+    pub acl: Option<BucketAcl>,
+    /// 通过 `x-cos-grant-*` 请求头向指定账号授权
+    pub grants: AclGrants,
+    /// 创建多 AZ 存储桶，仅部分地域支持，创建后不能修改
+    pub maz: bool,
+}
+
+/// 创建存储桶的请求体
+#[derive(Debug, Serialize)]
+#[serde(rename = "CreateBucketConfiguration")]
+struct CreateBucketConfiguration {
+    #[serde(rename = "BucketAZConfig")]
+    bucket_az_config: String,
+}
+
 /// 列出对象选项
 #[derive(Debug, Clone, Default)]
 pub struct ListObjectsOptions {
@@ -212,6 +700,11 @@ pub struct ListObjectsOptions {
     pub delimiter: Option<String>,
     pub marker: Option<String>,
     pub max_keys: Option<u32>,
+    /// 设为 `url` 时服务端对返回的对象键等字段做 URL 编码，SDK 会自动解码，
+    /// 用于包含控制字符等无法直接放入 XML 的对象键
+    pub encoding_type: Option<String>,
+    /// 本次请求的超时，未指定时使用 [`Config::timeout`](crate::config::Config::timeout)
+    pub timeout: Option<Duration>,
 }
 
 /// 列出对象V2选项
@@ -222,6 +715,35 @@ pub struct ListObjectsV2Options {
     pub continuation_token: Option<String>,
     pub max_keys: Option<u32>,
     pub start_after: Option<String>,
+    /// 是否在结果中返回对象的所有者信息
+    pub fetch_owner: bool,
+    /// 设为 `url` 时服务端对返回的对象键等字段做 URL 编码，SDK 会自动解码
+    pub encoding_type: Option<String>,
+    /// 本次请求的超时，未指定时使用 [`Config::timeout`](crate::config::Config::timeout)
+    pub timeout: Option<Duration>,
+}
+
+/// 列出对象版本选项
+#[derive(Debug, Clone, Default)]
+pub struct ListObjectVersionsOptions {
+    pub prefix: Option<String>,
+    pub delimiter: Option<String>,
+    pub key_marker: Option<String>,
+    pub version_id_marker: Option<String>,
+    pub max_keys: Option<u32>,
+    /// 本次请求的超时，未指定时使用 [`Config::timeout`](crate::config::Config::timeout)
+    pub timeout: Option<Duration>,
+}
+
+/// 获取存储桶元信息响应
+#[derive(Debug, Clone)]
+pub struct HeadBucketResponse {
+    /// 存储桶所在地域（`x-cos-bucket-region`）
+    pub region: Option<String>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    pub headers: HeaderMap,
 }
 
 /// 存储桶位置响应
@@ -242,6 +764,9 @@ pub struct ListObjectsResponse {
     pub prefix: String,
     #[serde(rename = "Marker", default)]
     pub marker: String,
+    /// 指定了 delimiter 且结果被截断时，下一页的起始位置
+    #[serde(rename = "NextMarker", default)]
+    pub next_marker: String,
     #[serde(rename = "MaxKeys")]
     pub max_keys: u32,
     #[serde(rename = "IsTruncated")]
@@ -250,6 +775,12 @@ pub struct ListObjectsResponse {
     pub contents: Vec<ObjectInfo>,
     #[serde(rename = "CommonPrefixes", default)]
     pub common_prefixes: Vec<CommonPrefix>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    #[serde(skip)]
+    pub headers: HeaderMap,
 }
 
 /// 列出对象V2响应
@@ -274,76 +805,171 @@ pub struct ListObjectsV2Response {
     pub contents: Vec<ObjectInfo>,
     #[serde(rename = "CommonPrefixes", default)]
     pub common_prefixes: Vec<CommonPrefix>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    #[serde(skip)]
+    pub headers: HeaderMap,
 }
 
-/// 对象信息
-#[derive(Debug, Deserialize)]
-pub struct ObjectInfo {
+/// 列出对象版本响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "ListVersionsResult")]
+pub struct ListObjectVersionsResponse {
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+    #[serde(rename = "KeyMarker", default)]
+    pub key_marker: String,
+    #[serde(rename = "VersionIdMarker", default)]
+    pub version_id_marker: String,
+    #[serde(rename = "NextKeyMarker", default)]
+    pub next_key_marker: String,
+    #[serde(rename = "NextVersionIdMarker", default)]
+    pub next_version_id_marker: String,
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "Version", default)]
+    pub versions: Vec<ObjectVersion>,
+    #[serde(rename = "DeleteMarker", default)]
+    pub delete_markers: Vec<DeleteMarkerEntry>,
+    #[serde(rename = "CommonPrefixes", default)]
+    pub common_prefixes: Vec<CommonPrefix>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    #[serde(skip)]
+    pub headers: HeaderMap,
+}
+
+/// 对象版本
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectVersion {
     #[serde(rename = "Key")]
     pub key: String,
-    #[serde(rename = "LastModified")]
-    pub last_modified: String,
-    #[serde(rename = "ETag")]
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+    #[serde(rename = "IsLatest", default)]
+    pub is_latest: bool,
+    /// 最后修改时间，由 `last_modified_raw` 解析得到
+    #[serde(skip)]
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 LastModified 字符串
+    #[serde(rename = "LastModified", default)]
+    pub last_modified_raw: String,
+    #[serde(rename = "ETag", default)]
     pub etag: String,
-    #[serde(rename = "Size")]
+    #[serde(rename = "Size", default)]
     pub size: u64,
     #[serde(rename = "StorageClass", default)]
-    pub storage_class: String,
+    pub storage_class: StorageClass,
 }
 
-/// 公共前缀
-#[derive(Debug, Deserialize)]
-pub struct CommonPrefix {
-    #[serde(rename = "Prefix")]
-    pub prefix: String,
+/// 删除标记
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteMarkerEntry {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+    #[serde(rename = "IsLatest", default)]
+    pub is_latest: bool,
+    /// 最后修改时间，由 `last_modified_raw` 解析得到
+    #[serde(skip)]
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 LastModified 字符串
+    #[serde(rename = "LastModified", default)]
+    pub last_modified_raw: String,
 }
 
-/// 存储桶ACL响应
-#[derive(Debug, Deserialize)]
-#[serde(rename = "AccessControlPolicy")]
-pub struct BucketAclResponse {
-    #[serde(rename = "Owner")]
-    pub owner: Owner,
-    #[serde(rename = "AccessControlList")]
-    pub access_control_list: AccessControlList,
+impl ListObjectsResponse {
+    fn parse_last_modified(&mut self) {
+        for object in &mut self.contents {
+            object.last_modified = parse_http_date(&object.last_modified_raw);
+        }
+    }
+
+    /// 解码 `encoding-type=url` 时服务端编码过的字段
+    fn decode_keys(&mut self) {
+        url_decode_in_place(&mut self.prefix);
+        url_decode_in_place(&mut self.marker);
+        url_decode_in_place(&mut self.next_marker);
+        for object in &mut self.contents {
+            url_decode_in_place(&mut object.key);
+        }
+        for prefix in &mut self.common_prefixes {
+            url_decode_in_place(&mut prefix.prefix);
+        }
+    }
 }
 
-/// 所有者信息
-#[derive(Debug, Deserialize)]
-pub struct Owner {
-    #[serde(rename = "ID")]
-    pub id: String,
-    #[serde(rename = "DisplayName", default)]
-    pub display_name: String,
+impl ListObjectsV2Response {
+    fn parse_last_modified(&mut self) {
+        for object in &mut self.contents {
+            object.last_modified = parse_http_date(&object.last_modified_raw);
+        }
+    }
+
+    /// 解码 `encoding-type=url` 时服务端编码过的字段
+    fn decode_keys(&mut self) {
+        url_decode_in_place(&mut self.prefix);
+        for object in &mut self.contents {
+            url_decode_in_place(&mut object.key);
+        }
+        for prefix in &mut self.common_prefixes {
+            url_decode_in_place(&mut prefix.prefix);
+        }
+    }
 }
 
-/// 访问控制列表
-#[derive(Debug, Deserialize)]
-pub struct AccessControlList {
-    #[serde(rename = "Grant", default)]
-    pub grants: Vec<Grant>,
+/// 原地解码 URL 编码的字符串，解码失败时保留原值
+fn url_decode_in_place(value: &mut String) {
+    if let Ok(decoded) = urlencoding::decode(value) {
+        *value = decoded.into_owned();
+    }
 }
 
-/// 授权信息
-#[derive(Debug, Deserialize)]
-pub struct Grant {
-    #[serde(rename = "Grantee")]
-    pub grantee: Grantee,
-    #[serde(rename = "Permission")]
-    pub permission: String,
+impl ListObjectVersionsResponse {
+    fn parse_last_modified(&mut self) {
+        for version in &mut self.versions {
+            version.last_modified = parse_http_date(&version.last_modified_raw);
+        }
+        for marker in &mut self.delete_markers {
+            marker.last_modified = parse_http_date(&marker.last_modified_raw);
+        }
+    }
 }
 
-/// 被授权者
+/// 对象信息
 #[derive(Debug, Deserialize)]
-pub struct Grantee {
-    #[serde(rename = "@type")]
-    pub grantee_type: String,
-    #[serde(rename = "ID", default)]
-    pub id: String,
-    #[serde(rename = "DisplayName", default)]
-    pub display_name: String,
-    #[serde(rename = "URI", default)]
-    pub uri: String,
+pub struct ObjectInfo {
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// 最后修改时间，由 `last_modified_raw` 解析得到
+    #[serde(skip)]
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 LastModified 字符串
+    #[serde(rename = "LastModified")]
+    pub last_modified_raw: String,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "Size")]
+    pub size: u64,
+    #[serde(rename = "StorageClass", default)]
+    pub storage_class: StorageClass,
+    /// 对象所有者，V2 列表只在指定 `fetch_owner` 时返回
+    #[serde(rename = "Owner", default)]
+    pub owner: Option<Owner>,
+}
+
+/// 公共前缀
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommonPrefix {
+    #[serde(rename = "Prefix")]
+    pub prefix: String,
 }
 
 /// 版本控制响应
@@ -354,22 +980,458 @@ pub struct VersioningResponse {
     pub status: String,
 }
 
+/// 全球加速配置
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "AccelerateConfiguration")]
+struct AccelerateConfiguration {
+    #[serde(rename = "Status", default)]
+    status: String,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    accelerate_type: Option<String>,
+}
+
+/// 判断 ISO 8601 格式的初始化时间是否早于截止时间，无法解析时返回 None
+fn initiated_before(initiated: &str, cutoff: DateTime<Utc>) -> Option<bool> {
+    DateTime::parse_from_rfc3339(initiated)
+        .ok()
+        .map(|t| t.with_timezone(&Utc) < cutoff)
+}
+
+/// 存储桶标签子资源的查询参数
+fn tagging_params() -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("tagging".to_string(), "".to_string());
+    params
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::Config;
+    use chrono::TimeZone;
     use std::time::Duration;
 
     #[tokio::test]
     async fn test_bucket_operations() {
+        use crate::testing::{MockResponse, MockTransport};
+
         let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
             .with_timeout(Duration::from_secs(60));
-        
-        let cos_client = CosClient::new(config).unwrap();
-        let bucket_client = BucketClient::new(cos_client);
-        
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(200, "").with_header("x-cos-bucket-region", "ap-beijing"))
+            .push_response(MockResponse::error(404, "NoSuchBucket", "The specified bucket does not exist."));
+        let bucket_client = BucketClient::new(transport.client(config).unwrap());
+
         // 测试存储桶存在性检查
-        let exists = bucket_client.bucket_exists().await;
-        // 在实际测试中，这里会根据具体情况返回结果
+        assert!(bucket_client.bucket_exists().await.unwrap());
+        assert!(!bucket_client.bucket_exists().await.unwrap());
+
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.method, reqwest::Method::HEAD);
+        assert_eq!(request.url.as_str(), "https://test-bucket-123.cos.ap-beijing.myqcloud.com/");
+        assert!(request.header("authorization").unwrap().starts_with("q-sign-algorithm=sha1"));
+    }
+
+    #[test]
+    fn test_initiated_before() {
+        let cutoff = DateTime::parse_from_rfc3339("2020-03-25T00:00:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(initiated_before("2020-03-24T10:07:12.000Z", cutoff), Some(true));
+        assert_eq!(initiated_before("2020-03-25T10:07:12.000Z", cutoff), Some(false));
+        assert_eq!(initiated_before("not a date", cutoff), None);
+    }
+
+    #[tokio::test]
+    async fn test_abort_multipart_uploads_older_than() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let upload = |key: &str, id: &str, initiated: &str| {
+            format!(
+                "<Upload><Key>{}</Key><UploadId>{}</UploadId><Initiated>{}</Initiated></Upload>",
+                key, id, initiated
+            )
+        };
+        let old = "2020-03-24T10:07:12.000Z";
+        let recent = Utc::now().to_rfc3339();
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(
+                200,
+                format!(
+                    "<ListMultipartUploadsResult><IsTruncated>true</IsTruncated>\
+                     <NextKeyMarker>b.txt</NextKeyMarker><NextUploadIdMarker>u2</NextUploadIdMarker>{}{}</ListMultipartUploadsResult>",
+                    upload("a.txt", "u1", old),
+                    upload("b.txt", "u2", &recent)
+                ),
+            ))
+            .push_response(MockResponse::new(204, ""))
+            .push_response(MockResponse::new(
+                200,
+                format!(
+                    "<ListMultipartUploadsResult><IsTruncated>false</IsTruncated>{}{}</ListMultipartUploadsResult>",
+                    upload("c.txt", "u3", old),
+                    upload("d.txt", "u4", "yesterday")
+                ),
+            ))
+            .push_response(MockResponse::error(403, "AccessDenied", "Access Denied."));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let client = BucketClient::new(transport.client(config).unwrap());
+
+        let report = client
+            .abort_multipart_uploads_older_than(chrono::Duration::days(7), None)
+            .await
+            .unwrap();
+        assert_eq!(report.aborted.len(), 1);
+        assert_eq!((report.aborted[0].key.as_str(), report.aborted[0].upload_id.as_str()), ("a.txt", "u1"));
+        assert_eq!(report.failed.len(), 2);
+        assert_eq!(report.failed[0].upload_id, "u3");
+        assert_eq!(report.failed[0].error.code(), Some("AccessDenied"));
+        // 初始化时间无法解析的分块上传记为失败，而不是静默跳过
+        assert_eq!(report.failed[1].upload_id, "u4");
+        assert!(report.failed[1].error.to_string().contains("yesterday"));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[2].query_param("key-marker").as_deref(), Some("b.txt"));
+        assert_eq!(requests[2].query_param("upload-id-marker").as_deref(), Some("u2"));
+        assert_eq!(requests[3].query_param("uploadId").as_deref(), Some("u3"));
+    }
+
+    #[test]
+    fn test_parse_list_object_versions() {
+        let xml = "<ListVersionsResult><Name>test-bucket-123</Name><Prefix>tmp/</Prefix>\
+            <IsTruncated>true</IsTruncated><NextKeyMarker>tmp/b.txt</NextKeyMarker>\
+            <NextVersionIdMarker>v3</NextVersionIdMarker>\
+            <Version><Key>tmp/a.txt</Key><VersionId>v1</VersionId><IsLatest>true</IsLatest>\
+            <LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>\"abc\"</ETag><Size>3</Size>\
+            <StorageClass>STANDARD</StorageClass></Version>\
+            <DeleteMarker><Key>tmp/b.txt</Key><VersionId>v2</VersionId><IsLatest>true</IsLatest>\
+            <LastModified>2024-01-02T00:00:00.000Z</LastModified></DeleteMarker>\
+            <Version><Key>tmp/b.txt</Key><VersionId>v3</VersionId><IsLatest>false</IsLatest>\
+            <LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>\"abc\"</ETag><Size>3</Size>\
+            <StorageClass>STANDARD</StorageClass></Version>\
+            </ListVersionsResult>";
+        let mut response: ListObjectVersionsResponse = quick_xml::de::from_str(xml).unwrap();
+        response.parse_last_modified();
+        assert!(response.is_truncated);
+        assert_eq!(response.versions.len(), 2);
+        assert_eq!(response.versions[1].version_id, "v3");
+        assert_eq!(response.delete_markers.len(), 1);
+        assert_eq!(response.delete_markers[0].key, "tmp/b.txt");
+        assert_eq!(
+            response.delete_markers[0].last_modified,
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap())
+        );
+        assert_eq!(response.versions[0].last_modified_raw, "2024-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn test_parse_object_storage_class() {
+        let xml = "<ListBucketResult><Name>test-bucket-123</Name><Prefix></Prefix><Marker></Marker>\
+            <MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated>\
+            <Contents><Key>a.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size><StorageClass>DEEP_ARCHIVE</StorageClass>\
+            <Owner><ID>1250000000</ID><DisplayName>1250000000</DisplayName></Owner></Contents>\
+            <Contents><Key>b.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size><StorageClass>COLD_FUTURE</StorageClass></Contents>\
+            </ListBucketResult>";
+        let response: ListObjectsResponse = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(response.contents[0].storage_class, StorageClass::DeepArchive);
+        assert_eq!(
+            response.contents[1].storage_class,
+            StorageClass::Other("COLD_FUTURE".to_string())
+        );
+        assert_eq!(response.contents[0].owner.as_ref().unwrap().id, "1250000000");
+        assert!(response.contents[1].owner.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_owner() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let contents = "<Contents><Key>a.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size>\
+            <Owner><ID>1250000000</ID><DisplayName>owner-name</DisplayName></Owner></Contents>\
+            <Contents><Key>b.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size></Contents>";
+        let v2 = format!(
+            "<ListBucketResult><Name>test-bucket-123</Name><Prefix></Prefix><KeyCount>2</KeyCount>\
+             <MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+            contents
+        );
+        let v1 = format!(
+            "<ListBucketResult><Name>test-bucket-123</Name><Prefix></Prefix><Marker></Marker>\
+             <MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+            contents
+        );
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(200, v2.clone()))
+            .push_response(MockResponse::new(200, v2))
+            .push_response(MockResponse::new(200, v1));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let client = BucketClient::new(transport.client(config).unwrap());
+
+        let response = client
+            .list_objects_v2(Some(ListObjectsV2Options {
+                fetch_owner: true,
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        let owner = response.contents[0].owner.as_ref().unwrap();
+        assert_eq!(owner.id, "1250000000");
+        assert_eq!(owner.display_name, "owner-name");
+        assert!(response.contents[1].owner.is_none());
+        assert_eq!(transport.last_request().unwrap().query_param("fetch-owner").as_deref(), Some("true"));
+
+        // 未指定时不请求所有者信息
+        client.list_objects_v2(None).await.unwrap();
+        assert_eq!(transport.last_request().unwrap().query_param("fetch-owner"), None);
+
+        let response = client.list_objects(None).await.unwrap();
+        let owner = response.contents[0].owner.as_ref().unwrap();
+        assert_eq!(owner.id, "1250000000");
+        assert_eq!(owner.display_name, "owner-name");
+        assert!(response.contents[1].owner.is_none());
+        assert_eq!(transport.last_request().unwrap().query_param("fetch-owner"), None);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_list_all_objects_paginates() {
+        use crate::test_server::{client_for, ServedResponse};
+        use futures::StreamExt;
+
+        fn page(keys: &[&str], next_token: Option<&str>) -> ServedResponse {
+            let contents: String = keys
+                .iter()
+                .map(|key| {
+                    format!(
+                        "<Contents><Key>{}</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+                         <ETag>\"abc\"</ETag><Size>1</Size><StorageClass>STANDARD</StorageClass></Contents>",
+                        key
+                    )
+                })
+                .collect();
+            let body = format!(
+                "<ListBucketResult><Name>test-bucket-123</Name><Prefix></Prefix><KeyCount>{}</KeyCount>\
+                 <MaxKeys>1000</MaxKeys><IsTruncated>{}</IsTruncated>\
+                 <NextContinuationToken>{}</NextContinuationToken>{}</ListBucketResult>",
+                keys.len(),
+                next_token.is_some(),
+                next_token.unwrap_or(""),
+                contents
+            );
+            ServedResponse::new(200, &body)
+        }
+
+        let client = BucketClient::new(
+            client_for(vec![page(&["a", "b"], Some("t1")), page(&["c"], None)]).await,
+        );
+        let keys: Vec<String> = client
+            .list_all_objects(None)
+            .map(|object| object.unwrap().key)
+            .collect()
+            .await;
+        assert_eq!(keys, vec!["a", "b", "c"]);
+
+        // 第二页请求失败时，错误在第一页的对象之后返回
+        let client = BucketClient::new(
+            client_for(vec![page(&["a", "b"], Some("t1")), ServedResponse::new(500, "")]).await,
+        );
+        let results: Vec<Result<ObjectInfo>> = client.list_all_objects(Some("a")).collect().await;
+        assert_eq!(results.len(), 3);
+        assert!(results[1].is_ok());
+        assert_eq!(results[2].as_ref().unwrap_err().status(), Some(500));
+
+        let client = BucketClient::new(
+            client_for(vec![page(&["a", "b"], Some("t1")), page(&["c"], None)]).await,
+        );
+        assert!(client.collect_all(None, Some(2)).await.is_err());
+        let client = BucketClient::new(client_for(vec![page(&["a", "b"], None)]).await);
+        assert_eq!(client.collect_all(None, Some(2)).await.unwrap().len(), 2);
+
+        // 生命周期模拟使用同样的分页
+        let config: LifecycleConfig = quick_xml::de::from_str(
+            "<LifecycleConfiguration><Rule><ID>logs</ID><Filter><Prefix>logs/</Prefix></Filter>\
+             <Status>Enabled</Status><Expiration><Days>1</Days></Expiration></Rule></LifecycleConfiguration>",
+        )
+        .unwrap();
+        let client = BucketClient::new(
+            client_for(vec![page(&["logs/a", "tmp/b"], Some("t1")), page(&["logs/c"], None)]).await,
+        );
+        let keys: Vec<String> = client
+            .simulate_lifecycle_with(config, None, Utc::now())
+            .map(|action| action.unwrap().key)
+            .collect()
+            .await;
+        assert_eq!(keys, vec!["logs/a", "logs/c"]);
+    }
+
+    #[test]
+    fn test_decode_url_encoded_listing() {
+        let xml = "<ListBucketResult><Name>test-bucket-123</Name><Prefix>dir%2F</Prefix>\
+            <Marker>dir%2Fa%01</Marker><NextMarker>dir%2Fz%20</NextMarker><EncodingType>url</EncodingType>\
+            <MaxKeys>1000</MaxKeys><IsTruncated>true</IsTruncated>\
+            <Contents><Key>dir%2Fb%0Ac%E4%B8%AD</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size></Contents>\
+            <CommonPrefixes><Prefix>dir%2Fsub%2F</Prefix></CommonPrefixes></ListBucketResult>";
+        let mut response: ListObjectsResponse = quick_xml::de::from_str(xml).unwrap();
+        response.decode_keys();
+        assert_eq!(response.prefix, "dir/");
+        assert_eq!(response.marker, "dir/a\u{1}");
+        assert_eq!(response.next_marker, "dir/z ");
+        assert_eq!(response.contents[0].key, "dir/b\nc中");
+        assert_eq!(response.common_prefixes[0].prefix, "dir/sub/");
+    }
+
+    #[tokio::test]
+    async fn test_create_bucket_already_exists() {
+        use crate::test_server::{recording_client_for, ServedResponse};
+
+        let (client, requests) = recording_client_for(vec![
+            ServedResponse::new(200, ""),
+            ServedResponse::new(409, "<Error><Code>BucketAlreadyOwnedByYou</Code></Error>"),
+            ServedResponse::new(409, "<Error><Code>BucketAlreadyExists</Code></Error>"),
+            ServedResponse::new(409, "<Error><Code>OperationConflict</Code></Error>"),
+        ])
+        .await;
+        let client = BucketClient::new(client);
+        let options = CreateBucketOptions {
+            acl: Some(BucketAcl::PublicRead),
+            maz: true,
+            ..Default::default()
+        };
+        client.create_bucket_with_options(options).await.unwrap();
+        {
+            let requests = requests.lock().unwrap();
+            assert_eq!(requests[0].header("x-cos-acl"), Some("public-read"));
+            assert_eq!(
+                requests[0].body,
+                b"<CreateBucketConfiguration><BucketAZConfig>MAZ</BucketAZConfig></CreateBucketConfiguration>"
+            );
+        }
+        assert!(matches!(
+            client.create_bucket(None).await,
+            Err(CosError::BucketAlreadyExists { owned_by_you: true, .. })
+        ));
+        assert!(matches!(
+            client.create_bucket(None).await,
+            Err(CosError::BucketAlreadyExists { owned_by_you: false, .. })
+        ));
+        let err = client.create_bucket(None).await.unwrap_err();
+        assert!(matches!(err, CosError::Client { .. }));
+        assert_eq!(err.status(), Some(409));
+    }
+
+    #[tokio::test]
+    async fn test_get_bucket_policy() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let body = r#"{"Statement":[{"Principal":{"qcs":["qcs::cam::anyone:anyone"]},"Effect":"allow",
+            "Action":["name/cos:GetObject"],"Resource":["qcs::cos:ap-beijing:uid/123:test-123/*"]}],"version":"2.0"}"#;
+        let no_such_policy = "<Error><Code>NoSuchPolicy</Code><Message>The bucket policy does not exist</Message></Error>";
+        let no_such_bucket = "<Error><Code>NoSuchBucket</Code><Message>The specified bucket does not exist</Message></Error>";
+        let client = BucketClient::new(
+            client_for(vec![
+                ServedResponse::new(200, body),
+                ServedResponse::new(404, no_such_policy),
+                ServedResponse::new(404, no_such_bucket),
+            ])
+            .await,
+        );
+
+        let policy = client.get_bucket_policy().await.unwrap();
+        assert_eq!(policy.statement[0].action, vec!["name/cos:GetObject"]);
+        assert!(matches!(client.get_bucket_policy().await, Err(CosError::NoSuchPolicy { .. })));
+        let err = client.get_bucket_policy().await.unwrap_err();
+        assert!(matches!(err, CosError::Client { .. }));
+        assert_eq!(err.status(), Some(404));
+    }
+
+    #[tokio::test]
+    async fn test_get_bucket_tagging() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let body = "<Tagging><TagSet><Tag><Key>team</Key><Value>storage</Value></Tag></TagSet></Tagging>";
+        let no_such_tag_set = "<Error><Code>NoSuchTagSet</Code><Message>There is no tag set</Message></Error>";
+        let client = BucketClient::new(
+            client_for(vec![
+                ServedResponse::new(200, body),
+                ServedResponse::new(404, no_such_tag_set),
+                ServedResponse::new(404, "<Error><Code>NoSuchBucket</Code></Error>"),
+            ])
+            .await,
+        );
+
+        assert_eq!(client.get_bucket_tagging().await.unwrap(), vec![Tag::new("team", "storage")]);
+        assert!(client.get_bucket_tagging().await.unwrap().is_empty());
+        assert_eq!(client.get_bucket_tagging().await.unwrap_err().status(), Some(404));
+
+        let too_many: Vec<(String, String)> = (0..51).map(|i| (format!("k{}", i), "v".to_string())).collect();
+        assert!(client.put_bucket_tagging(&too_many).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_bucket_accelerate() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let client = BucketClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "<AccelerateConfiguration><Status>Enabled</Status><Type>COS</Type></AccelerateConfiguration>"),
+                ServedResponse::new(200, "<AccelerateConfiguration><Status>Suspended</Status></AccelerateConfiguration>"),
+                ServedResponse::new(200, "<AccelerateConfiguration/>"),
+            ])
+            .await,
+        );
+        assert!(client.get_bucket_accelerate().await.unwrap());
+        assert!(!client.get_bucket_accelerate().await.unwrap());
+        assert!(!client.get_bucket_accelerate().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_exists_status_handling() {
+        use crate::test_server::{client_for, unreachable_client, ServedResponse};
+
+        let client = BucketClient::new(client_for(vec![ServedResponse::new(200, "")]).await);
+        assert!(client.bucket_exists().await.unwrap());
+
+        let client = BucketClient::new(client_for(vec![ServedResponse::new(404, "")]).await);
+        assert!(!client.bucket_exists().await.unwrap());
+
+        for status in [403, 500] {
+            let client = BucketClient::new(client_for(vec![ServedResponse::new(status, "")]).await);
+            let err = client.bucket_exists().await.unwrap_err();
+            assert_eq!(err.status(), Some(status));
+        }
+
+        let client = BucketClient::new(unreachable_client().await);
+        let err = client.bucket_exists().await.unwrap_err();
+        assert_eq!(err.status(), None);
+    }
+
+    #[tokio::test]
+    async fn test_head_bucket_outcomes() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let client = BucketClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "")
+                    .with_header("x-cos-bucket-region", "ap-beijing")
+                    .with_header("x-cos-request-id", "req-1"),
+                ServedResponse::new(404, ""),
+                ServedResponse::new(403, "").with_header("x-cos-bucket-region", "ap-guangzhou"),
+            ])
+            .await,
+        );
+
+        let response = client.head_bucket().await.unwrap();
+        assert_eq!(response.region.as_deref(), Some("ap-beijing"));
+        assert_eq!(response.request_id.as_deref(), Some("req-1"));
+        assert!(matches!(client.head_bucket().await, Err(CosError::NoSuchBucket { region: None, .. })));
+        match client.head_bucket().await {
+            Err(CosError::AccessDenied { region, .. }) => assert_eq!(region.as_deref(), Some("ap-guangzhou")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! 批量清理模块
+//!
+//! 提供按前缀列出并批量删除对象、清空存储桶的辅助功能
+
+use crate::bucket::{BucketClient, ListObjectVersionsOptions, ObjectInfo};
+use crate::error::{CosError, Result};
+use crate::multipart::{AbortReport, AbortedUpload, FailedAbort, ListMultipartUploadsOptions};
+use crate::object::{
+    DeleteError, DeleteObjectsOptions, DeleteObjectsResponse, ObjectClient, ObjectIdentifier,
+    MAX_DELETE_OBJECTS,
+};
+use crate::transfer::{join_part, DEFAULT_CONCURRENCY};
+use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+use tokio::task::JoinSet;
+
+/// 按前缀删除选项
+#[derive(Debug, Clone)]
+pub struct DeletePrefixOptions {
+    /// 只列出将要删除的对象，不实际删除
+    pub dry_run: bool,
+    /// 同时进行的批量删除请求数量
+    pub concurrency: usize,
+    /// 同时删除所有历史版本和删除标记，适用于开启了版本控制的存储桶
+    pub all_versions: bool,
+}
+
+impl Default for DeletePrefixOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            all_versions: false,
+        }
+    }
+}
+
+/// 按前缀删除的结果
+#[derive(Debug, Clone, Default)]
+pub struct DeletePrefixReport {
+    /// 列出的对象（或版本）数量
+    pub listed: u64,
+    /// 删除成功的数量，dry run 时为 0
+    pub deleted: u64,
+    /// dry run 时将要删除的对象
+    pub planned: Vec<ObjectIdentifier>,
+    /// 删除失败的对象
+    pub errors: Vec<DeleteError>,
+}
+
+impl ObjectClient {
+    /// 删除指定前缀下的所有对象
+    ///
+    /// 分页列出对象后按每批 1000 个并发删除，单个对象的失败记录在报告中，不会中断整个过程。
+    pub async fn delete_by_prefix(
+        &self,
+        prefix: &str,
+        options: DeletePrefixOptions,
+    ) -> Result<DeletePrefixReport> {
+        if options.concurrency == 0 {
+            return Err(CosError::config("Concurrency must be greater than zero"));
+        }
+
+        let bucket = BucketClient::new(self.client.clone());
+        let mut report = DeletePrefixReport::default();
+        let mut tasks = JoinSet::new();
+        let mut pages = PrefixPages::new(&bucket, prefix, options.all_versions);
+
+        while let Some(batch) = pages.next().await? {
+            report.listed += batch.len() as u64;
+            if options.dry_run {
+                report.planned.extend(batch);
+                continue;
+            }
+
+            if tasks.len() >= options.concurrency {
+                report.record(join_part(&mut tasks).await?);
+            }
+            let client = self.clone();
+            tasks.spawn(async move {
+                let response = client
+                    .delete_objects_with_options(&batch, DeleteObjectsOptions { quiet: true })
+                    .await?;
+                Ok((batch.len() as u64, response))
+            });
+        }
+
+        while !tasks.is_empty() {
+            report.record(join_part(&mut tasks).await?);
+        }
+
+        Ok(report)
+    }
+}
+
+/// 清空存储桶选项
+#[derive(Debug, Clone)]
+pub struct EmptyBucketOptions {
+    /// 再次填写要清空的存储桶名称（`<bucket-name>-<appid>`），
+    /// 必须与客户端配置的存储桶一致，防止误操作
+    pub confirm_bucket: String,
+    /// 只列出将要删除的内容，不实际删除
+    pub dry_run: bool,
+    /// 删除所有历史版本和删除标记，适用于开启了版本控制的存储桶。
+    /// 开启后通过版本列表删除，不再单独删除当前对象
+    pub all_versions: bool,
+    /// 同时终止所有进行中的分块上传
+    pub abort_uploads: bool,
+    /// 同时进行的批量删除请求数量
+    pub concurrency: usize,
+}
+
+impl EmptyBucketOptions {
+    /// 创建选项，默认删除当前对象并终止分块上传
+    pub fn new<S: Into<String>>(confirm_bucket: S) -> Self {
+        Self {
+            confirm_bucket: confirm_bucket.into(),
+            dry_run: false,
+            all_versions: false,
+            abort_uploads: true,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+}
+
+/// 清空存储桶的结果
+#[derive(Debug, Default)]
+pub struct EmptyReport {
+    /// 当前对象的删除结果，`all_versions` 时为空
+    pub objects: DeletePrefixReport,
+    /// 历史版本及删除标记的删除结果，未指定 `all_versions` 时为空
+    pub versions: DeletePrefixReport,
+    /// 分块上传的终止结果
+    pub uploads: AbortReport,
+    /// dry run 时将要终止的分块上传
+    pub planned_uploads: Vec<AbortedUpload>,
+}
+
+impl EmptyReport {
+    /// 是否全部成功
+    pub fn is_complete(&self) -> bool {
+        self.objects.errors.is_empty() && self.versions.errors.is_empty() && self.uploads.failed.is_empty()
+    }
+}
+
+impl BucketClient {
+    /// 清空存储桶：删除所有对象，可选删除所有历史版本，并终止进行中的分块上传
+    ///
+    /// `confirm_bucket` 与当前存储桶不一致时直接返回错误，不发送任何请求。
+    /// 单个对象或分块上传的失败记录在报告中，不会中断整个过程。
+    pub async fn empty_bucket(&self, options: EmptyBucketOptions) -> Result<EmptyReport> {
+        let bucket = &self.client.config().bucket;
+        if options.confirm_bucket != *bucket {
+            return Err(CosError::config(format!(
+                "Confirmation '{}' does not match bucket '{}'",
+                options.confirm_bucket, bucket
+            )));
+        }
+
+        let object_client = ObjectClient::new(self.client.clone());
+        let delete_options = DeletePrefixOptions {
+            dry_run: options.dry_run,
+            concurrency: options.concurrency,
+            all_versions: options.all_versions,
+        };
+        let mut report = EmptyReport::default();
+        let deleted = object_client.delete_by_prefix("", delete_options).await?;
+        if options.all_versions {
+            report.versions = deleted;
+        } else {
+            report.objects = deleted;
+        }
+
+        if options.abort_uploads {
+            self.abort_all_uploads(options.dry_run, &mut report).await?;
+        }
+
+        Ok(report)
+    }
+
+    /// 分页终止所有进行中的分块上传
+    async fn abort_all_uploads(&self, dry_run: bool, report: &mut EmptyReport) -> Result<()> {
+        let object_client = ObjectClient::new(self.client.clone());
+        let mut key_marker = None;
+        let mut upload_id_marker = None;
+
+        loop {
+            let page = self
+                .list_multipart_uploads(Some(ListMultipartUploadsOptions {
+                    key_marker: key_marker.take(),
+                    upload_id_marker: upload_id_marker.take(),
+                    ..Default::default()
+                }))
+                .await?;
+
+            for upload in page.uploads {
+                if dry_run {
+                    report.planned_uploads.push(AbortedUpload {
+                        key: upload.key,
+                        upload_id: upload.upload_id,
+                    });
+                    continue;
+                }
+
+                match object_client.abort_multipart_upload(&upload.key, &upload.upload_id).await {
+                    Ok(()) => report.uploads.aborted.push(AbortedUpload {
+                        key: upload.key,
+                        upload_id: upload.upload_id,
+                    }),
+                    Err(error) => report.uploads.failed.push(FailedAbort {
+                        key: upload.key,
+                        upload_id: upload.upload_id,
+                        error,
+                    }),
+                }
+            }
+
+            if !page.is_truncated || page.next_key_marker.is_empty() {
+                return Ok(());
+            }
+            key_marker = Some(page.next_key_marker);
+            upload_id_marker = Some(page.next_upload_id_marker);
+        }
+    }
+}
+
+impl DeletePrefixReport {
+    /// 记录一批删除的结果
+    fn record(&mut self, (count, response): (u64, DeleteObjectsResponse)) {
+        self.deleted += count.saturating_sub(response.errors.len() as u64);
+        self.errors.extend(response.errors);
+    }
+}
+
+/// 按前缀分页列出待删除的对象或版本，每页最多 1000 个
+struct PrefixPages<'a> {
+    bucket: &'a BucketClient,
+    prefix: String,
+    /// 不删除历史版本时通过 [`BucketClient::list_all_objects`] 列出当前对象
+    objects: Option<BoxStream<'a, Result<ObjectInfo>>>,
+    key_marker: Option<String>,
+    version_id_marker: Option<String>,
+    done: bool,
+}
+
+impl<'a> PrefixPages<'a> {
+    fn new(bucket: &'a BucketClient, prefix: &str, all_versions: bool) -> Self {
+        Self {
+            bucket,
+            prefix: prefix.to_string(),
+            objects: (!all_versions).then(|| bucket.list_all_objects(Some(prefix)).boxed()),
+            key_marker: None,
+            version_id_marker: None,
+            done: false,
+        }
+    }
+
+    async fn next(&mut self) -> Result<Option<Vec<ObjectIdentifier>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let batch = if let Some(ref mut objects) = self.objects {
+            let mut batch = Vec::new();
+            while batch.len() < MAX_DELETE_OBJECTS {
+                match objects.try_next().await? {
+                    Some(object) => batch.push(ObjectIdentifier::from(object.key.as_str())),
+                    None => {
+                        self.done = true;
+                        break;
+                    }
+                }
+            }
+            batch
+        } else {
+            let page = self
+                .bucket
+                .list_object_versions(Some(ListObjectVersionsOptions {
+                    prefix: Some(self.prefix.clone()),
+                    key_marker: self.key_marker.take(),
+                    version_id_marker: self.version_id_marker.take(),
+                    max_keys: Some(MAX_DELETE_OBJECTS as u32),
+                    ..Default::default()
+                }))
+                .await?;
+            self.done = !page.is_truncated;
+            self.key_marker = Some(page.next_key_marker);
+            self.version_id_marker = Some(page.next_version_id_marker);
+
+            page.versions
+                .into_iter()
+                .map(|v| ObjectIdentifier::with_version(v.key, v.version_id))
+                .chain(
+                    page.delete_markers
+                        .into_iter()
+                        .map(|m| ObjectIdentifier::with_version(m.key, m.version_id)),
+                )
+                .collect::<Vec<_>>()
+        };
+
+        if batch.is_empty() && self.done {
+            return Ok(None);
+        }
+        Ok(Some(batch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    #[test]
+    fn test_report_record() {
+        let mut report = DeletePrefixReport::default();
+        let response = DeleteObjectsResponse {
+            deleted: Vec::new(),
+            errors: vec![DeleteError {
+                key: "tmp/a.txt".to_string(),
+                version_id: None,
+                code: "AccessDenied".to_string(),
+                message: "Access Denied".to_string(),
+            }],
+        };
+        report.record((3, response));
+        report.record((2, DeleteObjectsResponse::default()));
+
+        assert_eq!(report.deleted, 4);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].key, "tmp/a.txt");
+    }
+
+    #[tokio::test]
+    async fn test_empty_bucket_dry_run() {
+        let objects = "<ListBucketResult><Name>test-bucket-123</Name><Prefix></Prefix><KeyCount>2</KeyCount>\
+            <MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated>\
+            <Contents><Key>a.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size></Contents>\
+            <Contents><Key>b.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+            <ETag>\"abc\"</ETag><Size>1</Size></Contents></ListBucketResult>";
+        let uploads = "<ListMultipartUploadsResult><Bucket>test-bucket-123</Bucket>\
+            <IsTruncated>false</IsTruncated><Upload><Key>big.bin</Key><UploadId>u1</UploadId>\
+            <Initiated>2024-01-01T00:00:00.000Z</Initiated></Upload></ListMultipartUploadsResult>";
+        let client = BucketClient::new(
+            client_for(vec![ServedResponse::new(200, objects), ServedResponse::new(200, uploads)]).await,
+        );
+
+        let mut options = EmptyBucketOptions::new("test-bucket-123");
+        options.dry_run = true;
+        let report = client.empty_bucket(options).await.unwrap();
+        assert_eq!(report.objects.listed, 2);
+        assert_eq!(report.objects.deleted, 0);
+        assert_eq!(report.objects.planned.len(), 2);
+        assert_eq!(report.planned_uploads.len(), 1);
+        assert_eq!(report.planned_uploads[0].upload_id, "u1");
+        assert!(report.is_complete());
+
+        let err = client.empty_bucket(EmptyBucketOptions::new("other-bucket-123")).await.unwrap_err();
+        assert!(matches!(err, CosError::Config { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_prefix_decodes_keys() {
+        use crate::config::Config;
+        use crate::testing::{MockResponse, MockTransport};
+
+        let page = |key: &str, next_token: Option<&str>| {
+            MockResponse::new(
+                200,
+                format!(
+                    "<ListBucketResult><Name>test-bucket-123</Name><Prefix>tmp/</Prefix>\
+                     <KeyCount>1</KeyCount><MaxKeys>1000</MaxKeys><IsTruncated>{}</IsTruncated><NextContinuationToken>{}</NextContinuationToken>\
+                     <Contents><Key>{}</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified>\
+                     <ETag>\"abc\"</ETag><Size>1</Size></Contents></ListBucketResult>",
+                    next_token.is_some(),
+                    next_token.unwrap_or(""),
+                    key
+                ),
+            )
+        };
+        let transport = MockTransport::new();
+        transport
+            .push_response(page("tmp/a%20b.txt", Some("t1")))
+            .push_response(page("tmp/%E4%B8%AD.txt", None));
+        let client = ObjectClient::new(
+            transport
+                .client(Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123"))
+                .unwrap(),
+        );
+
+        let options = DeletePrefixOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = client.delete_by_prefix("tmp/", options).await.unwrap();
+        let keys: Vec<&str> = report.planned.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(keys, vec!["tmp/a b.txt", "tmp/中.txt"]);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query_param("encoding-type").as_deref(), Some("url"));
+        assert_eq!(requests[1].query_param("continuation-token").as_deref(), Some("t1"));
+    }
+}
@@ -4,67 +4,294 @@
 
 use crate::auth::Auth;
 use crate::config::Config;
+use crate::credentials::{Credentials, CredentialsCache};
 use crate::error::{CosError, Result};
-use chrono::{Duration, Utc};
-use reqwest::{Client, Method, Response};
-use serde_json::Value;
+use crate::response::CosResponse;
+use crate::retry::RetryConfig;
+use crate::trace::{self, RequestTrace};
+use crate::transport::{configure, HttpTransport, ReqwestTransport};
+use bytes::Bytes;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::{Client, Method, StatusCode};
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::Semaphore;
 
 /// COS HTTP 客户端
 #[derive(Debug, Clone)]
 pub struct CosClient {
     config: Config,
-    auth: Auth,
+    /// 签名使用的凭证，由客户端的所有副本共享
+    credentials: Arc<CredentialsCache>,
+    /// 用于构建请求，请求由 `transport` 发送
     http_client: Client,
+    transport: Arc<dyn HttpTransport>,
+    /// 覆盖 [`Config::timeout`] 的单次请求超时
+    request_timeout: Option<std::time::Duration>,
+    /// 按服务端重定向纠正后的地域，由客户端的所有副本共享
+    corrected_region: Arc<RwLock<Option<String>>>,
+    /// 服务端时间减本地时间的秒数，由客户端的所有副本共享
+    clock_skew: Arc<AtomicI64>,
+    /// 客户端范围的请求并发限制，见 [`Config::max_concurrent_requests`]
+    request_limiter: Option<Arc<Semaphore>>,
+    in_flight: Arc<AtomicUsize>,
 }
 
+/// 请求结束（包括被取消）时减少发送中的请求数
+struct InFlightGuard<'a>(&'a AtomicUsize);
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// 自定义请求，见 [`CosClient::execute`]
+#[derive(Debug)]
+pub struct CustomRequest {
+    pub method: Method,
+    /// 存储桶内的请求路径（未编码），如 `/` 或 `/dir/a b.txt`
+    pub path: String,
+    /// 查询参数（未编码），子资源参数的值为空字符串，如 `("acl", "")`
+    pub query: HashMap<String, String>,
+    /// 额外的请求头，其中 `x-cos-*` 与 `Content-Type` 等内容相关的请求头与 Host 一起参与签名
+    pub headers: HashMap<String, String>,
+    pub body: Option<reqwest::Body>,
+}
+
+impl CustomRequest {
+    /// 创建没有查询参数、请求头和请求体的请求
+    pub fn new<S: Into<String>>(method: Method, path: S) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: HashMap::new(),
+            headers: HashMap::new(),
+            body: None,
+        }
+    }
+
+    /// 添加查询参数
+    pub fn with_query<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.query.insert(name.into(), value.into());
+        self
+    }
+
+    /// 添加请求头
+    pub fn with_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// 设置请求体
+    pub fn with_body<B: Into<reqwest::Body>>(mut self, body: B) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// 时钟偏差超过该秒数时记录警告
+const CLOCK_SKEW_WARN_SECS: i64 = 60;
+
 impl CosClient {
     /// 创建新的 COS 客户端
     pub fn new(config: Config) -> Result<Self> {
         config.validate()?;
-        
-        let auth = Auth::new(&config.secret_id, &config.secret_key);
-        let http_client = Client::builder()
-            .timeout(config.timeout)
+        Self::build(config)
+    }
+
+    /// 创建只用于服务级请求的客户端，不要求配置存储桶
+    pub(crate) fn for_service(config: Config) -> Result<Self> {
+        config.validate_credentials()?;
+        config.validate_default_headers()?;
+        Self::build(config)
+    }
+
+    fn build(config: Config) -> Result<Self> {
+        Self::build_with(config, Client::builder())
+    }
+
+    /// 使用自定义的 HTTP 客户端设置创建客户端
+    ///
+    /// 不跟随重定向：COS 的重定向只在地域配置错误时出现，跟随后签名与主机不匹配。
+    pub(crate) fn build_with(config: Config, builder: reqwest::ClientBuilder) -> Result<Self> {
+        let credentials = match &config.credentials_provider {
+            Some(provider) => CredentialsCache::new(provider.clone()),
+            None => CredentialsCache::fixed(config.static_credentials()),
+        };
+        let http_client = configure(builder, &config)?
+            .redirect(reqwest::redirect::Policy::none())
             .build()
             .map_err(|e| CosError::other(format!("Failed to create HTTP client: {}", e)))?;
 
         Ok(Self {
-            config,
-            auth,
+            credentials: Arc::new(credentials),
+            transport: Arc::new(ReqwestTransport(http_client.clone())),
             http_client,
+            request_timeout: None,
+            corrected_region: Arc::default(),
+            clock_skew: Arc::default(),
+            request_limiter: config.max_concurrent_requests.map(|n| Arc::new(Semaphore::new(n))),
+            in_flight: Arc::default(),
+            config,
         })
     }
 
+    /// 替换发送请求的传输层
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// 返回请求超时为 `timeout` 的客户端副本，`timeout` 为空时沿用当前的超时
+    pub(crate) fn with_timeout(&self, timeout: Option<std::time::Duration>) -> Self {
+        let mut client = self.clone();
+        if timeout.is_some() {
+            client.request_timeout = timeout;
+        }
+        client
+    }
+
+    /// 返回访问另一个存储桶的客户端副本
+    ///
+    /// 沿用传输层、凭证、并发限制与 `endpoint` 等连接设置；自定义域名只对应当前存储桶，不会沿用。
+    pub(crate) fn for_bucket(&self, bucket: &str, region: &str) -> Result<Self> {
+        let mut client = self.clone();
+        client.config.bucket = bucket.to_string();
+        client.config.region = region.to_string();
+        client.config.domain = None;
+        client.config.validate()?;
+        client.corrected_region = Arc::default();
+        Ok(client)
+    }
+
+    /// 返回使用 `retry` 重试策略的客户端副本，`retry` 为空时沿用当前的策略
+    pub(crate) fn with_retry(&self, retry: Option<&RetryConfig>) -> Result<Self> {
+        let mut client = self.clone();
+        if let Some(retry) = retry {
+            retry.validate()?;
+            client.config.retry = retry.clone();
+        }
+        Ok(client)
+    }
+
+    /// 发送自定义请求，用于 SDK 尚未封装的 COS 接口
+    ///
+    /// 与 SDK 内部的请求一样构建存储桶 URL、合并请求头并签名；路径和查询参数使用未编码的原文，由 SDK 负责编码。
+    /// 非 2xx 响应会转换为错误。
+    ///
+    /// ```no_run
+    /// # async fn example(client: cos_rust_sdk::CosClient) -> cos_rust_sdk::Result<()> {
+    /// use cos_rust_sdk::CustomRequest;
+    /// use reqwest::Method;
+    ///
+    /// let response = client
+    ///     .execute(CustomRequest::new(Method::GET, "/").with_query("object-lock", ""))
+    ///     .await?;
+    /// println!("{}", response.text().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute(&self, request: CustomRequest) -> Result<CosResponse> {
+        let path = if request.path.starts_with('/') {
+            request.path
+        } else {
+            format!("/{}", request.path)
+        };
+        self.request(request.method, &path, request.query, request.headers, request.body)
+            .await
+    }
+
     /// 发送 GET 请求
-    pub async fn get(&self, path: &str, params: HashMap<String, String>) -> Result<Response> {
-        self.request(Method::GET, path, params, None::<&[u8]>).await
+    pub async fn get(&self, path: &str, params: HashMap<String, String>) -> Result<CosResponse> {
+        self.get_with_headers(path, params, HashMap::new()).await
+    }
+
+    /// 发送带自定义请求头的 GET 请求
+    pub async fn get_with_headers(
+        &self,
+        path: &str,
+        params: HashMap<String, String>,
+        headers: HashMap<String, String>,
+    ) -> Result<CosResponse> {
+        self.request(Method::GET, path, params, headers, None::<&[u8]>).await
     }
 
     /// 发送 PUT 请求
-    pub async fn put<T>(&self, path: &str, params: HashMap<String, String>, body: Option<T>) -> Result<Response>
+    pub async fn put<T>(&self, path: &str, params: HashMap<String, String>, body: Option<T>) -> Result<CosResponse>
     where
         T: Into<reqwest::Body>,
     {
-        self.request(Method::PUT, path, params, body).await
+        self.put_with_headers(path, params, HashMap::new(), body).await
+    }
+
+    /// 发送带自定义请求头的 PUT 请求
+    pub async fn put_with_headers<T>(
+        &self,
+        path: &str,
+        params: HashMap<String, String>,
+        headers: HashMap<String, String>,
+        body: Option<T>,
+    ) -> Result<CosResponse>
+    where
+        T: Into<reqwest::Body>,
+    {
+        self.request(Method::PUT, path, params, headers, body).await
     }
 
     /// 发送 POST 请求
-    pub async fn post<T>(&self, path: &str, params: HashMap<String, String>, body: Option<T>) -> Result<Response>
+    pub async fn post<T>(&self, path: &str, params: HashMap<String, String>, body: Option<T>) -> Result<CosResponse>
     where
         T: Into<reqwest::Body>,
     {
-        self.request(Method::POST, path, params, body).await
+        self.post_with_headers(path, params, HashMap::new(), body).await
+    }
+
+    /// 发送带自定义请求头的 POST 请求
+    pub async fn post_with_headers<T>(
+        &self,
+        path: &str,
+        params: HashMap<String, String>,
+        headers: HashMap<String, String>,
+        body: Option<T>,
+    ) -> Result<CosResponse>
+    where
+        T: Into<reqwest::Body>,
+    {
+        self.request(Method::POST, path, params, headers, body).await
     }
 
     /// 发送 DELETE 请求
-    pub async fn delete(&self, path: &str, params: HashMap<String, String>) -> Result<Response> {
-        self.request(Method::DELETE, path, params, None::<&[u8]>).await
+    pub async fn delete(&self, path: &str, params: HashMap<String, String>) -> Result<CosResponse> {
+        self.delete_with_headers(path, params, HashMap::new()).await
+    }
+
+    /// 发送带自定义请求头的 DELETE 请求
+    pub async fn delete_with_headers(
+        &self,
+        path: &str,
+        params: HashMap<String, String>,
+        headers: HashMap<String, String>,
+    ) -> Result<CosResponse> {
+        self.request(Method::DELETE, path, params, headers, None::<&[u8]>).await
     }
 
     /// 发送 HEAD 请求
-    pub async fn head(&self, path: &str, params: HashMap<String, String>) -> Result<Response> {
-        self.request(Method::HEAD, path, params, None::<&[u8]>).await
+    pub async fn head(&self, path: &str, params: HashMap<String, String>) -> Result<CosResponse> {
+        self.head_with_headers(path, params, HashMap::new()).await
+    }
+
+    /// 发送带自定义请求头的 HEAD 请求
+    pub async fn head_with_headers(
+        &self,
+        path: &str,
+        params: HashMap<String, String>,
+        headers: HashMap<String, String>,
+    ) -> Result<CosResponse> {
+        self.request(Method::HEAD, path, params, headers, None::<&[u8]>).await
     }
 
     /// 通用请求方法
@@ -73,16 +300,220 @@ impl CosClient {
         method: Method,
         path: &str,
         params: HashMap<String, String>,
+        extra_headers: HashMap<String, String>,
         body: Option<T>,
-    ) -> Result<Response>
+    ) -> Result<CosResponse>
     where
         T: Into<reqwest::Body>,
     {
-        let url = self.build_url(path, &params)?;
-        let mut headers = self.build_headers(&method, path, &params)?;
+        let response = self.request_raw(method, path, params, extra_headers, body).await?;
+        Self::error_for_status(response).await
+    }
+
+    /// 发送请求但不检查响应状态，供需要自行处理特定状态码的操作使用
+    ///
+    /// 请求体可以重放时按 [`Config::retry`] 重试失败的请求。
+    /// 存储桶不在配置的地域时，开启 [`Config::auto_correct_region`] 且请求体可以重放则改用正确的地域重试一次，
+    /// 否则返回 [`CosError::WrongRegion`]。
+    /// 签名因本地时钟偏差被拒绝时，按响应的 `Date` 记录时钟偏差并重试一次。
+    pub(crate) async fn request_raw<T>(
+        &self,
+        method: Method,
+        path: &str,
+        params: HashMap<String, String>,
+        extra_headers: HashMap<String, String>,
+        body: Option<T>,
+    ) -> Result<CosResponse>
+    where
+        T: Into<reqwest::Body>,
+    {
+        // 可以重放的请求体，流式请求体无法重放。时钟偏差纠正随时可能重发请求，
+        // 所以内存中的请求体总是保留；`Bytes` 与发送的请求体共享数据，不会复制
+        let (mut body, replay): (Option<reqwest::Body>, Option<Option<Bytes>>) = match body.map(Into::into) {
+            Some(body) => match reusable_bytes(body) {
+                Ok(bytes) => (Some(bytes.clone().into()), Some(Some(bytes))),
+                Err(body) => (Some(body), None),
+            },
+            None => (None, Some(None)),
+        };
+
+        let retry = &self.config.retry;
+        let mut attempt = 1;
+        loop {
+            let mut result = self
+                .attempt(&method, path, &params, &extra_headers, body, &replay, attempt)
+                .await;
+            let Some(replayed) = replay.clone().filter(|_| attempt < retry.max_attempts) else {
+                return result;
+            };
+            if !retry.should_retry(&mut result).await {
+                return result;
+            }
+            body = replayed.map(reqwest::Body::from);
+            let backoff = retry.backoff(attempt);
+            trace::retrying(&method, path, attempt, retry.max_attempts, &result, backoff);
+            log::debug!(
+                "Retrying {} {} after {:?} (attempt {} of {})",
+                method,
+                path,
+                backoff,
+                attempt + 1,
+                retry.max_attempts
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// 发送一次请求，按需纠正地域或时钟偏差后重发
+    #[allow(clippy::too_many_arguments)]
+    async fn attempt(
+        &self,
+        method: &Method,
+        path: &str,
+        params: &HashMap<String, String>,
+        extra_headers: &HashMap<String, String>,
+        body: Option<reqwest::Body>,
+        replay: &Option<Option<Bytes>>,
+        attempt: u32,
+    ) -> Result<CosResponse> {
+        let replay_body = || replay.clone().map(|body| body.map(reqwest::Body::from));
+        let mut response = self
+            .send(method.clone(), path, params, extra_headers.clone(), body, attempt)
+            .await?;
+
+        if let Some(expected) = self.wrong_region(&response) {
+            let configured = self.region();
+            if !self.config.auto_correct_region || self.config.domain.is_some() || self.config.endpoint.is_some() {
+                return Err(CosError::WrongRegion { expected, configured });
+            }
+            log::warn!(
+                "Bucket {} is in region {}, not {}; using the correct region",
+                self.config.bucket,
+                expected,
+                configured
+            );
+            *self.corrected_region.write().unwrap_or_else(|e| e.into_inner()) = Some(expected.clone());
+
+            let Some(body) = replay_body() else {
+                // 流式请求体已被消费，无法重试，后续请求会使用纠正后的地域
+                return Err(CosError::WrongRegion { expected, configured });
+            };
+            response = self
+                .send(method.clone(), path, params, extra_headers.clone(), body, attempt)
+                .await?;
+            if let Some(expected) = self.wrong_region(&response) {
+                return Err(CosError::WrongRegion {
+                    expected,
+                    configured: self.region(),
+                });
+            }
+        }
+
+        if response.status() == StatusCode::FORBIDDEN {
+            let skewed = self.correct_clock_skew(&mut response).await?;
+            if let (true, Some(body)) = (skewed, replay_body()) {
+                response = self
+                    .send(method.clone(), path, params, extra_headers.clone(), body, attempt)
+                    .await?;
+            }
+        }
+        Ok(response)
+    }
+
+    /// 检查 403 响应是否因签名时间不在有效期内，是则按响应的 `Date` 记录时钟偏差
+    ///
+    /// 响应体读入内存后保留在响应中，之后仍可以读取。
+    async fn correct_clock_skew(&self, response: &mut CosResponse) -> Result<bool> {
+        let Some(server_time) = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok())
+        else {
+            return Ok(false);
+        };
+        let local_time = Utc::now();
+
+        let body = response.buffer().await?;
+        if !is_time_error(&String::from_utf8_lossy(&body)) {
+            return Ok(false);
+        }
+
+        let skew = server_time.with_timezone(&Utc) - local_time;
+        self.clock_skew.store(skew.num_seconds(), Ordering::Relaxed);
+        if skew.num_seconds().abs() > CLOCK_SKEW_WARN_SECS {
+            log::warn!(
+                "Local clock is off by {} seconds from the COS server; signing with the server time",
+                skew.num_seconds()
+            );
+        }
+        Ok(true)
+    }
+
+    /// 记录的本地时钟与服务端时钟的偏差（服务端时间减本地时间），签名时会加上该偏差
+    pub fn clock_skew(&self) -> Duration {
+        Duration::seconds(self.clock_skew.load(Ordering::Relaxed))
+    }
+
+    /// 签名使用的当前时间，已按记录的时钟偏差校正
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.clock_skew()
+    }
+
+    /// 响应为地域错误导致的重定向时，返回存储桶实际所在的地域
+    fn wrong_region(&self, response: &CosResponse) -> Option<String> {
+        if !matches!(
+            response.status(),
+            StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND | StatusCode::TEMPORARY_REDIRECT
+        ) {
+            return None;
+        }
+        response
+            .headers()
+            .get("x-cos-bucket-region")
+            .and_then(|v| v.to_str().ok())
+            .filter(|region| !region.is_empty() && *region != self.region())
+            .map(|region| region.to_string())
+    }
+
+    /// 当前使用的地域，地域被自动纠正后返回纠正后的地域
+    pub fn region(&self) -> String {
+        self.corrected_region
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .unwrap_or_else(|| self.config.region.clone())
+    }
+
+    /// 签名并发送单个请求
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        params: &HashMap<String, String>,
+        extra_headers: HashMap<String, String>,
+        body: Option<reqwest::Body>,
+        attempt: u32,
+    ) -> Result<CosResponse> {
+        let url = self.build_url(path, params)?;
+        let host = self.get_host(path)?;
+        let trace = RequestTrace::start(&method, &host, path, params, attempt);
+        let credentials = self.credentials().await?;
+        let mut extra_headers = extra_headers;
+        // 内存中的请求体由 SDK 设置 Content-Length，使其参与签名
+        if let Some(length) = body.as_ref().and_then(|body| body.as_bytes()).map(<[u8]>::len) {
+            if !extra_headers.keys().any(|key| key.eq_ignore_ascii_case("content-length")) {
+                extra_headers.insert("Content-Length".to_string(), length.to_string());
+            }
+        }
+        let headers = self.build_headers(&method, path, params, extra_headers, &credentials)?;
         
         // 构建请求
         let mut request_builder = self.http_client.request(method.clone(), &url);
+        if let Some(timeout) = self.request_timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
         
         // 添加请求头
         for (key, value) in headers.iter() {
@@ -95,46 +526,126 @@ impl CosClient {
         }
         
         // 发送请求
-        let response = request_builder
-            .send()
-            .await
-            .map_err(|e| CosError::other(format!("Request failed: {}", e)))?;
-        
-        // 检查响应状态
+        let result = match request_builder.build() {
+            Ok(request) => trace.instrument(self.dispatch(request)).await,
+            Err(e) => Err(CosError::other(format!("Request failed: {}", e))),
+        };
+        trace.finish(&result);
+        result
+    }
+
+    /// 在客户端的并发限制内通过传输层发送请求
+    ///
+    /// 并发名额从发送请求占用到收到响应头，读取响应体不占用名额。
+    async fn dispatch(&self, request: reqwest::Request) -> Result<CosResponse> {
+        let _permit = match &self.request_limiter {
+            Some(limiter) => Some(
+                limiter
+                    .acquire()
+                    .await
+                    .map_err(|_| CosError::other("Request limiter has been closed"))?,
+            ),
+            None => None,
+        };
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+        let _in_flight = InFlightGuard(&self.in_flight);
+        let response = self.transport.execute(request).await?;
+        Ok(CosResponse::new(response, self.config.max_response_body_size))
+    }
+
+    /// 正在发送中（尚未收到响应头）的请求数，由客户端的所有副本共享
+    pub fn in_flight_requests(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    /// 将非成功状态的响应转换为错误
+    pub(crate) async fn error_for_status(response: CosResponse) -> Result<CosResponse> {
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Err(CosError::NotModified);
+        }
+
         if !response.status().is_success() {
             let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
+            let request_id = response.request_id();
+            let error_text = match response.text().await {
+                Ok(text) => text,
+                Err(e @ CosError::Client { .. }) => return Err(e),
+                Err(_) => "Unknown error".to_string(),
+            };
+
+            if status == StatusCode::PRECONDITION_FAILED
+                || (status == StatusCode::CONFLICT && error_text.contains("FileAlreadyExists"))
+            {
+                return Err(CosError::PreconditionFailed {
+                    message: error_text,
+                    request_id,
+                });
+            }
             
-            return Err(CosError::server(
-                status.to_string(),
-                error_text
-            ));
+            return Err(CosError::from_response(status, error_text, request_id));
         }
         
         Ok(response)
     }
 
+    /// 向服务级接口发送 GET 请求，`endpoint` 为不含路径的完整 URL，如 `https://service.cos.myqcloud.com`
+    pub(crate) async fn get_service(&self, endpoint: &str, params: HashMap<String, String>) -> Result<CosResponse> {
+        let host = host_header(
+            &url::Url::parse(endpoint)
+                .map_err(|e| CosError::config(format!("Invalid service endpoint {:?}: {}", endpoint, e)))?,
+        )
+        .ok_or_else(|| CosError::config(format!("Service endpoint {:?} has no host", endpoint)))?;
+        let trace = RequestTrace::start(&Method::GET, &host, "/", &params, 1);
+        let credentials = self.credentials().await?;
+        let headers = self.sign_headers(&Method::GET, "/", &params, HashMap::new(), host, &credentials)?;
+
+        let mut url = format!("{}/", endpoint.trim_end_matches('/'));
+        if !params.is_empty() {
+            url.push('?');
+            url.push_str(&query_string(&params));
+        }
+        let mut request_builder = self.http_client.get(url);
+        for (key, value) in headers.iter() {
+            request_builder = request_builder.header(key, value);
+        }
+        let result = match request_builder.build() {
+            Ok(request) => trace.instrument(self.dispatch(request)).await,
+            Err(e) => Err(CosError::from(e)),
+        };
+        trace.finish(&result);
+        Self::error_for_status(result?).await
+    }
+
+    /// 请求的基础 URL，`/` 开头的路径使用存储桶域名，其余使用服务域名
+    fn base_url(&self, path: &str) -> Result<String> {
+        let corrected = self.corrected_region.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let corrected_config;
+        let config = match corrected {
+            Some(region) => {
+                corrected_config = Config {
+                    region,
+                    ..self.config.clone()
+                };
+                &corrected_config
+            }
+            None => &self.config,
+        };
+        if path.starts_with('/') {
+            config.bucket_url()
+        } else {
+            Ok(config.service_url())
+        }
+    }
+
     /// 构建完整的 URL
     fn build_url(&self, path: &str, params: &HashMap<String, String>) -> Result<String> {
-        let base_url = if path.starts_with('/') {
-            self.config.bucket_url()?
-        } else {
-            self.config.service_url()
-        };
+        let base_url = self.base_url(path)?;
         
-        let mut url = format!("{}{}", base_url, path);
+        let mut url = format!("{}{}", base_url, encode_key(path));
         
         if !params.is_empty() {
-            let query_string = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                .collect::<Vec<_>>()
-                .join("&");
             url.push('?');
-            url.push_str(&query_string);
+            url.push_str(&query_string(params));
         }
         
         Ok(url)
@@ -146,23 +657,72 @@ impl CosClient {
         method: &Method,
         path: &str,
         params: &HashMap<String, String>,
+        extra_headers: HashMap<String, String>,
+        credentials: &Credentials,
+    ) -> Result<HashMap<String, String>> {
+        let host = self.get_host(path)?;
+        self.sign_headers(method, &self.signed_path(path), params, extra_headers, host, credentials)
+    }
+
+    /// 参与签名的 URI 路径，路径风格下存储桶名称是路径的第一段
+    fn signed_path(&self, path: &str) -> String {
+        if self.config.path_style && path.starts_with('/') {
+            format!("/{}{}", self.config.bucket, path)
+        } else {
+            path.to_string()
+        }
+    }
+
+    /// 为指定主机生成带签名的请求头
+    fn sign_headers(
+        &self,
+        method: &Method,
+        path: &str,
+        params: &HashMap<String, String>,
+        extra_headers: HashMap<String, String>,
+        host: String,
+        credentials: &Credentials,
     ) -> Result<HashMap<String, String>> {
         let mut headers = HashMap::new();
-        
+
         // 基础请求头
         headers.insert("User-Agent".to_string(), crate::USER_AGENT.to_string());
-        headers.insert("Host".to_string(), self.get_host(path)?);
-        
-        // 时间相关
-        let now = Utc::now();
-        let start_time = now - Duration::minutes(5); // 提前5分钟
-        let end_time = now + Duration::hours(1);     // 1小时后过期
+        headers.insert("Host".to_string(), host);
+        if let Some(token) = &credentials.security_token {
+            headers.insert("x-cos-security-token".to_string(), token.clone());
+        }
+
+        // 自定义请求头，覆盖名称相同（不区分大小写）的基础请求头；只有大小写不同的自定义请求头合并为一个，
+        // 发送的取值与参与签名的一致
+        for (name, value) in crate::auth::normalize_headers(&extra_headers) {
+            headers.retain(|key: &String, _| !key.eq_ignore_ascii_case(&name));
+            headers.insert(name, value);
+        }
+
+        // 配置的默认请求头，请求自身设置了同名请求头时不覆盖
+        for (name, value) in &self.config.default_headers {
+            if !headers.keys().any(|key| key.eq_ignore_ascii_case(name)) {
+                headers.insert(name.clone(), value.clone());
+            }
+        }
+
+        // 与官方 SDK 一致，签名 Host、内容相关请求头与所有 x-cos-* 请求头；配置的默认请求头总是参与签名
+        let signed: HashMap<String, String> = headers
+            .iter()
+            .filter(|(name, _)| {
+                crate::auth::is_signed_header(name)
+                    || self.config.default_headers.keys().any(|key| key.eq_ignore_ascii_case(name))
+            })
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+
+        let (start_time, end_time) = self.signing_window(self.config.signature_validity, self.config.clock_skew_allowance)?;
         
         // 生成授权签名
-        let authorization = self.auth.sign(
+        let authorization = self.credentials.auth(credentials).sign(
             method.as_str(),
             path,
-            &headers,
+            &signed,
             params,
             start_time,
             end_time,
@@ -173,48 +733,188 @@ impl CosClient {
         Ok(headers)
     }
 
-    /// 获取主机名
+    /// 生成预签名 URL
+    ///
+    /// `headers` 中的请求头与 Host 一起参与签名，调用方发起请求时必须携带相同的值。
+    /// 签名从当前时间起生效，有效期恰好为 `expires_in`。
+    pub(crate) fn presign_url(
+        &self,
+        method: &Method,
+        path: &str,
+        params: &HashMap<String, String>,
+        mut headers: HashMap<String, String>,
+        expires_in: std::time::Duration,
+    ) -> Result<String> {
+        headers.insert("Host".to_string(), self.get_host(path)?);
+
+        let (start_time, end_time) = self.signing_window(expires_in, std::time::Duration::ZERO)?;
+        let query = self.auth()?.sign_query(
+            method.as_str(),
+            &self.signed_path(path),
+            &headers,
+            params,
+            start_time,
+            end_time,
+        )?;
+
+        let mut url = self.build_url(path, params)?;
+        url.push(if params.is_empty() { '?' } else { '&' });
+        url.push_str(&query.to_string());
+        Ok(url)
+    }
+
+    /// 签名的生效与过期时间：生效时间比当前时间（按服务端时间校正）提前 `allowance`，
+    /// 过期时间为当前时间之后 `valid_for`
+    fn signing_window(
+        &self,
+        valid_for: std::time::Duration,
+        allowance: std::time::Duration,
+    ) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+        let valid_for = Duration::from_std(valid_for)
+            .map_err(|e| CosError::config(format!("Invalid expiration: {}", e)))?;
+        let allowance = Duration::from_std(allowance)
+            .map_err(|e| CosError::config(format!("Invalid clock skew allowance: {}", e)))?;
+        let now = self.now();
+        Ok((now - allowance, now + valid_for))
+    }
+
+    /// 获取 Host 请求头的值，非协议默认端口时包含端口，与实际发送的一致
     fn get_host(&self, path: &str) -> Result<String> {
-        let url = if path.starts_with('/') {
-            self.config.bucket_url()?
-        } else {
-            self.config.service_url()
-        };
+        let url = self.base_url(path)?;
         
         let parsed_url = url::Url::parse(&url)
             .map_err(|e| CosError::other(format!("Invalid URL: {}", e)))?;
         
-        Ok(parsed_url.host_str().unwrap_or("localhost").to_string())
+        Ok(host_header(&parsed_url).unwrap_or_else(|| "localhost".to_string()))
     }
 
-    /// 解析 XML 响应
-    pub async fn parse_xml_response(response: Response) -> Result<Value> {
-        let text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        // 简单的 XML 到 JSON 转换（实际项目中可能需要更复杂的解析）
-        serde_json::from_str(&text)
-            .map_err(|e| CosError::other(format!("Failed to parse XML response: {}", e)))
+    /// 读取响应体并按 XML 解析为 `T`
+    pub async fn parse_xml<T: DeserializeOwned>(response: CosResponse) -> Result<T> {
+        Ok(Self::parse_xml_with_request_id(response).await?.0)
+    }
+
+    /// 读取响应体并按 XML 解析为 `T`，同时返回响应头中的请求 ID（`x-cos-request-id`）
+    pub async fn parse_xml_with_request_id<T: DeserializeOwned>(response: CosResponse) -> Result<(T, Option<String>)> {
+        let request_id = response.request_id();
+        let text = response.text().await?;
+        Ok((parse_xml_str(&text)?, request_id))
     }
 
     /// 获取配置
     pub fn config(&self) -> &Config {
         &self.config
     }
+
+    /// 获取当前凭证，缓存的凭证临近过期时向 [`Config::credentials_provider`] 重新获取
+    ///
+    /// 预签名 URL 与表单上传策略在本地同步生成，使用缓存的凭证；使用凭证提供者时，
+    /// 需要先发送过请求或调用本方法加载凭证。
+    pub async fn credentials(&self) -> Result<Credentials> {
+        self.credentials.get().await
+    }
+
+    /// 获取缓存凭证对应的认证信息，用于同步生成的签名
+    pub(crate) fn auth(&self) -> Result<Auth> {
+        Ok(self.credentials.auth(&self.credentials.cached_or_err()?))
+    }
+}
+
+/// 取出内存中请求体的 `Bytes` 而不复制数据，流式请求体原样返回
+///
+/// reqwest 以 `Bytes` 保存内存中的请求体，读取时整块返回。
+fn reusable_bytes(mut body: reqwest::Body) -> std::result::Result<Bytes, reqwest::Body> {
+    use http_body::Body as _;
+    use std::task::{Context, Poll};
+
+    if body.as_bytes().is_none() {
+        return Err(body);
+    }
+    let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+    let mut chunks = Vec::new();
+    while let Poll::Ready(Some(Ok(frame))) = std::pin::Pin::new(&mut body).poll_frame(&mut cx) {
+        chunks.extend(frame.into_data().ok());
+    }
+    Ok(match chunks.len() {
+        0 => Bytes::new(),
+        1 => chunks.remove(0),
+        _ => chunks.concat().into(),
+    })
+}
+
+/// URL 对应的 Host 请求头，端口为协议默认端口时省略
+fn host_header(url: &url::Url) -> Option<String> {
+    let host = url.host_str()?;
+    Some(match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    })
+}
+
+/// 将 XML 文本解析为 `T`，错误信息中包含目标类型名
+pub(crate) fn parse_xml_str<T: DeserializeOwned>(text: &str) -> Result<T> {
+    let type_name = std::any::type_name::<T>();
+    let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+    if text.trim().is_empty() {
+        return Err(CosError::other(format!("Failed to parse {}: empty response body", type_name)));
+    }
+    quick_xml::de::from_str(text).map_err(|e| CosError::other(format!("Failed to parse {}: {}", type_name, e)))
+}
+
+/// 错误响应是否表示签名时间不在有效期内：过期、尚未生效或与服务端时间相差过大
+fn is_time_error(body: &str) -> bool {
+    let error = CosError::from_response(StatusCode::FORBIDDEN, body.to_string(), None);
+    let message = match &error {
+        CosError::Client { message, .. } => message.to_ascii_lowercase(),
+        _ => return false,
+    };
+    match error.code() {
+        Some("RequestTimeTooSkewed") => true,
+        Some("AccessDenied") | Some("SignatureDoesNotMatch") => {
+            message.contains("expired") || message.contains("not yet")
+        }
+        _ => false,
+    }
 }
 
 /// URL 编码工具
+///
+/// 按 RFC 3986 编码，空格编码为 `%20` 而不是 `+`，与 COS 服务端计算签名的方式一致
 mod urlencoding {
     pub fn encode(input: &str) -> String {
-        url::form_urlencoded::byte_serialize(input.as_bytes()).collect()
+        ::urlencoding::encode(input).into_owned()
     }
 }
 
+/// 按路径段对对象键或请求路径进行百分号编码，保留 `/`
+///
+/// 每段按 RFC 3986 编码，只保留非保留字符，空格编码为 `%20`，`+` 编码为 `%2B`；连续的 `/` 原样保留。
+pub(crate) fn encode_key(key: &str) -> String {
+    key.split('/')
+        .map(urlencoding::encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 生成查询字符串，值为空的参数只写参数名，如 `?uploads`、`?imageMogr2/thumbnail/200x`
+fn query_string(params: &HashMap<String, String>) -> String {
+    params
+        .iter()
+        .map(|(k, v)| {
+            let k = urlencoding::encode(k).replace("%2F", "/");
+            if v.is_empty() {
+                k
+            } else {
+                format!("{}={}", k, urlencoding::encode(v))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_server::{proxied_client_for, recording_client_for, serve, ServedResponse};
     use std::time::Duration as StdDuration;
 
     #[test]
@@ -238,4 +938,725 @@ mod tests {
         assert!(url.contains("test-bucket-123.cos.ap-beijing.myqcloud.com"));
         assert!(url.contains("key=value"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_key_encoding_and_signature() {
+        // 签名按 COS 文档的算法独立计算：URL 中的路径按段编码，签名使用未编码的路径（与官方 SDK 一致）
+        let cases = [
+            ("a b.txt", "/a%20b.txt", "014b1db1e6236bf594cb72ddf8cbdfc9b48bd999"),
+            ("a+b.txt", "/a%2Bb.txt", "431d55a5d61552f07c3d6fce3489a9e077ced9c0"),
+            ("a#b?c.txt", "/a%23b%3Fc.txt", "d2ec636f433195f86d5ac314ae57d9c235d9439c"),
+            (
+                "中文/文件.txt",
+                "/%E4%B8%AD%E6%96%87/%E6%96%87%E4%BB%B6.txt",
+                "062a7344ed2f882c937e9b438547e035fa425a5d",
+            ),
+            ("dir//x.txt", "/dir//x.txt", "51c7391818c68706b789b83531634b45994c1bed"),
+            ("100%.txt", "/100%25.txt", "aa46773ed016d594107fcc400b5cd5274471a8d6"),
+            ("~tilde_-.txt", "/~tilde_-.txt", "140d156d12216ce6168ed5e5beb3ad3a82ebccc9"),
+        ];
+
+        let config = Config::new(
+            "AKIDQjz3ltompVjBni5LitkWHFlFpwkn9U5q",
+            "BQYIM75p8x0iWVFSIgqEKwFprpRSVHlz",
+            "ap-beijing",
+            "examplebucket-1250000000",
+        );
+        let client = CosClient::new(config).unwrap();
+        let host = "examplebucket-1250000000.cos.ap-beijing.myqcloud.com";
+        let mut headers = HashMap::new();
+        headers.insert("host".to_string(), host.to_string());
+        let start = chrono::TimeZone::timestamp_opt(&Utc, 1557989151, 0).unwrap();
+        let end = chrono::TimeZone::timestamp_opt(&Utc, 1557996351, 0).unwrap();
+
+        for (key, encoded, signature) in cases {
+            let path = format!("/{}", key);
+            assert_eq!(
+                client.build_url(&path, &HashMap::new()).unwrap(),
+                format!("https://{}{}", host, encoded),
+                "key {:?}",
+                key
+            );
+            let authorization = client
+                .auth()
+                .unwrap()
+                .sign("GET", &client.signed_path(&path), &headers, &HashMap::new(), start, end)
+                .unwrap();
+            assert!(
+                authorization.ends_with(&format!("&q-signature={}", signature)),
+                "key {:?}: {}",
+                key,
+                authorization
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_for_status_preconditions() {
+        let response = |status: u16, body: &'static str| {
+            CosResponse::new(http::Response::builder().status(status).body(body).unwrap().into(), None)
+        };
+
+        let err = CosClient::error_for_status(response(
+            409,
+            "<Error><Code>FileAlreadyExists</Code></Error>",
+        ))
+        .await
+        .unwrap_err();
+        assert!(matches!(err, CosError::PreconditionFailed { .. }));
+
+        let err = CosClient::error_for_status(response(412, "")).await.unwrap_err();
+        assert!(matches!(err, CosError::PreconditionFailed { .. }));
+
+        let err = CosClient::error_for_status(response(304, "")).await.unwrap_err();
+        assert!(matches!(err, CosError::NotModified));
+
+        let err = CosClient::error_for_status(response(409, "<Error><Code>BucketNotEmpty</Code></Error>"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CosError::Client { .. }));
+        assert_eq!(err.code(), Some("BucketNotEmpty"));
+
+        let err = CosClient::error_for_status(response(503, "<Error><Code>SlowDown</Code></Error>"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CosError::Server { .. }));
+
+        assert!(CosClient::error_for_status(response(200, "")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_path_style_request() {
+        let (client, requests) = proxied_client_for(
+            Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123").with_path_style(true),
+            vec![ServedResponse::new(200, "")],
+        )
+        .await;
+        assert_eq!(client.signed_path("/a.txt"), "/test-bucket-123/a.txt");
+        assert_eq!(client.signed_path("/"), "/test-bucket-123/");
+
+        client.get("/a.txt", HashMap::new()).await.unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            requests[0].request_line,
+            "GET http://cos.ap-beijing.myqcloud.com/test-bucket-123/a.txt HTTP/1.1"
+        );
+        assert_eq!(requests[0].header("host"), Some("cos.ap-beijing.myqcloud.com"));
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_correction() {
+        let server_time = Utc::now() + Duration::minutes(10);
+        let (client, requests) = recording_client_for(vec![
+            ServedResponse::new(
+                403,
+                "<Error><Code>RequestTimeTooSkewed</Code>\
+                 <Message>The difference between the request time and the server's time is too large.</Message></Error>",
+            )
+            .with_header("Date", &server_time.to_rfc2822()),
+            ServedResponse::new(200, "ok"),
+        ])
+        .await;
+
+        let response = client.put("/a.txt", HashMap::new(), Some("data")).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "ok");
+        assert!((client.clock_skew() - Duration::minutes(10)).num_seconds().abs() <= 5);
+
+        // 重试的签名按服务端时间生成
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].body, b"data");
+        let authorization = requests[1].header("authorization").unwrap();
+        let sign_time = authorization
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("q-sign-time="))
+            .unwrap();
+        let start: i64 = sign_time.split(';').next().unwrap().parse().unwrap();
+        assert!((start - (server_time - Duration::minutes(5)).timestamp()).abs() <= 5);
+    }
+
+    #[tokio::test]
+    async fn test_access_denied_is_not_retried() {
+        let (client, requests) = recording_client_for(vec![ServedResponse::new(
+            403,
+            "<Error><Code>AccessDenied</Code><Message>Access Denied.</Message></Error>",
+        )
+        .with_header("Date", &Utc::now().to_rfc2822())])
+        .await;
+
+        let err = client.get("/a.txt", HashMap::new()).await.unwrap_err();
+        assert_eq!(err.code(), Some("AccessDenied"));
+        assert_eq!(err.status(), Some(403));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert_eq!(client.clock_skew(), Duration::zero());
+    }
+
+    #[tokio::test]
+    async fn test_parse_xml() {
+        #[derive(Debug, serde::Deserialize)]
+        struct LocationConstraint {
+            #[serde(rename = "$text")]
+            region: String,
+        }
+        let response = |body: &'static str| {
+            CosResponse::new(
+                http::Response::builder()
+                    .header("x-cos-request-id", "req-1")
+                    .body(body)
+                    .unwrap()
+                    .into(),
+                None,
+            )
+        };
+
+        let (location, request_id): (LocationConstraint, _) = CosClient::parse_xml_with_request_id(response(
+            "<?xml version='1.0' encoding='utf-8' ?><LocationConstraint>ap-beijing</LocationConstraint>",
+        ))
+        .await
+        .unwrap();
+        assert_eq!(location.region, "ap-beijing");
+        assert_eq!(request_id.as_deref(), Some("req-1"));
+
+        let err = CosClient::parse_xml::<LocationConstraint>(response("")).await.unwrap_err();
+        assert_eq!(err.to_string(), "Other error: Failed to parse LocationConstraint: empty response body");
+
+        let err = CosClient::parse_xml::<LocationConstraint>(response("<LocationConstraint>ap-beijing"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Other error: Failed to parse LocationConstraint:"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_execute_custom_request() {
+        let (client, requests) = recording_client_for(vec![ServedResponse::new(200, "<ObjectLockConfiguration/>")]).await;
+
+        let response = client
+            .execute(
+                CustomRequest::new(Method::PUT, "dir/a b.txt")
+                    .with_query("x-custom", "a&b")
+                    .with_header("x-cos-meta-note", "hi")
+                    .with_body("payload"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "<ObjectLockConfiguration/>");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].request_line, "PUT /dir/a%20b.txt?x-custom=a%26b HTTP/1.1");
+        assert_eq!(requests[0].header("x-cos-meta-note"), Some("hi"));
+        assert_eq!(requests[0].body, b"payload");
+        let authorization = requests[0].header("authorization").unwrap();
+        assert!(authorization.contains("q-header-list=content-length;host;x-cos-meta-note"), "{}", authorization);
+        assert!(authorization.contains("q-url-param-list=x-custom"), "{}", authorization);
+    }
+
+    fn wrong_region_redirect() -> ServedResponse {
+        ServedResponse::new(301, "<Error><Code>PermanentRedirect</Code></Error>")
+            .with_header("x-cos-bucket-region", "ap-guangzhou")
+    }
+
+    #[tokio::test]
+    async fn test_wrong_region_error() {
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let (client, _) = proxied_client_for(config, vec![wrong_region_redirect()]).await;
+
+        let err = client.get("/a.txt", HashMap::new()).await.unwrap_err();
+        match err {
+            CosError::WrongRegion { expected, configured } => {
+                assert_eq!(expected, "ap-guangzhou");
+                assert_eq!(configured, "ap-beijing");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(client.region(), "ap-beijing");
+    }
+
+    #[tokio::test]
+    async fn test_wrong_region_auto_correct() {
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123").with_auto_correct_region(true);
+        let (client, requests) = proxied_client_for(
+            config,
+            vec![
+                wrong_region_redirect(),
+                ServedResponse::new(200, "corrected"),
+                ServedResponse::new(200, "cached"),
+            ],
+        )
+        .await;
+
+        let response = client.put("/a.txt", HashMap::new(), Some("data")).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "corrected");
+        assert_eq!(client.region(), "ap-guangzhou");
+
+        // 纠正后的地域由客户端的副本共享
+        let copy = client.clone();
+        assert_eq!(copy.get("/b.txt", HashMap::new()).await.unwrap().text().await.unwrap(), "cached");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            requests[0].request_line,
+            "PUT http://test-bucket-123.cos.ap-beijing.myqcloud.com/a.txt HTTP/1.1"
+        );
+        assert_eq!(
+            requests[1].request_line,
+            "PUT http://test-bucket-123.cos.ap-guangzhou.myqcloud.com/a.txt HTTP/1.1"
+        );
+        assert_eq!(requests[1].body, b"data");
+        assert_eq!(requests[1].header("host"), Some("test-bucket-123.cos.ap-guangzhou.myqcloud.com"));
+        assert_eq!(
+            requests[2].request_line,
+            "GET http://test-bucket-123.cos.ap-guangzhou.myqcloud.com/b.txt HTTP/1.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_security_token_is_sent_and_signed() {
+        let credentials = crate::sts::TemporaryCredentials {
+            tmp_secret_id: "tmp_id".to_string(),
+            tmp_secret_key: "tmp_key".to_string(),
+            token: "session-token".to_string(),
+            expired_time: None,
+        };
+        let config = Config::from_temporary_credentials(&credentials, "ap-beijing", "test-bucket-123");
+        let transport = crate::testing::MockTransport::new();
+        transport.push_response(crate::testing::MockResponse::new(200, ""));
+        let client = transport.client(config).unwrap();
+        client.put("/a.txt", HashMap::new(), Some("data")).await.unwrap();
+
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.header("x-cos-security-token"), Some("session-token"));
+        assert!(request.signed_headers().contains(&"x-cos-security-token".to_string()));
+        assert!(request.header("authorization").unwrap().contains("q-ak=tmp_id&"));
+
+        // 预签名 URL 将令牌作为查询参数签名并携带
+        let url = client
+            .presign_url(&Method::GET, "/a.txt", &HashMap::new(), HashMap::new(), StdDuration::from_secs(60))
+            .unwrap();
+        let url = url::Url::parse(&url).unwrap();
+        let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!(query["x-cos-security-token"], "session-token");
+        assert_eq!(query["q-url-param-list"], "x-cos-security-token");
+        assert_eq!(query["q-header-list"], "host");
+    }
+
+    #[tokio::test]
+    async fn test_credentials_provider() {
+        let provider = crate::credentials::StaticCredentialsProvider::new(
+            Credentials::new("provider_id", "provider_key").with_security_token("provider-token"),
+        );
+        let config = Config::new("", "", "ap-beijing", "test-bucket-123").with_credentials_provider(provider);
+        let transport = crate::testing::MockTransport::new();
+        transport.push_response(crate::testing::MockResponse::new(200, ""));
+        let client = transport.client(config).unwrap();
+
+        // 未加载凭证前无法同步生成签名
+        assert!(client
+            .presign_url(&Method::GET, "/a.txt", &HashMap::new(), HashMap::new(), StdDuration::from_secs(60))
+            .is_err());
+
+        client.get("/a.txt", HashMap::new()).await.unwrap();
+        let request = transport.last_request().unwrap();
+        assert!(request.header("authorization").unwrap().contains("q-ak=provider_id&"));
+        assert_eq!(request.header("x-cos-security-token"), Some("provider-token"));
+
+        let url = client
+            .presign_url(&Method::GET, "/a.txt", &HashMap::new(), HashMap::new(), StdDuration::from_secs(60))
+            .unwrap();
+        assert!(url.contains("q-ak=provider_id&"), "{}", url);
+    }
+
+    #[tokio::test]
+    async fn test_custom_domain_with_port() {
+        let cases = [
+            (
+                Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+                    .with_domain("localhost:9000")
+                    .with_https(false),
+                "http://localhost:9000/a.txt",
+                "localhost:9000",
+            ),
+            (
+                Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123").with_domain("cdn.example.com:443"),
+                "https://cdn.example.com/a.txt",
+                "cdn.example.com",
+            ),
+        ];
+        for (config, url, host) in cases {
+            let transport = crate::testing::MockTransport::new();
+            transport.push_response(crate::testing::MockResponse::new(200, ""));
+            let client = transport.client(config).unwrap();
+            client.get("/a.txt", HashMap::new()).await.unwrap();
+
+            let request = transport.last_request().unwrap();
+            assert_eq!(request.url.as_str(), url);
+            assert_eq!(request.header("host"), Some(host));
+            assert!(request.signed_headers().contains(&"host".to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let retry = RetryConfig::default().with_backoff(StdDuration::from_millis(1), StdDuration::from_millis(2));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123").with_retry(retry);
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::error(503, "ServiceUnavailable", "busy"))
+            .push_response(MockResponse::error(400, "RequestTimeout", "timed out"))
+            .push_response(MockResponse::new(200, "ok"));
+        let client = transport.client(config.clone()).unwrap();
+        let response = client.put("/a.txt", HashMap::new(), Some("data")).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "ok");
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|request| request.body.as_deref() == Some(&b"data"[..])));
+
+        // 重试次数用尽后返回最后一次的错误
+        for _ in 0..3 {
+            transport.push_response(MockResponse::error(500, "InternalError", "oops"));
+        }
+        let err = client.get("/a.txt", HashMap::new()).await.unwrap_err();
+        assert_eq!(err.code(), Some("InternalError"));
+        assert_eq!(transport.requests().len(), 6);
+
+        // 不在策略内的错误与流式请求体不重试
+        transport.push_response(MockResponse::error(404, "NoSuchKey", "missing"));
+        assert!(client.get("/a.txt", HashMap::new()).await.is_err());
+        transport.push_response(MockResponse::error(503, "ServiceUnavailable", "busy"));
+        let stream = futures::stream::iter([Ok::<_, std::io::Error>(Bytes::from_static(b"data"))]);
+        let body = reqwest::Body::wrap_stream(stream);
+        assert!(client.put("/a.txt", HashMap::new(), Some(body)).await.is_err());
+        assert_eq!(transport.requests().len(), 8);
+
+        // 默认不重试，按调用覆盖的策略生效
+        let client = transport
+            .client(Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123"))
+            .unwrap();
+        transport.push_response(MockResponse::error(503, "ServiceUnavailable", "busy"));
+        assert!(client.get("/a.txt", HashMap::new()).await.is_err());
+        assert_eq!(transport.requests().len(), 9);
+        transport
+            .push_response(MockResponse::error(503, "ServiceUnavailable", "busy"))
+            .push_response(MockResponse::new(200, ""));
+        let retry = RetryConfig::default().with_backoff(StdDuration::ZERO, StdDuration::ZERO);
+        let client = client.with_retry(Some(&retry)).unwrap();
+        assert!(client.get("/a.txt", HashMap::new()).await.is_ok());
+        assert_eq!(transport.pending_responses(), 0);
+        assert!(client.with_retry(Some(&RetryConfig::default().with_max_attempts(0))).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_reqwest_transport() {
+        use std::net::SocketAddr;
+        use std::sync::atomic::AtomicUsize;
+
+        /// 把所有域名解析到固定地址，并记录解析次数，即建立连接的次数
+        struct CountingResolver {
+            addr: SocketAddr,
+            lookups: AtomicUsize,
+        }
+
+        impl reqwest::dns::Resolve for CountingResolver {
+            fn resolve(&self, _: reqwest::dns::Name) -> reqwest::dns::Resolving {
+                self.lookups.fetch_add(1, Ordering::SeqCst);
+                let addrs: reqwest::dns::Addrs = Box::new(std::iter::once(self.addr));
+                Box::pin(async move { Ok(addrs) })
+            }
+        }
+
+        let retry = RetryConfig::default()
+            .with_max_attempts(3)
+            .with_backoff(StdDuration::from_millis(1), StdDuration::from_millis(2));
+
+        // 建立连接失败总是重试
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let resolver = Arc::new(CountingResolver {
+            addr,
+            lookups: AtomicUsize::new(0),
+        });
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+            .with_domain(format!("cos.test:{}", addr.port()))
+            .with_https(false)
+            .with_retry(retry.clone());
+        let builder = reqwest::Client::builder().no_proxy().dns_resolver(resolver.clone());
+        let client = CosClient::build_with(config, builder).unwrap();
+        let err = client.put("/a.txt", HashMap::new(), Some("data")).await.unwrap_err();
+        assert!(matches!(&err, CosError::Http(e) if e.is_connect()), "{:?}", err);
+        assert_eq!(resolver.lookups.load(Ordering::SeqCst), 3);
+
+        // 超时按 retry_on_timeouts 决定是否重试
+        let slow = || ServedResponse::new(200, "slow").with_delay(StdDuration::from_secs(2));
+        let (addr, requests) = serve(vec![slow(), slow(), ServedResponse::new(200, "ok")]).await;
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+            .with_domain(&addr)
+            .with_https(false)
+            .with_timeout(StdDuration::from_millis(200));
+        let client = CosClient::new(config.clone().with_retry(retry.clone())).unwrap();
+        let response = client.put("/a.txt", HashMap::new(), Some("data")).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "ok");
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        let (addr, requests) = serve(vec![slow(), ServedResponse::new(200, "ok")]).await;
+        let client = CosClient::new(
+            config
+                .with_domain(&addr)
+                .with_retry(retry.with_retry_on_timeouts(false)),
+        )
+        .unwrap();
+        let err = client.get("/a.txt", HashMap::new()).await.unwrap_err();
+        assert!(matches!(&err, CosError::Http(e) if e.is_timeout()), "{:?}", err);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_reusable_bytes() {
+        let data = Bytes::from(vec![7u8; 4096]);
+        let bytes = reusable_bytes(reqwest::Body::from(data.clone())).unwrap();
+        assert_eq!(bytes, data);
+        // 与原请求体共享数据
+        assert_eq!(bytes.as_ptr(), data.as_ptr());
+        assert_eq!(reusable_bytes(reqwest::Body::from(Vec::new())).unwrap(), Bytes::new());
+
+        let stream = futures::stream::iter([Ok::<_, std::io::Error>(Bytes::from_static(b"data"))]);
+        assert!(reusable_bytes(reqwest::Body::wrap_stream(stream)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_default_headers() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+            .with_default_header("x-cos-tagging", "team=storage")
+            .with_default_header("x-cos-meta-cost-center", "cc-42")
+            .with_default_header("Referer", "https://app.example.com");
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(200, ""))
+            .push_response(MockResponse::new(200, ""));
+        let client = transport.client(config.clone()).unwrap();
+
+        client.get("/a.txt", HashMap::new()).await.unwrap();
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.header("x-cos-tagging"), Some("team=storage"));
+        assert_eq!(request.header("x-cos-meta-cost-center"), Some("cc-42"));
+        assert_eq!(request.header("referer"), Some("https://app.example.com"));
+        let signed = request.signed_headers();
+        for name in ["x-cos-tagging", "x-cos-meta-cost-center", "referer", "host"] {
+            assert!(signed.contains(&name.to_string()), "{:?}", signed);
+        }
+
+        // 请求自身的请求头优先，不区分大小写
+        let mut headers = HashMap::new();
+        headers.insert("X-Cos-Tagging".to_string(), "team=billing".to_string());
+        client.put_with_headers("/a.txt", HashMap::new(), headers, Some("data")).await.unwrap();
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.headers.get_all("x-cos-tagging").iter().count(), 1);
+        assert_eq!(request.header("x-cos-tagging"), Some("team=billing"));
+
+        for name in ["Host", "authorization"] {
+            let err = CosClient::new(config.clone().with_default_header(name, "x")).unwrap_err();
+            assert!(err.to_string().contains("set by the SDK"), "{}", err);
+        }
+        assert!(CosClient::new(config.clone().with_default_header("bad header", "x")).is_err());
+        assert!(CosClient::new(config.with_default_header("x-cos-meta-a", "line\nbreak")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_signature_window() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let sign_time = |query: &str| -> (i64, i64) {
+            let sign_time = query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("q-sign-time="))
+                .unwrap();
+            let (start, end) = sign_time.split_once(';').unwrap();
+            (start.parse().unwrap(), end.parse().unwrap())
+        };
+        let transport = MockTransport::new();
+        transport.push_response(MockResponse::new(200, ""));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+            .with_signature_validity(StdDuration::from_secs(6 * 3600))
+            .with_clock_skew_allowance(StdDuration::from_secs(30));
+        let client = transport.client(config).unwrap();
+
+        let now = Utc::now().timestamp();
+        client.get("/a.txt", HashMap::new()).await.unwrap();
+        let (start, end) = sign_time(transport.last_request().unwrap().header("authorization").unwrap());
+        assert_eq!(end - start, 6 * 3600 + 30);
+        assert!((start - (now - 30)).abs() <= 5, "{} {}", start, now);
+
+        let url = client
+            .presign_url(&Method::GET, "/a.txt", &HashMap::new(), HashMap::new(), StdDuration::from_secs(600))
+            .unwrap();
+        let (start, end) = sign_time(url.split_once('?').unwrap().1);
+        assert_eq!(end - start, 600);
+    }
+
+    #[tokio::test]
+    async fn test_content_and_cos_headers_are_signed() {
+        use crate::testing::{MockResponse, MockTransport};
+        use chrono::TimeZone;
+
+        let transport = MockTransport::new();
+        transport.push_response(MockResponse::new(200, ""));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let client = transport.client(config).unwrap();
+
+        let headers = HashMap::from([
+            ("content-type".to_string(), "text/plain".to_string()),
+            ("Content-MD5".to_string(), "jxpVXX4E2cbRFCcmTAcA1A==".to_string()),
+            ("x-cos-meta-author".to_string(), "Zhang San".to_string()),
+            ("X-Cos-Storage-Class".to_string(), "STANDARD_IA".to_string()),
+            ("user-agent".to_string(), "custom-agent".to_string()),
+        ]);
+        let params = HashMap::from([("partNumber".to_string(), "1".to_string())]);
+        client
+            .put_with_headers("/dir/a b.txt", params.clone(), headers, Some("hello cos"))
+            .await
+            .unwrap();
+
+        let request = transport.last_request().unwrap();
+        assert_eq!(
+            request.signed_headers(),
+            ["content-length", "content-md5", "content-type", "host", "x-cos-meta-author", "x-cos-storage-class"]
+        );
+        assert_eq!(request.header("content-length"), Some("9"));
+        assert_eq!(request.headers.get_all("user-agent").iter().count(), 1);
+        assert_eq!(request.header("user-agent"), Some("custom-agent"));
+
+        // 只有大小写不同的请求头合并为一个发送，取值与签名一致
+        transport.push_response(MockResponse::new(200, ""));
+        let headers = HashMap::from([
+            ("X-Cos-Meta-Tag".to_string(), "b".to_string()),
+            ("x-cos-meta-tag".to_string(), "a".to_string()),
+        ]);
+        client.put_with_headers("/b.txt", HashMap::new(), headers, Some("x")).await.unwrap();
+        let merged = transport.last_request().unwrap();
+        assert_eq!(merged.headers.get_all("x-cos-meta-tag").iter().count(), 1);
+        assert_eq!(merged.header("x-cos-meta-tag"), Some("a,b"));
+        assert!(merged.signed_headers().contains(&"x-cos-meta-tag".to_string()));
+
+        // 按请求实际携带的请求头重新计算，签名一致
+        let authorization = request.header("authorization").unwrap();
+        let sign_time = authorization
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("q-sign-time="))
+            .unwrap();
+        let (start, end) = sign_time.split_once(';').unwrap();
+        let time = |secs: &str| Utc.timestamp_opt(secs.parse().unwrap(), 0).unwrap();
+        let signed: HashMap<String, String> = request
+            .signed_headers()
+            .into_iter()
+            .map(|name| {
+                let value = request.header(&name).unwrap().to_string();
+                (name, value)
+            })
+            .collect();
+        let expected = Auth::new("test_id", "test_key")
+            .sign("PUT", "/dir/a b.txt", &signed, &params, time(start), time(end))
+            .unwrap();
+        assert_eq!(authorization, expected);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_override() {
+        let (addr, requests) = serve(vec![ServedResponse::new(200, ""), ServedResponse::new(200, "")]).await;
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+            .with_endpoint(format!("http://{}", addr))
+            .with_path_style(true);
+        let client = CosClient::new(config).unwrap();
+
+        client.get("/a.txt", HashMap::new()).await.unwrap();
+        client.get_service(&client.config().service_url(), HashMap::new()).await.unwrap();
+        let url = client
+            .presign_url(&Method::GET, "/a.txt", &HashMap::new(), HashMap::new(), StdDuration::from_secs(60))
+            .unwrap();
+        assert!(url.starts_with(&format!("http://{}/test-bucket-123/a.txt?", addr)), "{}", url);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].request_line, "GET /test-bucket-123/a.txt HTTP/1.1");
+        assert_eq!(requests[1].request_line, "GET / HTTP/1.1");
+        for request in requests.iter() {
+            assert_eq!(request.header("host"), Some(addr.as_str()));
+            assert!(request.header("authorization").unwrap().contains("q-header-list=host&"));
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    fn test_tls_config() {
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        assert!(CosClient::new(config.clone().with_danger_accept_invalid_certs(true)).is_ok());
+
+        let err = CosClient::new(config.with_root_certificate("not a certificate")).unwrap_err();
+        assert!(err.to_string().contains("Invalid root certificate"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_max_response_body_size() {
+        let transport = crate::testing::MockTransport::new();
+        transport
+            .push_response(crate::testing::MockResponse::new(200, "0123456789"))
+            .push_response(crate::testing::MockResponse::new(200, "0123456789a"))
+            .push_response(crate::testing::MockResponse::error(404, "NoSuchKey", &"x".repeat(64)));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123").with_max_response_body_size(10);
+        let client = transport.client(config).unwrap();
+
+        let response = client.get("/a.txt", HashMap::new()).await.unwrap();
+        assert_eq!(response.bytes().await.unwrap(), "0123456789");
+
+        let response = client.get("/b.txt", HashMap::new()).await.unwrap();
+        let err = response.bytes().await.unwrap_err();
+        assert!(matches!(&err, CosError::Client { code, status: Some(200), .. } if code == "ResponseTooLarge"), "{:?}", err);
+        assert!(err.to_string().contains("limit of 10 bytes"), "{}", err);
+
+        // 错误响应体同样受限制
+        let err = client.get("/c.txt", HashMap::new()).await.unwrap_err();
+        assert!(matches!(&err, CosError::Client { code, status: Some(404), .. } if code == "ResponseTooLarge"), "{:?}", err);
+    }
+
+    /// 记录同时执行的最大请求数的传输层
+    #[derive(Debug, Default)]
+    struct ConcurrencyProbe {
+        current: AtomicUsize,
+        max: AtomicUsize,
+    }
+
+    impl HttpTransport for ConcurrencyProbe {
+        fn execute(&self, _request: reqwest::Request) -> futures::future::BoxFuture<'_, Result<reqwest::Response>> {
+            Box::pin(async move {
+                let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(StdDuration::from_millis(20)).await;
+                self.current.fetch_sub(1, Ordering::SeqCst);
+                Ok(reqwest::Response::from(http::Response::new(Bytes::new())))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests() {
+        let probe = Arc::new(ConcurrencyProbe::default());
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123").with_max_concurrent_requests(2);
+        let client = CosClient::new(config).unwrap().with_transport(probe.clone());
+
+        let requests = (0..6).map(|i| {
+            let client = client.clone();
+            async move { client.get(&format!("/{}.txt", i), HashMap::new()).await }
+        });
+        let in_flight = async {
+            tokio::time::sleep(StdDuration::from_millis(10)).await;
+            client.in_flight_requests()
+        };
+        let (results, in_flight) = tokio::join!(futures::future::join_all(requests), in_flight);
+
+        assert!(results.iter().all(|r| r.is_ok()));
+        assert_eq!(probe.max.load(Ordering::SeqCst), 2);
+        assert_eq!(in_flight, 2);
+        assert_eq!(client.in_flight_requests(), 0);
+    }
+}
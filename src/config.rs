@@ -1,8 +1,20 @@
 //! 配置模块
 
 use crate::error::{CosError, Result};
+use crate::credentials::{Credentials, CredentialsProvider};
+use crate::multipart::MIN_PART_SIZE;
+use crate::retry::RetryConfig;
+use crate::sts::TemporaryCredentials;
+use crate::transfer::{DEFAULT_CONCURRENCY, DEFAULT_MULTIPART_THRESHOLD, DEFAULT_PART_SIZE};
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// 默认的请求头签名有效期
+pub const DEFAULT_SIGNATURE_VALIDITY: Duration = Duration::from_secs(3600);
+/// 默认的请求头签名生效时间提前时长
+pub const DEFAULT_CLOCK_SKEW_ALLOWANCE: Duration = Duration::from_secs(300);
+
 /// COS 客户端配置
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -10,6 +22,10 @@ pub struct Config {
     pub secret_id: String,
     /// 腾讯云 SecretKey
     pub secret_key: String,
+    /// 临时密钥的安全令牌，设置后每个请求都携带 `x-cos-security-token` 请求头
+    pub security_token: Option<String>,
+    /// 动态凭证提供者，设置后忽略上面的密钥与安全令牌，每次请求前向提供者获取凭证
+    pub credentials_provider: Option<Arc<dyn CredentialsProvider>>,
     /// 地域
     pub region: String,
     /// 存储桶名称
@@ -20,8 +36,71 @@ pub struct Config {
     pub use_https: bool,
     /// 自定义域名
     pub domain: Option<String>,
+    /// 完整的服务地址（含协议），如 `http://127.0.0.1:9000`，设置后代替由地域推导的存储桶与服务地址，
+    /// 用于本地模拟服务或私有化部署
+    pub endpoint: Option<String>,
     /// 应用 ID（从存储桶名称中提取）
     pub app_id: Option<String>,
+    /// 上传本地文件时无法根据扩展名推断 Content-Type，是否读取文件开头按内容检测
+    pub sniff_content_type: bool,
+    /// 下载简单上传的对象时，校验数据的 MD5 与 ETag 是否一致
+    pub verify_md5: bool,
+    /// 文件大小达到该值时使用分块上传
+    pub multipart_threshold: u64,
+    /// 分块上传和断点续传下载的分块大小，不小于 1MB；分块数超过 10000 时会自动增大
+    pub part_size: u64,
+    /// 同时上传或下载的分块数量
+    pub max_upload_concurrency: usize,
+    /// 发送请求前是否校验对象键，并合并键中连续的 `/`
+    pub validate_keys: bool,
+    /// 是否使用全球加速域名 `<bucket>.cos.accelerate.myqcloud.com`，需要存储桶已开启全球加速
+    pub use_accelerate: bool,
+    /// 地域配置错误时，是否按服务端返回的 `x-cos-bucket-region` 自动改用正确的地域重试请求；
+    /// 关闭时返回 [`CosError::WrongRegion`]
+    pub auto_correct_region: bool,
+    /// 是否使用路径风格的地址 `<endpoint>/<bucket>`，用于只支持路径风格的 S3 兼容服务或本地模拟服务
+    pub path_style: bool,
+    /// 访问 COS 使用的网络，默认使用公网域名
+    pub network_type: NetworkType,
+    /// 客户端（包括其所有副本）同时发送的最大请求数，为空时不限制
+    pub max_concurrent_requests: Option<usize>,
+    /// 额外信任的根证书（PEM 格式），用于使用私有 CA 的服务
+    pub root_certificates: Vec<Vec<u8>>,
+    /// 是否跳过 TLS 证书校验，只应用于使用自签名证书的测试环境
+    pub danger_accept_invalid_certs: bool,
+    /// 是否只使用 HTTP/1.1，用于会中断 HTTP/2 连接的代理或网关
+    pub http1_only: bool,
+    /// 建立连接的超时时间，为空时只受请求超时限制
+    pub connect_timeout: Option<Duration>,
+    /// 读取到内存中的响应体（列表结果、错误信息、非流式下载等）的最大字节数，为空时不限制；
+    /// 流式下载不受限制
+    pub max_response_body_size: Option<u64>,
+    /// 请求失败后的重试策略，默认不重试
+    pub retry: RetryConfig,
+    /// 每个请求都携带的请求头，与请求自身的请求头合并后参与签名，同名时以请求自身的为准
+    pub default_headers: HashMap<String, String>,
+    /// 请求头签名的有效期，从签名时起计算，默认一小时；分片很多、链路很慢的上传可以适当延长
+    pub signature_validity: Duration,
+    /// 请求头签名的生效时间相对当前时间提前的时长，容忍本地与服务端的时钟偏差，默认五分钟
+    pub clock_skew_allowance: Duration,
+    /// 由 [`ConfigBuilder`] 创建时启用的严格校验
+    pub(crate) strict: Option<StrictValidation>,
+}
+
+/// [`ConfigBuilder`] 启用的额外校验
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StrictValidation {
+    allow_unknown_region: bool,
+}
+
+/// 访问 COS 使用的网络
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NetworkType {
+    /// 公网域名 `cos.<region>.myqcloud.com`
+    #[default]
+    Public,
+    /// 内网域名 `cos-internal.<region>.tencentcos.cn`，只能在同地域的腾讯云内网（如 CVM）中访问，不产生外网流量费用
+    Internal,
 }
 
 impl Config {
@@ -38,12 +117,81 @@ impl Config {
         Self {
             secret_id: secret_id.into(),
             secret_key: secret_key.into(),
+            security_token: None,
+            credentials_provider: None,
             region: region.into(),
             bucket: bucket_name,
             timeout: Duration::from_secs(30),
             use_https: true,
             domain: None,
+            endpoint: None,
             app_id,
+            sniff_content_type: true,
+            verify_md5: true,
+            multipart_threshold: DEFAULT_MULTIPART_THRESHOLD,
+            part_size: DEFAULT_PART_SIZE,
+            max_upload_concurrency: DEFAULT_CONCURRENCY,
+            validate_keys: true,
+            use_accelerate: false,
+            auto_correct_region: false,
+            path_style: false,
+            network_type: NetworkType::Public,
+            max_concurrent_requests: None,
+            root_certificates: Vec::new(),
+            danger_accept_invalid_certs: false,
+            http1_only: false,
+            connect_timeout: None,
+            max_response_body_size: None,
+            retry: RetryConfig::disabled(),
+            default_headers: HashMap::new(),
+            signature_validity: DEFAULT_SIGNATURE_VALIDITY,
+            clock_skew_allowance: DEFAULT_CLOCK_SKEW_ALLOWANCE,
+            strict: None,
+        }
+    }
+
+    /// 使用 STS 临时密钥创建配置
+    pub fn from_temporary_credentials<S: Into<String>>(
+        credentials: &TemporaryCredentials,
+        region: S,
+        bucket: S,
+    ) -> Self {
+        Self::new(
+            credentials.tmp_secret_id.clone(),
+            credentials.tmp_secret_key.clone(),
+            region.into(),
+            bucket.into(),
+        )
+        .with_security_token(credentials.token.clone())
+    }
+
+    /// 设置临时密钥的安全令牌
+    pub fn with_security_token<S: Into<String>>(mut self, token: S) -> Self {
+        self.security_token = Some(token.into());
+        self
+    }
+
+    /// 设置动态凭证提供者，用于需要轮换的密钥
+    pub fn with_credentials_provider<P: CredentialsProvider + 'static>(mut self, provider: P) -> Self {
+        self.credentials_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// 未设置凭证提供者时使用的固定凭证
+    pub(crate) fn static_credentials(&self) -> Credentials {
+        Credentials {
+            secret_id: self.secret_id.clone(),
+            secret_key: self.secret_key.clone(),
+            security_token: self.security_token.clone(),
+            expires_at: None,
+        }
+    }
+
+    /// 创建带严格校验的配置构建器，见 [`ConfigBuilder`]
+    pub fn builder<S: Into<String>>(secret_id: S, secret_key: S, region: S, bucket: S) -> ConfigBuilder {
+        ConfigBuilder {
+            config: Self::new(secret_id, secret_key, region, bucket),
+            allow_unknown_region: false,
         }
     }
 
@@ -59,53 +207,319 @@ impl Config {
         self
     }
 
-    /// 设置自定义域名
+    /// 设置自定义域名，格式为 `host[:port]`，不含协议，协议由 [`with_https`](Self::with_https) 决定
     pub fn with_domain<S: Into<String>>(mut self, domain: S) -> Self {
         self.domain = Some(domain.into());
         self
     }
 
+    /// 设置完整的服务地址，如 `http://127.0.0.1:9000`
+    ///
+    /// 存储桶请求、服务级请求与预签名 URL 都使用该地址，协议由地址决定，忽略 [`with_https`](Self::with_https)、
+    /// 全球加速与网络类型。开启路径风格时存储桶地址为 `<endpoint>/<bucket>`，否则为地址本身。
+    /// 签名使用请求实际携带的 Host。不能与自定义域名同时设置。
+    pub fn with_endpoint<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// 设置是否按文件内容检测 Content-Type，关闭后只根据扩展名推断
+    pub fn with_sniff_content_type(mut self, sniff: bool) -> Self {
+        self.sniff_content_type = sniff;
+        self
+    }
+
+    /// 设置下载后是否按 ETag 校验数据的 MD5
+    pub fn with_verify_md5(mut self, verify: bool) -> Self {
+        self.verify_md5 = verify;
+        self
+    }
+
+    /// 设置使用分块上传的文件大小阈值
+    pub fn with_multipart_threshold(mut self, threshold: u64) -> Self {
+        self.multipart_threshold = threshold;
+        self
+    }
+
+    /// 设置默认的分块大小
+    pub fn with_part_size(mut self, part_size: u64) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// 设置默认的分块并发数
+    pub fn with_max_upload_concurrency(mut self, concurrency: usize) -> Self {
+        self.max_upload_concurrency = concurrency;
+        self
+    }
+
+    /// 设置是否校验对象键，关闭后对象键原样发送
+    pub fn with_key_validation(mut self, validate: bool) -> Self {
+        self.validate_keys = validate;
+        self
+    }
+
+    /// 设置是否使用全球加速域名，设置了自定义域名时不生效
+    pub fn with_accelerate(mut self, accelerate: bool) -> Self {
+        self.use_accelerate = accelerate;
+        self
+    }
+
+    /// 设置地域配置错误时是否自动改用正确的地域，设置了自定义域名时不生效
+    pub fn with_auto_correct_region(mut self, auto_correct: bool) -> Self {
+        self.auto_correct_region = auto_correct;
+        self
+    }
+
+    /// 设置是否使用路径风格的地址，存储桶名称作为路径的第一段，而不是域名的一部分
+    pub fn with_path_style(mut self, path_style: bool) -> Self {
+        self.path_style = path_style;
+        self
+    }
+
+    /// 设置客户端同时发送的最大请求数，所有操作（包括传输接口的分块请求）共享该限制
+    pub fn with_max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max);
+        self
+    }
+
+    /// 添加一个信任的根证书（PEM 格式），可以多次调用
+    pub fn with_root_certificate<C: Into<Vec<u8>>>(mut self, pem: C) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// 设置是否跳过 TLS 证书校验
+    ///
+    /// 跳过校验后连接可能被中间人劫持，只应在访问使用自签名证书的测试服务时开启。
+    pub fn with_danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// 设置是否只使用 HTTP/1.1
+    pub fn with_http1_only(mut self, http1_only: bool) -> Self {
+        self.http1_only = http1_only;
+        self
+    }
+
+    /// 设置建立连接的超时时间
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 设置读取到内存中的响应体的最大字节数，超过时返回 `ResponseTooLarge` 错误
+    pub fn with_max_response_body_size(mut self, max: u64) -> Self {
+        self.max_response_body_size = Some(max);
+        self
+    }
+
+    /// 设置请求失败后的重试策略，如 `RetryConfig::default()`
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 添加每个请求都携带的请求头，如 `x-cos-tagging` 或 `Referer`，可以多次调用
+    ///
+    /// Host 与 Authorization 由 SDK 生成，不能设置，否则创建客户端时返回配置错误。
+    pub fn with_default_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.default_headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// 设置请求头签名的有效期
+    pub fn with_signature_validity(mut self, validity: Duration) -> Self {
+        self.signature_validity = validity;
+        self
+    }
+
+    /// 设置请求头签名的生效时间相对当前时间提前的时长
+    pub fn with_clock_skew_allowance(mut self, allowance: Duration) -> Self {
+        self.clock_skew_allowance = allowance;
+        self
+    }
+
+    /// 设置访问 COS 使用的网络，设置了自定义域名时不生效
+    pub fn with_network_type(mut self, network_type: NetworkType) -> Self {
+        self.network_type = network_type;
+        self
+    }
+
     /// 获取存储桶的完整 URL
+    ///
+    /// 使用路径风格时为 `<scheme>://<endpoint>/<bucket>`，endpoint 为自定义域名或地域的服务域名；
+    /// 设置了 [`endpoint`](Self::endpoint) 时以其代替
     pub fn bucket_url(&self) -> Result<String> {
-        if let Some(ref domain) = self.domain {
+        if let Some(ref endpoint) = self.endpoint {
+            let endpoint = endpoint.trim_end_matches('/');
+            if self.path_style {
+                Ok(format!("{}/{}", endpoint, self.bucket))
+            } else {
+                Ok(endpoint.to_string())
+            }
+        } else if self.path_style {
+            let endpoint = match self.domain {
+                Some(ref domain) => domain.clone(),
+                None if self.use_accelerate => "cos.accelerate.myqcloud.com".to_string(),
+                None => self.region_endpoint(),
+            };
+            Ok(format!(
+                "{}://{}/{}",
+                if self.use_https { "https" } else { "http" },
+                endpoint,
+                self.bucket
+            ))
+        } else if let Some(ref domain) = self.domain {
             Ok(format!(
                 "{}://{}",
                 if self.use_https { "https" } else { "http" },
                 domain
             ))
+        } else if self.use_accelerate {
+            Ok(format!(
+                "{}://{}.cos.accelerate.myqcloud.com",
+                if self.use_https { "https" } else { "http" },
+                self.bucket
+            ))
         } else {
             Ok(format!(
-                "{}://{}.cos.{}.myqcloud.com",
+                "{}://{}.{}",
                 if self.use_https { "https" } else { "http" },
                 self.bucket,
-                self.region
+                self.region_endpoint()
             ))
         }
     }
 
     /// 获取服务 URL（用于获取存储桶列表等操作）
     pub fn service_url(&self) -> String {
+        if let Some(ref endpoint) = self.endpoint {
+            return endpoint.trim_end_matches('/').to_string();
+        }
         format!(
-            "{}://cos.{}.myqcloud.com",
+            "{}://{}",
             if self.use_https { "https" } else { "http" },
-            self.region
+            self.region_endpoint()
         )
     }
 
+    /// 地域的服务域名，由地域和网络类型决定
+    fn region_endpoint(&self) -> String {
+        match self.network_type {
+            NetworkType::Public => format!("cos.{}.myqcloud.com", self.region),
+            NetworkType::Internal => format!("cos-internal.{}.tencentcos.cn", self.region),
+        }
+    }
+
     /// 验证配置
     pub fn validate(&self) -> Result<()> {
-        if self.secret_id.is_empty() {
-            return Err(CosError::config("SecretId cannot be empty"));
-        }
-        if self.secret_key.is_empty() {
-            return Err(CosError::config("SecretKey cannot be empty"));
-        }
+        self.validate_credentials()?;
         if self.region.is_empty() {
             return Err(CosError::config("Region cannot be empty"));
         }
         if self.bucket.is_empty() {
             return Err(CosError::config("Bucket cannot be empty"));
         }
+        if self.part_size < MIN_PART_SIZE {
+            return Err(CosError::config(format!(
+                "Part size {} is below the minimum of {} bytes",
+                self.part_size, MIN_PART_SIZE
+            )));
+        }
+        if self.max_upload_concurrency == 0 {
+            return Err(CosError::config("Upload concurrency must be greater than zero"));
+        }
+        if self.max_concurrent_requests == Some(0) {
+            return Err(CosError::config("Max concurrent requests must be greater than zero"));
+        }
+        if self.use_accelerate && self.network_type == NetworkType::Internal {
+            return Err(CosError::config("Global acceleration is not available on the internal network"));
+        }
+        if let Some(ref domain) = self.domain {
+            validate_domain(domain)?;
+        }
+        if let Some(ref endpoint) = self.endpoint {
+            if self.domain.is_some() {
+                return Err(CosError::config("Endpoint and custom domain cannot both be set"));
+            }
+            validate_endpoint(endpoint)?;
+        }
+        self.retry.validate()?;
+        self.validate_default_headers()?;
+        self.validate_signature_window()?;
+        if let Some(strict) = self.strict {
+            self.validate_strict(strict)?;
+        }
+        Ok(())
+    }
+
+    /// 构建器创建的配置额外校验存储桶名称、地域格式与超时时间
+    fn validate_strict(&self, strict: StrictValidation) -> Result<()> {
+        if !is_valid_bucket(&self.bucket) {
+            return Err(CosError::config(format!(
+                "Bucket {:?} must be in the form <name>-<appid>, e.g. examplebucket-1250000000",
+                self.bucket
+            )));
+        }
+        if !strict.allow_unknown_region && !is_known_region_format(&self.region) {
+            return Err(CosError::config(format!(
+                "Region {:?} does not look like a COS region such as ap-beijing; \
+                 use ConfigBuilder::allow_unknown_region for new regions",
+                self.region
+            )));
+        }
+        if self.timeout.is_zero() {
+            return Err(CosError::config("Timeout must be greater than zero"));
+        }
+        Ok(())
+    }
+
+    /// 验证签名有效期与时钟偏差容忍时长
+    pub(crate) fn validate_signature_window(&self) -> Result<()> {
+        if self.signature_validity.is_zero() {
+            return Err(CosError::config("Signature validity must be greater than zero"));
+        }
+        for (name, value) in [
+            ("Signature validity", self.signature_validity),
+            ("Clock skew allowance", self.clock_skew_allowance),
+        ] {
+            if chrono::Duration::from_std(value).is_err() {
+                return Err(CosError::config(format!("{} {:?} is too large", name, value)));
+            }
+        }
+        Ok(())
+    }
+
+    /// 验证默认请求头的名称与取值，并拒绝由 SDK 生成的请求头
+    pub(crate) fn validate_default_headers(&self) -> Result<()> {
+        for (name, value) in &self.default_headers {
+            if name.eq_ignore_ascii_case("host") || name.eq_ignore_ascii_case("authorization") {
+                return Err(CosError::config(format!(
+                    "Default header {:?} is set by the SDK and cannot be overridden",
+                    name
+                )));
+            }
+            http::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| CosError::config(format!("Invalid default header name {:?}", name)))?;
+            http::HeaderValue::from_str(value)
+                .map_err(|_| CosError::config(format!("Invalid value for default header {:?}", name)))?;
+        }
+        Ok(())
+    }
+
+    /// 只验证密钥，供列出存储桶等不依赖存储桶和地域的服务级请求使用
+    pub fn validate_credentials(&self) -> Result<()> {
+        if self.credentials_provider.is_some() {
+            return Ok(());
+        }
+        if self.secret_id.is_empty() {
+            return Err(CosError::config("SecretId cannot be empty"));
+        }
+        if self.secret_key.is_empty() {
+            return Err(CosError::config("SecretKey cannot be empty"));
+        }
         Ok(())
     }
 }
@@ -125,6 +539,152 @@ fn extract_app_id(bucket_name: &str) -> Option<String> {
         })
 }
 
+/// 带严格校验的配置构建器
+///
+/// `Config::new` 不检查存储桶名称与地域的格式，拼写错误要到发送请求时才表现为 DNS 解析失败或 403。
+/// 构建器在 [`build`](Self::build) 时校验：存储桶为 `<name>-<appid>` 格式、地域形如 `ap-beijing`、
+/// 超时时间大于零、自定义域名不含协议；由构建器创建的配置在 [`CosClient::new`](crate::CosClient::new) 时同样严格校验。
+///
+/// ```
+/// use cos_rust_sdk::Config;
+/// use std::time::Duration;
+///
+/// let config = Config::builder("id", "key", "ap-beijing", "examplebucket-1250000000")
+///     .timeout(Duration::from_secs(60))
+///     .configure(|config| config.with_part_size(16 * 1024 * 1024))
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.timeout, Duration::from_secs(60));
+///
+/// assert!(Config::builder("id", "key", "beijing", "examplebucket-1250000000").build().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfigBuilder {
+    config: Config,
+    allow_unknown_region: bool,
+}
+
+impl ConfigBuilder {
+    /// 设置请求超时时间
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    /// 设置是否使用 HTTPS
+    pub fn https(mut self, use_https: bool) -> Self {
+        self.config.use_https = use_https;
+        self
+    }
+
+    /// 设置自定义域名，格式为 `host[:port]`
+    pub fn domain<S: Into<String>>(mut self, domain: S) -> Self {
+        self.config.domain = Some(domain.into());
+        self
+    }
+
+    /// 添加默认请求头，见 [`Config::with_default_header`]
+    pub fn default_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.config.default_headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// 设置重试策略，见 [`Config::with_retry`]
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.config.retry = retry;
+        self
+    }
+
+    /// 设置完整的服务地址，见 [`Config::with_endpoint`]
+    pub fn endpoint<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.config.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// 允许不在已知格式内的地域（如新开放的地域），只要求地域非空
+    pub fn allow_unknown_region(mut self, allow: bool) -> Self {
+        self.allow_unknown_region = allow;
+        self
+    }
+
+    /// 通过 `Config` 的 `with_*` 方法设置其他选项
+    pub fn configure<F: FnOnce(Config) -> Config>(mut self, f: F) -> Self {
+        self.config = f(self.config);
+        self
+    }
+
+    /// 校验并返回配置
+    pub fn build(self) -> Result<Config> {
+        let config = Config {
+            strict: Some(StrictValidation {
+                allow_unknown_region: self.allow_unknown_region,
+            }),
+            ..self.config
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// 存储桶名称是否为 `<name>-<appid>` 格式，name 只包含小写字母、数字和 `-`
+fn is_valid_bucket(bucket: &str) -> bool {
+    let Some((name, app_id)) = bucket.rsplit_once('-') else {
+        return false;
+    };
+    !name.is_empty()
+        && name.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !app_id.is_empty()
+        && app_id.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// 地域是否形如 `ap-beijing`、`na-siliconvalley`、`eu-frankfurt`、`sa-saopaulo`
+fn is_known_region_format(region: &str) -> bool {
+    let Some((area, rest)) = region.split_once('-') else {
+        return false;
+    };
+    matches!(area, "ap" | "na" | "eu" | "sa")
+        && !rest.is_empty()
+        && !rest.starts_with('-')
+        && !rest.ends_with('-')
+        && rest.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// 校验自定义域名为 `host[:port]` 格式
+fn validate_domain(domain: &str) -> Result<()> {
+    if let Some((scheme, _)) = domain.split_once("://") {
+        return Err(CosError::config(format!(
+            "Domain {:?} must not include a scheme; use the host[:port] and with_https({}) instead",
+            domain,
+            scheme.eq_ignore_ascii_case("https")
+        )));
+    }
+    if domain.is_empty() || domain.contains(['/', '?', '#', '@']) {
+        return Err(CosError::config(format!("Domain {:?} must be in the form host[:port]", domain)));
+    }
+    url::Url::parse(&format!("http://{}", domain))
+        .map_err(|e| CosError::config(format!("Invalid domain {:?}: {}", domain, e)))?;
+    Ok(())
+}
+
+/// 校验服务地址为 `http(s)://host[:port]` 格式
+fn validate_endpoint(endpoint: &str) -> Result<()> {
+    let url = url::Url::parse(endpoint)
+        .map_err(|e| CosError::config(format!("Invalid endpoint {:?}: {}", endpoint, e)))?;
+    if !matches!(url.scheme(), "http" | "https")
+        || url.host_str().is_none()
+        || url.path() != "/"
+        || url.query().is_some()
+        || url.fragment().is_some()
+        || !url.username().is_empty()
+    {
+        return Err(CosError::config(format!(
+            "Endpoint {:?} must be in the form http(s)://host[:port]",
+            endpoint
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,5 +704,114 @@ mod tests {
 
         let config = Config::new("", "key", "region", "bucket-123");
         assert!(config.validate().is_err());
+
+        let config = Config::new("id", "key", "region", "bucket-123").with_part_size(512 * 1024);
+        assert!(config.validate().is_err());
+        let config = Config::new("id", "key", "region", "bucket-123").with_max_upload_concurrency(0);
+        assert!(config.validate().is_err());
+        let config = Config::new("id", "key", "region", "bucket-123").with_max_concurrent_requests(0);
+        assert!(config.validate().is_err());
+        let config = Config::new("id", "key", "region", "bucket-123").with_signature_validity(Duration::ZERO);
+        assert!(config.validate().is_err());
+        let config = Config::new("id", "key", "region", "bucket-123").with_clock_skew_allowance(Duration::MAX);
+        assert!(config.validate().unwrap_err().to_string().contains("too large"));
+        let config = Config::new("id", "key", "region", "bucket-123").with_clock_skew_allowance(Duration::ZERO);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_builder_validation() {
+        let build = |region: &str, bucket: &str| Config::builder("id", "key", region, bucket).build();
+        assert!(build("ap-beijing", "examplebucket-1250000000").is_ok());
+        assert!(build("ap-shanghai-fsi", "my-bucket-2-1250000000").is_ok());
+        assert!(build("na-siliconvalley", "a-1").is_ok());
+
+        assert!(build("ap-beijing", "examplebucket").is_err());
+        assert!(build("ap-beijing", "ExampleBucket-1250000000").is_err());
+        assert!(build("ap-beijing", "examplebucket-12a").is_err());
+        assert!(build("beijing", "examplebucket-1250000000").is_err());
+        assert!(build("ap-", "examplebucket-1250000000").is_err());
+
+        let builder = Config::builder("id", "key", "xx-newregion", "examplebucket-1250000000");
+        assert!(builder.clone().build().is_err());
+        assert!(builder.allow_unknown_region(true).build().is_ok());
+
+        let builder = Config::builder("id", "key", "ap-beijing", "examplebucket-1250000000");
+        assert!(builder.clone().timeout(Duration::ZERO).build().is_err());
+        assert!(builder.clone().retry(RetryConfig::default().with_max_attempts(0)).build().is_err());
+        assert!(builder
+            .clone()
+            .retry(RetryConfig::default().with_backoff(Duration::from_secs(10), Duration::from_secs(1)))
+            .build()
+            .is_err());
+        assert!(builder.domain("https://cdn.example.com").build().is_err());
+
+        // Config::new 保持宽松，构建器创建的配置在之后修改也会严格校验
+        assert!(Config::new("id", "key", "beijing", "examplebucket").validate().is_ok());
+        let config = Config::builder("id", "key", "ap-beijing", "examplebucket-1250000000").build().unwrap();
+        assert!(crate::CosClient::new(Config { region: "beijing".to_string(), ..config }).is_err());
+    }
+
+    #[test]
+    fn test_domain_validation() {
+        let config = Config::new("id", "key", "region", "bucket-123");
+        assert!(config.clone().with_domain("localhost:9000").validate().is_ok());
+        assert!(config.clone().with_domain("cdn.example.com").validate().is_ok());
+
+        let err = config.clone().with_domain("http://localhost:9000").validate().unwrap_err();
+        assert!(err.to_string().contains("with_https(false)"), "{}", err);
+        assert!(config.clone().with_domain("cdn.example.com/assets").validate().is_err());
+        assert!(config.with_domain("localhost:port").validate().is_err());
+    }
+
+    #[test]
+    fn test_accelerate_endpoint() {
+        let config = Config::new("id", "key", "ap-beijing", "bucket-123").with_accelerate(true);
+        assert_eq!(config.bucket_url().unwrap(), "https://bucket-123.cos.accelerate.myqcloud.com");
+
+        let config = config.with_domain("static.example.com");
+        assert_eq!(config.bucket_url().unwrap(), "https://static.example.com");
+    }
+
+    #[test]
+    fn test_internal_endpoint() {
+        let config = Config::new("id", "key", "ap-guangzhou", "bucket-123").with_network_type(NetworkType::Internal);
+        assert_eq!(config.bucket_url().unwrap(), "https://bucket-123.cos-internal.ap-guangzhou.tencentcos.cn");
+        assert_eq!(config.service_url(), "https://cos-internal.ap-guangzhou.tencentcos.cn");
+        assert!(config.clone().with_accelerate(true).validate().is_err());
+
+        let config = config.with_path_style(true);
+        assert_eq!(
+            config.bucket_url().unwrap(),
+            "https://cos-internal.ap-guangzhou.tencentcos.cn/bucket-123"
+        );
+    }
+
+    #[test]
+    fn test_path_style_endpoint() {
+        let config = Config::new("id", "key", "ap-beijing", "bucket-123").with_path_style(true);
+        assert_eq!(config.bucket_url().unwrap(), "https://cos.ap-beijing.myqcloud.com/bucket-123");
+
+        let config = config.with_domain("localhost:9000").with_https(false);
+        assert_eq!(config.bucket_url().unwrap(), "http://localhost:9000/bucket-123");
+    }
+
+    #[test]
+    fn test_endpoint_override() {
+        let config = Config::new("id", "key", "ap-beijing", "bucket-123")
+            .with_endpoint("http://127.0.0.1:9000/")
+            .with_accelerate(true);
+        assert!(config.validate().is_ok());
+        assert_eq!(config.bucket_url().unwrap(), "http://127.0.0.1:9000");
+        assert_eq!(config.service_url(), "http://127.0.0.1:9000");
+
+        let config = config.with_path_style(true);
+        assert_eq!(config.bucket_url().unwrap(), "http://127.0.0.1:9000/bucket-123");
+        assert_eq!(config.service_url(), "http://127.0.0.1:9000");
+
+        assert!(config.clone().with_domain("cdn.example.com").validate().is_err());
+        for endpoint in ["127.0.0.1:9000", "ftp://127.0.0.1", "http://127.0.0.1/cos", "http://127.0.0.1?a=b"] {
+            assert!(config.clone().with_endpoint(endpoint).validate().is_err(), "{}", endpoint);
+        }
     }
-}
\ No newline at end of file
+}
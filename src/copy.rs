@@ -0,0 +1,729 @@
+//! 对象复制模块
+//!
+//! 提供同存储桶或跨存储桶的对象复制功能
+
+use crate::client::{encode_key, CosClient};
+use crate::config::Config;
+use crate::crc64;
+use crate::encryption::ServerSideEncryption;
+use crate::error::{CosError, Result};
+use crate::multipart::{
+    CompleteMultipartUploadResponse, CompletedPart, MultipartUploadOptions, UploadPartOptions,
+    MAX_PART_NUMBER,
+};
+use crate::object::{
+    header_str, insert_traffic_limit, metadata_headers, HeadObjectResponse, ObjectClient, StorageClass,
+};
+use crate::transfer::{join_part, split_traffic_limit, DEFAULT_CONCURRENCY, DEFAULT_PART_TIMEOUT};
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+/// 复制源对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopySource {
+    /// 源存储桶名称（包含 APPID）
+    pub bucket: String,
+    /// 源存储桶地域
+    pub region: String,
+    /// 源对象键
+    pub key: String,
+    /// 源对象版本
+    pub version_id: Option<String>,
+}
+
+impl CopySource {
+    /// 创建复制源
+    pub fn new<S: Into<String>>(bucket: S, region: S, key: S) -> Self {
+        Self {
+            bucket: bucket.into(),
+            region: region.into(),
+            key: key.into(),
+            version_id: None,
+        }
+    }
+
+    /// 使用配置中的存储桶和地域创建复制源
+    pub fn from_config<S: Into<String>>(config: &Config, key: S) -> Self {
+        Self {
+            bucket: config.bucket.clone(),
+            region: config.region.clone(),
+            key: key.into(),
+            version_id: None,
+        }
+    }
+
+    /// 指定源对象版本
+    pub fn with_version_id<S: Into<String>>(mut self, version_id: S) -> Self {
+        self.version_id = Some(version_id.into());
+        self
+    }
+
+    /// 生成 `x-cos-copy-source` 请求头的值
+    pub fn header_value(&self) -> String {
+        let mut value = format!(
+            "{}.cos.{}.myqcloud.com/{}",
+            self.bucket,
+            self.region,
+            encode_key(self.key.trim_start_matches('/'))
+        );
+        if let Some(ref version_id) = self.version_id {
+            value.push_str("?versionId=");
+            value.push_str(&urlencoding::encode(version_id));
+        }
+        value
+    }
+}
+
+/// 元数据复制策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataDirective {
+    /// 复制源对象的元数据
+    Copy,
+    /// 使用请求中指定的元数据替换
+    Replaced,
+}
+
+impl fmt::Display for MetadataDirective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            MetadataDirective::Copy => "Copy",
+            MetadataDirective::Replaced => "Replaced",
+        };
+        f.write_str(s)
+    }
+}
+
+/// 复制对象选项
+#[derive(Debug, Clone, Default)]
+pub struct CopyObjectOptions {
+    pub metadata_directive: Option<MetadataDirective>,
+    /// 替换元数据时使用的 Content-Type
+    pub content_type: Option<String>,
+    /// 替换元数据时使用的自定义元数据，以 `x-cos-meta-` 前缀发送
+    pub metadata: HashMap<String, String>,
+    /// 目标对象的存储类型，可用于原地复制以修改存储类型
+    pub storage_class: Option<StorageClass>,
+    /// 目标对象的服务端加密方式
+    pub encryption: Option<ServerSideEncryption>,
+    /// 源对象 ETag 与该值一致时才复制，否则返回 [`CosError::PreconditionFailed`]
+    pub copy_source_if_match: Option<String>,
+    /// 源对象 ETag 与该值不一致时才复制，否则返回 [`CosError::PreconditionFailed`]
+    pub copy_source_if_none_match: Option<String>,
+    /// 复制请求的限速（bit/s），范围为 [`MIN_TRAFFIC_LIMIT`] 到 [`MAX_TRAFFIC_LIMIT`]；
+    /// 分块复制时请使用 [`CopyMultipartOptions::traffic_limit_bits_per_sec`]
+    ///
+    /// [`MIN_TRAFFIC_LIMIT`]: crate::object::MIN_TRAFFIC_LIMIT
+    /// [`MAX_TRAFFIC_LIMIT`]: crate::object::MAX_TRAFFIC_LIMIT
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 额外的请求头
+    pub headers: HashMap<String, String>,
+}
+
+impl CopyObjectOptions {
+    /// 生成复制请求头
+    pub(crate) fn to_headers(&self, source: &CopySource) -> Result<HashMap<String, String>> {
+        let mut headers = self.headers.clone();
+        headers.insert("x-cos-copy-source".to_string(), source.header_value());
+        if let Some(ref class) = self.storage_class {
+            headers.insert("x-cos-storage-class".to_string(), class.to_string());
+        }
+        if let Some(ref encryption) = self.encryption {
+            headers.extend(encryption.to_headers());
+        }
+        if let Some(ref etag) = self.copy_source_if_match {
+            headers.insert("x-cos-copy-source-if-match".to_string(), etag.clone());
+        }
+        if let Some(ref etag) = self.copy_source_if_none_match {
+            headers.insert("x-cos-copy-source-if-none-match".to_string(), etag.clone());
+        }
+
+        if let Some(directive) = self.metadata_directive {
+            headers.insert("x-cos-metadata-directive".to_string(), directive.to_string());
+            if directive == MetadataDirective::Replaced {
+                if let Some(ref ct) = self.content_type {
+                    headers.insert("Content-Type".to_string(), ct.clone());
+                }
+                headers.extend(metadata_headers(&self.metadata)?);
+            }
+        }
+        insert_traffic_limit(&mut headers, self.traffic_limit_bits_per_sec)?;
+
+        Ok(headers)
+    }
+}
+
+/// 复制对象响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "CopyObjectResult")]
+pub struct CopyObjectResponse {
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+    #[serde(rename = "LastModified", default)]
+    pub last_modified: String,
+    #[serde(rename = "CRC64")]
+    pub crc64: Option<String>,
+    /// 目标对象版本，来自响应头
+    #[serde(skip)]
+    pub version_id: Option<String>,
+    /// 目标对象的服务端加密方式，来自响应头
+    #[serde(skip)]
+    pub encryption: Option<ServerSideEncryption>,
+}
+
+impl ObjectClient {
+    /// 复制对象
+    pub async fn copy_object(
+        &self,
+        dest_key: &str,
+        source: CopySource,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<CopyObjectResponse> {
+        let headers = options.unwrap_or_default().to_headers(&source)?;
+
+        let response = self
+            .client
+            .put_with_headers(&self.object_path(dest_key)?, HashMap::new(), headers, None::<&[u8]>)
+            .await?;
+        let version_id = header_str(response.headers(), "x-cos-version-id").map(|s| s.to_string());
+        let encryption = ServerSideEncryption::from_headers(response.headers());
+        let mut copy_response: CopyObjectResponse = CosClient::parse_xml(response).await?;
+        copy_response.version_id = version_id;
+        copy_response.encryption = encryption;
+
+        Ok(copy_response)
+    }
+
+    /// 使用分块复制对象，适用于大于 5GB 的源对象
+    ///
+    /// 先获取源对象大小，按分块大小切分为多个范围并发复制，任一分块失败时终止本次上传。
+    /// 每个分块都以获取大小时的源对象 ETag 作为复制条件，源对象在复制过程中被修改时返回
+    /// [`CosError::PreconditionFailed`]；指定了 `copy_source_if_match` 时使用指定的值。
+    pub async fn copy_object_multipart(
+        &self,
+        dest_key: &str,
+        source: CopySource,
+        options: CopyMultipartOptions,
+    ) -> Result<CompleteMultipartUploadResponse> {
+        if options.part_size == 0 || options.concurrency == 0 {
+            return Err(CosError::config("Part size and concurrency must be greater than zero"));
+        }
+        let (traffic_limit, concurrency) =
+            split_traffic_limit(options.traffic_limit_bits_per_sec, options.concurrency)?;
+        let source_headers = self.head_copy_source(&source).await?;
+        // 每个分块都要求源对象仍是获取大小时的版本，避免复制过程中源对象被覆盖而拼接出两个版本的数据
+        let part_options = UploadPartOptions {
+            traffic_limit_bits_per_sec: traffic_limit,
+            timeout: Some(options.part_timeout.unwrap_or(DEFAULT_PART_TIMEOUT)),
+            copy_source_if_match: options
+                .copy_options
+                .copy_source_if_match
+                .clone()
+                .or_else(|| header_str(&source_headers, "etag").map(str::to_string)),
+            copy_source_if_none_match: options.copy_options.copy_source_if_none_match.clone(),
+        };
+        let source_size: u64 = header_str(&source_headers, "content-length")
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| CosError::other("Copy source response has no Content-Length"))?;
+
+        if source_size == 0 {
+            return Err(CosError::client("InvalidArgument", "Multipart copy requires a non-empty source object"));
+        }
+
+        let part_size = options
+            .part_size
+            .max(source_size.div_ceil(MAX_PART_NUMBER as u64));
+        let part_count = source_size.div_ceil(part_size) as u32;
+
+        let mut initiate_headers = options.copy_options.headers.clone();
+        if options.copy_options.metadata_directive == Some(MetadataDirective::Replaced) {
+            if let Some(ref ct) = options.copy_options.content_type {
+                initiate_headers.insert("Content-Type".to_string(), ct.clone());
+            }
+            initiate_headers.extend(metadata_headers(&options.copy_options.metadata)?);
+        } else {
+            for (name, value) in source_headers.iter() {
+                let name = name.as_str();
+                if name == "content-type" || name.starts_with("x-cos-meta-") {
+                    if let Ok(value) = value.to_str() {
+                        initiate_headers.insert(name.to_string(), value.to_string());
+                    }
+                }
+            }
+        }
+
+        let upload_id = self
+            .initiate_multipart_upload(
+                dest_key,
+                Some(MultipartUploadOptions {
+                    storage_class: options.copy_options.storage_class.clone(),
+                    encryption: options.copy_options.encryption.clone(),
+                    headers: initiate_headers,
+                    ..Default::default()
+                }),
+            )
+            .await?
+            .upload_id;
+
+        let mut tasks = JoinSet::new();
+        let mut parts = Vec::with_capacity(part_count as usize);
+        let mut result = Ok(());
+
+        for part_number in 1..=part_count {
+            if tasks.len() >= concurrency {
+                match join_part(&mut tasks).await {
+                    Ok(part) => parts.push(part),
+                    Err(e) => {
+                        result = Err(e);
+                        break;
+                    }
+                }
+            }
+
+            let first = (part_number as u64 - 1) * part_size;
+            let last = (first + part_size).min(source_size) - 1;
+            let client = self.clone();
+            let dest_key = dest_key.to_string();
+            let upload_id = upload_id.clone();
+            let source = source.clone();
+            let part_options = part_options.clone();
+            tasks.spawn(async move {
+                let _permit = client.part_permit().await?;
+                let response = client
+                    .upload_part_copy_with_options(
+                        &dest_key,
+                        &upload_id,
+                        part_number,
+                        &source,
+                        Some(first..=last),
+                        &part_options,
+                    )
+                    .await?;
+                Ok(CompletedPart::new(part_number, response.etag))
+            });
+        }
+
+        while result.is_ok() && !tasks.is_empty() {
+            match join_part(&mut tasks).await {
+                Ok(part) => parts.push(part),
+                Err(e) => result = Err(e),
+            }
+        }
+
+        if let Err(e) = result {
+            tasks.abort_all();
+            if let Err(abort_err) = self.abort_multipart_upload(dest_key, &upload_id).await {
+                log::warn!("Failed to abort multipart upload {}: {}", upload_id, abort_err);
+            }
+            return Err(e);
+        }
+
+        self.complete_multipart_upload(dest_key, &upload_id, parts).await
+    }
+
+    /// 获取复制源对象的响应头，源对象可以位于其他存储桶
+    pub(crate) async fn head_copy_source(&self, source: &CopySource) -> Result<HeaderMap> {
+        let config = self.client.config();
+        let client = if source.bucket == config.bucket && source.region == config.region {
+            self.client.clone()
+        } else {
+            self.client.for_bucket(&source.bucket, &source.region)?
+        };
+
+        let mut params = HashMap::new();
+        if let Some(ref version_id) = source.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        let response = client.head(&format!("/{}", source.key.trim_start_matches('/')), params).await?;
+        Ok(response.headers().clone())
+    }
+}
+
+impl ObjectClient {
+    /// 重命名（移动）对象
+    ///
+    /// 先复制到新键并校验 CRC64/ETag，校验通过后再删除源对象；源对象大于 5GB 时使用分块复制。
+    /// 该操作不是原子的，源对象删除失败时返回成功的复制结果，并在
+    /// [`RenameObjectResponse::delete_error`] 中给出原因，调用方可只重试删除。
+    pub async fn rename_object(
+        &self,
+        from_key: &str,
+        to_key: &str,
+        options: RenameObjectOptions,
+    ) -> Result<RenameObjectResponse> {
+        if from_key == to_key {
+            return Err(CosError::client("InvalidArgument", "Source and destination keys are the same"));
+        }
+
+        let source_info = self.head_object(from_key).await?;
+        if !options.overwrite && self.object_exists(to_key).await? {
+            return Err(CosError::PreconditionFailed {
+                message: format!("Destination object {} already exists", to_key),
+                request_id: None,
+            });
+        }
+
+        let source = CopySource::from_config(self.client.config(), from_key);
+        let mut copy_options = CopyObjectOptions::default();
+        if !options.overwrite {
+            copy_options
+                .headers
+                .insert("x-cos-forbid-overwrite".to_string(), "true".to_string());
+        }
+
+        let (etag, crc64, version_id) = if source_info.content_length > MAX_SIMPLE_COPY_SIZE {
+            let response = self
+                .copy_object_multipart(
+                    to_key,
+                    source,
+                    CopyMultipartOptions {
+                        part_size: options.part_size,
+                        concurrency: options.concurrency,
+                        traffic_limit_bits_per_sec: options.traffic_limit_bits_per_sec,
+                        part_timeout: None,
+                        copy_options,
+                    },
+                )
+                .await?;
+            // 分块复制的 ETag 与源对象不同，只能依据 CRC64 校验
+            let crc64 = match response.crc64 {
+                Some(crc64) => Some(crc64),
+                None => self.head_object(to_key).await?.crc64,
+            };
+            verify_copy(&source_info, None, crc64.as_deref())?;
+            (response.etag, crc64, None)
+        } else {
+            copy_options.traffic_limit_bits_per_sec =
+                split_traffic_limit(options.traffic_limit_bits_per_sec, 1)?.0;
+            let response = self.copy_object(to_key, source, Some(copy_options)).await?;
+            verify_copy(&source_info, Some(&response.etag), response.crc64.as_deref())?;
+            (response.etag, response.crc64, response.version_id)
+        };
+
+        let delete_error = self.delete_object(from_key).await.err();
+        if let Some(ref e) = delete_error {
+            log::warn!("Copied {} to {} but failed to delete source: {}", from_key, to_key, e);
+        }
+
+        Ok(RenameObjectResponse {
+            etag,
+            crc64,
+            version_id,
+            source_deleted: delete_error.is_none(),
+            delete_error,
+        })
+    }
+}
+
+/// 简单复制支持的最大源对象大小（5GB）
+pub const MAX_SIMPLE_COPY_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// 校验复制结果与源对象一致
+///
+/// 双方都有 CRC64 时比较 CRC64，否则比较 ETag；两者都无法比较时视为校验失败。
+fn verify_copy(source: &HeadObjectResponse, etag: Option<&str>, crc64: Option<&str>) -> Result<()> {
+    if let (Some(local), Some(_)) = (source.crc64.as_deref().and_then(crc64::parse), crc64) {
+        return crc64::verify(local, crc64);
+    }
+
+    match etag {
+        Some(etag) if etag.trim_matches('"') == source.etag.trim_matches('"') => Ok(()),
+        _ => Err(CosError::client(
+            "CopyVerificationFailed",
+            "Copied object does not match the source object",
+        )),
+    }
+}
+
+/// 重命名对象选项
+#[derive(Debug, Clone)]
+pub struct RenameObjectOptions {
+    /// 目标对象已存在时是否覆盖，默认不覆盖
+    pub overwrite: bool,
+    /// 分块复制时的分块大小
+    pub part_size: u64,
+    /// 分块复制时同时复制的分块数量
+    pub concurrency: usize,
+    /// 复制的总限速（bit/s），分块复制时平均分配给每个分块请求
+    pub traffic_limit_bits_per_sec: Option<u64>,
+}
+
+impl Default for RenameObjectOptions {
+    fn default() -> Self {
+        Self {
+            overwrite: false,
+            part_size: DEFAULT_COPY_PART_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+            traffic_limit_bits_per_sec: None,
+        }
+    }
+}
+
+/// 重命名对象响应
+#[derive(Debug)]
+pub struct RenameObjectResponse {
+    /// 目标对象的 ETag
+    pub etag: String,
+    /// 目标对象的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    /// 目标对象版本
+    pub version_id: Option<String>,
+    /// 源对象是否已删除
+    pub source_deleted: bool,
+    /// 删除源对象失败的原因
+    pub delete_error: Option<CosError>,
+}
+
+/// 分块复制默认的分块大小（64MB）
+pub const DEFAULT_COPY_PART_SIZE: u64 = 64 * 1024 * 1024;
+
+/// 分块复制选项
+#[derive(Debug, Clone)]
+pub struct CopyMultipartOptions {
+    /// 分块大小，分块数超过 10000 时会自动增大
+    pub part_size: u64,
+    /// 同时复制的分块数量
+    pub concurrency: usize,
+    /// 所有并发分块请求的总限速（bit/s），会平均分配给每个分块请求
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 单个分块复制请求的超时，未指定时为 [`DEFAULT_PART_TIMEOUT`]
+    pub part_timeout: Option<Duration>,
+    /// 元数据相关选项，未指定替换时沿用源对象的 Content-Type 和自定义元数据
+    pub copy_options: CopyObjectOptions,
+}
+
+impl Default for CopyMultipartOptions {
+    fn default() -> Self {
+        Self {
+            part_size: DEFAULT_COPY_PART_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+            traffic_limit_bits_per_sec: None,
+            part_timeout: None,
+            copy_options: CopyObjectOptions::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_source_header_value() {
+        let source = CopySource::new("examplebucket-1250000000", "ap-guangzhou", "dir/my photo 图片.jpg");
+        assert_eq!(
+            source.header_value(),
+            "examplebucket-1250000000.cos.ap-guangzhou.myqcloud.com/dir/my%20photo%20%E5%9B%BE%E7%89%87.jpg"
+        );
+
+        let source = CopySource::new("examplebucket-1250000000", "ap-guangzhou", "/a+b.txt")
+            .with_version_id("MTg0NDUxNzc2ODQ2NjQ1Mzg2MDk");
+        assert_eq!(
+            source.header_value(),
+            "examplebucket-1250000000.cos.ap-guangzhou.myqcloud.com/a%2Bb.txt?versionId=MTg0NDUxNzc2ODQ2NjQ1Mzg2MDk"
+        );
+    }
+
+    #[test]
+    fn test_copy_options_headers() {
+        let source = CopySource::new("src-1250000000", "ap-beijing", "a.txt");
+        let mut options = CopyObjectOptions {
+            metadata_directive: Some(MetadataDirective::Replaced),
+            content_type: Some("text/plain".to_string()),
+            ..Default::default()
+        };
+        options.metadata.insert("origin".to_string(), "backup".to_string());
+
+        let headers = options.to_headers(&source).unwrap();
+        assert_eq!(headers["x-cos-metadata-directive"], "Replaced");
+        assert_eq!(headers["Content-Type"], "text/plain");
+        assert_eq!(headers["x-cos-meta-origin"], "backup");
+
+        // 自定义元数据与上传时一样校验
+        let mut invalid = options.clone();
+        invalid.metadata.insert("bad key".to_string(), "v".to_string());
+        assert!(matches!(invalid.to_headers(&source), Err(CosError::Config { .. })));
+
+        let options = CopyObjectOptions {
+            storage_class: Some(StorageClass::Archive),
+            ..Default::default()
+        };
+        assert_eq!(options.to_headers(&source).unwrap()["x-cos-storage-class"], "ARCHIVE");
+
+        let options = CopyObjectOptions {
+            copy_source_if_match: Some("\"abc\"".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.to_headers(&source).unwrap()["x-cos-copy-source-if-match"], "\"abc\"");
+
+        let headers = CopyObjectOptions::default().to_headers(&source).unwrap();
+        assert_eq!(headers.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_copy_object_response() {
+        let xml = "<CopyObjectResult><ETag>\"ee8de918d05640145b18f70f4c3aa602\"</ETag>\
+                   <CRC64>16749565679157681890</CRC64><LastModified>2020-03-25T10:07:12Z</LastModified></CopyObjectResult>";
+        let response: CopyObjectResponse = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(response.etag, "\"ee8de918d05640145b18f70f4c3aa602\"");
+        assert_eq!(response.crc64.as_deref(), Some("16749565679157681890"));
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_multipart_pins_source_etag() {
+        use crate::config::Config;
+        use crate::testing::{MockResponse, MockTransport};
+        use reqwest::Method;
+
+        let initiated = || {
+            MockResponse::new(
+                200,
+                "<InitiateMultipartUploadResult><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>",
+            )
+        };
+        let part = |n: u32| MockResponse::new(200, format!("<CopyPartResult><ETag>\"p{}\"</ETag></CopyPartResult>", n));
+        let transport = MockTransport::new();
+        transport
+            .push_response(
+                MockResponse::new(200, "")
+                    .with_header("Content-Length", "10")
+                    .with_header("ETag", "\"v1\""),
+            )
+            .push_response(initiated())
+            .push_response(part(1))
+            .push_response(part(2))
+            .push_response(part(3))
+            .push_response(MockResponse::new(
+                200,
+                "<CompleteMultipartUploadResult><Key>b.txt</Key><ETag>\"abc-3\"</ETag></CompleteMultipartUploadResult>",
+            ));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let client = ObjectClient::new(transport.client(config.clone()).unwrap());
+        let options = || CopyMultipartOptions {
+            part_size: 4,
+            concurrency: 1,
+            ..Default::default()
+        };
+
+        let source = CopySource::from_config(&config, "a.txt");
+        let response = client.copy_object_multipart("b.txt", source.clone(), options()).await.unwrap();
+        assert_eq!(response.etag, "\"abc-3\"");
+
+        // 每个分块都以 HEAD 得到的 ETag 作为复制条件
+        let parts: Vec<_> = transport
+            .requests()
+            .into_iter()
+            .filter(|request| request.query_param("partNumber").is_some())
+            .collect();
+        assert_eq!(parts.len(), 3);
+        for part in &parts {
+            assert_eq!(part.header("x-cos-copy-source-if-match"), Some("\"v1\""));
+            assert_eq!(part.header("x-cos-copy-source-if-none-match"), None);
+        }
+
+        // 指定的复制条件优先，源对象已变化时终止上传
+        transport
+            .push_response(
+                MockResponse::new(200, "")
+                    .with_header("Content-Length", "10")
+                    .with_header("ETag", "\"v2\""),
+            )
+            .push_response(initiated())
+            .push_response(MockResponse::error(412, "PreconditionFailed", "Precondition failed"))
+            .push_response(MockResponse::new(204, ""));
+        let mut options = options();
+        options.copy_options.copy_source_if_match = Some("\"v1\"".to_string());
+        options.copy_options.copy_source_if_none_match = Some("\"v0\"".to_string());
+        let err = client.copy_object_multipart("b.txt", source, options).await.unwrap_err();
+        assert!(matches!(err, CosError::PreconditionFailed { .. }), "{:?}", err);
+        let requests = transport.requests();
+        let part = &requests[requests.len() - 2];
+        assert_eq!(part.header("x-cos-copy-source-if-match"), Some("\"v1\""));
+        assert_eq!(part.header("x-cos-copy-source-if-none-match"), Some("\"v0\""));
+        assert_eq!(requests[requests.len() - 1].method, Method::DELETE);
+    }
+
+    #[tokio::test]
+    async fn test_head_copy_source_in_other_bucket() {
+        use crate::config::Config;
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(200, "").with_header("ETag", "\"v1\""))
+            .push_response(MockResponse::new(200, "").with_header("ETag", "\"v1\""));
+        let source = CopySource::new("src-bucket-1250000000", "ap-shanghai", "dir/a.txt");
+
+        // 跨存储桶的源对象同样经由客户端的传输层发送
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let client = ObjectClient::new(transport.client(config.clone()).unwrap());
+        let headers = client.head_copy_source(&source).await.unwrap();
+        assert_eq!(header_str(&headers, "etag"), Some("\"v1\""));
+        let request = transport.last_request().unwrap();
+        assert_eq!(
+            request.url.as_str(),
+            "https://src-bucket-1250000000.cos.ap-shanghai.myqcloud.com/dir/a.txt"
+        );
+
+        // 沿用自定义的服务地址
+        let config = config.with_endpoint("http://127.0.0.1:9000").with_path_style(true);
+        let client = ObjectClient::new(transport.client(config).unwrap());
+        client.head_copy_source(&source).await.unwrap();
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.url.as_str(), "http://127.0.0.1:9000/src-bucket-1250000000/dir/a.txt");
+        assert_eq!(transport.pending_responses(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rename_object() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let source = || {
+            ServedResponse::new(200, "")
+                .with_header("ETag", "\"abc\"")
+                .with_header("x-cos-hash-crc64ecma", "123")
+        };
+        let copied = |crc64: &str| {
+            ServedResponse::new(
+                200,
+                &format!(
+                    "<CopyObjectResult><ETag>\"abc\"</ETag><CRC64>{}</CRC64></CopyObjectResult>",
+                    crc64
+                ),
+            )
+        };
+
+        let client = ObjectClient::new(
+            client_for(vec![source(), ServedResponse::new(404, ""), copied("123"), ServedResponse::new(204, "")]).await,
+        );
+        let response = client.rename_object("a.txt", "b.txt", RenameObjectOptions::default()).await.unwrap();
+        assert!(response.source_deleted);
+        assert_eq!(response.crc64.as_deref(), Some("123"));
+
+        // 源对象删除失败时复制结果仍然返回
+        let client = ObjectClient::new(
+            client_for(vec![source(), ServedResponse::new(404, ""), copied("123"), ServedResponse::new(500, "")]).await,
+        );
+        let response = client.rename_object("a.txt", "b.txt", RenameObjectOptions::default()).await.unwrap();
+        assert!(!response.source_deleted);
+        assert_eq!(response.delete_error.unwrap().status(), Some(500));
+
+        // 目标已存在且未允许覆盖
+        let client = ObjectClient::new(client_for(vec![source(), ServedResponse::new(200, "")]).await);
+        let err = client.rename_object("a.txt", "b.txt", RenameObjectOptions::default()).await.unwrap_err();
+        assert!(matches!(err, CosError::PreconditionFailed { .. }));
+
+        // 校验失败时不删除源对象
+        let client = ObjectClient::new(client_for(vec![source(), copied("456")]).await);
+        let options = RenameObjectOptions {
+            overwrite: true,
+            ..Default::default()
+        };
+        let err = client.rename_object("a.txt", "b.txt", options).await.unwrap_err();
+        assert!(matches!(err, CosError::Crc64Mismatch { local: 123, remote: 456 }));
+    }
+}
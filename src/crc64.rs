@@ -0,0 +1,183 @@
+//! CRC64 校验模块
+//!
+//! 实现 COS 使用的 CRC64-ECMA 校验（与 `x-cos-hash-crc64ecma` 一致），
+//! 并支持将各分块的校验值合并为整个对象的校验值
+
+use crate::error::{CosError, Result};
+
+/// CRC64-ECMA 多项式（反射形式）
+const POLY: u64 = 0xC96C_5795_D787_0F42;
+
+const TABLE: [u64; 256] = build_table();
+
+const fn build_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ POLY } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// 增量计算 CRC64
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Crc64 {
+    value: u64,
+}
+
+impl Crc64 {
+    /// 创建新的计算器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加数据
+    pub fn update(&mut self, data: &[u8]) {
+        let mut crc = !self.value;
+        for &byte in data {
+            crc = TABLE[((crc ^ byte as u64) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.value = !crc;
+    }
+
+    /// 获取当前的校验值
+    pub fn finish(&self) -> u64 {
+        self.value
+    }
+}
+
+/// 计算数据的 CRC64
+pub fn checksum(data: &[u8]) -> u64 {
+    let mut crc = Crc64::new();
+    crc.update(data);
+    crc.finish()
+}
+
+/// 合并两段连续数据的 CRC64
+///
+/// `crc1` 为前一段数据的校验值，`crc2` 为后一段长度为 `len2` 的数据的校验值，
+/// 返回两段数据拼接后的校验值。
+pub fn combine(crc1: u64, crc2: u64, len2: u64) -> u64 {
+    if len2 == 0 {
+        return crc1;
+    }
+
+    // 表示在 CRC 寄存器后追加一个 0 比特的运算矩阵
+    let mut odd = [0u64; 64];
+    odd[0] = POLY;
+    let mut row = 1u64;
+    for item in odd.iter_mut().skip(1) {
+        *item = row;
+        row <<= 1;
+    }
+
+    // 追加 2 个、4 个 0 比特的运算矩阵
+    let mut even = [0u64; 64];
+    gf2_matrix_square(&mut even, &odd);
+    gf2_matrix_square(&mut odd, &even);
+
+    let mut crc1 = crc1;
+    let mut len2 = len2;
+    loop {
+        // 第一次平方后表示追加一个 0 字节
+        gf2_matrix_square(&mut even, &odd);
+        if len2 & 1 == 1 {
+            crc1 = gf2_matrix_times(&even, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+
+        gf2_matrix_square(&mut odd, &even);
+        if len2 & 1 == 1 {
+            crc1 = gf2_matrix_times(&odd, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+    }
+
+    crc1 ^ crc2
+}
+
+fn gf2_matrix_times(matrix: &[u64; 64], mut vec: u64) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while vec != 0 {
+        if vec & 1 == 1 {
+            sum ^= matrix[i];
+        }
+        vec >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+fn gf2_matrix_square(square: &mut [u64; 64], matrix: &[u64; 64]) {
+    for (i, item) in square.iter_mut().enumerate() {
+        *item = gf2_matrix_times(matrix, matrix[i]);
+    }
+}
+
+/// 解析 `x-cos-hash-crc64ecma` 响应头中的十进制校验值
+pub fn parse(value: &str) -> Option<u64> {
+    value.trim().parse().ok()
+}
+
+/// 校验本地计算的 CRC64 与服务端返回值是否一致，服务端未返回时跳过校验
+pub(crate) fn verify(local: u64, remote: Option<&str>) -> Result<()> {
+    match remote.and_then(parse) {
+        Some(remote) if remote != local => Err(CosError::Crc64Mismatch { local, remote }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(b""), 0);
+        assert_eq!(checksum(b"123456789"), 0x995D_C9BB_DF19_39FA);
+
+        let mut crc = Crc64::new();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), checksum(b"123456789"));
+    }
+
+    #[test]
+    fn test_combine() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        for split in [0, 1, 7, 20, data.len()] {
+            let (a, b) = data.split_at(split);
+            assert_eq!(
+                combine(checksum(a), checksum(b), b.len() as u64),
+                checksum(data),
+                "split at {}",
+                split
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let local = checksum(b"123456789");
+        assert!(verify(local, Some("11051210869376104954")).is_ok());
+        assert!(verify(local, None).is_ok());
+        assert!(matches!(
+            verify(local, Some("1")),
+            Err(CosError::Crc64Mismatch { remote: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+//! 凭证模块
+//!
+//! [`CredentialsProvider`] 为客户端提供签名使用的密钥。构造时固定的密钥无法轮换，
+//! 通过提供者可以接入 CAM 角色、定期刷新的环境变量或密钥管理服务等来源，客户端在每次请求前向提供者获取凭证，
+//! 并缓存到凭证过期前。未设置提供者时使用 [`Config`](crate::Config) 中的密钥，行为与之前一致。
+
+use crate::auth::Auth;
+use crate::error::{CosError, Result};
+use chrono::{DateTime, Duration, Utc};
+use futures::future::BoxFuture;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// 距离过期不足该时长的缓存凭证不再使用，重新向提供者获取
+const DEFAULT_REFRESH_MARGIN: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// 签名使用的凭证
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub secret_id: String,
+    pub secret_key: String,
+    /// 临时密钥的安全令牌，请求时作为 `x-cos-security-token` 发送
+    pub security_token: Option<String>,
+    /// 过期时间，为空时视为长期有效
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Credentials {
+    /// 创建长期有效的凭证
+    pub fn new<S: Into<String>>(secret_id: S, secret_key: S) -> Self {
+        Self {
+            secret_id: secret_id.into(),
+            secret_key: secret_key.into(),
+            security_token: None,
+            expires_at: None,
+        }
+    }
+
+    /// 设置安全令牌
+    pub fn with_security_token<S: Into<String>>(mut self, token: S) -> Self {
+        self.security_token = Some(token.into());
+        self
+    }
+
+    /// 设置过期时间
+    pub fn with_expiration(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// 在 `margin` 之后是否已过期
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at - margin <= Utc::now())
+    }
+
+    /// 由凭证创建签名使用的认证信息
+    pub(crate) fn auth(&self) -> Auth {
+        let auth = Auth::new(self.secret_id.as_str(), self.secret_key.as_str());
+        match &self.security_token {
+            Some(token) => auth.with_security_token(token.as_str()),
+            None => auth,
+        }
+    }
+}
+
+/// 凭证提供者
+///
+/// 返回的凭证设置了 [`expires_at`](Credentials::expires_at) 时，客户端会在距离过期不足
+/// [`refresh_margin`](Self::refresh_margin)（默认一分钟）时重新获取；未设置时视为长期有效，只获取一次。
+/// 需要轮换的凭证必须设置过期时间。
+pub trait CredentialsProvider: Send + Sync + fmt::Debug {
+    /// 获取当前的凭证
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials>>;
+
+    /// 距离过期不足该时长时，客户端不再使用缓存的凭证
+    fn refresh_margin(&self) -> std::time::Duration {
+        DEFAULT_REFRESH_MARGIN
+    }
+}
+
+/// 返回固定凭证的提供者
+#[derive(Debug, Clone)]
+pub struct StaticCredentialsProvider {
+    credentials: Credentials,
+}
+
+impl StaticCredentialsProvider {
+    /// 创建返回 `credentials` 的提供者
+    pub fn new(credentials: Credentials) -> Self {
+        Self { credentials }
+    }
+}
+
+impl CredentialsProvider for StaticCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials>> {
+        let credentials = self.credentials.clone();
+        Box::pin(async move { Ok(credentials) })
+    }
+}
+
+/// 缓存提供者返回的凭证，由客户端的所有副本共享
+#[derive(Debug)]
+pub(crate) struct CredentialsCache {
+    provider: Arc<dyn CredentialsProvider>,
+    cached: RwLock<Option<Credentials>>,
+    /// 最近一次签名使用的认证信息，凭证不变时复用其中缓存的 SignKey
+    auth: RwLock<Option<Auth>>,
+}
+
+impl CredentialsCache {
+    /// 创建尚未获取凭证的缓存
+    pub(crate) fn new(provider: Arc<dyn CredentialsProvider>) -> Self {
+        Self {
+            provider,
+            cached: RwLock::new(None),
+            auth: RwLock::new(None),
+        }
+    }
+
+    /// 创建已缓存固定凭证的缓存
+    pub(crate) fn fixed(credentials: Credentials) -> Self {
+        Self {
+            provider: Arc::new(StaticCredentialsProvider::new(credentials.clone())),
+            cached: RwLock::new(Some(credentials)),
+            auth: RwLock::new(None),
+        }
+    }
+
+    /// 返回未临近过期的缓存凭证，否则向提供者重新获取
+    pub(crate) async fn get(&self) -> Result<Credentials> {
+        if let Some(credentials) = self.cached() {
+            return Ok(credentials);
+        }
+        let credentials = self.provider.credentials().await?;
+        *self.cached.write().unwrap_or_else(|e| e.into_inner()) = Some(credentials.clone());
+        Ok(credentials)
+    }
+
+    /// 未临近过期的缓存凭证
+    pub(crate) fn cached(&self) -> Option<Credentials> {
+        let margin = Duration::from_std(self.provider.refresh_margin()).unwrap_or(Duration::zero());
+        self.cached
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .filter(|credentials| !credentials.expires_within(margin))
+    }
+
+    /// 由 `credentials` 创建签名使用的认证信息，凭证与上次相同时复用上次的认证信息
+    pub(crate) fn auth(&self, credentials: &Credentials) -> Auth {
+        if let Some(auth) = self.auth.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            if auth.secret_id == credentials.secret_id
+                && auth.secret_key == credentials.secret_key
+                && auth.security_token == credentials.security_token
+            {
+                return auth.clone();
+            }
+        }
+        let auth = credentials.auth();
+        *self.auth.write().unwrap_or_else(|e| e.into_inner()) = Some(auth.clone());
+        auth
+    }
+
+    /// 供无法等待的同步操作（预签名、表单上传策略）使用的缓存凭证
+    pub(crate) fn cached_or_err(&self) -> Result<Credentials> {
+        self.cached().ok_or_else(|| {
+            CosError::config(
+                "Credentials from the provider are not loaded or have expired; \
+                 call CosClient::credentials before generating signatures offline",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 每次返回不同密钥并记录调用次数的提供者
+    #[derive(Debug)]
+    struct CountingProvider {
+        calls: AtomicUsize,
+        valid_for: Duration,
+    }
+
+    impl CredentialsProvider for CountingProvider {
+        fn credentials(&self) -> BoxFuture<'_, Result<Credentials>> {
+            Box::pin(async move {
+                let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(Credentials::new(format!("id-{}", n), "key".to_string()).with_expiration(Utc::now() + self.valid_for))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_credentials_cache_refreshes_before_expiry() {
+        let provider = Arc::new(CountingProvider {
+            calls: AtomicUsize::new(0),
+            valid_for: Duration::hours(1),
+        });
+        let cache = CredentialsCache::new(provider.clone());
+        assert!(cache.cached_or_err().is_err());
+        assert_eq!(cache.get().await.unwrap().secret_id, "id-1");
+        assert_eq!(cache.get().await.unwrap().secret_id, "id-1");
+        assert_eq!(cache.cached_or_err().unwrap().secret_id, "id-1");
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+        // 有效期短于刷新余量的凭证每次都重新获取
+        let provider = Arc::new(CountingProvider {
+            calls: AtomicUsize::new(0),
+            valid_for: Duration::seconds(30),
+        });
+        let cache = CredentialsCache::new(provider.clone());
+        assert_eq!(cache.get().await.unwrap().secret_id, "id-1");
+        assert_eq!(cache.get().await.unwrap().secret_id, "id-2");
+    }
+}
@@ -0,0 +1,685 @@
+//! 目录传输模块
+//!
+//! 提供整个本地目录与存储桶前缀之间的批量上传和下载功能
+
+use crate::bucket::{BucketClient, ObjectInfo};
+use crate::crc64::{self, Crc64};
+use crate::error::{CosError, Result};
+use crate::object::ObjectClient;
+use crate::transfer::{join_part, split_traffic_limit, DownloadOptions, UploadOptions, DEFAULT_CONCURRENCY};
+use futures::TryStreamExt;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncReadExt;
+use tokio::task::JoinSet;
+
+/// 上传目录选项
+#[derive(Debug, Clone)]
+pub struct UploadDirOptions {
+    /// 只上传匹配任一模式的文件，为空时上传全部文件
+    ///
+    /// 模式支持 `*`、`?` 和跨目录的 `**`；不含 `/` 的模式只与文件名匹配，
+    /// 否则与相对于根目录的路径匹配。
+    pub include: Vec<String>,
+    /// 跳过匹配任一模式的文件，优先于 `include`
+    pub exclude: Vec<String>,
+    /// 是否跟随符号链接，不跟随时忽略所有符号链接
+    pub follow_symlinks: bool,
+    /// 远端对象大小和 CRC64（或 ETag）与本地文件一致时跳过上传
+    pub skip_unchanged: bool,
+    /// 同时上传的文件数量
+    pub concurrency: usize,
+    /// 整个目录上传的总限速（bit/s），平均分配给并发上传的文件，
+    /// 设置后会覆盖 `upload_options` 中的限速
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 单个文件的上传选项，未指定 Content-Type 时根据扩展名推断
+    pub upload_options: UploadOptions,
+}
+
+impl Default for UploadDirOptions {
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            follow_symlinks: false,
+            skip_unchanged: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            traffic_limit_bits_per_sec: None,
+            upload_options: UploadOptions::default(),
+        }
+    }
+}
+
+impl UploadDirOptions {
+    /// 判断相对路径是否需要上传
+    fn matches(&self, relative: &str) -> bool {
+        let matches_any = |patterns: &[String]| patterns.iter().any(|p| pattern_matches(p, relative));
+        (self.include.is_empty() || matches_any(&self.include)) && !matches_any(&self.exclude)
+    }
+}
+
+/// 目录中的单个文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTransfer {
+    /// 本地路径
+    pub path: PathBuf,
+    /// 对象键
+    pub key: String,
+}
+
+/// 传输失败的文件
+#[derive(Debug)]
+pub struct FailedTransfer {
+    pub path: PathBuf,
+    pub key: String,
+    pub error: CosError,
+}
+
+/// 上传目录的结果
+#[derive(Debug, Default)]
+pub struct UploadDirReport {
+    /// 上传成功的文件
+    pub uploaded: Vec<FileTransfer>,
+    /// 远端已是最新而跳过的文件
+    pub skipped: Vec<FileTransfer>,
+    /// 上传失败的文件，包括无法读取的目录项
+    pub failed: Vec<FailedTransfer>,
+}
+
+/// 下载目录选项
+#[derive(Debug, Clone)]
+pub struct DownloadDirOptions {
+    /// 本地文件已存在且大小和 ETag 与远端一致时跳过下载
+    ///
+    /// 分块上传对象的 ETag 不是内容的 MD5，此时只比较大小。
+    pub skip_existing: bool,
+    /// 同时下载的对象数量
+    pub concurrency: usize,
+    /// 整个目录下载的总限速（bit/s），平均分配给并发下载的对象，
+    /// 设置后会覆盖 `download_options` 中的限速
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 单个对象的下载选项
+    pub download_options: DownloadOptions,
+}
+
+impl Default for DownloadDirOptions {
+    fn default() -> Self {
+        Self {
+            skip_existing: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            traffic_limit_bits_per_sec: None,
+            download_options: DownloadOptions::default(),
+        }
+    }
+}
+
+/// 下载目录的结果
+#[derive(Debug, Default)]
+pub struct DownloadDirReport {
+    /// 下载成功的对象
+    pub downloaded: Vec<FileTransfer>,
+    /// 本地已是最新而跳过的对象
+    pub skipped: Vec<FileTransfer>,
+    /// 下载失败的对象，包括会逃逸出目标目录而被拒绝的键
+    pub failed: Vec<FailedTransfer>,
+}
+
+/// 单个文件的传输结果
+enum TransferOutcome {
+    Transferred,
+    Skipped,
+}
+
+impl ObjectClient {
+    /// 递归上传本地目录
+    ///
+    /// 本地相对路径统一以 `/` 分隔拼接在 `key_prefix` 之后作为对象键。单个文件失败不会中断整个过程，
+    /// 只有根目录无法读取时返回错误。
+    pub async fn upload_directory(
+        &self,
+        local_dir: &Path,
+        key_prefix: &str,
+        options: UploadDirOptions,
+    ) -> Result<UploadDirReport> {
+        if options.concurrency == 0 {
+            return Err(CosError::config("Concurrency must be greater than zero"));
+        }
+
+        let mut upload_options = options.upload_options.clone();
+        let mut concurrency = options.concurrency;
+        if options.traffic_limit_bits_per_sec.is_some() {
+            let (per_file, files) = split_traffic_limit(options.traffic_limit_bits_per_sec, concurrency)?;
+            upload_options.traffic_limit_bits_per_sec = per_file;
+            concurrency = files;
+        }
+
+        let mut report = UploadDirReport::default();
+        let files = collect_files(local_dir, options.follow_symlinks, &mut report.failed).await?;
+        let prefix = normalize_prefix(key_prefix);
+
+        let mut tasks = JoinSet::new();
+        for (path, relative) in files {
+            if !options.matches(&relative) {
+                continue;
+            }
+            if tasks.len() >= concurrency {
+                report.record(join_part(&mut tasks).await?);
+            }
+
+            let client = self.clone();
+            let file = FileTransfer {
+                path,
+                key: format!("{}{}", prefix, relative),
+            };
+            let skip_unchanged = options.skip_unchanged;
+            let upload_options = upload_options.clone();
+            tasks.spawn(async move {
+                let result = client.upload_dir_entry(&file, skip_unchanged, upload_options).await;
+                Ok((file, result))
+            });
+        }
+
+        while !tasks.is_empty() {
+            report.record(join_part(&mut tasks).await?);
+        }
+
+        Ok(report)
+    }
+
+    /// 上传目录中的单个文件
+    async fn upload_dir_entry(
+        &self,
+        file: &FileTransfer,
+        skip_unchanged: bool,
+        options: UploadOptions,
+    ) -> Result<TransferOutcome> {
+        if skip_unchanged && self.is_unchanged(&file.key, &file.path).await? {
+            return Ok(TransferOutcome::Skipped);
+        }
+        self.upload_file(&file.key, &file.path, options).await?;
+        Ok(TransferOutcome::Transferred)
+    }
+
+    /// 判断远端对象是否与本地文件内容一致
+    ///
+    /// 优先比较 CRC64；远端没有 CRC64 时只能比较简单上传对象的 ETag（即内容 MD5）。
+    async fn is_unchanged(&self, key: &str, path: &Path) -> Result<bool> {
+        let remote = match self.head_object(key).await {
+            Ok(remote) => remote,
+            Err(e) if e.status() == Some(404) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+
+        let size = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to read file metadata: {}", e)))?
+            .len();
+        if size != remote.content_length {
+            return Ok(false);
+        }
+
+        if let Some(remote_crc) = remote.crc64.as_deref().and_then(crc64::parse) {
+            let (local_crc, _) = file_digests(path).await?;
+            return Ok(local_crc == remote_crc);
+        }
+
+        let etag = remote.etag.trim_matches('"');
+        if etag.len() == 32 && etag.bytes().all(|b| b.is_ascii_hexdigit()) {
+            let (_, local_md5) = file_digests(path).await?;
+            return Ok(local_md5.eq_ignore_ascii_case(etag));
+        }
+
+        Ok(false)
+    }
+
+    /// 将指定前缀下的所有对象下载到本地目录
+    ///
+    /// 对象键去掉 `key_prefix` 后按 `/` 拆分为相对路径，并自动创建中间目录；
+    /// 包含 `..` 等会逃逸出 `local_dir` 的键会被拒绝并记录在报告中。以 `/` 结尾的目录占位对象会被忽略。
+    pub async fn download_directory(
+        &self,
+        key_prefix: &str,
+        local_dir: &Path,
+        options: DownloadDirOptions,
+    ) -> Result<DownloadDirReport> {
+        if options.concurrency == 0 {
+            return Err(CosError::config("Concurrency must be greater than zero"));
+        }
+
+        let mut download_options = options.download_options.clone();
+        let mut concurrency = options.concurrency;
+        if options.traffic_limit_bits_per_sec.is_some() {
+            let (per_file, files) = split_traffic_limit(options.traffic_limit_bits_per_sec, concurrency)?;
+            download_options.traffic_limit_bits_per_sec = per_file;
+            concurrency = files;
+        }
+
+        let bucket = BucketClient::new(self.client.clone());
+        let mut report = DownloadDirReport::default();
+        let mut tasks = JoinSet::new();
+        let objects = bucket.list_all_objects(Some(key_prefix));
+        futures::pin_mut!(objects);
+
+        while let Some(object) = objects.try_next().await? {
+            if object.key.ends_with('/') {
+                continue;
+            }
+            let path = match local_path(local_dir, key_prefix, &object.key) {
+                Ok(path) => path,
+                Err(error) => {
+                    report.failed.push(FailedTransfer {
+                        path: local_dir.to_path_buf(),
+                        key: object.key,
+                        error,
+                    });
+                    continue;
+                }
+            };
+
+            if tasks.len() >= concurrency {
+                report.record(join_part(&mut tasks).await?);
+            }
+            let client = self.clone();
+            let skip_existing = options.skip_existing;
+            let download_options = download_options.clone();
+            tasks.spawn(async move {
+                let result = client
+                    .download_dir_entry(&object, &path, skip_existing, download_options)
+                    .await;
+                let file = FileTransfer { path, key: object.key };
+                Ok((file, result))
+            });
+        }
+
+        while !tasks.is_empty() {
+            report.record(join_part(&mut tasks).await?);
+        }
+
+        Ok(report)
+    }
+
+    /// 下载目录中的单个对象
+    async fn download_dir_entry(
+        &self,
+        object: &ObjectInfo,
+        path: &Path,
+        skip_existing: bool,
+        options: DownloadOptions,
+    ) -> Result<TransferOutcome> {
+        if skip_existing && is_local_unchanged(path, object).await? {
+            return Ok(TransferOutcome::Skipped);
+        }
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| CosError::other(format!("Failed to create directory {}: {}", parent.display(), e)))?;
+        }
+        self.get_object_to_file_with_options(&object.key, path, options).await?;
+        Ok(TransferOutcome::Transferred)
+    }
+}
+
+/// 判断本地文件是否与列表中的对象一致
+async fn is_local_unchanged(path: &Path, object: &ObjectInfo) -> Result<bool> {
+    let size = match tokio::fs::metadata(path).await {
+        Ok(metadata) if metadata.is_file() => metadata.len(),
+        _ => return Ok(false),
+    };
+    if size != object.size {
+        return Ok(false);
+    }
+
+    let etag = object.etag.trim_matches('"');
+    if etag.len() == 32 && etag.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let (_, local_md5) = file_digests(path).await?;
+        return Ok(local_md5.eq_ignore_ascii_case(etag));
+    }
+    Ok(true)
+}
+
+/// 将对象键转换为目标目录下的本地路径，拒绝会逃逸出目标目录的键
+fn local_path(local_dir: &Path, key_prefix: &str, key: &str) -> Result<PathBuf> {
+    let relative = key.strip_prefix(key_prefix).unwrap_or(key).trim_start_matches('/');
+    let invalid = || CosError::client("InvalidKey", format!("Key {} escapes the target directory", key).as_str());
+
+    let mut path = local_dir.to_path_buf();
+    let mut depth = 0;
+    for segment in relative.split('/') {
+        match segment {
+            "" | "." => continue,
+            ".." => return Err(invalid()),
+            s if s.contains('\\') || s.contains(':') => return Err(invalid()),
+            s => {
+                path.push(s);
+                depth += 1;
+            }
+        }
+    }
+
+    if depth == 0 {
+        return Err(invalid());
+    }
+    Ok(path)
+}
+
+impl UploadDirReport {
+    /// 记录单个文件的上传结果
+    fn record(&mut self, entry: (FileTransfer, Result<TransferOutcome>)) {
+        record(entry, &mut self.uploaded, &mut self.skipped, &mut self.failed);
+    }
+}
+
+impl DownloadDirReport {
+    /// 记录单个对象的下载结果
+    fn record(&mut self, entry: (FileTransfer, Result<TransferOutcome>)) {
+        record(entry, &mut self.downloaded, &mut self.skipped, &mut self.failed);
+    }
+}
+
+/// 按传输结果将文件归入对应的列表
+fn record(
+    (file, result): (FileTransfer, Result<TransferOutcome>),
+    transferred: &mut Vec<FileTransfer>,
+    skipped: &mut Vec<FileTransfer>,
+    failed: &mut Vec<FailedTransfer>,
+) {
+    match result {
+        Ok(TransferOutcome::Transferred) => transferred.push(file),
+        Ok(TransferOutcome::Skipped) => skipped.push(file),
+        Err(error) => failed.push(FailedTransfer {
+            path: file.path,
+            key: file.key,
+            error,
+        }),
+    }
+}
+
+/// 前缀非空时确保以 `/` 结尾，并去掉开头的 `/`
+fn normalize_prefix(prefix: &str) -> String {
+    let prefix = prefix.trim_start_matches('/');
+    if prefix.is_empty() || prefix.ends_with('/') {
+        prefix.to_string()
+    } else {
+        format!("{}/", prefix)
+    }
+}
+
+/// 递归列出目录下的所有文件，返回本地路径与以 `/` 分隔的相对路径
+///
+/// 无法读取的子目录和非 UTF-8 文件名记录到 `failed` 中；跟随符号链接时会跳过已访问过的目录以避免循环。
+async fn collect_files(
+    root: &Path,
+    follow_symlinks: bool,
+    failed: &mut Vec<FailedTransfer>,
+) -> Result<Vec<(PathBuf, String)>> {
+    let read_err = |path: &Path, e: std::io::Error| {
+        CosError::other(format!("Failed to read directory {}: {}", path.display(), e))
+    };
+
+    let mut visited = HashSet::new();
+    if let Ok(canonical) = tokio::fs::canonicalize(root).await {
+        visited.insert(canonical);
+    }
+
+    let mut files = Vec::new();
+    let mut dirs = vec![(root.to_path_buf(), String::new())];
+    let mut is_root = true;
+
+    while let Some((dir, relative)) = dirs.pop() {
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if is_root => return Err(read_err(&dir, e)),
+            Err(e) => {
+                failed.push(FailedTransfer {
+                    path: dir.clone(),
+                    key: relative,
+                    error: read_err(&dir, e),
+                });
+                continue;
+            }
+        };
+        is_root = false;
+
+        loop {
+            let entry = match entries.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => break,
+                Err(e) => {
+                    failed.push(FailedTransfer {
+                        path: dir.clone(),
+                        key: relative.clone(),
+                        error: read_err(&dir, e),
+                    });
+                    break;
+                }
+            };
+
+            let path = entry.path();
+            let Some(name) = entry.file_name().to_str().map(|s| s.to_string()) else {
+                failed.push(FailedTransfer {
+                    key: format!("{}{}", relative, entry.file_name().to_string_lossy()),
+                    path,
+                    error: CosError::other("File name is not valid UTF-8"),
+                });
+                continue;
+            };
+            let entry_relative = format!("{}{}", relative, name);
+
+            let file_type = match entry.file_type().await {
+                Ok(file_type) if file_type.is_symlink() && !follow_symlinks => continue,
+                Ok(file_type) if file_type.is_symlink() => tokio::fs::metadata(&path).await.map(|m| m.file_type()),
+                other => other,
+            };
+            let file_type = match file_type {
+                Ok(file_type) => file_type,
+                Err(e) => {
+                    failed.push(FailedTransfer {
+                        error: CosError::other(format!("Failed to read {}: {}", path.display(), e)),
+                        path,
+                        key: entry_relative,
+                    });
+                    continue;
+                }
+            };
+
+            if file_type.is_dir() {
+                if follow_symlinks {
+                    if let Ok(canonical) = tokio::fs::canonicalize(&path).await {
+                        if !visited.insert(canonical) {
+                            continue;
+                        }
+                    }
+                }
+                dirs.push((path, format!("{}/", entry_relative)));
+            } else if file_type.is_file() {
+                files.push((path, entry_relative));
+            }
+        }
+    }
+
+    files.sort_by(|a, b| a.1.cmp(&b.1));
+    Ok(files)
+}
+
+/// 计算文件的 CRC64 与十六进制 MD5
+async fn file_digests(path: &Path) -> Result<(u64, String)> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| CosError::other(format!("Failed to open file: {}", e)))?;
+    let mut crc = Crc64::new();
+    let mut md5 = md5::Context::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to read file: {}", e)))?;
+        if n == 0 {
+            break;
+        }
+        crc.update(&buf[..n]);
+        md5.consume(&buf[..n]);
+    }
+    Ok((crc.finish(), format!("{:x}", md5.compute())))
+}
+
+/// 判断相对路径是否匹配过滤模式，不含 `/` 的模式只与文件名匹配
+fn pattern_matches(pattern: &str, relative: &str) -> bool {
+    let target = if pattern.contains('/') {
+        relative
+    } else {
+        relative.rsplit('/').next().unwrap_or(relative)
+    };
+    let pattern: Vec<char> = pattern.trim_start_matches('/').chars().collect();
+    let target: Vec<char> = target.chars().collect();
+    glob_match(&pattern, &target)
+}
+
+/// 简单的通配符匹配：`*` 不跨越 `/`，`**` 可匹配任意层级目录
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') if pattern.get(1) == Some(&'*') => {
+            let rest = &pattern[2..];
+            // `**/` 也可以匹配零层目录
+            if rest.first() == Some(&'/') && glob_match(&rest[1..], text) {
+                return true;
+            }
+            (0..=text.len()).any(|i| glob_match(rest, &text[i..]))
+        }
+        Some('*') => {
+            let rest = &pattern[1..];
+            for i in 0..=text.len() {
+                if glob_match(rest, &text[i..]) {
+                    return true;
+                }
+                if i < text.len() && text[i] == '/' {
+                    break;
+                }
+            }
+            false
+        }
+        Some('?') => text.first().is_some_and(|c| *c != '/') && glob_match(&pattern[1..], &text[1..]),
+        Some(c) => text.first() == Some(c) && glob_match(&pattern[1..], &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    #[test]
+    fn test_pattern_matches() {
+        assert!(pattern_matches("*.log", "a/b/app.log"));
+        assert!(!pattern_matches("*.log", "a/b/app.txt"));
+        assert!(pattern_matches("a/*.txt", "a/x.txt"));
+        assert!(!pattern_matches("a/*.txt", "a/b/x.txt"));
+        assert!(pattern_matches("a/**/*.txt", "a/x.txt"));
+        assert!(pattern_matches("a/**/*.txt", "a/b/c/x.txt"));
+        assert!(pattern_matches("**/node_modules/**", "web/node_modules/x/index.js"));
+        assert!(pattern_matches("file?.bin", "file1.bin"));
+        assert!(!pattern_matches("file?.bin", "file10.bin"));
+    }
+
+    #[test]
+    fn test_normalize_prefix() {
+        assert_eq!(normalize_prefix(""), "");
+        assert_eq!(normalize_prefix("/backup"), "backup/");
+        assert_eq!(normalize_prefix("backup/"), "backup/");
+    }
+
+    fn create_dir(name: &str, files: &[&str]) -> PathBuf {
+        let root = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&root);
+        for file in files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, file.as_bytes()).unwrap();
+        }
+        root
+    }
+
+    #[tokio::test]
+    async fn test_collect_files() {
+        let root = create_dir("cos-rust-sdk-collect-files", &["b.txt", "a/c.txt", "a/d/e.log"]);
+
+        let mut failed = Vec::new();
+        let files = collect_files(&root, false, &mut failed).await.unwrap();
+        let relative: Vec<_> = files.iter().map(|(_, r)| r.as_str()).collect();
+        assert_eq!(relative, ["a/c.txt", "a/d/e.log", "b.txt"]);
+        assert!(failed.is_empty());
+
+        assert!(collect_files(&root.join("missing"), false, &mut failed).await.is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory_report() {
+        let root = create_dir("cos-rust-sdk-upload-directory", &["a.txt", "b.txt", "c.log"]);
+        let client = ObjectClient::new(
+            client_for(vec![ServedResponse::new(200, ""), ServedResponse::new(500, "")]).await,
+        );
+
+        let options = UploadDirOptions {
+            exclude: vec!["*.log".to_string()],
+            concurrency: 1,
+            ..Default::default()
+        };
+        let report = client.upload_directory(&root, "backup", options).await.unwrap();
+        assert_eq!(report.uploaded, [FileTransfer { path: root.join("a.txt"), key: "backup/a.txt".to_string() }]);
+        assert!(report.skipped.is_empty());
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].key, "backup/b.txt");
+        assert_eq!(report.failed[0].error.status(), Some(500));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_local_path() {
+        let root = Path::new("/data");
+        assert_eq!(local_path(root, "backup/", "backup/a/b.txt").unwrap(), root.join("a").join("b.txt"));
+        assert_eq!(local_path(root, "backup", "backup/./c.txt").unwrap(), root.join("c.txt"));
+        assert!(local_path(root, "backup/", "backup/../etc/passwd").is_err());
+        assert!(local_path(root, "backup/", "backup/a/../../b").is_err());
+        assert!(local_path(root, "backup/", "backup/").is_err());
+        assert!(local_path(root, "", "..\\windows").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_download_directory_report() {
+        let root = std::env::temp_dir().join("cos-rust-sdk-download-directory");
+        let _ = std::fs::remove_dir_all(&root);
+
+        let listing = "<ListBucketResult><Name>test-bucket-123</Name><Prefix>backup/</Prefix>\
+            <KeyCount>3</KeyCount><MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated>\
+            <Contents><Key>backup/a/b.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>x</ETag><Size>5</Size></Contents>\
+            <Contents><Key>backup/../evil</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>x</ETag><Size>1</Size></Contents>\
+            <Contents><Key>backup/c.txt</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>x</ETag><Size>1</Size></Contents>\
+            </ListBucketResult>";
+        let client = ObjectClient::new(
+            client_for(vec![
+                ServedResponse::new(200, listing),
+                ServedResponse::new(200, "hello"),
+                ServedResponse::new(403, ""),
+            ])
+            .await,
+        );
+
+        let options = DownloadDirOptions {
+            concurrency: 1,
+            ..Default::default()
+        };
+        let report = client.download_directory("backup/", &root, options).await.unwrap();
+        assert_eq!(report.downloaded.len(), 1);
+        assert_eq!(std::fs::read_to_string(root.join("a").join("b.txt")).unwrap(), "hello");
+
+        let mut failed: Vec<_> = report.failed.iter().map(|f| f.key.as_str()).collect();
+        failed.sort();
+        assert_eq!(failed, ["backup/../evil", "backup/c.txt"]);
+        assert!(!root.join("c.txt").exists());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
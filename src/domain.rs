@@ -0,0 +1,162 @@
+//! 自定义域名模块
+//!
+//! 提供存储桶自定义域名（CNAME）绑定配置的读写接口
+//!
+//! 参考文档：<https://cloud.tencent.com/document/product/436/43580>
+
+use crate::bucket::BucketClient;
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use crate::object::{header_str, request_id};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 自定义域名配置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "DomainConfiguration")]
+pub struct DomainConfiguration {
+    #[serde(rename = "DomainRule", default)]
+    pub rules: Vec<DomainRule>,
+}
+
+/// 单个域名的绑定规则
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainRule {
+    #[serde(rename = "Status")]
+    pub status: DomainStatus,
+    /// 完整域名，如 `www.example.com`
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub domain_type: DomainType,
+    /// 域名已绑定到其他存储桶时强制替换的方式
+    #[serde(rename = "ForcedReplacement", skip_serializing_if = "Option::is_none")]
+    pub forced_replacement: Option<ForcedReplacement>,
+}
+
+impl DomainRule {
+    /// 创建启用的 REST 域名规则
+    pub fn rest<S: Into<String>>(name: S) -> Self {
+        Self {
+            status: DomainStatus::Enabled,
+            name: name.into(),
+            domain_type: DomainType::Rest,
+            forced_replacement: None,
+        }
+    }
+
+    /// 创建启用的静态网站域名规则
+    pub fn website<S: Into<String>>(name: S) -> Self {
+        Self {
+            domain_type: DomainType::Website,
+            ..Self::rest(name)
+        }
+    }
+
+    /// 设置强制替换方式
+    pub fn with_forced_replacement(mut self, replacement: ForcedReplacement) -> Self {
+        self.forced_replacement = Some(replacement);
+        self
+    }
+}
+
+/// 域名状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DomainStatus {
+    Enabled,
+    Disabled,
+}
+
+/// 域名类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DomainType {
+    /// 访问存储桶的 REST 接口
+    Rest,
+    /// 访问存储桶的静态网站
+    Website,
+}
+
+/// 强制替换已绑定域名的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ForcedReplacement {
+    /// 域名已解析到本存储桶时直接替换
+    Cname,
+    /// 通过 TXT 记录验证域名归属后替换
+    Txt,
+}
+
+/// 设置自定义域名的响应
+#[derive(Debug, Clone)]
+pub struct PutBucketDomainResponse {
+    /// 需要通过 TXT 记录验证域名归属时，服务端返回的验证内容（`x-cos-domain-txt-verification`），
+    /// 需在域名下添加该 TXT 记录后重新设置
+    pub txt_verification: Option<String>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub request_id: Option<String>,
+}
+
+impl BucketClient {
+    /// 设置存储桶自定义域名，会覆盖已有的配置
+    pub async fn put_bucket_domain(&self, config: &DomainConfiguration) -> Result<PutBucketDomainResponse> {
+        let xml_body = quick_xml::se::to_string(config)
+            .map_err(|e| CosError::other(format!("Failed to serialize domain configuration: {}", e)))?;
+
+        let response = self.client.put("/", domain_params(), Some(xml_body)).await?;
+        Ok(PutBucketDomainResponse {
+            txt_verification: header_str(response.headers(), "x-cos-domain-txt-verification").map(|s| s.to_string()),
+            request_id: request_id(response.headers()),
+        })
+    }
+
+    /// 获取存储桶自定义域名配置
+    pub async fn get_bucket_domain(&self) -> Result<DomainConfiguration> {
+        let response = self.client.get("/", domain_params()).await?;
+        CosClient::parse_xml(response).await
+    }
+}
+
+/// 自定义域名子资源的查询参数
+fn domain_params() -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("domain".to_string(), "".to_string());
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    #[test]
+    fn test_domain_xml_round_trip() {
+        let config = DomainConfiguration {
+            rules: vec![DomainRule::rest("www.example.com").with_forced_replacement(ForcedReplacement::Cname)],
+        };
+        let xml = quick_xml::se::to_string(&config).unwrap();
+        assert_eq!(
+            xml,
+            "<DomainConfiguration><DomainRule><Status>ENABLED</Status><Name>www.example.com</Name>\
+             <Type>REST</Type><ForcedReplacement>CNAME</ForcedReplacement></DomainRule></DomainConfiguration>"
+        );
+        let parsed: DomainConfiguration = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[tokio::test]
+    async fn test_put_bucket_domain_txt_verification() {
+        let client = BucketClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "").with_header("x-cos-domain-txt-verification", "cos-verify=abc123"),
+            ])
+            .await,
+        );
+        let config = DomainConfiguration {
+            rules: vec![DomainRule::website("static.example.com")],
+        };
+        let response = client.put_bucket_domain(&config).await.unwrap();
+        assert_eq!(response.txt_verification.as_deref(), Some("cos-verify=abc123"));
+    }
+}
@@ -1,5 +1,6 @@
 //! 错误处理模块
 
+use serde::Deserialize;
 use thiserror::Error;
 
 /// COS SDK 错误类型
@@ -21,13 +22,33 @@ pub enum CosError {
     #[error("Authentication failed: {message}")]
     Auth { message: String },
 
-    /// 服务器错误
-    #[error("Server error: {code} - {message}")]
-    Server { code: String, message: String },
+    /// 服务器错误，服务端响应 5xx 时产生
+    #[error("Server error: {code} - {message}{}", request_id_suffix(.request_id))]
+    Server {
+        /// COS 错误码，如 `InternalError`；响应体无法解析时为状态码
+        code: String,
+        message: String,
+        /// HTTP 状态码，非服务端响应产生的错误为空
+        status: Option<u16>,
+        /// 请求 ID，向腾讯云反馈问题时需要提供
+        request_id: Option<String>,
+        /// 错误响应中的附加信息，非服务端响应产生的错误为空
+        details: Option<Box<ErrorDetails>>,
+    },
 
-    /// 客户端错误
-    #[error("Client error: {code} - {message}")]
-    Client { code: String, message: String },
+    /// 客户端错误，服务端响应 4xx 或请求发送前的本地校验失败时产生
+    #[error("Client error: {code} - {message}{}", request_id_suffix(.request_id))]
+    Client {
+        /// COS 错误码，如 `NoSuchKey`；响应体无法解析时为状态码
+        code: String,
+        message: String,
+        /// HTTP 状态码，本地校验产生的错误为空
+        status: Option<u16>,
+        /// 请求 ID，向腾讯云反馈问题时需要提供
+        request_id: Option<String>,
+        /// 错误响应中的附加信息，非服务端响应产生的错误为空
+        details: Option<Box<ErrorDetails>>,
+    },
 
     /// 配置错误
     #[error("Configuration error: {message}")]
@@ -37,6 +58,82 @@ pub enum CosError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// 追加上传的位置与对象当前长度不一致
+    #[error("Append position {position} does not match the object length (next position: {next_position:?})")]
+    AppendPositionMismatch {
+        position: u64,
+        next_position: Option<u64>,
+    },
+
+    /// 请求的字节范围无法满足（HTTP 416）
+    #[error("Range not satisfiable: {range} (object size: {object_size:?})")]
+    RangeNotSatisfiable {
+        range: String,
+        object_size: Option<u64>,
+    },
+
+    /// 归档对象已在恢复中（HTTP 409 RestoreAlreadyInProgress）
+    #[error("Restore already in progress for {key}")]
+    RestoreAlreadyInProgress { key: String },
+
+    /// 存储桶未设置策略（HTTP 404 NoSuchPolicy）
+    #[error("No bucket policy configured for {bucket}")]
+    NoSuchPolicy { bucket: String },
+
+    /// 存储桶不存在（HTTP 404 NoSuchBucket）
+    #[error("Bucket {bucket} does not exist")]
+    NoSuchBucket {
+        bucket: String,
+        /// 响应头 `x-cos-bucket-region`
+        region: Option<String>,
+    },
+
+    /// 没有访问存储桶的权限（HTTP 403 AccessDenied）
+    #[error("Access denied to bucket {bucket}{}", request_id_suffix(.request_id))]
+    AccessDenied {
+        bucket: String,
+        /// 响应头 `x-cos-bucket-region`，与配置的地域不一致时通常意味着地域配置错误
+        region: Option<String>,
+        request_id: Option<String>,
+    },
+
+    /// 存储桶不在配置的地域（HTTP 301/302/307，响应头 `x-cos-bucket-region` 给出实际地域）
+    #[error("Bucket is in region {expected}, but the client is configured for {configured}")]
+    WrongRegion {
+        /// 存储桶实际所在的地域
+        expected: String,
+        /// 请求使用的地域
+        configured: String,
+    },
+
+    /// 存储桶已存在（HTTP 409 BucketAlreadyExists / BucketAlreadyOwnedByYou）
+    #[error("Bucket {bucket} already exists{}", if *.owned_by_you { " and is owned by you" } else { "" })]
+    BucketAlreadyExists {
+        bucket: String,
+        /// 存储桶属于当前账号（BucketAlreadyOwnedByYou）
+        owned_by_you: bool,
+    },
+
+    /// 条件请求的对象未修改（HTTP 304）
+    #[error("Not modified")]
+    NotModified,
+
+    /// 写入条件不满足，如禁止覆盖时对象已存在（HTTP 409 FileAlreadyExists / 412）
+    #[error("Precondition failed: {message}{}", request_id_suffix(.request_id))]
+    PreconditionFailed {
+        message: String,
+        /// 客户端检查不满足时为空
+        request_id: Option<String>,
+    },
+
+    /// 下载数据的 MD5 与对象 ETag 不一致，通常意味着数据被截断或损坏
+    #[error("Integrity check failed: expected MD5 {expected}, got {actual}")]
+    IntegrityCheckFailed { expected: String, actual: String },
+
+    /// 本地与服务端计算的 CRC64 不一致
+    #[error("CRC64 mismatch: local {local}, remote {remote}")]
+    Crc64Mismatch { local: u64, remote: u64 },
+
     /// 其他错误
     #[error("Other error: {message}")]
     Other { message: String },
@@ -55,6 +152,109 @@ impl CosError {
         Self::Server {
             code: code.into(),
             message: message.into(),
+            status: None,
+            request_id: None,
+            details: None,
+        }
+    }
+
+    /// 根据服务端响应的状态码创建错误，4xx 为客户端错误，其余为服务器错误
+    pub fn http_status<S: Into<String>>(status: reqwest::StatusCode, message: S) -> Self {
+        let code = status.to_string();
+        let message = message.into();
+        if status.is_client_error() {
+            Self::Client {
+                code,
+                message,
+                status: Some(status.as_u16()),
+                request_id: None,
+                details: None,
+            }
+        } else {
+            Self::Server {
+                code,
+                message,
+                status: Some(status.as_u16()),
+                request_id: None,
+                details: None,
+            }
+        }
+    }
+
+    /// 由服务端的错误响应创建错误
+    ///
+    /// 解析 `<Error>` 响应体中的错误码、错误信息、资源、请求 ID 和 TraceId；
+    /// 响应体无法解析时错误码为状态码，错误信息为原始响应体。
+    /// `request_id` 为响应头中的请求 ID，响应体中没有请求 ID 时使用。
+    pub(crate) fn from_response(status: reqwest::StatusCode, body: String, request_id: Option<String>) -> Self {
+        let parsed = quick_xml::de::from_str::<ErrorBody>(&body).ok().filter(|e| !e.code.is_empty());
+        let message = parsed.as_ref().map_or(body.as_str(), |e| e.message.as_str()).to_string();
+        let mut error = Self::http_status(status, message);
+
+        if let Self::Client { code, request_id: id, details, .. } | Self::Server { code, request_id: id, details, .. } =
+            &mut error
+        {
+            let mut error_details = ErrorDetails {
+                body,
+                ..Default::default()
+            };
+            if let Some(parsed) = parsed {
+                *code = parsed.code;
+                *id = parsed.request_id.filter(|id| !id.is_empty());
+                error_details.resource = parsed.resource;
+                error_details.trace_id = parsed.trace_id;
+            }
+            if id.is_none() {
+                *id = request_id;
+            }
+            *details = Some(Box::new(error_details));
+        }
+        error
+    }
+
+    /// 服务端返回的请求 ID
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Server { request_id, .. }
+            | Self::Client { request_id, .. }
+            | Self::PreconditionFailed { request_id, .. }
+            | Self::AccessDenied { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// COS 错误码，如 `NoSuchKey`
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Self::Server { code, .. } | Self::Client { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// 错误响应中的附加信息
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        match self {
+            Self::Server { details, .. } | Self::Client { details, .. } => details.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// 错误响应中的 TraceId
+    pub fn trace_id(&self) -> Option<&str> {
+        self.details().and_then(|d| d.trace_id.as_deref())
+    }
+
+    /// 服务端响应的 HTTP 状态码
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Server { status, .. } | Self::Client { status, .. } => *status,
+            Self::NotModified => Some(304),
+            Self::NoSuchPolicy { .. } | Self::NoSuchBucket { .. } => Some(404),
+            Self::AccessDenied { .. } => Some(403),
+            Self::BucketAlreadyExists { .. } => Some(409),
+            Self::RangeNotSatisfiable { .. } => Some(416),
+            Self::Http(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
         }
     }
 
@@ -63,6 +263,9 @@ impl CosError {
         Self::Client {
             code: code.into(),
             message: message.into(),
+            status: None,
+            request_id: None,
+            details: None,
         }
     }
 
@@ -81,5 +284,80 @@ impl CosError {
     }
 }
 
+/// 服务端错误响应中的附加信息
+#[derive(Debug, Clone, Default)]
+pub struct ErrorDetails {
+    /// 错误响应中的 `<Resource>`
+    pub resource: Option<String>,
+    /// 错误响应中的 `<TraceId>`
+    pub trace_id: Option<String>,
+    /// 原始响应体，响应体不是 COS 错误格式时可以从这里获取
+    pub body: String,
+}
+
+/// COS 错误响应体
+#[derive(Debug, Deserialize)]
+#[serde(rename = "Error")]
+struct ErrorBody {
+    #[serde(rename = "Code", default)]
+    code: String,
+    #[serde(rename = "Message", default)]
+    message: String,
+    #[serde(rename = "Resource")]
+    resource: Option<String>,
+    #[serde(rename = "RequestId")]
+    request_id: Option<String>,
+    #[serde(rename = "TraceId")]
+    trace_id: Option<String>,
+}
+
+fn request_id_suffix(request_id: &Option<String>) -> String {
+    request_id
+        .as_ref()
+        .map(|id| format!(" (request id: {})", id))
+        .unwrap_or_default()
+}
+
 /// COS SDK 结果类型
-pub type Result<T> = std::result::Result<T, CosError>;
\ No newline at end of file
+pub type Result<T> = std::result::Result<T, CosError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_from_response() {
+        let body = "<?xml version='1.0' encoding='utf-8' ?><Error><Code>NoSuchKey</Code>\
+            <Message>The specified key does not exist.</Message>\
+            <Resource>test-1250000000.cos.ap-beijing.myqcloud.com/a.txt</Resource>\
+            <RequestId>NjQ2ZTQ2_body</RequestId><TraceId>OGVmYzZi</TraceId></Error>";
+        let err = CosError::from_response(StatusCode::NOT_FOUND, body.to_string(), Some("header-id".to_string()));
+        match &err {
+            CosError::Client { code, message, .. } => {
+                assert_eq!(code, "NoSuchKey");
+                assert_eq!(message, "The specified key does not exist.");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(
+            err.details().unwrap().resource.as_deref(),
+            Some("test-1250000000.cos.ap-beijing.myqcloud.com/a.txt")
+        );
+        assert_eq!(err.status(), Some(404));
+        assert_eq!(err.request_id(), Some("NjQ2ZTQ2_body"));
+        assert_eq!(err.trace_id(), Some("OGVmYzZi"));
+
+        let err = CosError::from_response(StatusCode::BAD_GATEWAY, "bad gateway".to_string(), Some("header-id".to_string()));
+        match &err {
+            CosError::Server { code, message, .. } => {
+                assert_eq!(code, "502 Bad Gateway");
+                assert_eq!(message, "bad gateway");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(err.details().unwrap().body, "bad gateway");
+        assert_eq!(err.request_id(), Some("header-id"));
+        assert_eq!(err.code(), Some("502 Bad Gateway"));
+    }
+}
@@ -0,0 +1,361 @@
+//! 图片处理模块
+//!
+//! 提供数据万象（CI）上传时处理的 `Pic-Operations` 请求头及其处理结果的解析，
+//! 以及下载时的图片处理参数
+
+use crate::error::{CosError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// 上传时的图片处理规则，以 `Pic-Operations` 请求头发送
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PicOperations {
+    /// 是否在结果中返回原图信息
+    #[serde(serialize_with = "serialize_flag")]
+    pub is_pic_info: bool,
+    /// 处理规则，每条规则生成一个处理后的对象
+    pub rules: Vec<PicRule>,
+}
+
+impl PicOperations {
+    /// 创建空的处理规则
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置是否返回原图信息
+    pub fn with_pic_info(mut self, is_pic_info: bool) -> Self {
+        self.is_pic_info = is_pic_info;
+        self
+    }
+
+    /// 添加一条处理规则
+    pub fn with_rule(mut self, rule: PicRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// 生成请求头的值
+    pub(crate) fn header_value(&self) -> Result<String> {
+        if self.rules.len() > MAX_PIC_RULES {
+            return Err(CosError::client(
+                "InvalidArgument",
+                format!("At most {} Pic-Operations rules are allowed", MAX_PIC_RULES).as_str(),
+            ));
+        }
+        serde_json::to_string(self)
+            .map_err(|e| CosError::other(format!("Failed to serialize Pic-Operations: {}", e)))
+    }
+}
+
+/// 单个请求最多可以包含的处理规则数量
+pub const MAX_PIC_RULES: usize = 5;
+
+/// 图片处理规则
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PicRule {
+    /// 处理结果的对象键；以 `/` 开头时为存储桶内的绝对路径，否则相对于原图所在目录
+    #[serde(rename = "fileid")]
+    pub file_id: String,
+    /// 处理参数，如 `imageMogr2/thumbnail/200x`
+    pub rule: String,
+    /// 处理结果保存的存储桶，默认与原图相同
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+}
+
+impl PicRule {
+    /// 创建处理规则
+    pub fn new<S: Into<String>>(file_id: S, rule: S) -> Self {
+        Self {
+            file_id: file_id.into(),
+            rule: rule.into(),
+            bucket: None,
+        }
+    }
+
+    /// 将处理结果保存到其他存储桶
+    pub fn with_bucket<S: Into<String>>(mut self, bucket: S) -> Self {
+        self.bucket = Some(bucket.into());
+        self
+    }
+}
+
+fn serialize_flag<S: serde::Serializer>(value: &bool, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u8(u8::from(*value))
+}
+
+/// 上传时图片处理的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename = "UploadResult")]
+pub struct UploadResult {
+    /// 原图信息
+    #[serde(rename = "OriginalInfo")]
+    pub original: Option<OriginalInfo>,
+    /// 处理后生成的对象
+    #[serde(rename = "ProcessResults", default)]
+    pub process_results: ProcessResults,
+}
+
+/// 原图信息
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct OriginalInfo {
+    #[serde(rename = "Key", default)]
+    pub key: String,
+    #[serde(rename = "Location", default)]
+    pub location: String,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+    /// 仅在 `is_pic_info` 开启时返回
+    #[serde(rename = "ImageInfo")]
+    pub image_info: Option<ImageInfo>,
+}
+
+/// 图片基本信息
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ImageInfo {
+    #[serde(rename = "Format", default)]
+    pub format: String,
+    #[serde(rename = "Width", default)]
+    pub width: u32,
+    #[serde(rename = "Height", default)]
+    pub height: u32,
+    #[serde(rename = "Quality", default)]
+    pub quality: u32,
+    /// 图片主色调
+    #[serde(rename = "Ave", default)]
+    pub ave: String,
+    #[serde(rename = "Orientation", default)]
+    pub orientation: u32,
+}
+
+/// 处理结果列表
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ProcessResults {
+    #[serde(rename = "Object", default)]
+    pub objects: Vec<ProcessedObject>,
+}
+
+/// 处理后生成的对象
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ProcessedObject {
+    #[serde(rename = "Key", default)]
+    pub key: String,
+    #[serde(rename = "Location", default)]
+    pub location: String,
+    #[serde(rename = "Format", default)]
+    pub format: String,
+    #[serde(rename = "Width", default)]
+    pub width: u32,
+    #[serde(rename = "Height", default)]
+    pub height: u32,
+    #[serde(rename = "Size", default)]
+    pub size: u64,
+    #[serde(rename = "Quality", default)]
+    pub quality: u32,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+}
+
+impl UploadResult {
+    /// 解析响应体中的处理结果
+    pub(crate) fn parse(xml: &str) -> Result<Self> {
+        quick_xml::de::from_str(xml)
+            .map_err(|e| CosError::other(format!("Failed to parse upload result: {}", e)))
+    }
+}
+
+/// 下载时的图片处理参数，以查询参数发送并参与签名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageProcess {
+    name: String,
+    value: String,
+}
+
+impl ImageProcess {
+    /// 使用原始的处理参数，如 `imageMogr2/thumbnail/200x` 或 `ci-process=originImage`
+    pub fn raw<S: AsRef<str>>(param: S) -> Self {
+        let param = param.as_ref().trim_start_matches('?');
+        let (name, value) = param.split_once('=').unwrap_or((param, ""));
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// 使用 `ci-process` 参数，如 `originImage`
+    pub fn ci_process<S: Into<String>>(process: S) -> Self {
+        Self {
+            name: "ci-process".to_string(),
+            value: process.into(),
+        }
+    }
+
+    /// 使用在控制台预先配置的图片样式
+    pub fn style<S: Into<String>>(style: S) -> Self {
+        Self {
+            name: style.into(),
+            value: String::new(),
+        }
+    }
+
+    /// 写入请求的查询参数
+    pub(crate) fn insert_into(&self, params: &mut HashMap<String, String>) {
+        params.insert(self.name.clone(), self.value.clone());
+    }
+}
+
+impl From<ImageMogr2> for ImageProcess {
+    fn from(mogr2: ImageMogr2) -> Self {
+        Self {
+            name: mogr2.to_string(),
+            value: String::new(),
+        }
+    }
+}
+
+/// 常用 `imageMogr2` 图片处理操作的构造器，按调用顺序依次处理
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageMogr2 {
+    operations: Vec<String>,
+}
+
+impl ImageMogr2 {
+    /// 创建空的处理操作
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按宽度等比缩放
+    pub fn thumbnail_width(self, width: u32) -> Self {
+        self.push(format!("thumbnail/{}x", width))
+    }
+
+    /// 按高度等比缩放
+    pub fn thumbnail_height(self, height: u32) -> Self {
+        self.push(format!("thumbnail/x{}", height))
+    }
+
+    /// 等比缩放到不超过指定的宽高
+    pub fn thumbnail(self, width: u32, height: u32) -> Self {
+        self.push(format!("thumbnail/{}x{}", width, height))
+    }
+
+    /// 从图片中心裁剪出指定宽高的区域
+    pub fn crop(self, width: u32, height: u32) -> Self {
+        self.push(format!("crop/{}x{}", width, height))
+    }
+
+    /// 转换图片格式，如 `webp`、`png`、`jpg`
+    pub fn format<S: AsRef<str>>(self, format: S) -> Self {
+        self.push(format!("format/{}", format.as_ref()))
+    }
+
+    /// 设置图片质量（1-100），仅对 JPG 和 WEBP 有效
+    pub fn quality(self, quality: u8) -> Self {
+        self.push(format!("quality/{}", quality.clamp(1, 100)))
+    }
+
+    /// 顺时针旋转指定角度（0-360）
+    pub fn rotate(self, degrees: u16) -> Self {
+        self.push(format!("rotate/{}", degrees % 360))
+    }
+
+    /// 去除图片元信息
+    pub fn strip(self) -> Self {
+        self.push("strip".to_string())
+    }
+
+    fn push(mut self, operation: String) -> Self {
+        self.operations.push(operation);
+        self
+    }
+}
+
+impl fmt::Display for ImageMogr2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("imageMogr2")?;
+        for operation in &self.operations {
+            write!(f, "/{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pic_operations_header() {
+        let operations = PicOperations::new()
+            .with_pic_info(true)
+            .with_rule(PicRule::new("thumb/photo_200.jpg", "imageMogr2/thumbnail/200x"))
+            .with_rule(PicRule::new("/watermark.png", "imageMogr2/format/png").with_bucket("other-1250000000"));
+
+        assert_eq!(
+            operations.header_value().unwrap(),
+            r#"{"is_pic_info":1,"rules":[{"fileid":"thumb/photo_200.jpg","rule":"imageMogr2/thumbnail/200x"},{"fileid":"/watermark.png","rule":"imageMogr2/format/png","bucket":"other-1250000000"}]}"#
+        );
+
+        let mut operations = PicOperations::new();
+        operations.rules = vec![PicRule::new("a", "b"); MAX_PIC_RULES + 1];
+        assert!(operations.header_value().is_err());
+    }
+
+    #[test]
+    fn test_parse_upload_result() {
+        let xml = r#"<UploadResult>
+            <OriginalInfo>
+                <Key>photo.jpg</Key>
+                <Location>examplebucket-1250000000.cos.ap-chongqing.myqcloud.com/photo.jpg</Location>
+                <ImageInfo>
+                    <Format>JPEG</Format>
+                    <Width>640</Width>
+                    <Height>427</Height>
+                    <Quality>100</Quality>
+                    <Ave>0xa08162</Ave>
+                    <Orientation>0</Orientation>
+                </ImageInfo>
+                <ETag>"8894dbe5e3ebfaf761e39b9d619c28f3"</ETag>
+            </OriginalInfo>
+            <ProcessResults>
+                <Object>
+                    <Key>thumb/photo_200.jpg</Key>
+                    <Location>examplebucket-1250000000.cos.ap-chongqing.myqcloud.com/thumb/photo_200.jpg</Location>
+                    <Format>JPEG</Format>
+                    <Width>200</Width>
+                    <Height>133</Height>
+                    <Size>8575</Size>
+                    <Quality>100</Quality>
+                    <ETag>"0c1a3b6f8c0f1d0e0d3b0d8b1c9d2e4f"</ETag>
+                </Object>
+            </ProcessResults>
+        </UploadResult>"#;
+
+        let result = UploadResult::parse(xml).unwrap();
+        let original = result.original.unwrap();
+        assert_eq!(original.key, "photo.jpg");
+        let info = original.image_info.unwrap();
+        assert_eq!((info.format.as_str(), info.width, info.height), ("JPEG", 640, 427));
+
+        let objects = result.process_results.objects;
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].key, "thumb/photo_200.jpg");
+        assert_eq!((objects[0].width, objects[0].height, objects[0].size), (200, 133, 8575));
+    }
+
+    #[test]
+    fn test_image_process_params() {
+        let mogr2 = ImageMogr2::new().thumbnail_width(200).crop(100, 100).format("webp").quality(120);
+        assert_eq!(mogr2.to_string(), "imageMogr2/thumbnail/200x/crop/100x100/format/webp/quality/100");
+
+        let mut params = HashMap::new();
+        ImageProcess::from(mogr2).insert_into(&mut params);
+        assert_eq!(params["imageMogr2/thumbnail/200x/crop/100x100/format/webp/quality/100"], "");
+
+        assert_eq!(ImageProcess::raw("ci-process=originImage"), ImageProcess::ci_process("originImage"));
+        assert_eq!(ImageProcess::raw("?imageMogr2/strip"), ImageProcess::from(ImageMogr2::new().strip()));
+    }
+}
@@ -0,0 +1,462 @@
+//! 清单模块
+//!
+//! 提供存储桶清单配置的读写接口，以及读取已生成清单文件的辅助功能
+
+use crate::bucket::BucketClient;
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use crate::object::{check_md5, content_md5, ObjectClient};
+use flate2::read::GzDecoder;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+
+/// 清单配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "InventoryConfiguration")]
+pub struct InventoryConfiguration {
+    /// 清单名称，同一存储桶内唯一
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "IsEnabled")]
+    pub is_enabled: bool,
+    #[serde(rename = "Destination")]
+    pub destination: InventoryDestination,
+    #[serde(rename = "Schedule")]
+    pub schedule: InventorySchedule,
+    /// 只列出指定前缀的对象
+    #[serde(rename = "Filter", skip_serializing_if = "Option::is_none")]
+    pub filter: Option<InventoryFilter>,
+    #[serde(rename = "IncludedObjectVersions")]
+    pub included_object_versions: IncludedObjectVersions,
+    /// 清单中除 Bucket 和 Key 外额外包含的字段
+    #[serde(rename = "OptionalFields", skip_serializing_if = "Option::is_none")]
+    pub optional_fields: Option<InventoryOptionalFields>,
+}
+
+impl InventoryConfiguration {
+    /// 创建每天生成、只包含当前版本的清单配置
+    pub fn new<S: Into<String>>(id: S, destination: InventoryDestination) -> Self {
+        Self {
+            id: id.into(),
+            is_enabled: true,
+            destination,
+            schedule: InventorySchedule {
+                frequency: InventoryFrequency::Daily,
+            },
+            filter: None,
+            included_object_versions: IncludedObjectVersions::Current,
+            optional_fields: None,
+        }
+    }
+}
+
+/// 清单的投递位置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryDestination {
+    #[serde(rename = "COSBucketDestination")]
+    pub cos_bucket_destination: CosBucketDestination,
+}
+
+impl InventoryDestination {
+    /// 投递到指定地域的存储桶，清单文件默认使用 CSV 格式
+    pub fn new(region: &str, bucket: &str) -> Self {
+        Self {
+            cos_bucket_destination: CosBucketDestination {
+                format: InventoryFormat::Csv,
+                account_id: bucket.rsplit_once('-').map(|(_, app_id)| app_id.to_string()),
+                bucket: format!("qcs::cos:{}::{}", region, bucket),
+                prefix: None,
+                encryption: None,
+            },
+        }
+    }
+}
+
+/// 目标存储桶信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CosBucketDestination {
+    #[serde(rename = "Format")]
+    pub format: InventoryFormat,
+    /// 目标存储桶所有者的 APPID
+    #[serde(rename = "AccountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// 目标存储桶，格式为 `qcs::cos:<region>::<bucket-appid>`
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    /// 清单文件的存放前缀
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// 清单文件的服务端加密方式
+    #[serde(rename = "Encryption", skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<InventoryEncryption>,
+}
+
+/// 清单文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InventoryFormat {
+    #[serde(rename = "CSV")]
+    Csv,
+}
+
+/// 清单文件的服务端加密方式，目前只支持 SSE-COS
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryEncryption {
+    #[serde(rename = "SSE-COS", default)]
+    sse_cos: String,
+}
+
+impl InventoryEncryption {
+    /// 使用 COS 托管密钥加密
+    pub fn sse_cos() -> Self {
+        Self::default()
+    }
+}
+
+/// 清单生成周期
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventorySchedule {
+    #[serde(rename = "Frequency")]
+    pub frequency: InventoryFrequency,
+}
+
+/// 清单生成频率
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InventoryFrequency {
+    Daily,
+    Weekly,
+}
+
+/// 清单过滤条件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InventoryFilter {
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+}
+
+/// 清单包含的对象版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IncludedObjectVersions {
+    /// 所有历史版本
+    All,
+    /// 只包含当前版本
+    Current,
+}
+
+/// 清单的可选字段
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InventoryOptionalFields {
+    /// 字段名，如 `Size`、`LastModifiedDate`、`ETag`、`StorageClass`、
+    /// `IsMultipartUploaded`、`ReplicationStatus`
+    #[serde(rename = "Field", default)]
+    pub fields: Vec<String>,
+}
+
+impl InventoryOptionalFields {
+    /// 由字段名列表创建
+    pub fn new<I, S>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            fields: fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// 列出清单配置的 XML 结构
+#[derive(Debug, Deserialize)]
+struct ListInventoryConfigurationResult {
+    #[serde(rename = "InventoryConfiguration", default)]
+    configurations: Vec<InventoryConfiguration>,
+    #[serde(rename = "IsTruncated", default)]
+    is_truncated: bool,
+    #[serde(rename = "NextContinuationToken")]
+    next_continuation_token: Option<String>,
+}
+
+impl BucketClient {
+    /// 创建或覆盖名为 `id` 的清单配置
+    pub async fn put_bucket_inventory(&self, id: &str, config: &InventoryConfiguration) -> Result<()> {
+        if config.id != id {
+            return Err(CosError::config(format!(
+                "Inventory id {:?} does not match the configuration id {:?}",
+                id, config.id
+            )));
+        }
+        let xml_body = quick_xml::se::to_string(config)
+            .map_err(|e| CosError::other(format!("Failed to serialize inventory configuration: {}", e)))?;
+        let mut headers = HashMap::new();
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
+
+        self.client
+            .put_with_headers("/", inventory_params(Some(id)), headers, Some(xml_body))
+            .await?;
+        Ok(())
+    }
+
+    /// 获取名为 `id` 的清单配置
+    pub async fn get_bucket_inventory(&self, id: &str) -> Result<InventoryConfiguration> {
+        let response = self.client.get("/", inventory_params(Some(id))).await?;
+        CosClient::parse_xml(response).await
+    }
+
+    /// 列出存储桶的全部清单配置，会自动翻页
+    pub async fn list_bucket_inventory_configurations(&self) -> Result<Vec<InventoryConfiguration>> {
+        let mut configurations = Vec::new();
+        let mut token = None;
+
+        loop {
+            let mut params = inventory_params(None);
+            if let Some(token) = token.take() {
+                params.insert("continuation-token".to_string(), token);
+            }
+            let response = self.client.get("/", params).await?;
+            let page: ListInventoryConfigurationResult = CosClient::parse_xml(response).await?;
+
+            configurations.extend(page.configurations);
+            match page.next_continuation_token {
+                Some(next) if page.is_truncated && !next.is_empty() => token = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(configurations)
+    }
+
+    /// 删除名为 `id` 的清单配置
+    pub async fn delete_bucket_inventory(&self, id: &str) -> Result<()> {
+        self.client.delete("/", inventory_params(Some(id))).await?;
+        Ok(())
+    }
+}
+
+/// 清单子资源的查询参数
+fn inventory_params(id: Option<&str>) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("inventory".to_string(), "".to_string());
+    if let Some(id) = id {
+        params.insert("id".to_string(), id.to_string());
+    }
+    params
+}
+
+/// 清单任务生成的 manifest.json
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryManifest {
+    pub source_bucket: String,
+    pub destination_bucket: String,
+    #[serde(default)]
+    pub version: String,
+    /// 生成时间，Unix 时间戳（秒）
+    #[serde(default)]
+    pub creation_timestamp: String,
+    pub file_format: String,
+    /// 以逗号分隔的 CSV 列名，如 `Bucket, Key, Size`
+    pub file_schema: String,
+    pub files: Vec<InventoryManifestFile>,
+}
+
+impl InventoryManifest {
+    /// CSV 每一列的字段名
+    pub fn schema(&self) -> Vec<String> {
+        self.file_schema.split(',').map(|s| s.trim().to_string()).collect()
+    }
+}
+
+/// manifest.json 中的清单文件
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryManifestFile {
+    /// 清单文件在目标存储桶中的对象键
+    pub key: String,
+    #[serde(default)]
+    pub size: u64,
+    /// gzip 压缩后文件的 MD5
+    #[serde(rename = "MD5checksum", alias = "md5Checksum", default)]
+    pub md5_checksum: Option<String>,
+}
+
+/// 清单中的一条对象记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InventoryRecord {
+    pub bucket: String,
+    /// 已解码的对象键
+    pub key: String,
+    /// 按列名索引的全部字段，值为清单中的原始内容
+    pub fields: HashMap<String, String>,
+}
+
+impl InventoryRecord {
+    /// 获取指定列的值
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.fields.get(field).map(|s| s.as_str())
+    }
+
+    /// 对象大小，清单未包含 Size 字段时为空
+    pub fn size(&self) -> Option<u64> {
+        self.get("Size").and_then(|s| s.parse().ok())
+    }
+}
+
+impl ObjectClient {
+    /// 读取清单任务生成的 manifest.json
+    ///
+    /// 客户端需指向清单的目标存储桶，`manifest_key` 形如
+    /// `<prefix>/<appid>/<bucket>/<inventory-id>/<date>/manifest.json`。
+    pub async fn get_inventory_manifest(&self, manifest_key: &str) -> Result<InventoryManifest> {
+        let response = self.get_object(manifest_key).await?;
+        Ok(serde_json::from_slice(&response.data)?)
+    }
+
+    /// 依次下载 manifest 引用的 CSV.gz 文件，并以流的形式返回解析后的记录
+    ///
+    /// 每次只下载并解压一个文件，文件带有 MD5 时会先校验再解析。
+    pub fn inventory_records<'a>(
+        &'a self,
+        manifest: &'a InventoryManifest,
+    ) -> impl Stream<Item = Result<InventoryRecord>> + 'a {
+        let schema = manifest.schema();
+        stream::iter(manifest.files.iter())
+            .then(move |file| {
+                let schema = schema.clone();
+                async move {
+                    let response = self.get_object(&file.key).await?;
+                    if let Some(expected) = file.md5_checksum.as_deref() {
+                        check_md5(&expected.to_lowercase(), md5::compute(&response.data))?;
+                    }
+                    let mut text = String::new();
+                    GzDecoder::new(&response.data[..])
+                        .read_to_string(&mut text)
+                        .map_err(|e| CosError::other(format!("Failed to decompress {}: {}", file.key, e)))?;
+                    Ok::<_, CosError>(stream::iter(parse_inventory_csv(&text, &schema)))
+                }
+            })
+            .try_flatten()
+    }
+}
+
+/// 解析清单 CSV 内容
+fn parse_inventory_csv(text: &str, schema: &[String]) -> Vec<Result<InventoryRecord>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let values = parse_csv_line(line)?;
+            if values.len() != schema.len() {
+                return Err(CosError::other(format!(
+                    "Inventory record has {} fields, schema has {}: {}",
+                    values.len(),
+                    schema.len(),
+                    line
+                )));
+            }
+            let fields: HashMap<String, String> = schema.iter().cloned().zip(values).collect();
+            let bucket = fields.get("Bucket").cloned().unwrap_or_default();
+            let raw_key = fields.get("Key").map(|s| s.as_str()).unwrap_or_default();
+            let key = urlencoding::decode(raw_key)
+                .map(|k| k.into_owned())
+                .unwrap_or_else(|_| raw_key.to_string());
+            Ok(InventoryRecord { bucket, key, fields })
+        })
+        .collect()
+}
+
+/// 解析一行 CSV，支持双引号包裹的字段以及 `""` 转义
+fn parse_csv_line(line: &str) -> Result<Vec<String>> {
+    let mut values = Vec::new();
+    let mut value = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => values.push(std::mem::take(&mut value)),
+            _ => value.push(c),
+        }
+    }
+    if quoted {
+        return Err(CosError::other(format!("Unterminated quote in inventory record: {}", line)));
+    }
+    values.push(value);
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    #[test]
+    fn test_inventory_xml_round_trip() {
+        let mut destination = InventoryDestination::new("ap-beijing", "dest-1250000000");
+        destination.cos_bucket_destination.prefix = Some("inventory".to_string());
+        destination.cos_bucket_destination.encryption = Some(InventoryEncryption::sse_cos());
+        let mut config = InventoryConfiguration::new("list1", destination);
+        config.optional_fields = Some(InventoryOptionalFields::new(["Size", "ETag"]));
+
+        let xml = quick_xml::se::to_string(&config).unwrap();
+        assert!(xml.contains("<Bucket>qcs::cos:ap-beijing::dest-1250000000</Bucket>"));
+        assert!(xml.contains("<AccountId>1250000000</AccountId>"));
+        assert!(xml.contains("<SSE-COS/>") || xml.contains("<SSE-COS></SSE-COS>"));
+        assert!(xml.contains("<OptionalFields><Field>Size</Field><Field>ETag</Field></OptionalFields>"));
+
+        let xml = "<ListInventoryConfigurationResult><InventoryConfiguration><Id>list1</Id>\
+            <IsEnabled>True</IsEnabled><Destination><COSBucketDestination><Format>CSV</Format>\
+            <Bucket>qcs::cos:ap-beijing::dest-1250000000</Bucket><Encryption><SSE-COS></SSE-COS></Encryption>\
+            </COSBucketDestination></Destination><Schedule><Frequency>Weekly</Frequency></Schedule>\
+            <IncludedObjectVersions>All</IncludedObjectVersions></InventoryConfiguration>\
+            <IsTruncated>false</IsTruncated></ListInventoryConfigurationResult>";
+        let list: ListInventoryConfigurationResult = quick_xml::de::from_str(xml).unwrap();
+        let config = &list.configurations[0];
+        assert!(config.is_enabled);
+        assert_eq!(config.schedule.frequency, InventoryFrequency::Weekly);
+        assert_eq!(config.included_object_versions, IncludedObjectVersions::All);
+        assert_eq!(
+            config.destination.cos_bucket_destination.encryption,
+            Some(InventoryEncryption::sse_cos())
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_line() {
+        assert_eq!(parse_csv_line(r#""b","a%2Cb","3""#).unwrap(), vec!["b", "a%2Cb", "3"]);
+        assert_eq!(parse_csv_line(r#""say ""hi""",,x"#).unwrap(), vec![r#"say "hi""#, "", "x"]);
+        assert!(parse_csv_line(r#""open"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_inventory_records() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(b"\"src-123\",\"dir%2Fa.txt\",\"5\"\n\"src-123\",\"b.txt\",\"7\"\n")
+            .unwrap();
+        let gz = encoder.finish().unwrap();
+        let manifest = format!(
+            r#"{{"sourceBucket":"src-123","destinationBucket":"qcs::cos:ap-beijing::dest-123",
+            "fileFormat":"CSV","fileSchema":"Bucket, Key, Size",
+            "files":[{{"key":"inv/data/1.csv.gz","size":{},"MD5checksum":"{:X}"}}]}}"#,
+            gz.len(),
+            md5::compute(&gz)
+        );
+
+        let client = ObjectClient::new(
+            client_for(vec![ServedResponse::new(200, &manifest), ServedResponse::bytes(200, gz)]).await,
+        );
+        let manifest = client.get_inventory_manifest("inv/manifest.json").await.unwrap();
+        let records: Vec<InventoryRecord> = client.inventory_records(&manifest).try_collect().await.unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].key, "dir/a.txt");
+        assert_eq!(records[0].size(), Some(5));
+        assert_eq!(records[1].get("Bucket"), Some("src-123"));
+    }
+}
@@ -4,7 +4,7 @@
 //!
 //! ## 快速开始
 //!
-//! ```rust
+//! ```rust,no_run
 //! use cos_rust_sdk::{Config, CosClient, ObjectClient};
 //! use std::time::Duration;
 //!
@@ -34,22 +34,101 @@
 //! }
 //! ```
 
+pub mod acl;
 pub mod auth;
 pub mod bucket;
+pub mod cleanup;
 pub mod client;
 pub mod config;
+pub mod copy;
+pub mod crc64;
+pub mod credentials;
+pub mod directory;
+pub mod domain;
+pub mod encryption;
 pub mod error;
+pub mod image;
+pub mod inventory;
+pub mod lifecycle;
+pub mod mime;
+pub mod multipart;
 pub mod object;
+pub mod origin;
+pub mod policy;
+#[cfg(feature = "config-file")]
+pub mod profile;
+pub mod post_policy;
+pub mod presign;
+pub mod range;
+pub mod referer;
+pub mod response;
+pub mod retry;
+pub mod restore;
+pub mod resumable;
+pub mod select;
+pub mod service;
+pub mod stream;
+pub mod symlink;
+pub mod tagging;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+pub mod tiering;
 pub mod sts;
+pub mod transfer;
+pub mod transfer_manager;
+
+mod tc3;
+mod trace;
+mod transport;
+
+#[cfg(test)]
+mod test_server;
 
 // 重新导出主要类型
-pub use auth::Auth;
-pub use bucket::{BucketClient, BucketAcl, ListObjectsOptions, ListObjectsV2Options};
-pub use client::CosClient;
-pub use config::Config;
-pub use error::{CosError, Result};
-pub use object::{ObjectClient, PutObjectResponse, GetObjectResponse, DeleteObjectResponse, HeadObjectResponse};
-pub use sts::{StsClient, TemporaryCredentials, Policy, Statement, GetCredentialsRequest};
+pub use acl::{ObjectAcl, AclGrants, AccessControlPolicy};
+pub use auth::{Auth, PresignedQuery, VerifiedSignature};
+pub use bucket::{BucketClient, BucketAcl, CreateBucketOptions, HeadBucketResponse, ListObjectsOptions, ListObjectsV2Options, ListObjectVersionsOptions, ListObjectVersionsResponse};
+pub use cleanup::{DeletePrefixOptions, DeletePrefixReport, EmptyBucketOptions, EmptyReport};
+pub use client::{CosClient, CustomRequest};
+pub use config::{Config, ConfigBuilder, NetworkType, DEFAULT_CLOCK_SKEW_ALLOWANCE, DEFAULT_SIGNATURE_VALIDITY};
+pub use copy::{
+    CopySource, CopyObjectOptions, CopyObjectResponse, CopyMultipartOptions, MetadataDirective,
+    RenameObjectOptions, RenameObjectResponse,
+};
+pub use credentials::{Credentials, CredentialsProvider, StaticCredentialsProvider};
+pub use directory::{UploadDirOptions, UploadDirReport, DownloadDirOptions, DownloadDirReport, FileTransfer, FailedTransfer};
+pub use domain::{DomainConfiguration, DomainRule, DomainStatus, DomainType, ForcedReplacement, PutBucketDomainResponse};
+pub use encryption::ServerSideEncryption;
+pub use error::{CosError, ErrorDetails, Result};
+pub use image::{ImageProcess, ImageMogr2, PicOperations, PicRule, UploadResult, OriginalInfo, ImageInfo, ProcessResults, ProcessedObject};
+pub use inventory::{InventoryConfiguration, InventoryDestination, CosBucketDestination, InventoryFormat, InventoryEncryption, InventorySchedule, InventoryFrequency, InventoryFilter, IncludedObjectVersions, InventoryOptionalFields, InventoryManifest, InventoryManifestFile, InventoryRecord};
+pub use lifecycle::{LifecycleConfig, LifecycleRule, LifecycleAction, PlannedAction};
+pub use multipart::{MultipartUploadOptions, InitiateMultipartUploadResponse, UploadPartOptions, UploadPartResponse, CompletedPart, UploadPartCopyResponse, CompleteMultipartUploadResponse, ListPartsOptions, ListPartsResponse, PartInfo, ListMultipartUploadsOptions, ListMultipartUploadsResponse, MultipartUploadInfo, AbortReport};
+pub use object::{attachment_disposition, validate_key, ObjectClient, PutObjectOptions, PutObjectResponse, GetObjectOptions, GetObjectResponse, DeleteObjectResponse, HeadObjectResponse, StorageClass, AppendObjectOptions, AppendObjectResponse, DeleteObjectsOptions, DeleteObjectsResponse, ObjectIdentifier, MIN_TRAFFIC_LIMIT, MAX_TRAFFIC_LIMIT};
+pub use origin::{OriginConfiguration, OriginRule, OriginType, OriginCondition, OriginParameter, OriginProtocol, OriginHttpHeader, OriginHeaderList, OriginHeader, OriginInfo, OriginHostInfo};
+pub use transfer::{UploadOptions, UploadFileResponse, DownloadOptions, DEFAULT_PART_TIMEOUT};
+pub use transfer_manager::{TransferManager, TransferConfig, TransferHandle, UploadSource, DownloadDest, DownloadResult, CopyResult, DEFAULT_MAX_CONCURRENT_REQUESTS};
+pub use policy::{BucketPolicy, Principal};
+#[cfg(feature = "config-file")]
+pub use profile::{ConfigFile, ProfileConfig};
+pub use post_policy::{PostPolicy, PostForm};
+pub use presign::{PresignOptions, PresignedRequest};
+pub use range::{ByteRange, ContentRange};
+pub use referer::{RefererConfiguration, RefererStatus, RefererType, EmptyReferer, DomainList};
+pub use response::CosResponse;
+pub use retry::RetryConfig;
+pub use restore::{RestoreTier, RestoreStatus};
+pub use resumable::{ResumableDownloadOptions, ResumableDownloadResponse};
+pub use select::{
+    SelectRequest, InputSerialization, OutputSerialization, CompressionType, FileHeaderInfo,
+    CsvInput, CsvOutput, JsonInput, JsonOutput, JsonType, QuoteFields, SelectStats, SelectObjectStream,
+};
+pub use service::{ServiceClient, ListBucketsResponse, BucketList, Bucket};
+pub use stream::{ObjectStream, ObjectMeta};
+pub use symlink::PutSymlinkOptions;
+pub use tagging::Tag;
+pub use tiering::{IntelligentTieringConfiguration, IntelligentTieringStatus, IntelligentTieringTransition};
+pub use sts::{AssumeRoleRequest, AssumeRoleResponse, AssumedRoleUser, StsCacheStats, StsClient, StsCredentialsProvider, StsSignatureMethod, TemporaryCredentials, Policy, Statement, GetCredentialsRequest};
 
 /// SDK 版本
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,414 @@
+//! 生命周期模块
+//!
+//! 提供存储桶生命周期规则的类型定义，以及在应用规则前预演其效果的模拟器
+
+use crate::bucket::ObjectInfo;
+use crate::error::{CosError, Result};
+use crate::object::StorageClass;
+pub use crate::tagging::Tag;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 生命周期配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "LifecycleConfiguration")]
+pub struct LifecycleConfig {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+/// 生命周期规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifecycleRule {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Filter", skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LifecycleFilter>,
+    /// 旧版规则直接在 Rule 下指定前缀
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Status")]
+    pub status: RuleStatus,
+    #[serde(rename = "Transition", default, skip_serializing_if = "Vec::is_empty")]
+    pub transitions: Vec<Transition>,
+    #[serde(rename = "Expiration", skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<Expiration>,
+    #[serde(
+        rename = "AbortIncompleteMultipartUpload",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUpload>,
+}
+
+/// 规则状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleStatus {
+    Enabled,
+    Disabled,
+}
+
+/// 规则过滤条件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LifecycleFilter {
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag", default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+    #[serde(rename = "And", skip_serializing_if = "Option::is_none")]
+    pub and: Option<LifecycleFilterAnd>,
+}
+
+/// 组合过滤条件（前缀与标签同时满足）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LifecycleFilterAnd {
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag", default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+}
+
+/// 沉降规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transition {
+    #[serde(rename = "Days", skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    /// ISO 8601 格式的日期，如 `2019-08-30T00:00:00+08:00`
+    #[serde(rename = "Date", skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: StorageClass,
+}
+
+/// 过期删除规则
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Expiration {
+    #[serde(rename = "Days", skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    /// ISO 8601 格式的日期，如 `2019-08-30T00:00:00+08:00`
+    #[serde(rename = "Date", skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(rename = "ExpiredObjectDeleteMarker", skip_serializing_if = "Option::is_none")]
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+/// 未完成分块上传的清理规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbortIncompleteMultipartUpload {
+    #[serde(rename = "DaysAfterInitiation")]
+    pub days_after_initiation: u32,
+}
+
+/// 参与模拟的对象
+#[derive(Debug, Clone)]
+pub struct LifecycleObject {
+    pub key: String,
+    /// 对象的最后修改时间，分块上传则为初始化时间
+    pub last_modified: DateTime<Utc>,
+    /// 对象标签，列表结果中不包含标签，带标签过滤的规则不会匹配未提供标签的对象
+    pub tags: Vec<Tag>,
+    /// 是否为未完成的分块上传
+    pub is_multipart_upload: bool,
+}
+
+impl LifecycleObject {
+    /// 创建普通对象
+    pub fn new<S: Into<String>>(key: S, last_modified: DateTime<Utc>) -> Self {
+        Self {
+            key: key.into(),
+            last_modified,
+            tags: Vec::new(),
+            is_multipart_upload: false,
+        }
+    }
+
+    /// 创建未完成的分块上传
+    pub fn multipart_upload<S: Into<String>>(key: S, initiated: DateTime<Utc>) -> Self {
+        Self {
+            is_multipart_upload: true,
+            ..Self::new(key, initiated)
+        }
+    }
+
+    /// 设置对象标签
+    pub fn with_tags(mut self, tags: Vec<Tag>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+impl TryFrom<&ObjectInfo> for LifecycleObject {
+    type Error = CosError;
+
+    fn try_from(info: &ObjectInfo) -> Result<Self> {
+        let last_modified = info.last_modified.ok_or_else(|| {
+            CosError::other(format!(
+                "Invalid LastModified '{}' for key '{}'",
+                info.last_modified_raw, info.key
+            ))
+        })?;
+        Ok(Self::new(info.key.clone(), last_modified))
+    }
+}
+
+/// 模拟得到的生命周期动作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleAction {
+    /// 过期删除
+    Expire,
+    /// 沉降到指定存储类型
+    Transition(StorageClass),
+    /// 清理未完成的分块上传
+    AbortMultipart,
+}
+
+/// 计划执行的生命周期动作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedAction {
+    pub key: String,
+    pub rule_id: Option<String>,
+    pub action: LifecycleAction,
+    /// 动作生效时间
+    pub effective_date: DateTime<Utc>,
+}
+
+impl LifecycleConfig {
+    /// 模拟在 `as_of` 时刻规则对给定对象产生的动作
+    ///
+    /// 每个对象最多产生一个动作：到期删除优先于沉降，多个沉降同时到期时取最晚生效的一个。
+    /// 按天数计算的规则以最后修改时间加上天数后向上取整到下一个 UTC 零点作为生效时间。
+    pub fn simulate<'a, I>(&self, objects: I, as_of: DateTime<Utc>) -> Vec<PlannedAction>
+    where
+        I: IntoIterator<Item = &'a LifecycleObject>,
+    {
+        objects
+            .into_iter()
+            .filter_map(|object| self.plan(object, as_of))
+            .collect()
+    }
+
+    /// 计算单个对象的动作
+    pub(crate) fn plan(&self, object: &LifecycleObject, as_of: DateTime<Utc>) -> Option<PlannedAction> {
+        let mut abort: Option<PlannedAction> = None;
+        let mut expire: Option<PlannedAction> = None;
+        let mut transition: Option<PlannedAction> = None;
+
+        for rule in self.rules.iter().filter(|r| r.matches(object)) {
+            let planned = |action, effective_date| PlannedAction {
+                key: object.key.clone(),
+                rule_id: rule.id.clone(),
+                action,
+                effective_date,
+            };
+
+            if object.is_multipart_upload {
+                if let Some(ref abort_rule) = rule.abort_incomplete_multipart_upload {
+                    let date = days_after(object.last_modified, abort_rule.days_after_initiation);
+                    if date <= as_of && abort.as_ref().is_none_or(|a| date < a.effective_date) {
+                        abort = Some(planned(LifecycleAction::AbortMultipart, date));
+                    }
+                }
+                continue;
+            }
+
+            if let Some(ref expiration) = rule.expiration {
+                if let Some(date) = effective_date(object, expiration.days, expiration.date.as_deref()) {
+                    if date <= as_of && expire.as_ref().is_none_or(|e| date < e.effective_date) {
+                        expire = Some(planned(LifecycleAction::Expire, date));
+                    }
+                }
+            }
+
+            for t in &rule.transitions {
+                if let Some(date) = effective_date(object, t.days, t.date.as_deref()) {
+                    if date <= as_of && transition.as_ref().is_none_or(|p| date > p.effective_date) {
+                        transition = Some(planned(LifecycleAction::Transition(t.storage_class.clone()), date));
+                    }
+                }
+            }
+        }
+
+        abort.or(expire).or(transition)
+    }
+}
+
+impl LifecycleRule {
+    /// 判断规则是否作用于该对象
+    fn matches(&self, object: &LifecycleObject) -> bool {
+        if self.status != RuleStatus::Enabled {
+            return false;
+        }
+
+        let mut prefixes = vec![self.prefix.as_deref()];
+        let mut tags: Vec<&Tag> = Vec::new();
+        if let Some(ref filter) = self.filter {
+            prefixes.push(filter.prefix.as_deref());
+            tags.extend(&filter.tags);
+            if let Some(ref and) = filter.and {
+                prefixes.push(and.prefix.as_deref());
+                tags.extend(&and.tags);
+            }
+        }
+
+        prefixes
+            .into_iter()
+            .flatten()
+            .all(|prefix| object.key.starts_with(prefix))
+            && tags.iter().all(|tag| object.tags.contains(tag))
+    }
+}
+
+/// 计算规则动作的生效时间
+fn effective_date(object: &LifecycleObject, days: Option<u32>, date: Option<&str>) -> Option<DateTime<Utc>> {
+    if let Some(days) = days {
+        return Some(days_after(object.last_modified, days));
+    }
+    date.and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// 在时间上加上天数，并向上取整到下一个 UTC 零点
+fn days_after(time: DateTime<Utc>, days: u32) -> DateTime<Utc> {
+    let shifted = time + Duration::days(days as i64);
+    let midnight = shifted.date_naive().and_time(NaiveTime::MIN).and_utc();
+    if midnight == shifted {
+        midnight
+    } else {
+        midnight + Duration::days(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn rule(id: &str, prefix: Option<&str>) -> LifecycleRule {
+        LifecycleRule {
+            id: Some(id.to_string()),
+            filter: Some(LifecycleFilter {
+                prefix: prefix.map(|p| p.to_string()),
+                ..Default::default()
+            }),
+            prefix: None,
+            status: RuleStatus::Enabled,
+            transitions: Vec::new(),
+            expiration: None,
+            abort_incomplete_multipart_upload: None,
+        }
+    }
+
+    #[test]
+    fn test_days_after_rounds_to_next_midnight() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        assert_eq!(days_after(time, 3), Utc.with_ymd_and_hms(2024, 1, 19, 0, 0, 0).unwrap());
+
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        assert_eq!(days_after(midnight, 3), Utc.with_ymd_and_hms(2024, 1, 18, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_simulate() {
+        let mut logs = rule("logs", Some("logs/"));
+        logs.transitions.push(Transition {
+            days: Some(30),
+            date: None,
+            storage_class: StorageClass::StandardIa,
+        });
+        logs.transitions.push(Transition {
+            days: Some(60),
+            date: None,
+            storage_class: StorageClass::Archive,
+        });
+        logs.expiration = Some(Expiration {
+            days: Some(365),
+            ..Default::default()
+        });
+
+        let mut tagged = rule("tagged", None);
+        tagged.filter = Some(LifecycleFilter {
+            and: Some(LifecycleFilterAnd {
+                prefix: Some("tmp/".to_string()),
+                tags: vec![Tag { key: "temp".to_string(), value: "true".to_string() }],
+            }),
+            ..Default::default()
+        });
+        tagged.expiration = Some(Expiration {
+            days: Some(1),
+            ..Default::default()
+        });
+        tagged.abort_incomplete_multipart_upload = Some(AbortIncompleteMultipartUpload {
+            days_after_initiation: 7,
+        });
+
+        let mut disabled = rule("disabled", None);
+        disabled.status = RuleStatus::Disabled;
+        disabled.expiration = Some(Expiration {
+            days: Some(0),
+            ..Default::default()
+        });
+
+        let config = LifecycleConfig {
+            rules: vec![logs, tagged, disabled],
+        };
+
+        let as_of = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let temp_tag = vec![Tag { key: "temp".to_string(), value: "true".to_string() }];
+        let objects = vec![
+            LifecycleObject::new("logs/a.log", Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap()),
+            LifecycleObject::new("logs/b.log", Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap()),
+            LifecycleObject::new("logs/new.log", Utc.with_ymd_and_hms(2024, 5, 31, 8, 0, 0).unwrap()),
+            LifecycleObject::new("tmp/x", Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap())
+                .with_tags(temp_tag.clone()),
+            LifecycleObject::new("tmp/untagged", Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap()),
+            LifecycleObject::multipart_upload("tmp/upload", Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap())
+                .with_tags(temp_tag),
+        ];
+
+        let actions = config.simulate(&objects, as_of);
+        assert_eq!(actions.len(), 4);
+
+        assert_eq!(actions[0].key, "logs/a.log");
+        assert_eq!(actions[0].action, LifecycleAction::Transition(StorageClass::Archive));
+        assert_eq!(actions[0].effective_date, Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap());
+
+        assert_eq!(actions[1].key, "logs/b.log");
+        assert_eq!(actions[1].action, LifecycleAction::Transition(StorageClass::StandardIa));
+
+        assert_eq!(actions[2].key, "tmp/x");
+        assert_eq!(actions[2].rule_id.as_deref(), Some("tagged"));
+        assert_eq!(actions[2].action, LifecycleAction::Expire);
+
+        assert_eq!(actions[3].key, "tmp/upload");
+        assert_eq!(actions[3].action, LifecycleAction::AbortMultipart);
+        assert_eq!(actions[3].effective_date, Utc.with_ymd_and_hms(2024, 5, 8, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_lifecycle_config() {
+        let xml = r#"<LifecycleConfiguration>
+            <Rule>
+                <ID>archive</ID>
+                <Filter><Prefix>backup/</Prefix></Filter>
+                <Status>Enabled</Status>
+                <Transition><Days>30</Days><StorageClass>ARCHIVE</StorageClass></Transition>
+                <Expiration><Date>2030-01-01T00:00:00+08:00</Date></Expiration>
+            </Rule>
+            <Rule>
+                <ID>uploads</ID>
+                <Filter/>
+                <Status>Disabled</Status>
+                <AbortIncompleteMultipartUpload><DaysAfterInitiation>7</DaysAfterInitiation></AbortIncompleteMultipartUpload>
+            </Rule>
+        </LifecycleConfiguration>"#;
+
+        let config: LifecycleConfig = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(config.rules.len(), 2);
+        assert_eq!(config.rules[0].filter.as_ref().unwrap().prefix.as_deref(), Some("backup/"));
+        assert_eq!(config.rules[0].transitions[0].storage_class, StorageClass::Archive);
+        assert_eq!(config.rules[1].status, RuleStatus::Disabled);
+        assert_eq!(
+            config.rules[1].abort_incomplete_multipart_upload.as_ref().unwrap().days_after_initiation,
+            7
+        );
+    }
+}
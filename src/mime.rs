@@ -0,0 +1,289 @@
+//! MIME 类型推断模块
+//!
+//! 根据文件扩展名或数据开头的文件签名（魔数）推断 Content-Type
+
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+/// 按内容检测时最多读取的字节数
+pub const SNIFF_LEN: usize = 512;
+
+/// 根据文件扩展名推断 Content-Type
+pub fn from_extension(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    match ext.as_str() {
+        // 文本文件
+        "txt" => Some("text/plain"),
+        "html" | "htm" => Some("text/html"),
+        "css" => Some("text/css"),
+        "js" => Some("application/javascript"),
+        "json" => Some("application/json"),
+        "xml" => Some("application/xml"),
+        "csv" => Some("text/csv"),
+        "md" => Some("text/markdown"),
+
+        // 图片格式
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "png" => Some("image/png"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        "bmp" => Some("image/bmp"),
+        "tiff" | "tif" => Some("image/tiff"),
+        "svg" => Some("image/svg+xml"),
+        "ico" => Some("image/x-icon"),
+        "heic" => Some("image/heic"),
+        "heif" => Some("image/heif"),
+        "avif" => Some("image/avif"),
+        "jxl" => Some("image/jxl"),
+
+        // 视频格式
+        "mp4" => Some("video/mp4"),
+        "avi" => Some("video/x-msvideo"),
+        "mov" => Some("video/quicktime"),
+        "wmv" => Some("video/x-ms-wmv"),
+        "flv" => Some("video/x-flv"),
+        "webm" => Some("video/webm"),
+        "mkv" => Some("video/x-matroska"),
+        "m4v" => Some("video/x-m4v"),
+        "3gp" => Some("video/3gpp"),
+        "3g2" => Some("video/3gpp2"),
+        "ts" => Some("video/mp2t"),
+        "mts" => Some("video/mp2t"),
+        "m2ts" => Some("video/mp2t"),
+        "ogv" => Some("video/ogg"),
+
+        // 音频格式
+        "mp3" => Some("audio/mpeg"),
+        "wav" => Some("audio/wav"),
+        "flac" => Some("audio/flac"),
+        "aac" => Some("audio/aac"),
+        "ogg" => Some("audio/ogg"),
+        "wma" => Some("audio/x-ms-wma"),
+        "m4a" => Some("audio/mp4"),
+        "opus" => Some("audio/opus"),
+
+        // 文档格式
+        "pdf" => Some("application/pdf"),
+        "doc" => Some("application/msword"),
+        "docx" => Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+        "xls" => Some("application/vnd.ms-excel"),
+        "xlsx" => Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        "ppt" => Some("application/vnd.ms-powerpoint"),
+        "pptx" => Some("application/vnd.openxmlformats-officedocument.presentationml.presentation"),
+        "rtf" => Some("application/rtf"),
+
+        // 压缩文件
+        "zip" => Some("application/zip"),
+        "rar" => Some("application/vnd.rar"),
+        "7z" => Some("application/x-7z-compressed"),
+        "tar" => Some("application/x-tar"),
+        "gz" => Some("application/gzip"),
+        "bz2" => Some("application/x-bzip2"),
+
+        // 其他常见格式
+        "bin" => Some("application/octet-stream"),
+        "exe" => Some("application/octet-stream"),
+        "dmg" => Some("application/x-apple-diskimage"),
+        "iso" => Some("application/x-iso9660-image"),
+
+        _ => None,
+    }
+}
+
+/// 根据数据开头的文件签名推断 Content-Type，只检查前 [`SNIFF_LEN`] 字节
+///
+/// 只识别签名明确的常见格式；Office 文档等基于 ZIP 的格式会识别为 `application/zip`。
+pub fn from_content(data: &[u8]) -> Option<&'static str> {
+    let data = &data[..data.len().min(SNIFF_LEN)];
+    let at = |offset: usize, signature: &[u8]| data.get(offset..offset + signature.len()) == Some(signature);
+
+    // 图片格式
+    if at(0, b"\x89PNG\r\n\x1a\n") {
+        return Some("image/png");
+    }
+    if at(0, b"\xff\xd8\xff") {
+        return Some("image/jpeg");
+    }
+    if at(0, b"GIF87a") || at(0, b"GIF89a") {
+        return Some("image/gif");
+    }
+    if at(0, b"II*\0") || at(0, b"MM\0*") {
+        return Some("image/tiff");
+    }
+    if at(0, b"\0\0\x01\0") {
+        return Some("image/x-icon");
+    }
+    if at(0, b"\xff\x0a") || at(0, b"\0\0\0\x0cJXL \r\n\x87\n") {
+        return Some("image/jxl");
+    }
+    if at(0, b"BM") && at(6, b"\0\0\0\0") {
+        return Some("image/bmp");
+    }
+
+    // RIFF 容器
+    if at(0, b"RIFF") {
+        if at(8, b"WEBP") {
+            return Some("image/webp");
+        }
+        if at(8, b"WAVE") {
+            return Some("audio/wav");
+        }
+        if at(8, b"AVI ") {
+            return Some("video/x-msvideo");
+        }
+    }
+
+    // ISO 基础媒体文件格式，按主品牌区分
+    if at(4, b"ftyp") {
+        let brand = data.get(8..12)?;
+        return Some(match brand {
+            b"avif" | b"avis" => "image/avif",
+            b"heic" | b"heix" | b"heim" | b"heis" => "image/heic",
+            b"mif1" | b"msf1" => "image/heif",
+            b"qt  " => "video/quicktime",
+            b"M4A " => "audio/mp4",
+            b"M4V " => "video/x-m4v",
+            _ if brand.starts_with(b"3g2") => "video/3gpp2",
+            _ if brand.starts_with(b"3gp") => "video/3gpp",
+            _ => "video/mp4",
+        });
+    }
+
+    // 音视频格式
+    if at(0, b"\x1a\x45\xdf\xa3") {
+        let is_webm = data.windows(4).any(|w| w == b"webm");
+        return Some(if is_webm { "video/webm" } else { "video/x-matroska" });
+    }
+    if at(0, b"FLV\x01") {
+        return Some("video/x-flv");
+    }
+    if at(0, b"ID3") || at(0, b"\xff\xfb") || at(0, b"\xff\xf3") || at(0, b"\xff\xf2") {
+        return Some("audio/mpeg");
+    }
+    if at(0, b"\xff\xf1") || at(0, b"\xff\xf9") {
+        return Some("audio/aac");
+    }
+    if at(0, b"fLaC") {
+        return Some("audio/flac");
+    }
+    if at(0, b"OggS") {
+        return Some(if data.windows(8).any(|w| w == b"OpusHead") { "audio/opus" } else { "audio/ogg" });
+    }
+
+    // 文档与压缩格式
+    if at(0, b"%PDF-") {
+        return Some("application/pdf");
+    }
+    if at(0, b"{\\rtf") {
+        return Some("application/rtf");
+    }
+    if at(0, b"PK\x03\x04") || at(0, b"PK\x05\x06") {
+        return Some("application/zip");
+    }
+    if at(0, b"\x1f\x8b") {
+        return Some("application/gzip");
+    }
+    if at(0, b"BZh") {
+        return Some("application/x-bzip2");
+    }
+    if at(0, b"7z\xbc\xaf\x27\x1c") {
+        return Some("application/x-7z-compressed");
+    }
+    if at(0, b"Rar!\x1a\x07") {
+        return Some("application/vnd.rar");
+    }
+    if at(257, b"ustar") {
+        return Some("application/x-tar");
+    }
+
+    // 文本标记格式，忽略开头的 BOM 与空白
+    let text = data.strip_prefix(b"\xef\xbb\xbf").unwrap_or(data);
+    let start = text.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(text.len());
+    let text = text[start..].to_ascii_lowercase();
+    if text.starts_with(b"<!doctype html") || text.starts_with(b"<html") {
+        return Some("text/html");
+    }
+    if text.starts_with(b"<svg") || (text.starts_with(b"<?xml") && text.windows(4).any(|w| w == b"<svg")) {
+        return Some("image/svg+xml");
+    }
+    if text.starts_with(b"<?xml") {
+        return Some("application/xml");
+    }
+
+    None
+}
+
+/// 推断本地文件的 Content-Type
+///
+/// 先按扩展名查找，失败且 `sniff` 为 true 时再读取文件开头的 [`SNIFF_LEN`] 字节按内容检测。
+pub async fn from_file(path: &Path, sniff: bool) -> Option<&'static str> {
+    if let Some(content_type) = from_extension(path) {
+        return Some(content_type);
+    }
+    if !sniff {
+        return None;
+    }
+
+    let file = tokio::fs::File::open(path).await.ok()?;
+    let mut data = Vec::with_capacity(SNIFF_LEN);
+    file.take(SNIFF_LEN as u64).read_to_end(&mut data).await.ok()?;
+    from_content(&data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(from_extension(Path::new("a/photo.JPG")), Some("image/jpeg"));
+        assert_eq!(from_extension(Path::new("report.pdf")), Some("application/pdf"));
+        assert_eq!(from_extension(Path::new("README")), None);
+        assert_eq!(from_extension(Path::new("data.unknown")), None);
+    }
+
+    #[test]
+    fn test_from_content() {
+        let cases: &[(&[u8], &str)] = &[
+            (b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", "image/png"),
+            (b"\xff\xd8\xff\xe0\0\x10JFIF", "image/jpeg"),
+            (b"GIF89a\x01\0", "image/gif"),
+            (b"RIFF\x24\0\0\0WEBPVP8 ", "image/webp"),
+            (b"RIFF\x24\0\0\0WAVEfmt ", "audio/wav"),
+            (b"%PDF-1.7\n", "application/pdf"),
+            (b"PK\x03\x04\x14\0", "application/zip"),
+            (b"\0\0\0\x20ftypisom\0\0\x02\0", "video/mp4"),
+            (b"\0\0\0\x1cftypheic\0\0\0\0", "image/heic"),
+            (b"\0\0\0\x14ftypqt  \0\0\0\0", "video/quicktime"),
+            (b"ID3\x04\0\0\0\0\0\0", "audio/mpeg"),
+            (b"\xff\xfb\x90\x64", "audio/mpeg"),
+            (b"fLaC\0\0\0\x22", "audio/flac"),
+            (b"\x1a\x45\xdf\xa3\x9f\x42\x82\x84webm", "video/webm"),
+            (b"\x1f\x8b\x08\0", "application/gzip"),
+            (b"\xef\xbb\xbf  <!DOCTYPE HTML>", "text/html"),
+            (b"<?xml version=\"1.0\"?><svg xmlns=\"http://www.w3.org/2000/svg\">", "image/svg+xml"),
+        ];
+        for (data, expected) in cases {
+            assert_eq!(from_content(data), Some(*expected), "{:?}", data);
+        }
+
+        let mut tar = vec![0u8; 512];
+        tar[257..262].copy_from_slice(b"ustar");
+        assert_eq!(from_content(&tar), Some("application/x-tar"));
+
+        assert_eq!(from_content(b"plain text"), None);
+        assert_eq!(from_content(b""), None);
+        assert_eq!(from_content(b"\0\0\0\x20ftyp"), None);
+    }
+
+    #[tokio::test]
+    async fn test_from_file() {
+        let path = std::env::temp_dir().join("cos-rust-sdk-mime-sniff");
+        std::fs::write(&path, b"%PDF-1.4\n%...").unwrap();
+
+        assert_eq!(from_file(&path, true).await, Some("application/pdf"));
+        assert_eq!(from_file(&path, false).await, None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,622 @@
+//! 分块上传模块
+//!
+//! 提供初始化、上传分块、完成和终止分块上传的底层接口
+
+use crate::acl::Owner;
+use crate::bucket::CommonPrefix;
+use crate::client::CosClient;
+use crate::copy::CopySource;
+use crate::encryption::ServerSideEncryption;
+use crate::error::{CosError, Result};
+use crate::object::{header_str, insert_traffic_limit, request_id, ObjectClient, StorageClass};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// 分块编号的最小值
+pub const MIN_PART_NUMBER: u32 = 1;
+
+/// 分块编号的最大值
+pub const MAX_PART_NUMBER: u32 = 10000;
+
+/// 分块的最小大小（1MB），最后一个分块除外
+pub const MIN_PART_SIZE: u64 = 1024 * 1024;
+
+impl ObjectClient {
+    /// 初始化分块上传
+    pub async fn initiate_multipart_upload(
+        &self,
+        key: &str,
+        options: Option<MultipartUploadOptions>,
+    ) -> Result<InitiateMultipartUploadResponse> {
+        let mut params = HashMap::new();
+        params.insert("uploads".to_string(), "".to_string());
+
+        let mut headers = HashMap::new();
+        if let Some(opts) = options {
+            if let Some(ct) = opts.content_type {
+                headers.insert("Content-Type".to_string(), ct);
+            }
+            if let Some(class) = opts.storage_class {
+                headers.insert("x-cos-storage-class".to_string(), class.to_string());
+            }
+            if let Some(encryption) = opts.encryption {
+                headers.extend(encryption.to_headers());
+            }
+            headers.extend(opts.headers);
+        }
+
+        let response = self
+            .client
+            .post_with_headers(&self.object_path(key)?, params, headers, None::<&[u8]>)
+            .await?;
+        let initiate_response: InitiateMultipartUploadResponse = CosClient::parse_xml(response).await?;
+
+        Ok(initiate_response)
+    }
+
+    /// 上传分块
+    pub async fn upload_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: u32,
+        data: Vec<u8>,
+    ) -> Result<UploadPartResponse> {
+        self.upload_part_with_options(key, upload_id, part_number, data, &UploadPartOptions::default())
+            .await
+    }
+
+    /// 使用指定选项上传分块
+    pub async fn upload_part_with_options(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: u32,
+        data: Vec<u8>,
+        options: &UploadPartOptions,
+    ) -> Result<UploadPartResponse> {
+        validate_part_number(part_number)?;
+
+        let mut params = HashMap::new();
+        params.insert("partNumber".to_string(), part_number.to_string());
+        params.insert("uploadId".to_string(), upload_id.to_string());
+
+        let mut headers = HashMap::new();
+        insert_traffic_limit(&mut headers, options.traffic_limit_bits_per_sec)?;
+
+        let response = self
+            .client
+            .with_timeout(options.timeout)
+            .put_with_headers(&self.object_path(key)?, params, headers, Some(data))
+            .await?;
+
+        Ok(UploadPartResponse {
+            etag: header_str(response.headers(), "etag").unwrap_or("").to_string(),
+        })
+    }
+
+    /// 复制已有对象的一段数据作为分块
+    ///
+    /// `range` 为源对象的闭区间字节范围，为空时复制整个源对象。
+    pub async fn upload_part_copy(
+        &self,
+        dest_key: &str,
+        upload_id: &str,
+        part_number: u32,
+        source: &CopySource,
+        range: Option<RangeInclusive<u64>>,
+    ) -> Result<UploadPartCopyResponse> {
+        self.upload_part_copy_with_options(
+            dest_key,
+            upload_id,
+            part_number,
+            source,
+            range,
+            &UploadPartOptions::default(),
+        )
+        .await
+    }
+
+    /// 使用指定选项复制分块
+    pub async fn upload_part_copy_with_options(
+        &self,
+        dest_key: &str,
+        upload_id: &str,
+        part_number: u32,
+        source: &CopySource,
+        range: Option<RangeInclusive<u64>>,
+        options: &UploadPartOptions,
+    ) -> Result<UploadPartCopyResponse> {
+        validate_part_number(part_number)?;
+
+        let mut params = HashMap::new();
+        params.insert("partNumber".to_string(), part_number.to_string());
+        params.insert("uploadId".to_string(), upload_id.to_string());
+
+        let mut headers = HashMap::new();
+        insert_traffic_limit(&mut headers, options.traffic_limit_bits_per_sec)?;
+        headers.insert("x-cos-copy-source".to_string(), source.header_value());
+        if let Some(ref etag) = options.copy_source_if_match {
+            headers.insert("x-cos-copy-source-if-match".to_string(), etag.clone());
+        }
+        if let Some(ref etag) = options.copy_source_if_none_match {
+            headers.insert("x-cos-copy-source-if-none-match".to_string(), etag.clone());
+        }
+        if let Some(range) = range {
+            headers.insert(
+                "x-cos-copy-source-range".to_string(),
+                format!("bytes={}-{}", range.start(), range.end()),
+            );
+        }
+
+        let response = self
+            .client
+            .with_timeout(options.timeout)
+            .put_with_headers(&self.object_path(dest_key)?, params, headers, None::<&[u8]>)
+            .await?;
+        let copy_response: UploadPartCopyResponse = CosClient::parse_xml(response).await?;
+
+        Ok(copy_response)
+    }
+
+    /// 完成分块上传
+    ///
+    /// 分块会按编号排序后提交，编号必须在 1 到 10000 之间且不能重复。
+    pub async fn complete_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        mut parts: Vec<CompletedPart>,
+    ) -> Result<CompleteMultipartUploadResponse> {
+        if parts.is_empty() {
+            return Err(CosError::client("InvalidArgument", "At least one part is required to complete a multipart upload"));
+        }
+        for part in &parts {
+            validate_part_number(part.part_number)?;
+        }
+        parts.sort_by_key(|p| p.part_number);
+        if let Some(pair) = parts.windows(2).find(|w| w[0].part_number == w[1].part_number) {
+            return Err(CosError::client(
+                "InvalidArgument".to_string(),
+                format!("Duplicate part number {}", pair[0].part_number),
+            ));
+        }
+
+        let complete_request = CompleteMultipartUpload { parts };
+        let xml_body = quick_xml::se::to_string(&complete_request)
+            .map_err(|e| CosError::other(format!("Failed to serialize complete multipart upload request: {}", e)))?;
+
+        let mut params = HashMap::new();
+        params.insert("uploadId".to_string(), upload_id.to_string());
+
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "application/xml".to_string());
+
+        let response = self
+            .client
+            .post_with_headers(&self.object_path(key)?, params, headers, Some(xml_body))
+            .await?;
+        let crc64 = header_str(response.headers(), "x-cos-hash-crc64ecma").map(|s| s.to_string());
+        let encryption = ServerSideEncryption::from_headers(response.headers());
+        let version_id = header_str(response.headers(), "x-cos-version-id").map(|s| s.to_string());
+        let mut complete_response: CompleteMultipartUploadResponse = CosClient::parse_xml(response).await?;
+        complete_response.crc64 = crc64;
+        complete_response.encryption = encryption;
+        complete_response.version_id = version_id;
+
+        Ok(complete_response)
+    }
+
+    /// 列出分块上传中已上传的分块
+    pub async fn list_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        options: Option<ListPartsOptions>,
+    ) -> Result<ListPartsResponse> {
+        let mut params = HashMap::new();
+        params.insert("uploadId".to_string(), upload_id.to_string());
+
+        if let Some(opts) = options {
+            if let Some(max_parts) = opts.max_parts {
+                params.insert("max-parts".to_string(), max_parts.to_string());
+            }
+            if let Some(marker) = opts.part_number_marker {
+                params.insert("part-number-marker".to_string(), marker.to_string());
+            }
+        }
+
+        let response = self.client.get(&self.object_path(key)?, params).await?;
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+        let mut list_response: ListPartsResponse = CosClient::parse_xml(response).await?;
+        list_response.request_id = request_id;
+        list_response.headers = headers;
+
+        Ok(list_response)
+    }
+
+    /// 终止分块上传并清理已上传的分块
+    pub async fn abort_multipart_upload(&self, key: &str, upload_id: &str) -> Result<()> {
+        let mut params = HashMap::new();
+        params.insert("uploadId".to_string(), upload_id.to_string());
+
+        let _response = self.client.delete(&self.object_path(key)?, params).await?;
+        Ok(())
+    }
+}
+
+/// 校验分块编号
+pub(crate) fn validate_part_number(part_number: u32) -> Result<()> {
+    if !(MIN_PART_NUMBER..=MAX_PART_NUMBER).contains(&part_number) {
+        return Err(CosError::client(
+            "InvalidArgument".to_string(),
+            format!(
+                "Part number {} is out of range {}..={}",
+                part_number, MIN_PART_NUMBER, MAX_PART_NUMBER
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// 初始化分块上传选项
+#[derive(Debug, Clone, Default)]
+pub struct MultipartUploadOptions {
+    pub content_type: Option<String>,
+    pub storage_class: Option<StorageClass>,
+    /// 服务端加密方式，完成上传后的对象使用该方式加密
+    pub encryption: Option<ServerSideEncryption>,
+    /// 额外的请求头
+    pub headers: HashMap<String, String>,
+}
+
+/// 初始化分块上传响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "InitiateMultipartUploadResult")]
+pub struct InitiateMultipartUploadResponse {
+    #[serde(rename = "Bucket", default)]
+    pub bucket: String,
+    #[serde(rename = "Key", default)]
+    pub key: String,
+    #[serde(rename = "UploadId")]
+    pub upload_id: String,
+}
+
+/// 上传或复制分块选项
+#[derive(Debug, Clone, Default)]
+pub struct UploadPartOptions {
+    /// 单个分块请求的限速（bit/s），并发上传的分块各自限速
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 单个分块请求的超时，未指定时使用 [`Config::timeout`](crate::config::Config::timeout)
+    pub timeout: Option<std::time::Duration>,
+    /// 复制分块时源对象 ETag 与该值一致才复制，上传分块时忽略
+    pub copy_source_if_match: Option<String>,
+    /// 复制分块时源对象 ETag 与该值不一致才复制，上传分块时忽略
+    pub copy_source_if_none_match: Option<String>,
+}
+
+/// 上传分块响应
+#[derive(Debug, Clone)]
+pub struct UploadPartResponse {
+    pub etag: String,
+}
+
+/// 复制分块响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "CopyPartResult")]
+pub struct UploadPartCopyResponse {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "LastModified", default)]
+    pub last_modified: String,
+    #[serde(rename = "CRC64")]
+    pub crc64: Option<String>,
+}
+
+/// 已上传的分块
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompletedPart {
+    #[serde(rename = "PartNumber")]
+    pub part_number: u32,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+impl CompletedPart {
+    /// 创建已上传的分块
+    pub fn new<S: Into<String>>(part_number: u32, etag: S) -> Self {
+        Self {
+            part_number,
+            etag: etag.into(),
+        }
+    }
+}
+
+/// 完成分块上传请求
+#[derive(Debug, Serialize)]
+#[serde(rename = "CompleteMultipartUpload")]
+struct CompleteMultipartUpload {
+    #[serde(rename = "Part")]
+    parts: Vec<CompletedPart>,
+}
+
+/// 完成分块上传响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "CompleteMultipartUploadResult")]
+pub struct CompleteMultipartUploadResponse {
+    #[serde(rename = "Location", default)]
+    pub location: String,
+    #[serde(rename = "Bucket", default)]
+    pub bucket: String,
+    #[serde(rename = "Key", default)]
+    pub key: String,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+    /// 服务端计算的 CRC64-ECMA 校验值，来自响应头
+    #[serde(skip)]
+    pub crc64: Option<String>,
+    /// 服务端加密方式，来自响应头
+    #[serde(skip)]
+    pub encryption: Option<ServerSideEncryption>,
+    /// 开启版本控制时合并后对象的版本 ID，来自响应头
+    #[serde(skip)]
+    pub version_id: Option<String>,
+}
+
+/// 列出分块选项
+#[derive(Debug, Clone, Default)]
+pub struct ListPartsOptions {
+    pub max_parts: Option<u32>,
+    /// 从该分块编号之后开始列出
+    pub part_number_marker: Option<u32>,
+}
+
+/// 列出分块响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "ListPartsResult")]
+pub struct ListPartsResponse {
+    #[serde(rename = "Bucket", default)]
+    pub bucket: String,
+    #[serde(rename = "Key", default)]
+    pub key: String,
+    #[serde(rename = "UploadId", default)]
+    pub upload_id: String,
+    #[serde(rename = "Initiator")]
+    pub initiator: Option<Owner>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<Owner>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<StorageClass>,
+    #[serde(rename = "PartNumberMarker", default)]
+    pub part_number_marker: u32,
+    #[serde(rename = "NextPartNumberMarker", default)]
+    pub next_part_number_marker: u32,
+    #[serde(rename = "MaxParts", default)]
+    pub max_parts: u32,
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "Part", default)]
+    pub parts: Vec<PartInfo>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    #[serde(skip)]
+    pub headers: HeaderMap,
+}
+
+/// 分块信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct PartInfo {
+    #[serde(rename = "PartNumber")]
+    pub part_number: u32,
+    #[serde(rename = "LastModified", default)]
+    pub last_modified: String,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "Size")]
+    pub size: u64,
+}
+
+impl From<&PartInfo> for CompletedPart {
+    fn from(part: &PartInfo) -> Self {
+        CompletedPart::new(part.part_number, part.etag.clone())
+    }
+}
+
+/// 列出分块上传选项
+#[derive(Debug, Clone, Default)]
+pub struct ListMultipartUploadsOptions {
+    pub prefix: Option<String>,
+    pub delimiter: Option<String>,
+    pub key_marker: Option<String>,
+    pub upload_id_marker: Option<String>,
+    pub max_uploads: Option<u32>,
+}
+
+/// 列出分块上传响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "ListMultipartUploadsResult")]
+pub struct ListMultipartUploadsResponse {
+    #[serde(rename = "Bucket", default)]
+    pub bucket: String,
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+    #[serde(rename = "KeyMarker", default)]
+    pub key_marker: String,
+    #[serde(rename = "UploadIdMarker", default)]
+    pub upload_id_marker: String,
+    #[serde(rename = "NextKeyMarker", default)]
+    pub next_key_marker: String,
+    #[serde(rename = "NextUploadIdMarker", default)]
+    pub next_upload_id_marker: String,
+    #[serde(rename = "MaxUploads", default)]
+    pub max_uploads: u32,
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "Upload", default)]
+    pub uploads: Vec<MultipartUploadInfo>,
+    #[serde(rename = "CommonPrefixes", default)]
+    pub common_prefixes: Vec<CommonPrefix>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    #[serde(skip)]
+    pub headers: HeaderMap,
+}
+
+/// 进行中的分块上传信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct MultipartUploadInfo {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "UploadId")]
+    pub upload_id: String,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<StorageClass>,
+    #[serde(rename = "Initiator")]
+    pub initiator: Option<Owner>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<Owner>,
+    /// ISO 8601 格式的初始化时间
+    #[serde(rename = "Initiated", default)]
+    pub initiated: String,
+}
+
+/// 清理分块上传的结果报告
+#[derive(Debug, Default)]
+pub struct AbortReport {
+    pub aborted: Vec<AbortedUpload>,
+    pub failed: Vec<FailedAbort>,
+}
+
+impl AbortReport {
+    /// 成功终止的数量
+    pub fn aborted_count(&self) -> usize {
+        self.aborted.len()
+    }
+
+    /// 终止失败的数量
+    pub fn failed_count(&self) -> usize {
+        self.failed.len()
+    }
+}
+
+/// 已终止的分块上传
+#[derive(Debug, Clone)]
+pub struct AbortedUpload {
+    pub key: String,
+    pub upload_id: String,
+}
+
+/// 终止失败的分块上传
+#[derive(Debug)]
+pub struct FailedAbort {
+    pub key: String,
+    pub upload_id: String,
+    pub error: CosError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_part_number() {
+        assert!(validate_part_number(1).is_ok());
+        assert!(validate_part_number(10000).is_ok());
+        assert!(validate_part_number(0).is_err());
+        assert!(validate_part_number(10001).is_err());
+    }
+
+    #[test]
+    fn test_complete_multipart_upload_xml() {
+        let request = CompleteMultipartUpload {
+            parts: vec![CompletedPart::new(1, "\"etag-1\""), CompletedPart::new(2, "\"etag-2\"")],
+        };
+        let xml = quick_xml::se::to_string(&request).unwrap();
+        assert_eq!(
+            xml,
+            "<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>&quot;etag-1&quot;</ETag></Part>\
+             <Part><PartNumber>2</PartNumber><ETag>&quot;etag-2&quot;</ETag></Part></CompleteMultipartUpload>"
+        );
+
+        let response: CompleteMultipartUploadResponse = quick_xml::de::from_str(
+            "<CompleteMultipartUploadResult><Location>examplebucket-1250000000.cos.ap-beijing.myqcloud.com/exampleobject</Location>\
+             <Bucket>examplebucket-1250000000</Bucket><Key>exampleobject</Key><ETag>\"aa259a62513358f69e98e72e59856d88-3\"</ETag>\
+             </CompleteMultipartUploadResult>",
+        )
+        .unwrap();
+        assert_eq!(response.key, "exampleobject");
+        assert_eq!(response.etag, "\"aa259a62513358f69e98e72e59856d88-3\"");
+    }
+
+    #[test]
+    fn test_parse_upload_part_copy_response() {
+        let xml = "<CopyPartResult><ETag>\"ba82b57cfdfda8bd17ad4e5879ebb4fe\"</ETag>\
+                   <LastModified>2020-03-25T10:07:12Z</LastModified></CopyPartResult>";
+        let response: UploadPartCopyResponse = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(response.etag, "\"ba82b57cfdfda8bd17ad4e5879ebb4fe\"");
+        assert_eq!(response.crc64, None);
+    }
+
+    #[test]
+    fn test_parse_list_parts_response() {
+        let xml = r#"<ListPartsResult>
+            <Bucket>examplebucket-1250000000</Bucket>
+            <Key>exampleobject</Key>
+            <UploadId>1585130821cbb7df1d11846c073ad648e8f33b087cec2381df437acdc833cf654b9ecc6361</UploadId>
+            <Initiator><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Initiator>
+            <Owner><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Owner>
+            <StorageClass>STANDARD</StorageClass>
+            <PartNumberMarker>0</PartNumberMarker>
+            <NextPartNumberMarker>2</NextPartNumberMarker>
+            <MaxParts>2</MaxParts>
+            <IsTruncated>true</IsTruncated>
+            <Part><PartNumber>1</PartNumber><LastModified>2020-03-25T10:07:12.000Z</LastModified><ETag>"e1"</ETag><Size>1048576</Size></Part>
+            <Part><PartNumber>2</PartNumber><LastModified>2020-03-25T10:07:13.000Z</LastModified><ETag>"e2"</ETag><Size>1048576</Size></Part>
+        </ListPartsResult>"#;
+
+        let response: ListPartsResponse = quick_xml::de::from_str(xml).unwrap();
+        assert!(response.is_truncated);
+        assert_eq!(response.next_part_number_marker, 2);
+        assert_eq!(response.storage_class, Some(StorageClass::Standard));
+        assert_eq!(response.parts.len(), 2);
+        assert_eq!(CompletedPart::from(&response.parts[1]), CompletedPart::new(2, "\"e2\""));
+    }
+
+    #[test]
+    fn test_parse_list_multipart_uploads_response() {
+        let xml = r#"<ListMultipartUploadsResult>
+            <Bucket>examplebucket-1250000000</Bucket>
+            <KeyMarker/>
+            <UploadIdMarker/>
+            <NextKeyMarker>b.bin</NextKeyMarker>
+            <NextUploadIdMarker>upload-b</NextUploadIdMarker>
+            <MaxUploads>2</MaxUploads>
+            <IsTruncated>true</IsTruncated>
+            <Prefix/>
+            <Upload>
+                <Key>a.bin</Key><UploadId>upload-a</UploadId><StorageClass>STANDARD</StorageClass>
+                <Initiator><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Initiator>
+                <Owner><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Owner>
+                <Initiated>2020-03-25T10:07:12.000Z</Initiated>
+            </Upload>
+            <Upload>
+                <Key>b.bin</Key><UploadId>upload-b</UploadId><StorageClass>ARCHIVE</StorageClass>
+                <Initiated>2020-03-26T10:07:12.000Z</Initiated>
+            </Upload>
+        </ListMultipartUploadsResult>"#;
+
+        let response: ListMultipartUploadsResponse = quick_xml::de::from_str(xml).unwrap();
+        assert!(response.is_truncated);
+        assert_eq!(response.next_key_marker, "b.bin");
+        assert_eq!(response.uploads.len(), 2);
+        assert_eq!(response.uploads[0].upload_id, "upload-a");
+        assert_eq!(response.uploads[1].storage_class, Some(StorageClass::Archive));
+    }
+}
@@ -3,55 +3,147 @@
 //! 提供对象的上传、下载、删除等核心功能
 
 use crate::client::CosClient;
+use crate::crc64::{self, Crc64};
+use crate::encryption::ServerSideEncryption;
 use crate::error::{CosError, Result};
+use crate::image::{ImageProcess, PicOperations, UploadResult};
+use crate::mime;
+use crate::retry::RetryConfig;
+use reqwest::header::HeaderMap;
+use crate::range::{ByteRange, ContentRange};
+use crate::response::CosResponse;
+use crate::restore::RestoreStatus;
+use crate::stream::ObjectStream;
+use crate::symlink::symlink_target_from_headers;
+use crate::transfer::DownloadOptions;
+use crate::tagging::{tagging_header, Tag};
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use bytes::Bytes;
+use reqwest::{Body, Method, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs::File;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 /// 对象操作客户端
 #[derive(Debug, Clone)]
 pub struct ObjectClient {
-    client: CosClient,
+    pub(crate) client: CosClient,
+    /// 多个传输共享的分块请求并发限制，由 [`TransferManager`](crate::TransferManager) 设置
+    part_limiter: Option<Arc<Semaphore>>,
 }
 
 impl ObjectClient {
     /// 创建新的对象操作客户端
     pub fn new(client: CosClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            part_limiter: None,
+        }
+    }
+
+    /// 返回共享分块请求并发限制的客户端
+    pub(crate) fn with_part_limiter(&self, limiter: Arc<Semaphore>) -> Self {
+        Self {
+            client: self.client.clone(),
+            part_limiter: Some(limiter),
+        }
+    }
+
+    /// 返回按调用覆盖重试策略的客户端，`retry` 为空时沿用配置中的策略
+    pub(crate) fn with_retry(&self, retry: Option<&RetryConfig>) -> Result<Self> {
+        Ok(Self {
+            client: self.client.with_retry(retry)?,
+            part_limiter: self.part_limiter.clone(),
+        })
+    }
+
+    /// 获取一个分块请求的并发许可，未设置共享限制时立即返回
+    pub(crate) async fn part_permit(&self) -> Result<Option<OwnedSemaphorePermit>> {
+        match &self.part_limiter {
+            Some(limiter) => limiter
+                .clone()
+                .acquire_owned()
+                .await
+                .map(Some)
+                .map_err(|_| CosError::other("Transfer manager has been shut down")),
+            None => Ok(None),
+        }
+    }
+
+    /// 校验对象键并返回请求路径
+    ///
+    /// 配置关闭键校验时原样使用；否则合并误写的连续 `/` 并记录警告。
+    pub(crate) fn object_path(&self, key: &str) -> Result<String> {
+        if !self.client.config().validate_keys {
+            return Ok(format!("/{}", key));
+        }
+        validate_key(key)?;
+        if key.contains("//") {
+            let normalized = collapse_slashes(key);
+            log::warn!("Object key {:?} contains consecutive slashes, using {:?}", key, normalized);
+            return Ok(format!("/{}", normalized));
+        }
+        Ok(format!("/{}", key))
     }
 
     /// 上传对象
+    ///
+    /// `data` 可以是 `Vec<u8>`、[`Bytes`]、`String` 等内存数据，也可以是流式的 [`Body`]；
+    /// 内存数据会设置 Content-Length，且 [`Bytes`] 不会被复制。
     pub async fn put_object(
         &self,
         key: &str,
-        data: Vec<u8>,
+        data: impl Into<Body>,
         content_type: Option<&str>,
+    ) -> Result<PutObjectResponse> {
+        let options = PutObjectOptions {
+            content_type: content_type.map(|s| s.to_string()),
+            ..Default::default()
+        };
+        self.put_object_with_options(key, data, options).await
+    }
+
+    /// 使用指定选项上传对象
+    pub async fn put_object_with_options(
+        &self,
+        key: &str,
+        data: impl Into<Body>,
+        options: PutObjectOptions,
     ) -> Result<PutObjectResponse> {
         let params = HashMap::new();
+        let has_pic_operations = options.pic_operations.is_some();
+        let timeout = options.timeout;
+        let data = data.into();
         
-        let mut headers = HashMap::new();
-        if let Some(ct) = content_type {
-            headers.insert("Content-Type".to_string(), ct.to_string());
+        let mut headers = options.into_headers()?;
+        if let Some(bytes) = data.as_bytes() {
+            headers.insert("Content-Length".to_string(), bytes.len().to_string());
         }
-        headers.insert("Content-Length".to_string(), data.len().to_string());
         
-        let response = self.client.put(&format!("/{}", key), params, Some(data)).await?;
+        let response = self
+            .client
+            .with_timeout(timeout)
+            .put_with_headers(&self.object_path(key)?, params, headers, Some(data))
+            .await?;
         
-        Ok(PutObjectResponse {
-            etag: response
-                .headers()
-                .get("etag")
-                .and_then(|v| v.to_str().ok())
-                .unwrap_or("")
-                .to_string(),
-            version_id: response
-                .headers()
-                .get("x-cos-version-id")
-                .and_then(|v| v.to_str().ok())
-                .map(|s| s.to_string()),
-        })
+        let mut put_response = PutObjectResponse::from_headers(response.headers());
+        // 使用 Pic-Operations 时响应体为图片处理结果
+        if has_pic_operations {
+            let body = response.text().await?;
+            if !body.trim().is_empty() {
+                put_response.upload_result = Some(UploadResult::parse(&body)?);
+            }
+        }
+
+        Ok(put_response)
     }
 
     /// 从文件上传对象
@@ -70,160 +162,139 @@ impl ObjectClient {
             .await
             .map_err(|e| CosError::other(format!("Failed to read file: {}", e)))?;
         
-        let content_type = content_type.or_else(|| {
-            file_path
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .and_then(|ext| match ext.to_lowercase().as_str() {
-                    // 文本文件
-                    "txt" => Some("text/plain"),
-                    "html" | "htm" => Some("text/html"),
-                    "css" => Some("text/css"),
-                    "js" => Some("application/javascript"),
-                    "json" => Some("application/json"),
-                    "xml" => Some("application/xml"),
-                    "csv" => Some("text/csv"),
-                    "md" => Some("text/markdown"),
-                    
-                    // 图片格式
-                    "jpg" | "jpeg" => Some("image/jpeg"),
-                    "png" => Some("image/png"),
-                    "gif" => Some("image/gif"),
-                    "webp" => Some("image/webp"),
-                    "bmp" => Some("image/bmp"),
-                    "tiff" | "tif" => Some("image/tiff"),
-                    "svg" => Some("image/svg+xml"),
-                    "ico" => Some("image/x-icon"),
-                    "heic" => Some("image/heic"),
-                    "heif" => Some("image/heif"),
-                    "avif" => Some("image/avif"),
-                    "jxl" => Some("image/jxl"),
-                    
-                    // 视频格式
-                    "mp4" => Some("video/mp4"),
-                    "avi" => Some("video/x-msvideo"),
-                    "mov" => Some("video/quicktime"),
-                    "wmv" => Some("video/x-ms-wmv"),
-                    "flv" => Some("video/x-flv"),
-                    "webm" => Some("video/webm"),
-                    "mkv" => Some("video/x-matroska"),
-                    "m4v" => Some("video/x-m4v"),
-                    "3gp" => Some("video/3gpp"),
-                    "3g2" => Some("video/3gpp2"),
-                    "ts" => Some("video/mp2t"),
-                    "mts" => Some("video/mp2t"),
-                    "m2ts" => Some("video/mp2t"),
-                    "ogv" => Some("video/ogg"),
-                    
-                    // 音频格式
-                    "mp3" => Some("audio/mpeg"),
-                    "wav" => Some("audio/wav"),
-                    "flac" => Some("audio/flac"),
-                    "aac" => Some("audio/aac"),
-                    "ogg" => Some("audio/ogg"),
-                    "wma" => Some("audio/x-ms-wma"),
-                    "m4a" => Some("audio/mp4"),
-                    "opus" => Some("audio/opus"),
-                    
-                    // 文档格式
-                    "pdf" => Some("application/pdf"),
-                    "doc" => Some("application/msword"),
-                    "docx" => Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
-                    "xls" => Some("application/vnd.ms-excel"),
-                    "xlsx" => Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
-                    "ppt" => Some("application/vnd.ms-powerpoint"),
-                    "pptx" => Some("application/vnd.openxmlformats-officedocument.presentationml.presentation"),
-                    "rtf" => Some("application/rtf"),
-                    
-                    // 压缩文件
-                    "zip" => Some("application/zip"),
-                    "rar" => Some("application/vnd.rar"),
-                    "7z" => Some("application/x-7z-compressed"),
-                    "tar" => Some("application/x-tar"),
-                    "gz" => Some("application/gzip"),
-                    "bz2" => Some("application/x-bzip2"),
-                    
-                    // 其他常见格式
-                    "bin" => Some("application/octet-stream"),
-                    "exe" => Some("application/octet-stream"),
-                    "dmg" => Some("application/x-apple-diskimage"),
-                    "iso" => Some("application/x-iso9660-image"),
-                    
-                    _ => None,
-                })
-        });
+        let sniff = self.client.config().sniff_content_type;
+        let content_type = content_type
+            .or_else(|| mime::from_extension(file_path))
+            .or_else(|| if sniff { mime::from_content(&data) } else { None });
         
         self.put_object(key, data, content_type).await
     }
 
     /// 获取对象
     pub async fn get_object(&self, key: &str) -> Result<GetObjectResponse> {
-        let params = HashMap::new();
-        let response = self.client.get(&format!("/{}", key), params).await?;
-        
-        let content_length = response
-            .headers()
-            .get("content-length")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0);
-        
-        let content_type = response
-            .headers()
-            .get("content-type")
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("application/octet-stream")
-            .to_string();
-        
-        let etag = response
-            .headers()
-            .get("etag")
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("")
-            .to_string();
-        
-        let last_modified = response
-            .headers()
-            .get("last-modified")
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_string());
-        
-        let data = response
-            .bytes()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response body: {}", e)))?
-            .to_vec();
+        self.get_object_with_options(key, GetObjectOptions::default()).await
+    }
+
+    /// 使用条件请求头获取对象
+    ///
+    /// 条件不满足导致服务端返回 304 时返回 [`CosError::NotModified`]，
+    /// 调用方可继续使用本地缓存的内容。
+    pub async fn get_object_with_options(
+        &self,
+        key: &str,
+        options: GetObjectOptions,
+    ) -> Result<GetObjectResponse> {
+        let mut params = version_params(options.version_id.as_deref());
+        response_override_params(
+            &mut params,
+            options.response_content_type.as_deref(),
+            options.response_content_disposition.as_deref(),
+        );
+        if let Some(ref image_process) = options.image_process {
+            image_process.insert_into(&mut params);
+        }
+        let response = self
+            .client
+            .with_timeout(options.timeout)
+            .get_with_headers(&self.object_path(key)?, params, options.to_headers()?)
+            .await?;
         
-        Ok(GetObjectResponse {
-            data,
-            content_length,
-            content_type,
-            etag,
-            last_modified,
-        })
+        // 图片处理后的内容与 ETag 无关
+        let verify_md5 = self.client.config().verify_md5 && options.image_process.is_none();
+        GetObjectResponse::from_response(response, verify_md5).await
+    }
+
+    /// 获取对象的部分数据
+    ///
+    /// 支持 `0..=99`、`0..100`、`500..` 以及 [`ByteRange::last`] 形式的范围，
+    /// 范围无法满足时返回 [`CosError::RangeNotSatisfiable`]。
+    pub async fn get_object_range(
+        &self,
+        key: &str,
+        range: impl Into<ByteRange>,
+    ) -> Result<GetObjectResponse> {
+        let range = range.into();
+        let mut headers = HashMap::new();
+        headers.insert("Range".to_string(), range.header_value());
+
+        let response = self
+            .client
+            .request_raw(Method::GET, &self.object_path(key)?, HashMap::new(), headers, None::<&[u8]>)
+            .await?;
+
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            let object_size = header_str(response.headers(), "content-range")
+                .and_then(ContentRange::parse)
+                .and_then(|r| r.total);
+            return Err(CosError::RangeNotSatisfiable {
+                range: range.header_value(),
+                object_size,
+            });
+        }
+
+        let response = CosClient::error_for_status(response).await?;
+        GetObjectResponse::from_response(response, false).await
     }
 
     /// 下载对象到文件
+    ///
+    /// 响应体逐块写入 `<文件名>.part` 临时文件，长度与 ETag 校验通过后再重命名为目标文件；
+    /// 失败时会删除临时文件，内存占用与对象大小无关。
     pub async fn get_object_to_file(&self, key: &str, file_path: &Path) -> Result<()> {
-        let response = self.get_object(key).await?;
-        
-        let mut file = File::create(file_path)
-            .await
-            .map_err(|e| CosError::other(format!("Failed to create file: {}", e)))?;
-        
-        file.write_all(&response.data)
+        self.get_object_to_file_with_options(key, file_path, DownloadOptions::default())
             .await
-            .map_err(|e| CosError::other(format!("Failed to write file: {}", e)))?;
-        
-        Ok(())
+    }
+
+    /// 使用指定选项下载对象到文件
+    pub async fn get_object_to_file_with_options(
+        &self,
+        key: &str,
+        file_path: &Path,
+        options: DownloadOptions,
+    ) -> Result<()> {
+        let this = self.with_retry(options.retry.as_ref())?;
+        let mut headers = HashMap::new();
+        insert_traffic_limit(&mut headers, options.traffic_limit_bits_per_sec)?;
+        let stream = this.get_object_stream_with_headers(key, headers).await?;
+
+        let mut temp_path = file_path.as_os_str().to_owned();
+        temp_path.push(".part");
+        let temp_path = PathBuf::from(temp_path);
+
+        let verify_md5 = self.client.config().verify_md5;
+        let result = write_stream_to_file(stream, &temp_path, verify_md5, options.verify_crc64).await;
+        let result = match result {
+            Ok(()) => tokio::fs::rename(&temp_path, file_path)
+                .await
+                .map_err(|e| CosError::other(format!("Failed to rename file: {}", e))),
+            Err(e) => Err(e),
+        };
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+        }
+        result
     }
 
     /// 删除对象
     pub async fn delete_object(&self, key: &str) -> Result<DeleteObjectResponse> {
-        let params = HashMap::new();
-        let response = self.client.delete(&format!("/{}", key), params).await?;
+        self.delete_object_version(key, None).await
+    }
+
+    /// 删除对象的指定版本
+    ///
+    /// 指定版本时永久删除该版本；被删除的版本是删除标记时，响应中的 `delete_marker` 为 true，
+    /// 删除标记移除后对象即恢复为上一个版本。
+    pub async fn delete_object_version(
+        &self,
+        key: &str,
+        version_id: Option<&str>,
+    ) -> Result<DeleteObjectResponse> {
+        let params = version_params(version_id);
+        let response = self.client.delete(&self.object_path(key)?, params).await?;
         
         Ok(DeleteObjectResponse {
+            request_id: request_id(response.headers()),
+            headers: response.headers().clone(),
             version_id: response
                 .headers()
                 .get("x-cos-version-id")
@@ -240,12 +311,52 @@ impl ObjectClient {
 
     /// 批量删除对象
     pub async fn delete_objects(&self, keys: &[String]) -> Result<DeleteObjectsResponse> {
+        let objects: Vec<ObjectIdentifier> = keys.iter().map(ObjectIdentifier::from).collect();
+        self.delete_objects_with_options(&objects, DeleteObjectsOptions::default())
+            .await
+    }
+
+    /// 使用指定选项批量删除对象
+    ///
+    /// 超过 1000 个对象时自动拆分为多次请求并合并结果；某次请求整体失败时，
+    /// 该批次的每个对象都会记录到 `errors` 中，不影响其他批次的结果。
+    pub async fn delete_objects_with_options(
+        &self,
+        objects: &[ObjectIdentifier],
+        options: DeleteObjectsOptions,
+    ) -> Result<DeleteObjectsResponse> {
+        let mut result = DeleteObjectsResponse::default();
+
+        for chunk in objects.chunks(MAX_DELETE_OBJECTS) {
+            match self.delete_objects_batch(chunk, options.quiet).await {
+                Ok(response) => {
+                    result.deleted.extend(response.deleted);
+                    result.errors.extend(response.errors);
+                }
+                Err(e) => {
+                    let message = e.to_string();
+                    result.errors.extend(chunk.iter().map(|object| DeleteError {
+                        key: object.key.clone(),
+                        version_id: object.version_id.clone(),
+                        code: "RequestFailed".to_string(),
+                        message: message.clone(),
+                    }));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// 发送一次批量删除请求，最多 1000 个对象
+    async fn delete_objects_batch(
+        &self,
+        objects: &[ObjectIdentifier],
+        quiet: bool,
+    ) -> Result<DeleteObjectsResponse> {
         let delete_request = DeleteRequest {
-            objects: keys.iter().map(|key| DeleteObject {
-                key: key.clone(),
-                version_id: None,
-            }).collect(),
-            quiet: false,
+            objects: objects.to_vec(),
+            quiet,
         };
         
         let xml_body = quick_xml::se::to_string(&delete_request)
@@ -253,24 +364,34 @@ impl ObjectClient {
         
         let mut params = HashMap::new();
         params.insert("delete".to_string(), "".to_string());
+
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "application/xml".to_string());
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
         
-        let response = self.client.post("/", params, Some(xml_body)).await?;
+        let response = self
+            .client
+            .post_with_headers("/", params, headers, Some(xml_body))
+            .await?;
         
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
-        let delete_response: DeleteObjectsResponse = quick_xml::de::from_str(&response_text)
-            .map_err(|e| CosError::other(format!("Failed to parse delete response: {}", e)))?;
+        let delete_response: DeleteObjectsResponse = CosClient::parse_xml(response).await?;
         
         Ok(delete_response)
     }
 
     /// 获取对象元数据
     pub async fn head_object(&self, key: &str) -> Result<HeadObjectResponse> {
-        let params = HashMap::new();
-        let response = self.client.head(&format!("/{}", key), params).await?;
+        self.head_object_version(key, None).await
+    }
+
+    /// 获取对象指定版本的元数据
+    pub async fn head_object_version(
+        &self,
+        key: &str,
+        version_id: Option<&str>,
+    ) -> Result<HeadObjectResponse> {
+        let params = version_params(version_id);
+        let response = self.client.head(&self.object_path(key)?, params).await?;
         
         let content_length = response
             .headers()
@@ -293,25 +414,123 @@ impl ObjectClient {
             .unwrap_or("")
             .to_string();
         
-        let last_modified = response
-            .headers()
-            .get("last-modified")
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_string());
+        let last_modified_raw = header_str(response.headers(), "last-modified").map(|s| s.to_string());
+        let last_modified = last_modified_raw.as_deref().and_then(parse_http_date);
+        let expires = header_str(response.headers(), "expires").and_then(parse_http_date);
         
+        let storage_class = header_str(response.headers(), "x-cos-storage-class")
+            .map(StorageClass::from)
+            .unwrap_or_default();
+        let restore = header_str(response.headers(), "x-cos-restore").and_then(RestoreStatus::parse);
+        let crc64 = header_str(response.headers(), "x-cos-hash-crc64ecma").map(|s| s.to_string());
+        let encryption = ServerSideEncryption::from_headers(response.headers());
+        let version_id = header_str(response.headers(), "x-cos-version-id").map(|s| s.to_string());
+        let metadata = metadata_from_headers(response.headers());
+        let symlink_target = symlink_target_from_headers(response.headers());
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+
         Ok(HeadObjectResponse {
             content_length,
             content_type,
             etag,
             last_modified,
+            last_modified_raw,
+            expires,
+            storage_class,
+            restore,
+            crc64,
+            encryption,
+            version_id,
+            metadata,
+            symlink_target,
+            request_id,
+            headers,
         })
     }
 
+    /// 追加上传对象
+    ///
+    /// `position` 必须等于对象当前长度，首次追加时为 0；
+    /// 位置不一致时返回 [`CosError::AppendPositionMismatch`]，调用方可据此重新同步位置。
+    pub async fn append_object(
+        &self,
+        key: &str,
+        position: u64,
+        data: Vec<u8>,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResponse> {
+        let mut params = HashMap::new();
+        params.insert("append".to_string(), "".to_string());
+        params.insert("position".to_string(), position.to_string());
+
+        let mut headers = HashMap::new();
+        if let Some(opts) = options {
+            if let Some(ct) = opts.content_type {
+                headers.insert("Content-Type".to_string(), ct);
+            }
+            headers.extend(opts.headers);
+        }
+
+        let response = self
+            .client
+            .request_raw(Method::POST, &self.object_path(key)?, params, headers, Some(data))
+            .await?;
+
+        if response.status() == StatusCode::CONFLICT {
+            let next_position = header_str(response.headers(), "x-cos-next-append-position")
+                .and_then(|s| s.parse().ok());
+            let request_id = request_id(response.headers());
+            let body = response.text().await?;
+            if next_position.is_some() || body.contains("PositionNotEqualToLength") {
+                return Err(CosError::AppendPositionMismatch {
+                    position,
+                    next_position,
+                });
+            }
+            return Err(CosError::from_response(StatusCode::CONFLICT, body, request_id));
+        }
+
+        let response = CosClient::error_for_status(response).await?;
+        let headers = response.headers();
+
+        Ok(AppendObjectResponse {
+            next_append_position: header_str(headers, "x-cos-next-append-position")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0),
+            etag: header_str(headers, "etag").unwrap_or("").to_string(),
+            crc64: header_str(headers, "x-cos-hash-crc64ecma").map(|s| s.to_string()),
+        })
+    }
+
+    /// 追加上传对象，自动获取对象当前长度作为追加位置
+    ///
+    /// 对象不存在时从位置 0 开始追加。
+    pub async fn append_object_auto(&self, key: &str, data: Vec<u8>) -> Result<AppendObjectResponse> {
+        let response = self
+            .client
+            .request_raw(Method::HEAD, &self.object_path(key)?, HashMap::new(), HashMap::new(), None::<&[u8]>)
+            .await?;
+
+        let position = if response.status() == StatusCode::NOT_FOUND {
+            0
+        } else {
+            let response = CosClient::error_for_status(response).await?;
+            header_str(response.headers(), "content-length")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0)
+        };
+
+        self.append_object(key, position, data, None).await
+    }
+
     /// 检查对象是否存在
+    ///
+    /// 仅在服务端返回 404 时返回 `false`，权限不足、服务端错误和网络故障都会作为错误返回。
     pub async fn object_exists(&self, key: &str) -> Result<bool> {
         match self.head_object(key).await {
             Ok(_) => Ok(true),
-            Err(CosError::Server { .. }) => Ok(false),
+            Err(e) if e.status() == Some(404) => Ok(false),
             Err(e) => Err(e),
         }
     }
@@ -322,23 +541,637 @@ impl ObjectClient {
 pub struct PutObjectResponse {
     pub etag: String,
     pub version_id: Option<String>,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    /// 服务端加密方式
+    pub encryption: Option<ServerSideEncryption>,
+    /// 上传时图片处理的结果，仅在设置了 [`PutObjectOptions::pic_operations`] 时返回
+    pub upload_result: Option<UploadResult>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    pub headers: HeaderMap,
+}
+
+impl PutObjectResponse {
+    /// 从响应头中解析上传结果
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            etag: header_str(headers, "etag").unwrap_or("").to_string(),
+            version_id: header_str(headers, "x-cos-version-id").map(|s| s.to_string()),
+            crc64: header_str(headers, "x-cos-hash-crc64ecma").map(|s| s.to_string()),
+            encryption: ServerSideEncryption::from_headers(headers),
+            upload_result: None,
+            request_id: request_id(headers),
+            headers: headers.clone(),
+        }
+    }
+}
+
+/// 对象 ETag 为内容的 MD5 时返回该摘要（小写十六进制）
+///
+/// 分块上传对象的 ETag 带有 `-` 分块标记，SSE-C 加密对象的 ETag 与明文无关，这两种情况都返回 None。
+pub(crate) fn md5_from_etag(headers: &HeaderMap) -> Option<String> {
+    if headers.contains_key("x-cos-server-side-encryption-customer-algorithm") {
+        return None;
+    }
+    plain_md5(header_str(headers, "etag")?)
+}
+
+/// ETag 形如 32 位十六进制的 MD5 时返回其小写形式
+pub(crate) fn plain_md5(etag: &str) -> Option<String> {
+    let etag = etag.trim_matches('"');
+    (etag.len() == 32 && etag.bytes().all(|b| b.is_ascii_hexdigit())).then(|| etag.to_ascii_lowercase())
+}
+
+/// 比较期望的 MD5 与实际数据的摘要
+pub(crate) fn check_md5(expected: &str, digest: md5::Digest) -> Result<()> {
+    let actual = format!("{:x}", digest);
+    if actual != expected {
+        return Err(CosError::IntegrityCheckFailed {
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// 将数据流写入文件，并校验长度以及可选的 MD5 和 CRC64
+async fn write_stream_to_file(
+    mut stream: ObjectStream,
+    path: &Path,
+    verify_md5: bool,
+    verify_crc64: bool,
+) -> Result<()> {
+    let mut file = File::create(path)
+        .await
+        .map_err(|e| CosError::other(format!("Failed to create file: {}", e)))?;
+    copy_stream_to_writer(&mut stream, &mut file, verify_md5, verify_crc64).await?;
+    Ok(())
+}
+
+/// 将数据流写入任意 [`AsyncWrite`]，并校验长度以及可选的 MD5 和 CRC64，返回写入的字节数
+pub(crate) async fn copy_stream_to_writer<W>(
+    stream: &mut ObjectStream,
+    writer: &mut W,
+    verify_md5: bool,
+    verify_crc64: bool,
+) -> Result<u64>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut written = 0u64;
+    let mut md5 = md5::Context::new();
+    let mut crc = Crc64::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        md5.consume(&chunk);
+        if verify_crc64 {
+            crc.update(&chunk);
+        }
+        written += chunk.len() as u64;
+        writer
+            .write_all(&chunk)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to write data: {}", e)))?;
+    }
+    writer
+        .flush()
+        .await
+        .map_err(|e| CosError::other(format!("Failed to flush data: {}", e)))?;
+
+    if written != stream.content_length {
+        return Err(CosError::other(format!(
+            "Incomplete download: expected {} bytes, got {}",
+            stream.content_length, written
+        )));
+    }
+
+    if let Some(expected) = stream.expected_md5.as_deref().filter(|_| verify_md5) {
+        check_md5(expected, md5.compute())?;
+    }
+
+    if verify_crc64 {
+        crc64::verify(crc.finish(), stream.crc64.as_deref())?;
+    }
+
+    Ok(written)
+}
+
+/// 读取字符串形式的响应头
+pub(crate) fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// 校验对象键
+///
+/// 空键、以 `/` 开头、包含 `\` 或控制字符、含有 `.` 或 `..` 路径段的键会导致签名错误或被服务端拒绝，
+/// 在发送请求前直接返回 [`CosError::Client`]。
+pub fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() {
+        return Err(CosError::client("InvalidObjectKey", "Object key cannot be empty"));
+    }
+    if key.starts_with('/') {
+        return Err(CosError::client(
+            "InvalidObjectKey".to_string(),
+            format!("Object key {:?} must not start with '/'", key),
+        ));
+    }
+    if key.contains('\\') {
+        return Err(CosError::client(
+            "InvalidObjectKey".to_string(),
+            format!("Object key {:?} must not contain '\\', use '/' as the separator", key),
+        ));
+    }
+    if let Some(c) = key.chars().find(|c| c.is_control()) {
+        return Err(CosError::client(
+            "InvalidObjectKey".to_string(),
+            format!("Object key {:?} contains control character {:?}", key, c),
+        ));
+    }
+    if let Some(segment) = key.split('/').find(|s| *s == "." || *s == "..") {
+        return Err(CosError::client(
+            "InvalidObjectKey".to_string(),
+            format!("Object key {:?} must not contain a '{}' path segment", key, segment),
+        ));
+    }
+    Ok(())
+}
+
+/// 合并对象键中连续的 `/`
+fn collapse_slashes(key: &str) -> String {
+    let mut result = String::with_capacity(key.len());
+    for c in key.chars() {
+        if c == '/' && result.ends_with('/') {
+            continue;
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// 自定义元数据的请求头前缀
+pub const METADATA_PREFIX: &str = "x-cos-meta-";
+
+/// 自定义元数据的总大小上限（2KB）
+pub const MAX_METADATA_SIZE: usize = 2 * 1024;
+
+/// 单个请求限速的下限（819200 bit/s，即 100KB/s）
+pub const MIN_TRAFFIC_LIMIT: u64 = 819_200;
+
+/// 单个请求限速的上限（838860800 bit/s，即 100MB/s）
+pub const MAX_TRAFFIC_LIMIT: u64 = 838_860_800;
+
+/// 校验限速值并写入 `x-cos-traffic-limit` 请求头
+///
+/// 限速作用于单个请求，并发的 N 个请求总带宽为 N 倍的限速值。
+pub(crate) fn insert_traffic_limit(headers: &mut HashMap<String, String>, limit: Option<u64>) -> Result<()> {
+    if let Some(limit) = limit {
+        if !(MIN_TRAFFIC_LIMIT..=MAX_TRAFFIC_LIMIT).contains(&limit) {
+            return Err(CosError::config(format!(
+                "Traffic limit {} bit/s is outside the range {} to {}",
+                limit, MIN_TRAFFIC_LIMIT, MAX_TRAFFIC_LIMIT
+            )));
+        }
+        headers.insert("x-cos-traffic-limit".to_string(), limit.to_string());
+    }
+    Ok(())
+}
+
+/// 上传对象选项
+///
+/// 所有请求头都会参与签名。
+#[derive(Debug, Clone, Default)]
+pub struct PutObjectOptions {
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    pub content_encoding: Option<String>,
+    /// HTTP 日期格式，如 `Wed, 21 Oct 2015 07:28:00 GMT`
+    pub expires: Option<String>,
+    /// 存储类型，未指定时使用存储桶默认的标准存储
+    pub storage_class: Option<StorageClass>,
+    /// 对象标签，以 `x-cos-tagging` 请求头发送
+    pub tags: Vec<Tag>,
+    /// 禁止覆盖同名对象，对象已存在时返回 [`CosError::PreconditionFailed`]
+    pub forbid_overwrite: bool,
+    /// 服务端加密方式
+    pub encryption: Option<ServerSideEncryption>,
+    /// 自定义元数据，以 `x-cos-meta-<key>` 请求头发送
+    pub metadata: HashMap<String, String>,
+    /// 上传时的图片处理规则，以 `Pic-Operations` 请求头发送
+    pub pic_operations: Option<PicOperations>,
+    /// 单个请求的限速（bit/s），范围为 [`MIN_TRAFFIC_LIMIT`] 到 [`MAX_TRAFFIC_LIMIT`]
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 额外的请求头
+    pub headers: HashMap<String, String>,
+    /// 本次请求的超时，未指定时使用 [`Config::timeout`](crate::config::Config::timeout)
+    pub timeout: Option<Duration>,
+}
+
+impl PutObjectOptions {
+    /// 创建空的上传选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置 Content-Type
+    pub fn with_content_type<S: Into<String>>(mut self, content_type: S) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// 设置 Cache-Control
+    pub fn with_cache_control<S: Into<String>>(mut self, cache_control: S) -> Self {
+        self.cache_control = Some(cache_control.into());
+        self
+    }
+
+    /// 设置 Content-Disposition
+    pub fn with_content_disposition<S: Into<String>>(mut self, content_disposition: S) -> Self {
+        self.content_disposition = Some(content_disposition.into());
+        self
+    }
+
+    /// 设置 Content-Encoding
+    pub fn with_content_encoding<S: Into<String>>(mut self, content_encoding: S) -> Self {
+        self.content_encoding = Some(content_encoding.into());
+        self
+    }
+
+    /// 设置 Expires
+    pub fn with_expires<S: Into<String>>(mut self, expires: S) -> Self {
+        self.expires = Some(expires.into());
+        self
+    }
+
+    /// 设置存储类型
+    pub fn with_storage_class(mut self, storage_class: StorageClass) -> Self {
+        self.storage_class = Some(storage_class);
+        self
+    }
+
+    /// 禁止覆盖同名对象，可用于实现“不存在时才创建”
+    pub fn with_forbid_overwrite(mut self, forbid_overwrite: bool) -> Self {
+        self.forbid_overwrite = forbid_overwrite;
+        self
+    }
+
+    /// 设置服务端加密方式
+    pub fn with_encryption(mut self, encryption: ServerSideEncryption) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// 添加一个对象标签
+    pub fn with_tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tags.push(Tag::new(key, value));
+        self
+    }
+
+    /// 添加一项自定义元数据
+    pub fn with_metadata<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// 设置上传时的图片处理规则
+    pub fn with_pic_operations(mut self, pic_operations: PicOperations) -> Self {
+        self.pic_operations = Some(pic_operations);
+        self
+    }
+
+    /// 设置单个请求的限速（bit/s）
+    pub fn with_traffic_limit(mut self, bits_per_sec: u64) -> Self {
+        self.traffic_limit_bits_per_sec = Some(bits_per_sec);
+        self
+    }
+
+    /// 添加额外的请求头
+    pub fn with_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// 设置本次请求的超时
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 转换为请求头
+    pub(crate) fn into_headers(self) -> Result<HashMap<String, String>> {
+        let mut headers = metadata_headers(&self.metadata)?;
+        let standard = [
+            ("Content-Type", self.content_type),
+            ("Cache-Control", self.cache_control),
+            ("Content-Disposition", self.content_disposition),
+            ("Content-Encoding", self.content_encoding),
+            ("Expires", self.expires),
+            ("x-cos-storage-class", self.storage_class.map(|s| s.to_string())),
+        ];
+        for (name, value) in standard {
+            if let Some(value) = value {
+                headers.insert(name.to_string(), value);
+            }
+        }
+        if !self.tags.is_empty() {
+            headers.insert("x-cos-tagging".to_string(), tagging_header(&self.tags)?);
+        }
+        if self.forbid_overwrite {
+            headers.insert("x-cos-forbid-overwrite".to_string(), "true".to_string());
+        }
+        if let Some(ref encryption) = self.encryption {
+            headers.extend(encryption.to_headers());
+        }
+        if let Some(ref pic_operations) = self.pic_operations {
+            headers.insert("Pic-Operations".to_string(), pic_operations.header_value()?);
+        }
+        insert_traffic_limit(&mut headers, self.traffic_limit_bits_per_sec)?;
+        headers.extend(self.headers);
+        Ok(headers)
+    }
+}
+
+/// 校验自定义元数据并转换为 `x-cos-meta-*` 请求头
+///
+/// 键只能包含 ASCII 字母、数字和 `-`、`_`，值只能包含可见 ASCII 字符和空格；
+/// 非 ASCII 的值请由调用方自行编码（如百分号编码）后再传入。
+pub(crate) fn metadata_headers(metadata: &HashMap<String, String>) -> Result<HashMap<String, String>> {
+    let mut headers = HashMap::new();
+    let mut size = 0;
+
+    for (key, value) in metadata {
+        if key.is_empty()
+            || !key
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        {
+            return Err(CosError::config(format!("Invalid metadata key: {:?}", key)));
+        }
+        if !value.bytes().all(|b| b == b' ' || b.is_ascii_graphic()) {
+            return Err(CosError::config(format!(
+                "Metadata value for {:?} must be printable ASCII",
+                key
+            )));
+        }
+
+        let name = format!("{}{}", METADATA_PREFIX, key.to_lowercase());
+        size += name.len() + value.len();
+        headers.insert(name, value.clone());
+    }
+
+    if size > MAX_METADATA_SIZE {
+        return Err(CosError::config(format!(
+            "Metadata size {} exceeds the limit of {} bytes",
+            size, MAX_METADATA_SIZE
+        )));
+    }
+
+    Ok(headers)
+}
+
+/// 从响应头中提取所有 `x-cos-meta-*` 自定义元数据，键不含前缀
+pub(crate) fn metadata_from_headers(headers: &HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let key = name.as_str().strip_prefix(METADATA_PREFIX)?;
+            let value = value.to_str().ok()?;
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// 追加上传选项
+#[derive(Debug, Clone, Default)]
+pub struct AppendObjectOptions {
+    /// 仅在首次追加时生效
+    pub content_type: Option<String>,
+    /// 额外的请求头
+    pub headers: HashMap<String, String>,
+}
+
+/// 追加上传响应
+#[derive(Debug, Clone)]
+pub struct AppendObjectResponse {
+    /// 下一次追加的位置
+    pub next_append_position: u64,
+    pub etag: String,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+}
+
+/// 获取对象选项
+#[derive(Debug, Clone, Default)]
+pub struct GetObjectOptions {
+    /// 获取指定版本的对象
+    pub version_id: Option<String>,
+    /// ETag 与该值一致时才返回对象，否则返回 412
+    pub if_match: Option<String>,
+    /// ETag 与该值不一致时才返回对象，否则返回 304
+    pub if_none_match: Option<String>,
+    /// 对象在该时间之后被修改过才返回，否则返回 304
+    pub if_modified_since: Option<DateTime<Utc>>,
+    /// 对象在该时间之后未被修改过才返回，否则返回 412
+    pub if_unmodified_since: Option<DateTime<Utc>>,
+    /// 覆盖响应的 Content-Type
+    pub response_content_type: Option<String>,
+    /// 覆盖响应的 Content-Disposition，可使用 [`attachment_disposition`] 生成带文件名的值
+    pub response_content_disposition: Option<String>,
+    /// 返回经过数据万象处理后的图片，如缩放、裁剪或格式转换
+    pub image_process: Option<ImageProcess>,
+    /// 单个请求的限速（bit/s），范围为 [`MIN_TRAFFIC_LIMIT`] 到 [`MAX_TRAFFIC_LIMIT`]
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 只获取指定范围的数据，此时不校验 MD5
+    pub range: Option<ByteRange>,
+    /// 本次请求的超时，未指定时使用 [`Config::timeout`](crate::config::Config::timeout)；
+    /// 超时包括读取响应体的时间
+    pub timeout: Option<Duration>,
+}
+
+impl GetObjectOptions {
+    /// 生成范围、条件与限速请求头
+    pub(crate) fn to_headers(&self) -> Result<HashMap<String, String>> {
+        let mut headers = HashMap::new();
+        if let Some(range) = self.range {
+            headers.insert("Range".to_string(), range.header_value());
+        }
+        if let Some(ref etag) = self.if_match {
+            headers.insert("If-Match".to_string(), etag.clone());
+        }
+        if let Some(ref etag) = self.if_none_match {
+            headers.insert("If-None-Match".to_string(), etag.clone());
+        }
+        if let Some(time) = self.if_modified_since {
+            headers.insert("If-Modified-Since".to_string(), http_date(time));
+        }
+        if let Some(time) = self.if_unmodified_since {
+            headers.insert("If-Unmodified-Since".to_string(), http_date(time));
+        }
+        insert_traffic_limit(&mut headers, self.traffic_limit_bits_per_sec)?;
+        Ok(headers)
+    }
+}
+
+/// 读取响应头中的请求 ID
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    header_str(headers, "x-cos-request-id").map(|s| s.to_string())
+}
+
+/// 写入覆盖响应头的查询参数，会参与签名
+pub(crate) fn response_override_params(
+    params: &mut HashMap<String, String>,
+    content_type: Option<&str>,
+    content_disposition: Option<&str>,
+) {
+    if let Some(ct) = content_type {
+        params.insert("response-content-type".to_string(), ct.to_string());
+    }
+    if let Some(cd) = content_disposition {
+        params.insert("response-content-disposition".to_string(), cd.to_string());
+    }
+}
+
+/// 生成以附件形式下载的 Content-Disposition
+///
+/// 文件名包含非 ASCII 字符时按 RFC 5987 额外给出 `filename*`，
+/// 并在 `filename` 中用 `_` 替换无法表示的字符，供不支持 RFC 5987 的客户端使用。
+pub fn attachment_disposition(filename: &str) -> String {
+    let plain = filename
+        .chars()
+        .all(|c| c.is_ascii() && !c.is_ascii_control() && c != '"' && c != '\\');
+    if plain {
+        return format!("attachment; filename=\"{}\"", filename);
+    }
+
+    let fallback: String = filename
+        .chars()
+        .map(|c| if c.is_ascii() && !c.is_ascii_control() && c != '"' && c != '\\' { c } else { '_' })
+        .collect();
+    let mut encoded = String::new();
+    for byte in filename.bytes() {
+        if byte.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    format!("attachment; filename=\"{}\"; filename*=UTF-8''{}", fallback, encoded)
+}
+
+/// 指定对象版本的查询参数，会参与签名
+pub(crate) fn version_params(version_id: Option<&str>) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    if let Some(version_id) = version_id {
+        params.insert("versionId".to_string(), version_id.to_string());
+    }
+    params
+}
+
+/// 格式化为 HTTP 日期，如 `Wed, 21 Oct 2015 07:28:00 GMT`
+pub(crate) fn http_date(time: DateTime<Utc>) -> String {
+    time.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// 解析时间字符串
+///
+/// 响应头使用 HTTP-date（如 `Wed, 21 Oct 2026 07:28:00 GMT`），列表结果使用 ISO 8601
+/// （如 `2026-10-21T07:28:00.000Z`），两种格式都能解析，无法解析时返回 `None`。
+pub(crate) fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc2822(value)
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .map(|time| time.with_timezone(&Utc))
+        .ok()
 }
 
 /// 获取对象响应
 #[derive(Debug, Clone)]
 pub struct GetObjectResponse {
-    pub data: Vec<u8>,
+    pub data: Bytes,
     pub content_length: u64,
     pub content_type: String,
     pub etag: String,
-    pub last_modified: Option<String>,
+    /// 最后修改时间，响应头无法解析时为空
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 Last-Modified 响应头
+    pub last_modified_raw: Option<String>,
+    /// Expires 响应头表示的过期时间
+    pub expires: Option<DateTime<Utc>>,
+    /// 范围下载时返回的 Content-Range
+    pub content_range: Option<ContentRange>,
+    /// 对象版本，存储桶未开启版本控制时为空
+    pub version_id: Option<String>,
+    /// 自定义元数据，键不含 `x-cos-meta-` 前缀
+    pub metadata: HashMap<String, String>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    pub headers: HeaderMap,
+}
+
+impl GetObjectResponse {
+    /// 读取响应头和响应体
+    ///
+    /// `verify_md5` 为 true 且响应为完整的对象内容时，按 ETag 校验数据的 MD5。
+    pub(crate) async fn from_response(response: CosResponse, verify_md5: bool) -> Result<Self> {
+        let headers = response.headers();
+        let content_length = header_str(headers, "content-length")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        let content_type = header_str(headers, "content-type")
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let etag = header_str(headers, "etag").unwrap_or("").to_string();
+        let last_modified_raw = header_str(headers, "last-modified").map(|s| s.to_string());
+        let last_modified = last_modified_raw.as_deref().and_then(parse_http_date);
+        let expires = header_str(headers, "expires").and_then(parse_http_date);
+        let content_range = header_str(headers, "content-range").and_then(ContentRange::parse);
+        let version_id = header_str(headers, "x-cos-version-id").map(|s| s.to_string());
+        let metadata = metadata_from_headers(headers);
+        let request_id = request_id(headers);
+        let expected_md5 = md5_from_etag(headers).filter(|_| verify_md5 && content_range.is_none());
+        let headers = headers.clone();
+
+        let data = response.bytes().await?;
+        if let Some(expected) = expected_md5 {
+            check_md5(&expected, md5::compute(&data))?;
+        }
+
+        Ok(Self {
+            data,
+            content_length,
+            content_type,
+            etag,
+            last_modified,
+            last_modified_raw,
+            expires,
+            content_range,
+            version_id,
+            metadata,
+            request_id,
+            headers,
+        })
+    }
+
+    /// 取出对象数据，数据未被共享时不会复制
+    pub fn into_vec(self) -> Vec<u8> {
+        self.data.into()
+    }
 }
 
 /// 删除对象响应
 #[derive(Debug, Clone)]
 pub struct DeleteObjectResponse {
+    /// 被删除的版本，或新创建的删除标记的版本
     pub version_id: Option<String>,
+    /// 删除的是删除标记，或本次删除创建了删除标记
     pub delete_marker: bool,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    pub headers: HeaderMap,
 }
 
 /// 获取对象元数据响应
@@ -347,30 +1180,161 @@ pub struct HeadObjectResponse {
     pub content_length: u64,
     pub content_type: String,
     pub etag: String,
-    pub last_modified: Option<String>,
+    /// 最后修改时间，响应头无法解析时为空
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 Last-Modified 响应头
+    pub last_modified_raw: Option<String>,
+    /// Expires 响应头表示的过期时间
+    pub expires: Option<DateTime<Utc>>,
+    /// 响应未返回 `x-cos-storage-class` 时为标准存储
+    pub storage_class: StorageClass,
+    /// 归档对象的恢复状态，未发起恢复时为空
+    pub restore: Option<RestoreStatus>,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    /// 服务端加密方式，未加密时为空
+    pub encryption: Option<ServerSideEncryption>,
+    /// 对象版本，存储桶未开启版本控制时为空
+    pub version_id: Option<String>,
+    /// 自定义元数据，键不含 `x-cos-meta-` 前缀
+    pub metadata: HashMap<String, String>,
+    /// 对象为软链接时指向的目标对象键
+    pub symlink_target: Option<String>,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    pub headers: HeaderMap,
+}
+
+/// 存储类型
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum StorageClass {
+    #[default]
+    Standard,
+    StandardIa,
+    IntelligentTiering,
+    Archive,
+    DeepArchive,
+    MazStandard,
+    MazStandardIa,
+    MazIntelligentTiering,
+    /// 未识别的存储类型，保留原始字符串
+    Other(String),
+}
+
+impl StorageClass {
+    /// 获取 COS 使用的存储类型字符串
+    pub fn as_str(&self) -> &str {
+        match self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            StorageClass::Archive => "ARCHIVE",
+            StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            StorageClass::MazStandard => "MAZ_STANDARD",
+            StorageClass::MazStandardIa => "MAZ_STANDARD_IA",
+            StorageClass::MazIntelligentTiering => "MAZ_INTELLIGENT_TIERING",
+            StorageClass::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for StorageClass {
+    fn from(s: &str) -> Self {
+        match s.to_uppercase().as_str() {
+            "STANDARD" => StorageClass::Standard,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            "INTELLIGENT_TIERING" => StorageClass::IntelligentTiering,
+            "ARCHIVE" => StorageClass::Archive,
+            "DEEP_ARCHIVE" => StorageClass::DeepArchive,
+            "MAZ_STANDARD" => StorageClass::MazStandard,
+            "MAZ_STANDARD_IA" => StorageClass::MazStandardIa,
+            "MAZ_INTELLIGENT_TIERING" => StorageClass::MazIntelligentTiering,
+            _ => StorageClass::Other(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for StorageClass {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for StorageClass {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(StorageClass::from(s.as_str()))
+    }
+}
+
+/// 单次批量删除请求的对象数量上限
+pub const MAX_DELETE_OBJECTS: usize = 1000;
+
+/// 计算请求体的 Content-MD5（Base64 编码的 MD5）
+pub(crate) fn content_md5(body: &[u8]) -> String {
+    general_purpose::STANDARD.encode(md5::compute(body).0)
+}
+
+/// 批量删除选项
+#[derive(Debug, Clone, Default)]
+pub struct DeleteObjectsOptions {
+    /// 安静模式，响应中只返回删除失败的对象
+    pub quiet: bool,
 }
 
 /// 批量删除请求
 #[derive(Debug, Serialize)]
 #[serde(rename = "Delete")]
 struct DeleteRequest {
-    #[serde(rename = "Object")]
-    objects: Vec<DeleteObject>,
     #[serde(rename = "Quiet")]
     quiet: bool,
+    #[serde(rename = "Object")]
+    objects: Vec<ObjectIdentifier>,
 }
 
-/// 删除对象项
-#[derive(Debug, Serialize)]
-struct DeleteObject {
+/// 要删除的对象，可指定版本
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ObjectIdentifier {
     #[serde(rename = "Key")]
-    key: String,
+    pub key: String,
     #[serde(rename = "VersionId", skip_serializing_if = "Option::is_none")]
-    version_id: Option<String>,
+    pub version_id: Option<String>,
+}
+
+impl ObjectIdentifier {
+    /// 指定对象的某个版本
+    pub fn with_version<K: Into<String>, V: Into<String>>(key: K, version_id: V) -> Self {
+        Self {
+            key: key.into(),
+            version_id: Some(version_id.into()),
+        }
+    }
+}
+
+impl From<&str> for ObjectIdentifier {
+    fn from(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            version_id: None,
+        }
+    }
+}
+
+impl From<&String> for ObjectIdentifier {
+    fn from(key: &String) -> Self {
+        Self::from(key.as_str())
+    }
 }
 
 /// 批量删除响应
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename = "DeleteResult")]
 pub struct DeleteObjectsResponse {
     #[serde(rename = "Deleted", default)]
@@ -380,7 +1344,7 @@ pub struct DeleteObjectsResponse {
 }
 
 /// 已删除对象
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct DeletedObject {
     #[serde(rename = "Key")]
     pub key: String,
@@ -388,13 +1352,18 @@ pub struct DeletedObject {
     pub version_id: Option<String>,
     #[serde(rename = "DeleteMarker")]
     pub delete_marker: Option<bool>,
+    /// 本次删除创建的删除标记的版本
+    #[serde(rename = "DeleteMarkerVersionId")]
+    pub delete_marker_version_id: Option<String>,
 }
 
 /// 删除错误
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct DeleteError {
     #[serde(rename = "Key")]
     pub key: String,
+    #[serde(rename = "VersionId")]
+    pub version_id: Option<String>,
     #[serde(rename = "Code")]
     pub code: String,
     #[serde(rename = "Message")]
@@ -405,18 +1374,505 @@ pub struct DeleteError {
 mod tests {
     use super::*;
     use crate::config::Config;
-    use std::time::Duration;
 
     #[tokio::test]
     async fn test_object_operations() {
+        use crate::testing::{MockResponse, MockTransport};
+
         let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
             .with_timeout(Duration::from_secs(60));
-        
-        let cos_client = CosClient::new(config).unwrap();
-        let object_client = ObjectClient::new(cos_client);
-        
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(200, "").with_header("Content-Length", "10"))
+            .push_response(MockResponse::new(404, ""));
+        let object_client = ObjectClient::new(transport.client(config).unwrap());
+
         // 测试对象存在性检查
-        let exists = object_client.object_exists("test-key").await;
-        // 在实际测试中，这里会根据具体情况返回结果
+        assert!(object_client.object_exists("test-key").await.unwrap());
+        assert!(!object_client.object_exists("missing-key").await.unwrap());
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].method, Method::HEAD);
+        assert_eq!(requests[0].url.as_str(), "https://test-bucket-123.cos.ap-beijing.myqcloud.com/test-key");
+        assert_eq!(requests[1].url.path(), "/missing-key");
+        assert_eq!(requests[0].header("host"), Some("test-bucket-123.cos.ap-beijing.myqcloud.com"));
+        assert!(requests[0].signed_headers().contains(&"host".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_write_stream_to_file() {
+        let path = std::env::temp_dir().join("cos-rust-sdk-write-stream.part");
+
+        // "hello, cos" 的 MD5
+        let etag = "\"eee93ecfbef850ca0a84dd59d4061428\"";
+        let stream = ObjectStream::from_chunks(vec![b"hello, ", b"cos"], etag);
+        write_stream_to_file(stream, &path, true, true).await.unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello, cos");
+
+        let stream = ObjectStream::from_chunks(vec![b"hello"], etag);
+        assert!(matches!(
+            write_stream_to_file(stream, &path, true, true).await,
+            Err(CosError::IntegrityCheckFailed { .. })
+        ));
+        let stream = ObjectStream::from_chunks(vec![b"hello"], etag);
+        write_stream_to_file(stream, &path, false, false).await.unwrap();
+
+        let mut stream = ObjectStream::from_chunks(vec![b"hello, ", b"cos"], etag);
+        stream.crc64 = Some("1".to_string());
+        assert!(matches!(
+            write_stream_to_file(stream, &path, true, true).await,
+            Err(CosError::Crc64Mismatch { remote: 1, .. })
+        ));
+
+        // 分块上传的 ETag 不参与校验
+        let stream = ObjectStream::from_chunks(vec![b"hello"], "\"abc-2\"");
+        write_stream_to_file(stream, &path, true, true).await.unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        let expected = "2026-10-21T07:28:00+00:00";
+        assert_eq!(parse_http_date("Wed, 21 Oct 2026 07:28:00 GMT").unwrap().to_rfc3339(), expected);
+        assert_eq!(parse_http_date("2026-10-21T07:28:00.000Z").unwrap().to_rfc3339(), expected);
+        assert_eq!(parse_http_date(" 2026-10-21T15:28:00+08:00 ").unwrap().to_rfc3339(), expected);
+        assert!(parse_http_date("0").is_none());
+        assert!(parse_http_date("").is_none());
+    }
+
+    #[test]
+    fn test_validate_key() {
+        for key in ["a.txt", "dir/", "dir/sub/file", "中文/文件.txt", "a..b", ".hidden", "dir/.env", "a b+c"] {
+            assert!(validate_key(key).is_ok(), "{} should be accepted", key);
+        }
+        for key in ["", "/a.txt", "dir\\file", "a\nb", "a\u{7f}b", ".", "..", "./a", "dir/../a", "dir/.", "a/./b"] {
+            let err = validate_key(key).unwrap_err();
+            assert!(matches!(err, CosError::Client { .. }), "{:?} should be rejected", key);
+        }
+    }
+
+    #[test]
+    fn test_object_path() {
+        let config = Config::new("id", "key", "ap-beijing", "bucket-123");
+        let client = ObjectClient::new(CosClient::new(config.clone()).unwrap());
+        assert_eq!(client.object_path("a//b///c").unwrap(), "/a/b/c");
+        assert!(client.object_path("../a").is_err());
+
+        let client = ObjectClient::new(CosClient::new(config.with_key_validation(false)).unwrap());
+        assert_eq!(client.object_path("a//b").unwrap(), "/a//b");
+        assert_eq!(client.object_path("../a").unwrap(), "/../a");
+    }
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let mut metadata = HashMap::new();
+        metadata.insert("Origin-Filename".to_string(), "report 2024.pdf".to_string());
+        let headers = metadata_headers(&metadata).unwrap();
+        assert_eq!(headers.get("x-cos-meta-origin-filename").unwrap(), "report 2024.pdf");
+
+        let mut header_map = HeaderMap::new();
+        for (name, value) in &headers {
+            header_map.insert(
+                reqwest::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        header_map.insert("content-type", "text/plain".parse().unwrap());
+        let parsed = metadata_from_headers(&header_map);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed.get("origin-filename").unwrap(), "report 2024.pdf");
+    }
+
+    #[test]
+    fn test_put_object_options_headers() {
+        let headers = PutObjectOptions::new()
+            .with_content_type("text/css")
+            .with_cache_control("max-age=31536000")
+            .with_content_disposition("inline")
+            .with_content_encoding("gzip")
+            .with_expires("Wed, 21 Oct 2026 07:28:00 GMT")
+            .with_metadata("origin", "build")
+            .with_header("x-cos-acl", "public-read")
+            .with_storage_class(StorageClass::DeepArchive)
+            .with_tag("team", "media")
+            .with_forbid_overwrite(true)
+            .with_encryption(ServerSideEncryption::Cos)
+            .into_headers()
+            .unwrap();
+
+        assert_eq!(headers.len(), 11);
+        assert_eq!(headers.get("x-cos-server-side-encryption").unwrap(), "AES256");
+        assert_eq!(headers.get("x-cos-forbid-overwrite").unwrap(), "true");
+        assert_eq!(headers.get("x-cos-tagging").unwrap(), "team=media");
+        assert_eq!(headers.get("x-cos-storage-class").unwrap(), "DEEP_ARCHIVE");
+        assert_eq!(headers.get("Content-Type").unwrap(), "text/css");
+        assert_eq!(headers.get("Cache-Control").unwrap(), "max-age=31536000");
+        assert_eq!(headers.get("Content-Disposition").unwrap(), "inline");
+        assert_eq!(headers.get("Content-Encoding").unwrap(), "gzip");
+        assert_eq!(headers.get("Expires").unwrap(), "Wed, 21 Oct 2026 07:28:00 GMT");
+        assert_eq!(headers.get("x-cos-meta-origin").unwrap(), "build");
+        assert_eq!(headers.get("x-cos-acl").unwrap(), "public-read");
+    }
+
+    #[test]
+    fn test_get_object_options_headers() {
+        let time = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let options = GetObjectOptions {
+            if_none_match: Some("\"abc\"".to_string()),
+            if_modified_since: Some(time),
+            ..Default::default()
+        };
+
+        let headers = options.to_headers().unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers["If-None-Match"], "\"abc\"");
+        assert_eq!(headers["If-Modified-Since"], "Wed, 21 Oct 2015 07:28:00 GMT");
+        assert!(GetObjectOptions::default().to_headers().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_version_params_are_signed() {
+        assert!(version_params(None).is_empty());
+
+        let params = version_params(Some("MTg0NDUxNzc2ODQ2NjQ1Mzg2MDk"));
+        let now = Utc::now();
+        let authorization = crate::auth::Auth::new("test_id", "test_key")
+            .sign("GET", "/a.txt", &HashMap::new(), &params, now, now)
+            .unwrap();
+        assert!(authorization.contains("q-url-param-list=versionid"));
+    }
+
+    #[test]
+    fn test_delete_request_xml() {
+        let request = DeleteRequest {
+            quiet: true,
+            objects: vec![
+                ObjectIdentifier::from("a.txt"),
+                ObjectIdentifier::with_version("b.txt", "v1"),
+            ],
+        };
+        let xml = quick_xml::se::to_string(&request).unwrap();
+        assert_eq!(
+            xml,
+            "<Delete><Quiet>true</Quiet><Object><Key>a.txt</Key></Object>\
+             <Object><Key>b.txt</Key><VersionId>v1</VersionId></Object></Delete>"
+        );
+
+        // 与 `echo -n 'hello' | openssl md5 -binary | base64` 一致
+        assert_eq!(content_md5(b"hello"), "XUFAKrxLKna5cZ2REBfFkg==");
+    }
+
+    #[test]
+    fn test_parse_delete_result() {
+        let xml = "<DeleteResult>\
+            <Deleted><Key>a.txt</Key><DeleteMarker>true</DeleteMarker><DeleteMarkerVersionId>v2</DeleteMarkerVersionId></Deleted>\
+            <Error><Key>b.txt</Key><VersionId>v1</VersionId><Code>AccessDenied</Code><Message>Access Denied</Message></Error>\
+            </DeleteResult>";
+        let response: DeleteObjectsResponse = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(response.deleted[0].delete_marker_version_id.as_deref(), Some("v2"));
+        assert_eq!(response.errors[0].version_id.as_deref(), Some("v1"));
+        assert_eq!(response.errors[0].code, "AccessDenied");
+    }
+
+    #[test]
+    fn test_metadata_validation() {
+        let mut metadata = HashMap::new();
+        metadata.insert("name".to_string(), "报告.pdf".to_string());
+        assert!(metadata_headers(&metadata).is_err());
+
+        // 百分号编码后的非 ASCII 值可以正常上传
+        metadata.insert("name".to_string(), urlencoding::encode("报告.pdf").into_owned());
+        assert!(metadata_headers(&metadata).is_ok());
+
+        let mut metadata = HashMap::new();
+        metadata.insert("名称".to_string(), "a".to_string());
+        assert!(metadata_headers(&metadata).is_err());
+
+        let mut metadata = HashMap::new();
+        metadata.insert("big".to_string(), "a".repeat(MAX_METADATA_SIZE));
+        assert!(matches!(metadata_headers(&metadata), Err(CosError::Config { .. })));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_object_exists_status_handling() {
+        use crate::test_server::{client_for, unreachable_client, ServedResponse};
+
+        let client = ObjectClient::new(client_for(vec![ServedResponse::new(200, "")]).await);
+        assert!(client.object_exists("missing.txt").await.unwrap());
+
+        let client = ObjectClient::new(client_for(vec![ServedResponse::new(404, "")]).await);
+        assert!(!client.object_exists("missing.txt").await.unwrap());
+
+        for status in [403, 500] {
+            let client = ObjectClient::new(client_for(vec![ServedResponse::new(status, "")]).await);
+            let err = client.object_exists("missing.txt").await.unwrap_err();
+            assert_eq!(err.status(), Some(status));
+        }
+
+        let client = ObjectClient::new(unreachable_client().await);
+        let err = client.object_exists("missing.txt").await.unwrap_err();
+        assert_eq!(err.status(), None);
+    }
+
+    #[tokio::test]
+    async fn test_append_object() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .push_response(
+                MockResponse::new(200, "")
+                    .with_header("x-cos-next-append-position", "15")
+                    .with_header("ETag", "\"abc\"")
+                    .with_header("x-cos-hash-crc64ecma", "123"),
+            )
+            .push_response(
+                MockResponse::error(409, "PositionNotEqualToLength", "Position is not equal to the object length")
+                    .with_header("x-cos-next-append-position", "15"),
+            )
+            .push_response(MockResponse::error(409, "ObjectNotAppendable", "The object is not appendable"));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let object_client = ObjectClient::new(transport.client(config).unwrap());
+
+        let response = object_client
+            .append_object("log.txt", 10, b"hello".to_vec(), None)
+            .await
+            .unwrap();
+        assert_eq!(response.next_append_position, 15);
+        assert_eq!(response.etag, "\"abc\"");
+        assert_eq!(response.crc64.as_deref(), Some("123"));
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.query_param("append").as_deref(), Some(""));
+        assert_eq!(request.query_param("position").as_deref(), Some("10"));
+        assert_eq!(request.body.as_deref(), Some(&b"hello"[..]));
+
+        // 位置与对象长度不一致时返回服务端给出的下一个追加位置
+        let err = object_client
+            .append_object("log.txt", 10, b"hello".to_vec(), None)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, CosError::AppendPositionMismatch { position: 10, next_position: Some(15) }),
+            "{:?}",
+            err
+        );
+
+        // 其他 409 错误保留错误码
+        let err = object_client
+            .append_object("log.txt", 0, b"hello".to_vec(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some("ObjectNotAppendable"));
+    }
+
+    #[tokio::test]
+    async fn test_append_object_auto() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(404, ""))
+            .push_response(MockResponse::new(200, "").with_header("x-cos-next-append-position", "5"))
+            .push_response(MockResponse::new(200, "").with_header("Content-Length", "5"))
+            .push_response(MockResponse::new(200, "").with_header("x-cos-next-append-position", "10"));
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let object_client = ObjectClient::new(transport.client(config).unwrap());
+
+        // 对象不存在时从位置 0 开始
+        let response = object_client.append_object_auto("log.txt", b"hello".to_vec()).await.unwrap();
+        assert_eq!(response.next_append_position, 5);
+        // 对象存在时从当前长度开始
+        let response = object_client.append_object_auto("log.txt", b"world".to_vec()).await.unwrap();
+        assert_eq!(response.next_append_position, 10);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].method, Method::HEAD);
+        assert_eq!(requests[1].query_param("position").as_deref(), Some("0"));
+        assert_eq!(requests[2].method, Method::HEAD);
+        assert_eq!(requests[3].query_param("position").as_deref(), Some("5"));
+        assert_eq!(requests[3].body.as_deref(), Some(&b"world"[..]));
+    }
+
+    #[tokio::test]
+    async fn test_put_object_with_pic_operations() {
+        use crate::image::PicRule;
+        use crate::test_server::{client_for, ServedResponse};
+
+        let body = "<UploadResult><OriginalInfo><Key>photo.jpg</Key></OriginalInfo>\
+            <ProcessResults><Object><Key>thumb.jpg</Key><Format>JPEG</Format><Width>200</Width>\
+            <Height>100</Height></Object></ProcessResults></UploadResult>";
+        let client = ObjectClient::new(
+            client_for(vec![ServedResponse::new(200, body).with_header("ETag", "\"abc\"")]).await,
+        );
+
+        let options = PutObjectOptions::new().with_pic_operations(
+            PicOperations::new().with_rule(PicRule::new("thumb.jpg", "imageMogr2/thumbnail/200x")),
+        );
+        let response = client
+            .put_object_with_options("photo.jpg", b"jpeg".to_vec(), options)
+            .await
+            .unwrap();
+        assert_eq!(response.etag, "\"abc\"");
+        let result = response.upload_result.unwrap();
+        assert_eq!(result.original.unwrap().key, "photo.jpg");
+        assert_eq!(result.process_results.objects[0].width, 200);
+    }
+
+    #[tokio::test]
+    async fn test_put_bytes_and_get_bytes() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let client = ObjectClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "").with_header("ETag", "\"abc\""),
+                ServedResponse::new(200, "hello"),
+            ])
+            .await,
+        );
+
+        let response = client
+            .put_object("a.txt", Bytes::from_static(b"hello"), Some("text/plain"))
+            .await
+            .unwrap();
+        assert_eq!(response.etag, "\"abc\"");
+
+        let response = client.get_object("a.txt").await.unwrap();
+        assert_eq!(response.data, Bytes::from_static(b"hello"));
+        assert_eq!(response.into_vec(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_get_object_verifies_md5() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        // "hello" 的 MD5
+        let etag = "\"5d41402abc4b2a76b9719d911017c592\"";
+        let client = ObjectClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "hello").with_header("ETag", etag),
+                ServedResponse::new(200, "hellp").with_header("ETag", etag),
+                ServedResponse::new(200, "hellp")
+                    .with_header("ETag", etag)
+                    .with_header("x-cos-server-side-encryption-customer-algorithm", "AES256"),
+                ServedResponse::new(200, "hellp").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592-2\""),
+            ])
+            .await,
+        );
+
+        client.get_object("a.txt").await.unwrap();
+        match client.get_object("a.txt").await.unwrap_err() {
+            CosError::IntegrityCheckFailed { expected, .. } => {
+                assert_eq!(expected, "5d41402abc4b2a76b9719d911017c592")
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+        // SSE-C 与分块上传的对象不校验
+        client.get_object("a.txt").await.unwrap();
+        client.get_object("a.txt").await.unwrap();
+    }
+
+    #[test]
+    fn test_traffic_limit_header() {
+        let headers = PutObjectOptions::new()
+            .with_traffic_limit(MIN_TRAFFIC_LIMIT)
+            .into_headers()
+            .unwrap();
+        assert_eq!(headers["x-cos-traffic-limit"], "819200");
+
+        for limit in [MIN_TRAFFIC_LIMIT - 1, MAX_TRAFFIC_LIMIT + 1] {
+            let err = PutObjectOptions::new().with_traffic_limit(limit).into_headers().unwrap_err();
+            assert!(matches!(err, CosError::Config { .. }));
+        }
+
+        let options = GetObjectOptions {
+            traffic_limit_bits_per_sec: Some(MAX_TRAFFIC_LIMIT),
+            ..Default::default()
+        };
+        assert_eq!(options.to_headers().unwrap()["x-cos-traffic-limit"], "838860800");
+    }
+
+    #[tokio::test]
+    async fn test_request_id_exposed() {
+        use crate::test_server::{client_for, ServedResponse};
+
+        let client = ObjectClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "").with_header("x-cos-request-id", "NjRh-ok"),
+                ServedResponse::new(403, "AccessDenied").with_header("x-cos-request-id", "NjRh-denied"),
+            ])
+            .await,
+        );
+
+        let response = client.head_object("a.txt").await.unwrap();
+        assert_eq!(response.request_id.as_deref(), Some("NjRh-ok"));
+        assert_eq!(response.headers.get("x-cos-request-id").unwrap(), "NjRh-ok");
+
+        let err = client.delete_object("a.txt").await.unwrap_err();
+        assert_eq!(err.request_id(), Some("NjRh-denied"));
+        assert_eq!(err.status(), Some(403));
+        assert!(err.to_string().ends_with("(request id: NjRh-denied)"));
+    }
+
+    #[test]
+    fn test_attachment_disposition() {
+        assert_eq!(attachment_disposition("report.pdf"), "attachment; filename=\"report.pdf\"");
+        assert_eq!(
+            attachment_disposition("年度 报告.pdf"),
+            "attachment; filename=\"__ __.pdf\"; filename*=UTF-8''%E5%B9%B4%E5%BA%A6%20%E6%8A%A5%E5%91%8A.pdf"
+        );
+        assert_eq!(
+            attachment_disposition("a\"b.txt"),
+            "attachment; filename=\"a_b.txt\"; filename*=UTF-8''a%22b.txt"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_object_timeout_override() {
+        // 服务端接受连接后不响应
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        });
+
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+            .with_domain(addr.to_string())
+            .with_https(false);
+        let client = ObjectClient::new(CosClient::new(config).unwrap());
+        let options = GetObjectOptions {
+            timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        };
+
+        // 配置的超时为 30 秒，请求应在覆盖的超时后失败
+        let started = std::time::Instant::now();
+        assert!(client.get_object_with_options("a.txt", options).await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_put_object_sends_signed_content_type() {
+        use crate::test_server::{recording_client_for, ServedResponse};
+
+        let (client, requests) = recording_client_for(vec![ServedResponse::new(200, "")]).await;
+        let client = ObjectClient::new(client);
+        client.put_object("a.json", b"{}".to_vec(), Some("application/json")).await.unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].request_line, "PUT /a.json HTTP/1.1");
+        assert_eq!(requests[0].header("content-type"), Some("application/json"));
+        assert_eq!(requests[0].body, b"{}");
+        let authorization = requests[0].header("authorization").unwrap();
+        let header_list = authorization
+            .split('&')
+            .find_map(|part| part.strip_prefix("q-header-list="))
+            .unwrap();
+        assert!(header_list.split(';').any(|h| h == "content-type"), "{}", header_list);
+    }
+}
@@ -0,0 +1,286 @@
+//! 回源模块
+//!
+//! 提供存储桶回源（镜像/代理源站）配置的读写接口
+//!
+//! 参考文档：<https://cloud.tencent.com/document/product/436/31508>
+
+use crate::bucket::BucketClient;
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use crate::object::content_md5;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 回源配置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "OriginConfiguration")]
+pub struct OriginConfiguration {
+    #[serde(rename = "OriginRule", default)]
+    pub rules: Vec<OriginRule>,
+}
+
+/// 回源规则
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginRule {
+    /// 规则优先级，数字越小优先级越高
+    #[serde(rename = "RulePriority")]
+    pub priority: u32,
+    #[serde(rename = "OriginType")]
+    pub origin_type: OriginType,
+    #[serde(rename = "OriginCondition")]
+    pub condition: OriginCondition,
+    #[serde(rename = "OriginParameter")]
+    pub parameter: OriginParameter,
+    #[serde(rename = "OriginInfo")]
+    pub info: OriginInfo,
+}
+
+impl OriginRule {
+    /// 创建镜像回源规则：对象不存在时从源站拉取并保存到存储桶
+    pub fn mirror<S: Into<String>>(priority: u32, host: S) -> Self {
+        Self {
+            priority,
+            origin_type: OriginType::Mirror,
+            condition: OriginCondition::default(),
+            parameter: OriginParameter::default(),
+            info: OriginInfo {
+                host_info: OriginHostInfo { host_name: host.into() },
+            },
+        }
+    }
+
+    /// 创建代理回源规则：对象不存在时从源站拉取并直接返回，不保存到存储桶
+    pub fn proxy<S: Into<String>>(priority: u32, host: S) -> Self {
+        Self {
+            origin_type: OriginType::Proxy,
+            ..Self::mirror(priority, host)
+        }
+    }
+
+    /// 只对指定前缀的对象回源
+    pub fn with_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.condition.prefix = Some(prefix.into());
+        self
+    }
+
+    /// 设置回源协议
+    pub fn with_protocol(mut self, protocol: OriginProtocol) -> Self {
+        self.parameter.protocol = protocol;
+        self
+    }
+
+    /// 设置是否跟随源站的 3xx 跳转
+    pub fn with_follow_redirection(mut self, follow: bool) -> Self {
+        self.parameter.follow_redirection = follow;
+        self
+    }
+
+    /// 回源时透传请求中的指定请求头
+    pub fn with_follow_header<S: Into<String>>(mut self, key: S) -> Self {
+        let http_header = self.parameter.http_header.get_or_insert_with(Default::default);
+        http_header
+            .follow_http_headers
+            .get_or_insert_with(Default::default)
+            .headers
+            .push(OriginHeader {
+                key: key.into(),
+                value: None,
+            });
+        self
+    }
+
+    /// 回源时附加固定的请求头
+    pub fn with_new_header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let http_header = self.parameter.http_header.get_or_insert_with(Default::default);
+        http_header
+            .new_http_headers
+            .get_or_insert_with(Default::default)
+            .headers
+            .push(OriginHeader {
+                key: key.into(),
+                value: Some(value.into()),
+            });
+        self
+    }
+}
+
+/// 回源类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OriginType {
+    /// 镜像回源，拉取的对象会保存到存储桶
+    Mirror,
+    /// 代理回源，拉取的对象不保存
+    Proxy,
+}
+
+/// 触发回源的条件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginCondition {
+    /// 触发回源的状态码，目前只支持 404
+    #[serde(rename = "HTTPStatusCode")]
+    pub http_status_code: String,
+    /// 只对指定前缀的对象回源，为空时对所有对象回源
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+}
+
+impl Default for OriginCondition {
+    fn default() -> Self {
+        Self {
+            http_status_code: "404".to_string(),
+            prefix: None,
+        }
+    }
+}
+
+/// 回源参数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginParameter {
+    #[serde(rename = "Protocol")]
+    pub protocol: OriginProtocol,
+    /// 是否透传请求的查询字符串
+    #[serde(rename = "FollowQueryString", default)]
+    pub follow_query_string: bool,
+    #[serde(rename = "HttpHeader", skip_serializing_if = "Option::is_none")]
+    pub http_header: Option<OriginHttpHeader>,
+    /// 是否跟随源站的 3xx 跳转
+    #[serde(rename = "FollowRedirection", default)]
+    pub follow_redirection: bool,
+    /// 跟随的跳转状态码，如 `301,302`
+    #[serde(rename = "HttpRedirectCode", skip_serializing_if = "Option::is_none")]
+    pub http_redirect_code: Option<String>,
+}
+
+impl Default for OriginParameter {
+    fn default() -> Self {
+        Self {
+            protocol: OriginProtocol::Follow,
+            follow_query_string: true,
+            http_header: None,
+            follow_redirection: true,
+            http_redirect_code: None,
+        }
+    }
+}
+
+/// 回源协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OriginProtocol {
+    Http,
+    Https,
+    /// 与请求使用的协议一致
+    Follow,
+}
+
+/// 回源时的请求头设置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginHttpHeader {
+    /// 透传的请求头
+    #[serde(rename = "FollowHttpHeaders", skip_serializing_if = "Option::is_none")]
+    pub follow_http_headers: Option<OriginHeaderList>,
+    /// 附加的固定请求头
+    #[serde(rename = "NewHttpHeaders", skip_serializing_if = "Option::is_none")]
+    pub new_http_headers: Option<OriginHeaderList>,
+}
+
+/// 请求头列表，对应 `<Header>` 结构
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginHeaderList {
+    #[serde(rename = "Header", default)]
+    pub headers: Vec<OriginHeader>,
+}
+
+/// 单个请求头
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginHeader {
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// 透传的请求头没有取值
+    #[serde(rename = "Value", skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// 源站信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginInfo {
+    #[serde(rename = "HostInfo")]
+    pub host_info: OriginHostInfo,
+}
+
+/// 源站地址
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginHostInfo {
+    /// 源站域名或 IP，可带端口，如 `artifacts.example.com:8080`
+    #[serde(rename = "HostName")]
+    pub host_name: String,
+}
+
+impl BucketClient {
+    /// 设置存储桶回源配置，会覆盖已有的配置
+    pub async fn put_bucket_origin(&self, config: &OriginConfiguration) -> Result<()> {
+        if config.rules.is_empty() {
+            return Err(CosError::config("Origin configuration must contain at least one rule"));
+        }
+        let xml_body = quick_xml::se::to_string(config)
+            .map_err(|e| CosError::other(format!("Failed to serialize origin configuration: {}", e)))?;
+        let mut headers = HashMap::new();
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
+
+        self.client.put_with_headers("/", origin_params(), headers, Some(xml_body)).await?;
+        Ok(())
+    }
+
+    /// 获取存储桶回源配置
+    pub async fn get_bucket_origin(&self) -> Result<OriginConfiguration> {
+        let response = self.client.get("/", origin_params()).await?;
+        CosClient::parse_xml(response).await
+    }
+
+    /// 删除存储桶回源配置
+    pub async fn delete_bucket_origin(&self) -> Result<()> {
+        self.client.delete("/", origin_params()).await?;
+        Ok(())
+    }
+}
+
+/// 回源子资源的查询参数
+fn origin_params() -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("origin".to_string(), "".to_string());
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin_xml_round_trip() {
+        let config = OriginConfiguration {
+            rules: vec![OriginRule::mirror(1, "artifacts.example.com")
+                .with_prefix("releases/")
+                .with_protocol(OriginProtocol::Https)
+                .with_follow_header("Authorization")
+                .with_new_header("x-origin-from", "cos")],
+        };
+        let xml = quick_xml::se::to_string(&config).unwrap();
+        assert_eq!(
+            xml,
+            "<OriginConfiguration><OriginRule><RulePriority>1</RulePriority><OriginType>Mirror</OriginType>\
+             <OriginCondition><HTTPStatusCode>404</HTTPStatusCode><Prefix>releases/</Prefix></OriginCondition>\
+             <OriginParameter><Protocol>HTTPS</Protocol><FollowQueryString>true</FollowQueryString>\
+             <HttpHeader><FollowHttpHeaders><Header><Key>Authorization</Key></Header></FollowHttpHeaders>\
+             <NewHttpHeaders><Header><Key>x-origin-from</Key><Value>cos</Value></Header></NewHttpHeaders>\
+             </HttpHeader><FollowRedirection>true</FollowRedirection></OriginParameter>\
+             <OriginInfo><HostInfo><HostName>artifacts.example.com</HostName></HostInfo></OriginInfo>\
+             </OriginRule></OriginConfiguration>"
+        );
+        let parsed: OriginConfiguration = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, config);
+
+        let proxy = OriginRule::proxy(2, "10.0.0.1:8080").with_follow_redirection(false);
+        assert_eq!(proxy.origin_type, OriginType::Proxy);
+        assert!(!proxy.parameter.follow_redirection);
+    }
+}
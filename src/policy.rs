@@ -0,0 +1,263 @@
+//! 权限策略模块
+//!
+//! STS 临时密钥与存储桶策略共用的策略语法定义
+//!
+//! 参考文档：<https://cloud.tencent.com/document/product/436/12469>
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 权限策略
+///
+/// 序列化为小写字段名；解析时同时接受 COS 返回的首字母大写字段名。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Policy {
+    /// 策略语法版本
+    #[serde(alias = "Version")]
+    pub version: String,
+    /// 策略声明列表
+    #[serde(alias = "Statement")]
+    pub statement: Vec<Statement>,
+}
+
+/// 策略声明
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Statement {
+    /// 授权对象，STS 策略中为空，存储桶策略中必填
+    #[serde(alias = "Principal", default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    /// 效果：allow 或 deny
+    #[serde(alias = "Effect")]
+    pub effect: String,
+    /// 允许的操作列表
+    #[serde(alias = "Action")]
+    pub action: Vec<String>,
+    /// 资源列表
+    #[serde(alias = "Resource")]
+    pub resource: Vec<String>,
+    /// 条件（可选）
+    #[serde(alias = "Condition", default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<HashMap<String, HashMap<String, serde_json::Value>>>,
+}
+
+
+/// 策略的授权对象
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Principal {
+    /// 授权的用户列表，如 `qcs::cam::uin/100000000001:uin/100000000011`，`*` 表示所有人
+    pub qcs: Vec<String>,
+}
+
+impl Principal {
+    /// 授权给指定的用户
+    pub fn qcs<I, S>(users: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            qcs: users.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// 授权给所有人（匿名访问）
+    pub fn anyone() -> Self {
+        Self::qcs(["*"])
+    }
+}
+
+/// 存储桶策略，与 STS 策略使用相同的语法
+pub type BucketPolicy = Policy;
+
+impl Policy {
+    /// 创建新的权限策略
+    pub fn new() -> Self {
+        Self {
+            version: "2.0".to_string(),
+            statement: Vec::new(),
+        }
+    }
+    
+    /// 添加策略声明
+    pub fn add_statement(mut self, statement: Statement) -> Self {
+        self.statement.push(statement);
+        self
+    }
+    
+    /// 创建允许上传对象的策略
+    pub fn allow_put_object(bucket: &str, prefix: Option<&str>) -> Self {
+        // 从bucket名称中提取appid (格式: bucket-appid)
+        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
+        let (bucket_name, appid) = if parts.len() == 2 {
+            (parts[1], parts[0])
+        } else {
+            (bucket, "*")
+        };
+        
+        let resource = if let Some(prefix) = prefix {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
+        } else {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
+        };
+        
+        Self::new().add_statement(Statement {
+            principal: None,
+            effect: "allow".to_string(),
+            action: vec![
+                "name/cos:PutObject".to_string(),
+                "name/cos:PostObject".to_string(),
+                "name/cos:InitiateMultipartUpload".to_string(),
+                "name/cos:ListMultipartUploads".to_string(),
+                "name/cos:ListParts".to_string(),
+                "name/cos:UploadPart".to_string(),
+                "name/cos:CompleteMultipartUpload".to_string(),
+            ],
+            resource: vec![resource],
+            condition: None,
+        })
+    }
+    
+    /// 创建允许下载对象的策略
+    pub fn allow_get_object(bucket: &str, prefix: Option<&str>) -> Self {
+        // 从bucket名称中提取appid (格式: bucket-appid)
+        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
+        let (bucket_name, appid) = if parts.len() == 2 {
+            (parts[1], parts[0])
+        } else {
+            (bucket, "*")
+        };
+        
+        let resource = if let Some(prefix) = prefix {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
+        } else {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
+        };
+        
+        Self::new().add_statement(Statement {
+            principal: None,
+            effect: "allow".to_string(),
+            action: vec![
+                "name/cos:GetObject".to_string(),
+                "name/cos:HeadObject".to_string(),
+            ],
+            resource: vec![resource],
+            condition: None,
+        })
+    }
+    
+    /// 创建允许删除对象的策略
+    pub fn allow_delete_object(bucket: &str, prefix: Option<&str>) -> Self {
+        // 从bucket名称中提取appid (格式: bucket-appid)
+        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
+        let (bucket_name, appid) = if parts.len() == 2 {
+            (parts[1], parts[0])
+        } else {
+            (bucket, "*")
+        };
+        
+        let resource = if let Some(prefix) = prefix {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
+        } else {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
+        };
+        
+        Self::new().add_statement(Statement {
+            principal: None,
+            effect: "allow".to_string(),
+            action: vec![
+                "name/cos:DeleteObject".to_string(),
+            ],
+            resource: vec![resource],
+            condition: None,
+        })
+    }
+    
+    /// 创建允许上传和下载对象的策略
+    pub fn allow_read_write(bucket: &str, prefix: Option<&str>) -> Self {
+        // 从bucket名称中提取appid (格式: bucket-appid)
+        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
+        let (bucket_name, appid) = if parts.len() == 2 {
+            (parts[1], parts[0])
+        } else {
+            (bucket, "*")
+        };
+        
+        let resource = if let Some(prefix) = prefix {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
+        } else {
+            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
+        };
+        
+        Self::new().add_statement(Statement {
+            principal: None,
+            effect: "allow".to_string(),
+            action: vec![
+                "name/cos:PutObject".to_string(),
+                "name/cos:PostObject".to_string(),
+                "name/cos:GetObject".to_string(),
+                "name/cos:HeadObject".to_string(),
+                "name/cos:DeleteObject".to_string(),
+                "name/cos:InitiateMultipartUpload".to_string(),
+                "name/cos:ListMultipartUploads".to_string(),
+                "name/cos:ListParts".to_string(),
+                "name/cos:UploadPart".to_string(),
+                "name/cos:CompleteMultipartUpload".to_string(),
+            ],
+            resource: vec![resource],
+            condition: None,
+        })
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_policy_creation() {
+        let policy = Policy::allow_put_object("test-bucket-1234567890", Some("uploads/"));
+        assert_eq!(policy.version, "2.0");
+        assert_eq!(policy.statement.len(), 1);
+        assert_eq!(policy.statement[0].effect, "allow");
+        assert!(policy.statement[0].action.contains(&"name/cos:PutObject".to_string()));
+    }
+    
+    #[test]
+    fn test_policy_serialization() {
+        let policy = Policy::allow_read_write("test-bucket", None);
+        let json = serde_json::to_string(&policy).unwrap();
+        assert!(json.contains("version"));
+        assert!(json.contains("statement"));
+    }
+
+    #[test]
+    fn test_parse_bucket_policy() {
+        let json = r#"{
+            "Statement": [{
+                "Principal": {"qcs": ["qcs::cam::uin/100000000001:uin/100000000011"]},
+                "Effect": "allow",
+                "Action": ["name/cos:GetObject"],
+                "Resource": ["qcs::cos:ap-beijing:uid/1250000000:examplebucket-1250000000/*"],
+                "Condition": {"ip_equal": {"qcs:ip": ["10.0.0.0/8"]}}
+            }],
+            "version": "2.0"
+        }"#;
+        let policy: BucketPolicy = serde_json::from_str(json).unwrap();
+        assert_eq!(policy.version, "2.0");
+        let statement = &policy.statement[0];
+        assert_eq!(
+            statement.principal,
+            Some(Principal::qcs(["qcs::cam::uin/100000000001:uin/100000000011"]))
+        );
+        assert!(statement.condition.as_ref().unwrap().contains_key("ip_equal"));
+
+        let json = serde_json::to_string(&Policy::allow_get_object("examplebucket-1250000000", None)).unwrap();
+        assert!(!json.contains("principal"));
+    }
+}
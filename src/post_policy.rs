@@ -0,0 +1,206 @@
+//! 表单上传模块
+//!
+//! 生成 POST Object 表单上传所需的策略与签名字段，供浏览器直接上传到 COS
+
+use crate::error::{CosError, Result};
+use crate::object::ObjectClient;
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value};
+
+/// 表单上传策略
+///
+/// 策略中的每个条件都会由 COS 校验，表单中的字段必须满足全部条件才能上传成功。
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    expires_in: std::time::Duration,
+    conditions: Vec<Value>,
+    fields: Vec<(String, String)>,
+}
+
+impl PostPolicy {
+    /// 创建在 `expires_in` 后过期的策略
+    pub fn new(expires_in: std::time::Duration) -> Self {
+        Self {
+            expires_in,
+            conditions: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// 限制上传文件的大小范围（字节，闭区间）
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.conditions.push(json!(["content-length-range", min, max]));
+        self
+    }
+
+    /// 要求上传文件使用指定的 Content-Type，该字段会加入表单
+    pub fn with_content_type<S: Into<String>>(mut self, content_type: S) -> Self {
+        let content_type = content_type.into();
+        self.conditions.push(json!({ "Content-Type": content_type }));
+        self.fields.push(("Content-Type".to_string(), content_type));
+        self
+    }
+
+    /// 要求 Content-Type 以指定前缀开头，如 `image/`，由前端在表单中填写
+    pub fn with_content_type_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.conditions
+            .push(json!(["starts-with", "$Content-Type", prefix.into()]));
+        self
+    }
+
+    /// 上传成功后返回的 HTTP 状态码（200、201 或 204），该字段会加入表单
+    pub fn with_success_action_status(mut self, status: u16) -> Self {
+        self.conditions
+            .push(json!({ "success_action_status": status.to_string() }));
+        self.fields
+            .push(("success_action_status".to_string(), status.to_string()));
+        self
+    }
+
+    /// 添加自定义条件，如 `json!(["starts-with", "$x-cos-meta-owner", ""])`
+    pub fn with_condition(mut self, condition: Value) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// 生成策略 JSON
+    fn to_json(&self, bucket: &str, key_or_prefix: &str, secret_id: &str, key_time: &str, expiration: DateTime<Utc>) -> String {
+        let mut conditions = vec![
+            json!({ "q-sign-algorithm": "sha1" }),
+            json!({ "q-ak": secret_id }),
+            json!({ "q-sign-time": key_time }),
+            json!({ "bucket": bucket }),
+        ];
+        conditions.push(if key_or_prefix.ends_with('/') {
+            json!(["starts-with", "$key", key_or_prefix])
+        } else {
+            json!({ "key": key_or_prefix })
+        });
+        conditions.extend(self.conditions.iter().cloned());
+
+        json!({
+            "expiration": expiration.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            "conditions": conditions,
+        })
+        .to_string()
+    }
+}
+
+/// 表单上传所需的地址与字段
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostForm {
+    /// 表单提交地址
+    pub url: String,
+    /// 按顺序放入表单的字段，文件字段 `file` 必须放在这些字段之后
+    pub fields: Vec<(String, String)>,
+}
+
+impl ObjectClient {
+    /// 生成表单上传的字段
+    ///
+    /// `key_or_prefix` 以 `/` 结尾时作为前缀，表单中的 `key` 为 `<prefix>${filename}`，
+    /// 由 COS 替换为上传文件的文件名；否则只允许上传到该对象键。
+    pub fn generate_post_form(&self, key_or_prefix: &str, policy: PostPolicy) -> Result<PostForm> {
+        let config = self.client.config();
+        let expires_in = Duration::from_std(policy.expires_in)
+            .map_err(|e| CosError::config(format!("Invalid expiration: {}", e)))?;
+
+        let start_time = Utc::now();
+        let end_time = start_time + expires_in;
+        let key_time = format!("{};{}", start_time.timestamp(), end_time.timestamp());
+
+        let auth = self.client.auth()?;
+        let policy_json = policy.to_json(&config.bucket, key_or_prefix, &auth.secret_id, &key_time, end_time);
+        let signature = auth.sign_post_policy(&policy_json, &key_time)?;
+
+        let key = if key_or_prefix.ends_with('/') {
+            format!("{}${{filename}}", key_or_prefix)
+        } else {
+            key_or_prefix.to_string()
+        };
+
+        let mut fields = vec![
+            ("key".to_string(), key),
+            ("policy".to_string(), general_purpose::STANDARD.encode(&policy_json)),
+            ("q-sign-algorithm".to_string(), "sha1".to_string()),
+            ("q-ak".to_string(), auth.secret_id.clone()),
+            ("q-key-time".to_string(), key_time),
+            ("q-signature".to_string(), signature),
+        ];
+        fields.extend(policy.fields);
+
+        Ok(PostForm {
+            url: format!("{}/", config.bucket_url()?),
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::CosClient;
+    use crate::config::Config;
+    use chrono::TimeZone;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn test_policy_json() {
+        let policy = PostPolicy::new(StdDuration::from_secs(600))
+            .with_content_length_range(1, 1024)
+            .with_content_type_prefix("image/");
+        let expiration = Utc.timestamp_opt(1700000600, 0).unwrap();
+
+        let json: Value = serde_json::from_str(&policy.to_json(
+            "test-bucket-123",
+            "uploads/",
+            "AKID",
+            "1700000000;1700000600",
+            expiration,
+        ))
+        .unwrap();
+        assert_eq!(json["expiration"], "2023-11-14T22:23:20.000Z");
+        let conditions = json["conditions"].as_array().unwrap();
+        assert_eq!(conditions[2], json!({ "q-sign-time": "1700000000;1700000600" }));
+        assert_eq!(conditions[3], json!({ "bucket": "test-bucket-123" }));
+        assert_eq!(conditions[4], json!(["starts-with", "$key", "uploads/"]));
+        assert_eq!(conditions[5], json!(["content-length-range", 1, 1024]));
+        assert_eq!(conditions[6], json!(["starts-with", "$Content-Type", "image/"]));
+    }
+
+    #[test]
+    fn test_generate_post_form() {
+        let config = Config::new("AKID", "secret", "ap-beijing", "test-bucket-123");
+        let client = ObjectClient::new(CosClient::new(config).unwrap());
+
+        let policy = PostPolicy::new(StdDuration::from_secs(600))
+            .with_content_type("image/png")
+            .with_success_action_status(201);
+        let form = client.generate_post_form("avatars/", policy).unwrap();
+        assert_eq!(form.url, "https://test-bucket-123.cos.ap-beijing.myqcloud.com/");
+
+        let names: Vec<_> = form.fields.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "key",
+                "policy",
+                "q-sign-algorithm",
+                "q-ak",
+                "q-key-time",
+                "q-signature",
+                "Content-Type",
+                "success_action_status",
+            ]
+        );
+        assert_eq!(form.fields[0].1, "avatars/${filename}");
+
+        // 签名必须基于表单中的策略原文计算
+        let policy = general_purpose::STANDARD.decode(&form.fields[1].1).unwrap();
+        let policy = String::from_utf8(policy).unwrap();
+        let auth = client.client.auth().unwrap();
+        assert_eq!(form.fields[5].1, auth.sign_post_policy(&policy, &form.fields[4].1).unwrap());
+        assert!(policy.contains(r#"["starts-with","$key","avatars/"]"#));
+    }
+}
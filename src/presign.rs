@@ -0,0 +1,190 @@
+//! 预签名 URL 模块
+//!
+//! 生成带有查询字符串签名的临时访问链接，无需携带 Authorization 请求头即可访问对象
+
+use crate::error::Result;
+use crate::image::ImageProcess;
+use crate::object::{response_override_params, ObjectClient};
+use reqwest::Method;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// 预签名选项
+#[derive(Debug, Clone, Default)]
+pub struct PresignOptions {
+    /// 覆盖下载响应的 Content-Type
+    pub response_content_type: Option<String>,
+    /// 覆盖下载响应的 Content-Disposition，如 `attachment; filename="a.txt"`，
+    /// 非 ASCII 文件名可使用 [`attachment_disposition`](crate::object::attachment_disposition) 生成
+    pub response_content_disposition: Option<String>,
+    /// 客户端发起请求时必须携带的请求头，会写入 `q-header-list`
+    pub headers: HashMap<String, String>,
+    /// 额外的查询参数，会写入 `q-url-param-list`
+    pub params: HashMap<String, String>,
+    /// 下载经过数据万象处理后的图片
+    pub image_process: Option<ImageProcess>,
+}
+
+impl PresignOptions {
+    /// 需要写入 URL 并参与签名的查询参数
+    fn params(&self) -> HashMap<String, String> {
+        let mut params = self.params.clone();
+        response_override_params(
+            &mut params,
+            self.response_content_type.as_deref(),
+            self.response_content_disposition.as_deref(),
+        );
+        if let Some(image_process) = &self.image_process {
+            image_process.insert_into(&mut params);
+        }
+        params
+    }
+}
+
+/// 预签名请求
+#[derive(Debug, Clone)]
+pub struct PresignedRequest {
+    pub method: Method,
+    pub url: String,
+    /// 发起请求时必须原样携带的请求头（Host 由 HTTP 客户端自动设置）
+    pub headers: HashMap<String, String>,
+}
+
+impl ObjectClient {
+    /// 生成下载对象的预签名 URL
+    ///
+    /// 生成的链接在 `expires_in` 时间内有效，可直接交给浏览器等客户端下载。
+    pub fn presigned_get_url(
+        &self,
+        key: &str,
+        expires_in: Duration,
+        options: Option<PresignOptions>,
+    ) -> Result<String> {
+        self.presigned_url(Method::GET, key, expires_in, options.unwrap_or_default())
+            .map(|request| request.url)
+    }
+
+    /// 生成任意方法的预签名请求
+    ///
+    /// 例如生成预签名 PUT 供客户端直传；`options.headers` 中的请求头参与签名，
+    /// 客户端发起请求时缺少或修改任意一个都会导致签名校验失败。
+    pub fn presigned_url(
+        &self,
+        method: Method,
+        key: &str,
+        expires_in: Duration,
+        options: PresignOptions,
+    ) -> Result<PresignedRequest> {
+        let params = options.params();
+        let url = self.client.presign_url(
+            &method,
+            &self.object_path(key)?,
+            &params,
+            options.headers.clone(),
+            expires_in,
+        )?;
+
+        Ok(PresignedRequest {
+            method,
+            url,
+            headers: options.headers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::CosClient;
+    use crate::config::Config;
+
+    #[test]
+    fn test_presigned_get_url() {
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let object_client = ObjectClient::new(CosClient::new(config).unwrap());
+
+        let options = PresignOptions {
+            response_content_disposition: Some("attachment".to_string()),
+            ..Default::default()
+        };
+        let url = object_client
+            .presigned_get_url("dir/a.txt", Duration::from_secs(600), Some(options))
+            .unwrap();
+
+        assert!(url.starts_with("https://test-bucket-123.cos.ap-beijing.myqcloud.com/dir/a.txt?"));
+        assert!(url.contains("response-content-disposition=attachment&q-sign-algorithm=sha1"));
+        assert!(url.contains("q-ak=test_id"));
+        assert!(url.contains("q-header-list=host"));
+        assert!(url.contains("q-url-param-list=response-content-disposition"));
+
+        let key_time = url.split("q-key-time=").nth(1).unwrap().split('&').next().unwrap();
+        let (start, end) = key_time.split_once(';').unwrap();
+        assert_eq!(end.parse::<i64>().unwrap() - start.parse::<i64>().unwrap(), 600);
+    }
+
+    #[test]
+    fn test_presigned_put_with_headers() {
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let object_client = ObjectClient::new(CosClient::new(config).unwrap());
+
+        let mut options = PresignOptions::default();
+        options
+            .headers
+            .insert("Content-Type".to_string(), "image/png".to_string());
+        options
+            .params
+            .insert("x-cos-traffic-limit".to_string(), "819200".to_string());
+        let request = object_client
+            .presigned_url(Method::PUT, "a.png", Duration::from_secs(60), options)
+            .unwrap();
+
+        assert_eq!(request.method, Method::PUT);
+        assert_eq!(request.headers.get("Content-Type").unwrap(), "image/png");
+        assert!(!request.headers.contains_key("Host"));
+        assert!(request.url.contains("q-header-list=content-type;host"));
+        assert!(request.url.contains("q-url-param-list=x-cos-traffic-limit"));
+        assert!(request.url.contains("x-cos-traffic-limit=819200"));
+    }
+
+    #[test]
+    fn test_presigned_url_with_image_process() {
+        use crate::image::ImageMogr2;
+
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let object_client = ObjectClient::new(CosClient::new(config).unwrap());
+
+        let options = PresignOptions {
+            image_process: Some(ImageMogr2::new().thumbnail_width(200).into()),
+            ..Default::default()
+        };
+        let url = object_client
+            .presigned_get_url("photo.jpg", Duration::from_secs(600), Some(options))
+            .unwrap();
+
+        assert!(url.contains("/photo.jpg?imageMogr2/thumbnail/200x&q-sign-algorithm=sha1"));
+        assert!(url.contains("q-url-param-list=imagemogr2%2fthumbnail%2f200x"));
+    }
+
+    #[test]
+    fn test_presigned_url_with_response_overrides() {
+        use crate::object::attachment_disposition;
+
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let object_client = ObjectClient::new(CosClient::new(config).unwrap());
+
+        let options = PresignOptions {
+            response_content_type: Some("text/plain".to_string()),
+            response_content_disposition: Some(attachment_disposition("报告.txt")),
+            ..Default::default()
+        };
+        let url = object_client
+            .presigned_get_url("report.txt", Duration::from_secs(600), Some(options))
+            .unwrap();
+
+        assert!(url.contains(
+            "response-content-disposition=attachment%3B%20filename%3D%22__.txt%22%3B%20filename%2A%3DUTF-8%27%27%25E6%258A%25A5%25E5%2591%258A.txt"
+        ));
+        assert!(url.contains("response-content-type=text%2Fplain"));
+        assert!(url.contains("q-url-param-list=response-content-disposition;response-content-type"));
+    }
+}
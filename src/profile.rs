@@ -0,0 +1,333 @@
+//! 配置文件模块
+//!
+//! 开启 `config-file` feature 后可用。配置文件按命名 profile 保存密钥与存储桶设置，
+//! 默认位于 `~/.cos/config.toml`，扩展名为 `.json` 时按 JSON 解析：
+//!
+//! ```toml
+//! [profiles.default]
+//! secret_id = "AKID..."
+//! secret_key = "..."
+//! region = "ap-beijing"
+//! bucket = "examplebucket-1250000000"
+//!
+//! [profiles.local]
+//! secret_id = "minio"
+//! secret_key = "minio123"
+//! region = "ap-beijing"
+//! bucket = "test-1250000000"
+//! endpoint = "http://127.0.0.1:9000"
+//! path_style = true
+//! ```
+//!
+//! [`Config::from_file`] 读取指定的 profile，并用环境变量（如 `COS_SECRET_ID`）覆盖文件中的值。
+//! 文件保存了密钥，在 unix 上权限比 0600 宽松时会记录警告。
+
+use crate::config::Config;
+use crate::error::{CosError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// 覆盖配置文件中字符串字段的环境变量
+const ENV_OVERRIDES: [(&str, &str); 7] = [
+    ("secret_id", "COS_SECRET_ID"),
+    ("secret_key", "COS_SECRET_KEY"),
+    ("security_token", "COS_SECURITY_TOKEN"),
+    ("region", "COS_REGION"),
+    ("bucket", "COS_BUCKET"),
+    ("domain", "COS_DOMAIN"),
+    ("endpoint", "COS_ENDPOINT"),
+];
+
+/// 配置文件
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigFile {
+    /// 按名称保存的 profile
+    #[serde(default)]
+    pub profiles: HashMap<String, ProfileConfig>,
+}
+
+/// 配置文件中的一个 profile，未设置的字段使用 [`Config::new`] 的默认值
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    pub secret_id: Option<String>,
+    pub secret_key: Option<String>,
+    /// 临时密钥的安全令牌
+    pub security_token: Option<String>,
+    pub region: Option<String>,
+    pub bucket: Option<String>,
+    /// 自定义域名，格式为 `host[:port]`
+    pub domain: Option<String>,
+    /// 完整的服务地址，如 `http://127.0.0.1:9000`
+    pub endpoint: Option<String>,
+    pub use_https: Option<bool>,
+    pub path_style: Option<bool>,
+    /// 请求超时（秒）
+    pub timeout_secs: Option<u64>,
+}
+
+impl ConfigFile {
+    /// 默认的配置文件路径 `~/.cos/config.toml`，无法确定用户主目录时为空
+    pub fn default_path() -> Option<PathBuf> {
+        let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+        Some(PathBuf::from(home).join(".cos").join("config.toml"))
+    }
+
+    /// 读取并解析配置文件，扩展名为 `.json` 时按 JSON 解析，否则按 TOML 解析
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| CosError::config(format!("Failed to read config file {}: {}", path.display(), e)))?;
+        warn_if_readable_by_others(path);
+        Self::parse(&text, is_json(path))
+            .map_err(|e| CosError::config(format!("Failed to parse config file {}: {}", path.display(), e)))
+    }
+
+    fn parse(text: &str, json: bool) -> std::result::Result<Self, String> {
+        if json {
+            serde_json::from_str(text).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(text).map_err(|e| e.to_string())
+        }
+    }
+
+    /// 按 profile 与环境变量创建配置，`env` 返回环境变量的值
+    pub(crate) fn to_config(
+        &self,
+        path: &Path,
+        profile_name: &str,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config> {
+        let profile = self.profiles.get(profile_name).ok_or_else(|| {
+            let mut names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            CosError::config(format!(
+                "Profile {:?} not found in {} (available: {})",
+                profile_name,
+                path.display(),
+                if names.is_empty() { "none".to_string() } else { names.join(", ") }
+            ))
+        })?;
+        let file_source = Source::File {
+            path: path.to_path_buf(),
+            profile: profile_name.to_string(),
+        };
+
+        // 每个字段的取值与来源，环境变量优先
+        let mut values = HashMap::new();
+        for (field, var) in ENV_OVERRIDES {
+            let from_file = match field {
+                "secret_id" => &profile.secret_id,
+                "secret_key" => &profile.secret_key,
+                "security_token" => &profile.security_token,
+                "region" => &profile.region,
+                "bucket" => &profile.bucket,
+                "domain" => &profile.domain,
+                _ => &profile.endpoint,
+            };
+            if let Some(value) = env(var).filter(|value| !value.is_empty()) {
+                values.insert(field, (value, Source::Env(var)));
+            } else if let Some(value) = from_file {
+                values.insert(field, (value.clone(), file_source.clone()));
+            }
+        }
+
+        // 配置无效时在错误中说明每个值的来源
+        let sources: Vec<String> = ENV_OVERRIDES
+            .iter()
+            .filter_map(|(field, _)| values.get(field).map(|(_, source)| format!("{} from {}", field, source)))
+            .collect();
+        let value = |field: &str| values.get(field).map(|(value, _)| value.clone());
+        let required = |field: &'static str, var: &str| {
+            value(field).ok_or_else(|| {
+                CosError::config(format!(
+                    "Missing {}: set `{}` in {} or the {} environment variable",
+                    field, field, file_source, var
+                ))
+            })
+        };
+        let secret_id = required("secret_id", "COS_SECRET_ID")?;
+        let secret_key = required("secret_key", "COS_SECRET_KEY")?;
+        let region = required("region", "COS_REGION")?;
+        let bucket = required("bucket", "COS_BUCKET")?;
+
+        let mut config = Config::new(secret_id, secret_key, region, bucket);
+        config.security_token = value("security_token");
+        config.domain = value("domain");
+        config.endpoint = value("endpoint");
+        if let Some(use_https) = profile.use_https {
+            config.use_https = use_https;
+        }
+        if let Some(path_style) = profile.path_style {
+            config.path_style = path_style;
+        }
+        if let Some(timeout) = profile.timeout_secs {
+            config.timeout = Duration::from_secs(timeout);
+        }
+
+        config
+            .validate()
+            .map_err(|e| CosError::config(format!("{} ({})", e, sources.join(", "))))?;
+        Ok(config)
+    }
+}
+
+/// 配置值的来源
+#[derive(Debug, Clone)]
+enum Source {
+    Env(&'static str),
+    File { path: PathBuf, profile: String },
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Env(var) => write!(f, "environment variable {}", var),
+            Source::File { path, profile } => write!(f, "profile {:?} of {}", profile, path.display()),
+        }
+    }
+}
+
+impl Config {
+    /// 从配置文件的指定 profile 加载配置，环境变量优先于文件中的值
+    ///
+    /// 可以覆盖的环境变量为 `COS_SECRET_ID`、`COS_SECRET_KEY`、`COS_SECURITY_TOKEN`、`COS_REGION`、
+    /// `COS_BUCKET`、`COS_DOMAIN` 与 `COS_ENDPOINT`，取值为空时视为未设置。
+    /// 缺少必需的值或配置无效时返回配置错误，错误信息中包含各个值的来源。
+    ///
+    /// ```no_run
+    /// use cos_rust_sdk::profile::ConfigFile;
+    /// use cos_rust_sdk::{Config, CosClient};
+    ///
+    /// # fn main() -> cos_rust_sdk::Result<()> {
+    /// let path = ConfigFile::default_path().expect("no home directory");
+    /// let client = CosClient::new(Config::from_file(path, "default")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P, profile_name: &str) -> Result<Self> {
+        let path = path.as_ref();
+        ConfigFile::load(path)?.to_config(path, profile_name, |var| std::env::var(var).ok())
+    }
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// 文件权限允许属主以外的用户访问时记录警告
+#[cfg(unix)]
+fn warn_if_readable_by_others(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Ok(metadata) = std::fs::metadata(path) {
+        let mode = metadata.permissions().mode() & 0o777;
+        if is_too_open(mode) {
+            log::warn!(
+                "Config file {} holds secrets but has permissions {:o}; restrict it with chmod 600",
+                path.display(),
+                mode
+            );
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn warn_if_readable_by_others(_path: &Path) {}
+
+#[cfg_attr(not(unix), allow(dead_code))]
+fn is_too_open(mode: u32) -> bool {
+    mode & 0o077 != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"
+[profiles.default]
+secret_id = "file_id"
+secret_key = "file_key"
+region = "ap-beijing"
+bucket = "examplebucket-1250000000"
+timeout_secs = 60
+
+[profiles.local]
+secret_id = "minio"
+secret_key = "minio123"
+region = "ap-beijing"
+bucket = "test-1250000000"
+endpoint = "http://127.0.0.1:9000"
+path_style = true
+"#;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_load_profiles() {
+        let dir = std::env::temp_dir();
+        let toml_path = dir.join("cos-rust-sdk-profile-test.toml");
+        std::fs::write(&toml_path, TOML).unwrap();
+        let file = ConfigFile::load(&toml_path).unwrap();
+        assert_eq!(file.profiles.len(), 2);
+
+        let json_path = dir.join("cos-rust-sdk-profile-test.json");
+        std::fs::write(&json_path, serde_json::to_string(&file).unwrap()).unwrap();
+        assert_eq!(ConfigFile::load(&json_path).unwrap(), file);
+
+        let config = file.to_config(&toml_path, "default", no_env).unwrap();
+        assert_eq!(config.secret_id, "file_id");
+        assert_eq!(config.timeout, Duration::from_secs(60));
+        let config = file.to_config(&toml_path, "local", no_env).unwrap();
+        assert!(config.path_style);
+        assert_eq!(config.bucket_url().unwrap(), "http://127.0.0.1:9000/test-1250000000");
+
+        std::fs::write(&toml_path, "[profiles.default]\nsecret = \"typo\"\n").unwrap();
+        assert!(ConfigFile::load(&toml_path).is_err());
+        std::fs::remove_file(&toml_path).unwrap();
+        std::fs::remove_file(&json_path).unwrap();
+    }
+
+    #[test]
+    fn test_env_overrides_and_sources() {
+        let path = Path::new("/etc/cos/config.toml");
+        let file = ConfigFile::parse(TOML, false).unwrap();
+        let env = |var: &str| match var {
+            "COS_SECRET_ID" => Some("env_id".to_string()),
+            "COS_SECURITY_TOKEN" => Some("env_token".to_string()),
+            "COS_REGION" => Some(String::new()),
+            _ => None,
+        };
+        let config = file.to_config(path, "default", env).unwrap();
+        assert_eq!(config.secret_id, "env_id");
+        assert_eq!(config.secret_key, "file_key");
+        assert_eq!(config.security_token.as_deref(), Some("env_token"));
+        assert_eq!(config.region, "ap-beijing");
+
+        let err = file.to_config(path, "staging", no_env).unwrap_err().to_string();
+        assert!(err.contains("available: default, local"), "{}", err);
+
+        let mut file = file;
+        file.profiles.get_mut("default").unwrap().region = None;
+        let err = file.to_config(path, "default", no_env).unwrap_err().to_string();
+        assert!(err.contains("Missing region") && err.contains("COS_REGION"), "{}", err);
+
+        let env = |var: &str| (var == "COS_DOMAIN").then(|| "https://cdn.example.com".to_string());
+        let err = file.to_config(path, "local", env).unwrap_err().to_string();
+        assert!(err.contains("domain from environment variable COS_DOMAIN"), "{}", err);
+        assert!(err.contains("endpoint from profile \"local\" of /etc/cos/config.toml"), "{}", err);
+    }
+
+    #[test]
+    fn test_permission_check() {
+        assert!(!is_too_open(0o600));
+        assert!(!is_too_open(0o400));
+        assert!(is_too_open(0o644));
+        assert!(is_too_open(0o660));
+    }
+}
@@ -0,0 +1,125 @@
+//! 字节范围模块
+//!
+//! 提供 HTTP `Range` 请求头与 `Content-Range` 响应头的构造与解析
+
+use std::fmt;
+use std::ops::{Range, RangeFrom, RangeInclusive};
+
+/// 下载时请求的字节范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// 闭区间 `[start, end]`
+    Inclusive { start: u64, end: u64 },
+    /// 从 `start` 到对象末尾
+    From { start: u64 },
+    /// 对象末尾的最后 N 个字节
+    Suffix { length: u64 },
+}
+
+impl ByteRange {
+    /// 对象末尾的最后 `length` 个字节
+    pub fn last(length: u64) -> Self {
+        ByteRange::Suffix { length }
+    }
+
+    /// 生成 `Range` 请求头的值
+    pub fn header_value(&self) -> String {
+        format!("bytes={}", self)
+    }
+}
+
+impl fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ByteRange::Inclusive { start, end } => write!(f, "{}-{}", start, end),
+            ByteRange::From { start } => write!(f, "{}-", start),
+            ByteRange::Suffix { length } => write!(f, "-{}", length),
+        }
+    }
+}
+
+impl From<RangeInclusive<u64>> for ByteRange {
+    fn from(range: RangeInclusive<u64>) -> Self {
+        ByteRange::Inclusive {
+            start: *range.start(),
+            end: *range.end(),
+        }
+    }
+}
+
+impl From<Range<u64>> for ByteRange {
+    /// 半开区间会转换为闭区间，空区间转换为 `start..=start`
+    fn from(range: Range<u64>) -> Self {
+        ByteRange::Inclusive {
+            start: range.start,
+            end: range.end.saturating_sub(1).max(range.start),
+        }
+    }
+}
+
+impl From<RangeFrom<u64>> for ByteRange {
+    fn from(range: RangeFrom<u64>) -> Self {
+        ByteRange::From { start: range.start }
+    }
+}
+
+/// 响应中的 `Content-Range`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    /// 本次返回数据的起始位置，范围无法满足时为空
+    pub range: Option<(u64, u64)>,
+    /// 对象总大小，服务端返回 `*` 时为空
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// 解析 `bytes 0-99/1000`、`bytes */1000` 形式的响应头
+    pub fn parse(value: &str) -> Option<Self> {
+        let rest = value.trim().strip_prefix("bytes")?.trim_start();
+        let (range, total) = rest.split_once('/')?;
+
+        let total = match total.trim() {
+            "*" => None,
+            t => Some(t.parse().ok()?),
+        };
+        let range = match range.trim() {
+            "*" => None,
+            r => {
+                let (start, end) = r.split_once('-')?;
+                Some((start.trim().parse().ok()?, end.trim().parse().ok()?))
+            }
+        };
+
+        Some(Self { range, total })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_range_header_value() {
+        assert_eq!(ByteRange::from(0..=99).header_value(), "bytes=0-99");
+        assert_eq!(ByteRange::from(0..100).header_value(), "bytes=0-99");
+        assert_eq!(ByteRange::from(500..).header_value(), "bytes=500-");
+        assert_eq!(ByteRange::last(8).header_value(), "bytes=-8");
+    }
+
+    #[test]
+    fn test_parse_content_range() {
+        assert_eq!(
+            ContentRange::parse("bytes 0-99/1000"),
+            Some(ContentRange { range: Some((0, 99)), total: Some(1000) })
+        );
+        assert_eq!(
+            ContentRange::parse("bytes */1000"),
+            Some(ContentRange { range: None, total: Some(1000) })
+        );
+        assert_eq!(
+            ContentRange::parse("bytes 10-19/*"),
+            Some(ContentRange { range: Some((10, 19)), total: None })
+        );
+        assert_eq!(ContentRange::parse("items 0-1/2"), None);
+    }
+}
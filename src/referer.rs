@@ -0,0 +1,172 @@
+//! 防盗链模块
+//!
+//! 提供存储桶 Referer 黑白名单配置的读写接口
+//!
+//! 参考文档：<https://cloud.tencent.com/document/product/436/32492>
+
+use crate::bucket::BucketClient;
+use crate::error::{CosError, Result};
+use crate::object::content_md5;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 防盗链配置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "RefererConfiguration")]
+pub struct RefererConfiguration {
+    #[serde(rename = "Status", default)]
+    pub status: RefererStatus,
+    #[serde(rename = "RefererType", default)]
+    pub referer_type: RefererType,
+    /// 名单中的域名，支持 `*.example.com` 形式的通配
+    #[serde(rename = "DomainList", default)]
+    pub domain_list: DomainList,
+    /// 是否允许 Referer 为空的请求访问
+    #[serde(rename = "EmptyReferConfiguration", default)]
+    pub empty_referer: EmptyReferer,
+}
+
+impl RefererConfiguration {
+    /// 创建启用的白名单配置，默认允许 Referer 为空的请求
+    pub fn white_list<I, S>(domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            status: RefererStatus::Enabled,
+            referer_type: RefererType::WhiteList,
+            domain_list: DomainList::new(domains),
+            empty_referer: EmptyReferer::Allow,
+        }
+    }
+
+    /// 创建启用的黑名单配置，默认允许 Referer 为空的请求
+    pub fn black_list<I, S>(domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            referer_type: RefererType::BlackList,
+            ..Self::white_list(domains)
+        }
+    }
+
+    /// 设置是否允许 Referer 为空的请求访问
+    pub fn with_empty_referer(mut self, empty_referer: EmptyReferer) -> Self {
+        self.empty_referer = empty_referer;
+        self
+    }
+}
+
+/// 防盗链状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RefererStatus {
+    Enabled,
+    #[default]
+    Disabled,
+}
+
+/// 名单类型
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RefererType {
+    /// 名单中的域名不能访问
+    #[serde(rename = "Black-List")]
+    BlackList,
+    /// 只有名单中的域名可以访问
+    #[default]
+    #[serde(rename = "White-List")]
+    WhiteList,
+}
+
+/// 空 Referer 的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmptyReferer {
+    #[default]
+    Allow,
+    Deny,
+}
+
+/// 域名列表，对应 `<DomainList><Domain>` 结构
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainList {
+    #[serde(rename = "Domain", default)]
+    pub domains: Vec<String>,
+}
+
+impl DomainList {
+    /// 由域名列表创建
+    pub fn new<I, S>(domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            domains: domains.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl BucketClient {
+    /// 设置存储桶防盗链配置，会覆盖已有的配置
+    pub async fn put_bucket_referer(&self, config: &RefererConfiguration) -> Result<()> {
+        if config.status == RefererStatus::Enabled && config.domain_list.domains.is_empty() {
+            return Err(CosError::config("Referer domain list cannot be empty when enabled"));
+        }
+        let xml_body = quick_xml::se::to_string(config)
+            .map_err(|e| CosError::other(format!("Failed to serialize referer configuration: {}", e)))?;
+        let mut headers = HashMap::new();
+        headers.insert("Content-MD5".to_string(), content_md5(xml_body.as_bytes()));
+
+        self.client.put_with_headers("/", referer_params(), headers, Some(xml_body)).await?;
+        Ok(())
+    }
+
+    /// 获取存储桶防盗链配置，未设置时返回关闭状态的配置
+    pub async fn get_bucket_referer(&self) -> Result<RefererConfiguration> {
+        let response = self.client.get("/", referer_params()).await?;
+        let response_text = response.text().await?;
+        if response_text.trim().is_empty() {
+            return Ok(RefererConfiguration::default());
+        }
+
+        quick_xml::de::from_str(&response_text)
+            .map_err(|e| CosError::other(format!("Failed to parse referer configuration: {}", e)))
+    }
+}
+
+/// 防盗链子资源的查询参数
+fn referer_params() -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("referer".to_string(), "".to_string());
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_referer_xml_round_trip() {
+        let config = RefererConfiguration::white_list(["*.example.com", "example.com"])
+            .with_empty_referer(EmptyReferer::Deny);
+        let xml = quick_xml::se::to_string(&config).unwrap();
+        assert_eq!(
+            xml,
+            "<RefererConfiguration><Status>Enabled</Status><RefererType>White-List</RefererType>\
+             <DomainList><Domain>*.example.com</Domain><Domain>example.com</Domain></DomainList>\
+             <EmptyReferConfiguration>Deny</EmptyReferConfiguration></RefererConfiguration>"
+        );
+        let parsed: RefererConfiguration = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, config);
+
+        let parsed: RefererConfiguration = quick_xml::de::from_str(
+            "<RefererConfiguration><Status>Disabled</Status><RefererType>Black-List</RefererType></RefererConfiguration>",
+        )
+        .unwrap();
+        assert_eq!(parsed.referer_type, RefererType::BlackList);
+        assert!(parsed.domain_list.domains.is_empty());
+        assert_eq!(parsed.empty_referer, EmptyReferer::Allow);
+    }
+}
@@ -0,0 +1,196 @@
+//! 响应模块
+
+use crate::error::{CosError, Result};
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+/// COS 返回的响应
+///
+/// 状态码与响应头在收到响应时取出，响应体在需要时才读取：可以一次读入内存（[`bytes`](Self::bytes)、
+/// [`text`](Self::text)），也可以按流读取（[`bytes_stream`](Self::bytes_stream)）。
+/// 读入内存的响应体受 [`Config::max_response_body_size`](crate::Config::max_response_body_size) 限制。
+#[derive(Debug)]
+pub struct CosResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Body,
+    body_limit: Option<u64>,
+}
+
+#[derive(Debug)]
+enum Body {
+    /// 尚未读取
+    Pending(reqwest::Response),
+    /// 已读入内存
+    Buffered(Bytes),
+}
+
+impl CosResponse {
+    pub(crate) fn new(mut response: reqwest::Response, body_limit: Option<u64>) -> Self {
+        Self {
+            status: response.status(),
+            headers: std::mem::take(response.headers_mut()),
+            body: Body::Pending(response),
+            body_limit,
+        }
+    }
+
+    /// HTTP 状态码
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// 响应头
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    pub fn request_id(&self) -> Option<String> {
+        crate::object::request_id(&self.headers)
+    }
+
+    /// 响应头中的 `Content-Length`
+    pub fn content_length(&self) -> Option<u64> {
+        self.headers
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+    }
+
+    /// 读取完整的响应体，超过响应体大小限制时返回 `ResponseTooLarge` 错误
+    pub async fn bytes(mut self) -> Result<Bytes> {
+        self.buffer().await
+    }
+
+    /// 按 UTF-8 读取完整的响应体，限制同 [`bytes`](Self::bytes)
+    pub async fn text(mut self) -> Result<String> {
+        let body = self.buffer().await?;
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// 按流读取响应体，不受响应体大小限制
+    pub fn bytes_stream(self) -> BoxStream<'static, Result<Bytes>> {
+        match self.body {
+            Body::Pending(response) => response.bytes_stream().map_err(CosError::from).boxed(),
+            Body::Buffered(body) => stream::once(async move { Ok(body) }).boxed(),
+        }
+    }
+
+    /// 将响应体读入内存并保留在响应中，之后仍可以读取响应体
+    pub(crate) async fn buffer(&mut self) -> Result<Bytes> {
+        let data = match &mut self.body {
+            Body::Buffered(data) => return Ok(data.clone()),
+            Body::Pending(response) => read_limited(response, self.status, &self.headers, self.body_limit).await?,
+        };
+        self.body = Body::Buffered(data.clone());
+        Ok(data)
+    }
+}
+
+/// 读取响应体，超过 `limit` 时停止读取并返回错误
+async fn read_limited(
+    response: &mut reqwest::Response,
+    status: StatusCode,
+    headers: &HeaderMap,
+    limit: Option<u64>,
+) -> Result<Bytes> {
+    let too_large = |size: String, limit: u64| CosError::Client {
+        code: "ResponseTooLarge".to_string(),
+        message: format!("Response body of {} exceeds the limit of {} bytes", size, limit),
+        status: Some(status.as_u16()),
+        request_id: crate::object::request_id(headers),
+        details: None,
+    };
+    if let (Some(limit), Some(length)) = (limit, response.content_length()) {
+        if length > limit {
+            return Err(too_large(format!("{} bytes", length), limit));
+        }
+    }
+
+    let mut body = BytesMut::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?
+    {
+        if let Some(limit) = limit.filter(|&limit| (body.len() + chunk.len()) as u64 > limit) {
+            return Err(too_large("more than the limit".to_string(), limit));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::object::{GetObjectOptions, ObjectClient};
+    use crate::testing::{MockResponse, MockTransport};
+
+    fn client(transport: &MockTransport) -> ObjectClient {
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        ObjectClient::new(transport.client(config).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_response_statuses() {
+        let transport = MockTransport::new();
+        transport
+            .push_response(MockResponse::new(200, "hello").with_header("x-cos-request-id", "req-200"))
+            .push_response(
+                MockResponse::new(206, "ell")
+                    .with_header("Content-Range", "bytes 1-3/5")
+                    .with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""),
+            )
+            .push_response(MockResponse::new(304, ""))
+            .push_response(MockResponse::error(404, "NoSuchKey", "The specified key does not exist."))
+            .push_response(MockResponse::new(404, ""))
+            .push_response(MockResponse::error(500, "InternalError", "We encountered an internal error."));
+        let client = client(&transport);
+
+        let response = client.get_object("a.txt").await.unwrap();
+        assert_eq!(&response.data[..], b"hello");
+        assert_eq!(response.request_id.as_deref(), Some("req-200"));
+
+        let response = client.get_object_range("a.txt", 1..=3).await.unwrap();
+        assert_eq!(&response.data[..], b"ell");
+        assert_eq!(response.content_range.map(|r| r.total), Some(Some(5)));
+
+        let options = GetObjectOptions {
+            if_none_match: Some("\"etag\"".to_string()),
+            ..Default::default()
+        };
+        let err = client.get_object_with_options("a.txt", options).await.unwrap_err();
+        assert!(matches!(err, CosError::NotModified), "{:?}", err);
+
+        let err = client.get_object("a.txt").await.unwrap_err();
+        assert_eq!(err.status(), Some(404));
+        assert!(matches!(&err, CosError::Client { code, .. } if code == "NoSuchKey"), "{:?}", err);
+
+        assert!(!client.object_exists("a.txt").await.unwrap());
+
+        let err = client.get_object("a.txt").await.unwrap_err();
+        assert_eq!(err.status(), Some(500));
+        assert!(matches!(&err, CosError::Server { code, .. } if code == "InternalError"), "{:?}", err);
+        assert_eq!(transport.pending_responses(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_buffered_body_can_be_read_again() {
+        let response = http::Response::builder()
+            .status(403)
+            .header("x-cos-request-id", "req-1")
+            .body("denied")
+            .unwrap();
+        let mut response = CosResponse::new(response.into(), None);
+
+        assert_eq!(response.buffer().await.unwrap(), "denied");
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(response.request_id().as_deref(), Some("req-1"));
+        assert_eq!(response.text().await.unwrap(), "denied");
+    }
+}
@@ -0,0 +1,163 @@
+//! 归档恢复模块
+//!
+//! 提供归档存储与深度归档存储对象的恢复接口，以及恢复状态的解析
+
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use crate::object::{request_id, ObjectClient};
+use chrono::{DateTime, Utc};
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+impl ObjectClient {
+    /// 恢复归档对象
+    ///
+    /// 恢复出的临时副本保留 `days` 天；对象已在恢复中时返回
+    /// [`CosError::RestoreAlreadyInProgress`]，调用方可通过 `head_object` 轮询恢复状态。
+    pub async fn restore_object(&self, key: &str, days: u32, tier: RestoreTier) -> Result<()> {
+        let request = RestoreRequest {
+            days,
+            job_parameters: JobParameters { tier },
+        };
+        let xml_body = quick_xml::se::to_string(&request)
+            .map_err(|e| CosError::other(format!("Failed to serialize restore request: {}", e)))?;
+
+        let mut params = HashMap::new();
+        params.insert("restore".to_string(), "".to_string());
+
+        let response = self
+            .client
+            .request_raw(Method::POST, &self.object_path(key)?, params, HashMap::new(), Some(xml_body))
+            .await?;
+
+        if response.status() == StatusCode::CONFLICT {
+            let request_id = request_id(response.headers());
+            let body = response.text().await?;
+            if body.contains("RestoreAlreadyInProgress") {
+                return Err(CosError::RestoreAlreadyInProgress { key: key.to_string() });
+            }
+            return Err(CosError::from_response(StatusCode::CONFLICT, body, request_id));
+        }
+
+        CosClient::error_for_status(response).await?;
+        Ok(())
+    }
+}
+
+/// 恢复模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreTier {
+    /// 极速模式，深度归档不支持
+    Expedited,
+    /// 标准模式
+    Standard,
+    /// 批量模式
+    Bulk,
+}
+
+impl fmt::Display for RestoreTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            RestoreTier::Expedited => "Expedited",
+            RestoreTier::Standard => "Standard",
+            RestoreTier::Bulk => "Bulk",
+        };
+        f.write_str(s)
+    }
+}
+
+impl Serialize for RestoreTier {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// 恢复请求
+#[derive(Debug, Serialize)]
+#[serde(rename = "RestoreRequest")]
+struct RestoreRequest {
+    #[serde(rename = "Days")]
+    days: u32,
+    #[serde(rename = "CASJobParameters")]
+    job_parameters: JobParameters,
+}
+
+#[derive(Debug, Serialize)]
+struct JobParameters {
+    #[serde(rename = "Tier")]
+    tier: RestoreTier,
+}
+
+/// 归档对象的恢复状态，来自 `x-cos-restore` 响应头
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreStatus {
+    /// 是否仍在恢复中
+    pub ongoing: bool,
+    /// 恢复完成后临时副本的过期时间
+    pub expiry_date: Option<DateTime<Utc>>,
+}
+
+impl RestoreStatus {
+    /// 解析 `ongoing-request="false", expiry-date="Wed, 12 Aug 2026 00:00:00 GMT"` 形式的响应头
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut ongoing = None;
+        let mut expiry_date = None;
+
+        for item in value.split("\",") {
+            let (name, value) = item.split_once('=')?;
+            let value = value.trim().trim_matches('"');
+            match name.trim() {
+                "ongoing-request" => ongoing = Some(value.eq_ignore_ascii_case("true")),
+                "expiry-date" => {
+                    expiry_date = DateTime::parse_from_rfc2822(value)
+                        .ok()
+                        .map(|t| t.with_timezone(&Utc))
+                }
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            ongoing: ongoing?,
+            expiry_date,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_request_xml() {
+        let request = RestoreRequest {
+            days: 3,
+            job_parameters: JobParameters {
+                tier: RestoreTier::Bulk,
+            },
+        };
+        assert_eq!(
+            quick_xml::se::to_string(&request).unwrap(),
+            "<RestoreRequest><Days>3</Days><CASJobParameters><Tier>Bulk</Tier></CASJobParameters></RestoreRequest>"
+        );
+    }
+
+    #[test]
+    fn test_parse_restore_status() {
+        assert_eq!(
+            RestoreStatus::parse("ongoing-request=\"true\""),
+            Some(RestoreStatus { ongoing: true, expiry_date: None })
+        );
+
+        let status = RestoreStatus::parse(
+            "ongoing-request=\"false\", expiry-date=\"Wed, 12 Aug 2026 00:00:00 GMT\"",
+        )
+        .unwrap();
+        assert!(!status.ongoing);
+        assert_eq!(status.expiry_date.unwrap().to_rfc3339(), "2026-08-12T00:00:00+00:00");
+
+        assert_eq!(RestoreStatus::parse("garbage"), None);
+    }
+}
@@ -0,0 +1,476 @@
+//! 断点续传下载模块
+//!
+//! 按范围分块下载大对象，在检查点文件中记录已完成的分块，中断后可从上次的进度继续
+
+use crate::crc64::{self, Crc64};
+use crate::error::{CosError, Result};
+use crate::object::{insert_traffic_limit, ObjectClient};
+use crate::retry::RetryConfig;
+use crate::transfer::{join_part, split_traffic_limit, DEFAULT_PART_TIMEOUT};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::task::JoinSet;
+
+/// 断点续传下载选项
+#[derive(Debug, Clone)]
+pub struct ResumableDownloadOptions {
+    /// 每个范围请求的字节数，未指定时使用 [`Config::part_size`]；与检查点中记录的不一致时重新下载
+    ///
+    /// [`Config::part_size`]: crate::config::Config::part_size
+    pub part_size: Option<u64>,
+    /// 同时下载的范围数量，未指定时使用 [`Config::max_upload_concurrency`]
+    ///
+    /// [`Config::max_upload_concurrency`]: crate::config::Config::max_upload_concurrency
+    pub concurrency: Option<usize>,
+    /// 检查点文件路径，默认为 `<目标文件>.cpt`
+    pub checkpoint_path: Option<PathBuf>,
+    /// 服务端提供 CRC64 时校验下载的文件
+    pub verify_crc64: bool,
+    /// 所有并发范围请求的总限速（bit/s），会平均分配给每个请求
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 单个范围请求的超时，未指定时为 [`DEFAULT_PART_TIMEOUT`]
+    pub part_timeout: Option<Duration>,
+    /// 本次下载所有请求的重试策略，未指定时使用 [`Config::retry`]
+    ///
+    /// [`Config::retry`]: crate::config::Config::retry
+    pub retry: Option<RetryConfig>,
+}
+
+impl Default for ResumableDownloadOptions {
+    fn default() -> Self {
+        Self {
+            part_size: None,
+            concurrency: None,
+            checkpoint_path: None,
+            verify_crc64: true,
+            traffic_limit_bits_per_sec: None,
+            part_timeout: None,
+            retry: None,
+        }
+    }
+}
+
+/// 断点续传下载结果
+#[derive(Debug, Clone)]
+pub struct ResumableDownloadResponse {
+    pub etag: String,
+    /// 对象大小
+    pub size: u64,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    /// 从检查点恢复、本次无需重新下载的字节数
+    pub resumed_bytes: u64,
+}
+
+/// 检查点文件内容
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Checkpoint {
+    key: String,
+    etag: String,
+    size: u64,
+    part_size: u64,
+    /// 已下载的字节数
+    downloaded: u64,
+    /// 已完成的分块序号，从 0 开始
+    completed: BTreeSet<u64>,
+}
+
+impl Checkpoint {
+    fn new(key: &str, etag: &str, size: u64, part_size: u64) -> Self {
+        Self {
+            key: key.to_string(),
+            etag: etag.to_string(),
+            size,
+            part_size,
+            downloaded: 0,
+            completed: BTreeSet::new(),
+        }
+    }
+
+    /// 检查点是否对应同一个对象的同一种切分方式
+    fn matches(&self, key: &str, etag: &str, size: u64, part_size: u64) -> bool {
+        self.key == key && self.etag == etag && self.size == size && self.part_size == part_size
+    }
+
+    fn part_count(&self) -> u64 {
+        self.size.div_ceil(self.part_size)
+    }
+
+    /// 分块的起始位置与长度
+    fn part_range(&self, index: u64) -> (u64, u64) {
+        let offset = index * self.part_size;
+        (offset, self.part_size.min(self.size - offset))
+    }
+
+    fn complete(&mut self, index: u64) {
+        if self.completed.insert(index) {
+            self.downloaded += self.part_range(index).1;
+        }
+    }
+
+    async fn load(path: &Path) -> Option<Self> {
+        let content = tokio::fs::read_to_string(path).await.ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// 先写入临时文件再重命名，避免中断时留下不完整的检查点
+    async fn save(&self, path: &Path) -> Result<()> {
+        let temp_path = path_with_suffix(path, ".tmp");
+        tokio::fs::write(&temp_path, serde_json::to_vec(self)?)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to write checkpoint: {}", e)))?;
+        tokio::fs::rename(&temp_path, path)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to write checkpoint: {}", e)))
+    }
+}
+
+impl ObjectClient {
+    /// 断点续传下载对象到文件
+    ///
+    /// 数据写入预分配大小的 `<文件名>.part` 临时文件，每完成一个范围就更新检查点。
+    /// 再次调用时，远端 ETag 与大小未变则跳过已完成的范围，否则从头下载；
+    /// 全部完成后校验文件大小和 CRC64，再重命名为目标文件并删除检查点。
+    pub async fn download_file_resumable(
+        &self,
+        key: &str,
+        file_path: &Path,
+        options: ResumableDownloadOptions,
+    ) -> Result<ResumableDownloadResponse> {
+        self.with_retry(options.retry.as_ref())?
+            .download_file_resumable_with_retry(key, file_path, options)
+            .await
+    }
+
+    /// 断点续传下载，请求使用客户端当前的重试策略
+    async fn download_file_resumable_with_retry(
+        &self,
+        key: &str,
+        file_path: &Path,
+        options: ResumableDownloadOptions,
+    ) -> Result<ResumableDownloadResponse> {
+        let config = self.client.config();
+        let part_size = options.part_size.unwrap_or(config.part_size);
+        let concurrency = options.concurrency.unwrap_or(config.max_upload_concurrency);
+        if part_size == 0 || concurrency == 0 {
+            return Err(CosError::config("Part size and concurrency must be greater than zero"));
+        }
+        let (traffic_limit, concurrency) = split_traffic_limit(options.traffic_limit_bits_per_sec, concurrency)?;
+
+        let checkpoint_path = options
+            .checkpoint_path
+            .clone()
+            .unwrap_or_else(|| path_with_suffix(file_path, ".cpt"));
+        let temp_path = path_with_suffix(file_path, ".part");
+
+        let head = self.head_object(key).await?;
+        let size = head.content_length;
+        let temp_len = tokio::fs::metadata(&temp_path).await.map(|m| m.len()).ok();
+
+        let mut checkpoint = match Checkpoint::load(&checkpoint_path).await {
+            Some(checkpoint)
+                if checkpoint.matches(key, &head.etag, size, part_size) && temp_len == Some(size) =>
+            {
+                checkpoint
+            }
+            Some(_) => {
+                log::info!("Checkpoint for {} is stale, restarting download", key);
+                Checkpoint::new(key, &head.etag, size, part_size)
+            }
+            None => Checkpoint::new(key, &head.etag, size, part_size),
+        };
+        let resumed_bytes = checkpoint.downloaded;
+
+        if checkpoint.completed.is_empty() {
+            let file = File::create(&temp_path)
+                .await
+                .map_err(|e| CosError::other(format!("Failed to create file: {}", e)))?;
+            file.set_len(size)
+                .await
+                .map_err(|e| CosError::other(format!("Failed to allocate file: {}", e)))?;
+            checkpoint.save(&checkpoint_path).await?;
+        }
+
+        let pending: Vec<u64> = (0..checkpoint.part_count())
+            .filter(|index| !checkpoint.completed.contains(index))
+            .collect();
+        // 范围请求使用独立于 Config::timeout 的超时
+        let mut part_client = self.clone();
+        part_client.client = self
+            .client
+            .with_timeout(Some(options.part_timeout.unwrap_or(DEFAULT_PART_TIMEOUT)));
+        let mut tasks = JoinSet::new();
+        for index in pending {
+            if tasks.len() >= concurrency {
+                checkpoint.complete(join_part(&mut tasks).await?);
+                checkpoint.save(&checkpoint_path).await?;
+            }
+
+            let (offset, length) = checkpoint.part_range(index);
+            let client = part_client.clone();
+            let key = key.to_string();
+            let etag = head.etag.clone();
+            let path = temp_path.clone();
+            tasks.spawn(async move {
+                let _permit = client.part_permit().await?;
+                client
+                    .download_range(&key, &etag, &path, offset, length, traffic_limit)
+                    .await?;
+                Ok(index)
+            });
+        }
+        while !tasks.is_empty() {
+            checkpoint.complete(join_part(&mut tasks).await?);
+            checkpoint.save(&checkpoint_path).await?;
+        }
+
+        if let Err(e) = verify_file(&temp_path, size, head.crc64.as_deref().filter(|_| options.verify_crc64)).await {
+            // 数据已损坏，从检查点继续也无法恢复
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            let _ = tokio::fs::remove_file(&checkpoint_path).await;
+            return Err(e);
+        }
+
+        tokio::fs::rename(&temp_path, file_path)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to rename file: {}", e)))?;
+        let _ = tokio::fs::remove_file(&checkpoint_path).await;
+
+        Ok(ResumableDownloadResponse {
+            etag: head.etag,
+            size,
+            crc64: head.crc64,
+            resumed_bytes,
+        })
+    }
+
+    /// 下载一个范围并写入文件的对应位置
+    ///
+    /// 请求带有 `If-Match`，对象在下载过程中被修改时返回 [`CosError::PreconditionFailed`]。
+    async fn download_range(
+        &self,
+        key: &str,
+        etag: &str,
+        path: &Path,
+        offset: u64,
+        length: u64,
+        traffic_limit: Option<u64>,
+    ) -> Result<()> {
+        let mut headers = HashMap::new();
+        headers.insert("Range".to_string(), format!("bytes={}-{}", offset, offset + length - 1));
+        headers.insert("If-Match".to_string(), etag.to_string());
+        insert_traffic_limit(&mut headers, traffic_limit)?;
+
+        let response = self
+            .client
+            .get_with_headers(&self.object_path(key)?, HashMap::new(), headers)
+            .await?;
+        let data = response.bytes().await?;
+        if data.len() as u64 != length {
+            return Err(CosError::other(format!(
+                "Incomplete range at offset {}: expected {} bytes, got {}",
+                offset,
+                length,
+                data.len()
+            )));
+        }
+
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(path)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to open file: {}", e)))?;
+        file.seek(SeekFrom::Start(offset))
+            .await
+            .map_err(|e| CosError::other(format!("Failed to seek file: {}", e)))?;
+        file.write_all(&data)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to write file: {}", e)))?;
+        // 写入落盘后才能记入检查点
+        file.sync_data()
+            .await
+            .map_err(|e| CosError::other(format!("Failed to sync file: {}", e)))
+    }
+}
+
+/// 校验下载完成的文件大小，并在给出远端 CRC64 时校验内容
+async fn verify_file(path: &Path, size: u64, remote_crc64: Option<&str>) -> Result<()> {
+    let mut file = File::open(path)
+        .await
+        .map_err(|e| CosError::other(format!("Failed to open file: {}", e)))?;
+    let actual = file
+        .metadata()
+        .await
+        .map_err(|e| CosError::other(format!("Failed to read file metadata: {}", e)))?
+        .len();
+    if actual != size {
+        return Err(CosError::other(format!(
+            "Incomplete download: expected {} bytes, got {}",
+            size, actual
+        )));
+    }
+
+    if remote_crc64.is_some() {
+        let mut crc = Crc64::new();
+        let mut buf = vec![0u8; 1024 * 1024];
+        loop {
+            let n = file
+                .read(&mut buf)
+                .await
+                .map_err(|e| CosError::other(format!("Failed to read file: {}", e)))?;
+            if n == 0 {
+                break;
+            }
+            crc.update(&buf[..n]);
+        }
+        crc64::verify(crc.finish(), remote_crc64)?;
+    }
+    Ok(())
+}
+
+fn path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    const DATA: &str = "0123456789";
+
+    fn head_response(etag: &str) -> ServedResponse {
+        ServedResponse::new(200, DATA)
+            .with_header("ETag", etag)
+            .with_header("x-cos-hash-crc64ecma", &crc64::checksum(DATA.as_bytes()).to_string())
+    }
+
+    fn options() -> ResumableDownloadOptions {
+        ResumableDownloadOptions {
+            part_size: Some(4),
+            concurrency: Some(1),
+            ..Default::default()
+        }
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path_with_suffix(&path, ".part"));
+        let _ = std::fs::remove_file(path_with_suffix(&path, ".cpt"));
+        path
+    }
+
+    #[tokio::test]
+    async fn test_download_file_resumable() {
+        let path = temp_file("cos-rust-sdk-resumable.bin");
+        let client = ObjectClient::new(
+            client_for(vec![
+                head_response("\"abc\""),
+                ServedResponse::new(206, "0123"),
+                ServedResponse::new(206, "4567"),
+                ServedResponse::new(206, "89"),
+            ])
+            .await,
+        );
+
+        let response = client
+            .download_file_resumable("big.bin", &path, options())
+            .await
+            .unwrap();
+        assert_eq!(response.size, 10);
+        assert_eq!(response.resumed_bytes, 0);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), DATA);
+        assert!(!path_with_suffix(&path, ".cpt").exists());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_resumes_from_checkpoint() {
+        let path = temp_file("cos-rust-sdk-resumable-resume.bin");
+        std::fs::write(path_with_suffix(&path, ".part"), b"0123\0\0\0\0\0\0").unwrap();
+        let mut checkpoint = Checkpoint::new("big.bin", "\"abc\"", 10, 4);
+        checkpoint.complete(0);
+        checkpoint.save(&path_with_suffix(&path, ".cpt")).await.unwrap();
+
+        // 只提供剩余两个范围的响应，多余的请求会连接失败
+        let client = ObjectClient::new(
+            client_for(vec![
+                head_response("\"abc\""),
+                ServedResponse::new(206, "4567"),
+                ServedResponse::new(206, "89"),
+            ])
+            .await,
+        );
+
+        let response = client
+            .download_file_resumable("big.bin", &path, options())
+            .await
+            .unwrap();
+        assert_eq!(response.resumed_bytes, 4);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), DATA);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_restarts_when_etag_changes() {
+        let path = temp_file("cos-rust-sdk-resumable-stale.bin");
+        std::fs::write(path_with_suffix(&path, ".part"), b"xxxx\0\0\0\0\0\0").unwrap();
+        let mut checkpoint = Checkpoint::new("big.bin", "\"old\"", 10, 4);
+        checkpoint.complete(0);
+        checkpoint.save(&path_with_suffix(&path, ".cpt")).await.unwrap();
+
+        let client = ObjectClient::new(
+            client_for(vec![
+                head_response("\"new\""),
+                ServedResponse::new(206, "0123"),
+                ServedResponse::new(206, "4567"),
+                ServedResponse::new(206, "89"),
+            ])
+            .await,
+        );
+
+        let response = client
+            .download_file_resumable("big.bin", &path, options())
+            .await
+            .unwrap();
+        assert_eq!(response.resumed_bytes, 0);
+        assert_eq!(response.etag, "\"new\"");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), DATA);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_keeps_checkpoint_on_failure() {
+        let path = temp_file("cos-rust-sdk-resumable-fail.bin");
+        let client = ObjectClient::new(
+            client_for(vec![
+                head_response("\"abc\""),
+                ServedResponse::new(206, "0123"),
+                ServedResponse::new(500, "InternalError"),
+            ])
+            .await,
+        );
+
+        assert!(client
+            .download_file_resumable("big.bin", &path, options())
+            .await
+            .is_err());
+        let checkpoint = Checkpoint::load(&path_with_suffix(&path, ".cpt")).await.unwrap();
+        assert_eq!(checkpoint.downloaded, 4);
+        assert_eq!(checkpoint.completed, BTreeSet::from([0]));
+
+        std::fs::remove_file(path_with_suffix(&path, ".part")).unwrap();
+        std::fs::remove_file(path_with_suffix(&path, ".cpt")).unwrap();
+    }
+}
@@ -0,0 +1,167 @@
+//! 重试策略
+//!
+//! [`RetryConfig`] 决定请求失败后是否重试、最多尝试几次以及每次重试前等待多久。
+//! 通过 [`Config::with_retry`](crate::Config::with_retry) 设置到客户端，未设置时不重试；
+//! 高级传输接口（如 [`UploadOptions::retry`](crate::UploadOptions::retry)）可以按调用覆盖。
+//!
+//! 只有可以重放的请求体（内存中的数据或没有请求体）会被重试，流式请求体只发送一次。
+
+use crate::error::{CosError, Result};
+use crate::response::CosResponse;
+use rand::Rng;
+use std::time::Duration;
+
+/// 重试策略
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// 最多尝试的次数（包括第一次请求），为 1 时不重试
+    pub max_attempts: u32,
+    /// 第一次重试前的等待时间，之后每次翻倍
+    pub initial_backoff: Duration,
+    /// 单次等待时间的上限
+    pub max_backoff: Duration,
+    /// 请求超时后是否重试；建立连接失败总是重试
+    pub retry_on_timeouts: bool,
+    /// 需要重试的 HTTP 状态码
+    pub retryable_status_codes: Vec<u16>,
+    /// 需要重试的 COS 错误码，如 `SlowDown`，不论响应的状态码
+    pub retryable_cos_error_codes: Vec<String>,
+}
+
+impl Default for RetryConfig {
+    /// 最多尝试 3 次，等待时间从 200ms 开始翻倍、不超过 5s，重试超时、5xx 与限流错误
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            retry_on_timeouts: true,
+            retryable_status_codes: vec![429, 500, 502, 503, 504],
+            retryable_cos_error_codes: ["SlowDown", "RequestTimeout", "InternalError", "ServiceUnavailable"]
+                .iter()
+                .map(|code| code.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// 不重试的策略
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            retry_on_timeouts: false,
+            retryable_status_codes: Vec::new(),
+            retryable_cos_error_codes: Vec::new(),
+            ..Self::default()
+        }
+    }
+
+    /// 设置最多尝试的次数
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// 设置第一次重试前的等待时间与单次等待时间的上限
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// 设置请求超时后是否重试
+    pub fn with_retry_on_timeouts(mut self, retry: bool) -> Self {
+        self.retry_on_timeouts = retry;
+        self
+    }
+
+    /// 设置需要重试的 HTTP 状态码
+    pub fn with_retryable_status_codes<I: IntoIterator<Item = u16>>(mut self, codes: I) -> Self {
+        self.retryable_status_codes = codes.into_iter().collect();
+        self
+    }
+
+    /// 设置需要重试的 COS 错误码
+    pub fn with_retryable_cos_error_codes<I, S>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.retryable_cos_error_codes = codes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 校验尝试次数与等待时间
+    pub fn validate(&self) -> Result<()> {
+        if self.max_attempts == 0 {
+            return Err(CosError::config("Retry max attempts must be at least 1"));
+        }
+        if self.initial_backoff > self.max_backoff {
+            return Err(CosError::config(format!(
+                "Initial retry backoff {:?} exceeds the max backoff {:?}",
+                self.initial_backoff, self.max_backoff
+            )));
+        }
+        Ok(())
+    }
+
+    /// 第 `attempt` 次尝试失败后的等待时间，在翻倍后的时间的一半到全部之间随机选取，避免并发请求同时重试
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+        let half = exponential / 2;
+        half + rand::thread_rng().gen_range(Duration::ZERO..=exponential - half)
+    }
+
+    /// 请求结果是否需要重试
+    ///
+    /// 配置了 COS 错误码时会把错误响应的响应体读入内存，之后仍可以读取。
+    pub(crate) async fn should_retry(&self, result: &mut Result<CosResponse>) -> bool {
+        let response = match result {
+            Ok(response) => response,
+            Err(CosError::Http(e)) => return e.is_connect() || (e.is_timeout() && self.retry_on_timeouts),
+            Err(_) => return false,
+        };
+        let status = response.status();
+        if self.retryable_status_codes.contains(&status.as_u16()) {
+            return true;
+        }
+        if !(status.is_client_error() || status.is_server_error()) || self.retryable_cos_error_codes.is_empty() {
+            return false;
+        }
+        let Ok(body) = response.buffer().await else {
+            return false;
+        };
+        let error = CosError::from_response(status, String::from_utf8_lossy(&body).into_owned(), None);
+        error
+            .code()
+            .is_some_and(|code| self.retryable_cos_error_codes.iter().any(|c| c == code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_config_validation_and_backoff() {
+        assert!(RetryConfig::default().validate().is_ok());
+        assert!(RetryConfig::disabled().validate().is_ok());
+        assert!(RetryConfig::default().with_max_attempts(0).validate().is_err());
+        let err = RetryConfig::default()
+            .with_backoff(Duration::from_secs(2), Duration::from_secs(1))
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("exceeds the max backoff"), "{}", err);
+
+        let config = RetryConfig::default().with_backoff(Duration::from_millis(100), Duration::from_millis(300));
+        for (attempt, max) in [(1, 100), (2, 200), (3, 300), (10, 300), (u32::MAX, 300)] {
+            let backoff = config.backoff(attempt);
+            assert!(backoff >= Duration::from_millis(max / 2), "{:?}", backoff);
+            assert!(backoff <= Duration::from_millis(max), "{:?}", backoff);
+        }
+    }
+}
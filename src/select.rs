@@ -0,0 +1,792 @@
+//! 对象查询模块
+//!
+//! 提供 SELECT Object Content 接口，使用 SQL 从 CSV 或 JSON 对象中检索数据，
+//! 并解析服务端返回的事件流（event stream）格式响应
+
+use crate::error::{CosError, Result};
+use crate::object::ObjectClient;
+use bytes::{Buf, Bytes, BytesMut};
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+impl ObjectClient {
+    /// 使用 SQL 查询对象内容
+    ///
+    /// 返回的数据流按服务端的 Records 事件逐块输出查询结果，读取完毕后可通过
+    /// [`SelectObjectStream::stats`] 获取扫描与返回的字节数。
+    pub async fn select_object_content(
+        &self,
+        key: &str,
+        request: SelectRequest,
+    ) -> Result<SelectObjectStream> {
+        let xml_body = quick_xml::se::to_string(&request)
+            .map_err(|e| CosError::other(format!("Failed to serialize select request: {}", e)))?;
+
+        let mut params = HashMap::new();
+        params.insert("select".to_string(), "".to_string());
+        params.insert("select-type".to_string(), "2".to_string());
+
+        let response = self
+            .client
+            .post(&self.object_path(key)?, params, Some(xml_body))
+            .await?;
+
+        Ok(SelectObjectStream::new(response.bytes_stream()))
+    }
+}
+
+/// 查询请求
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename = "SelectRequest")]
+pub struct SelectRequest {
+    /// SQL 表达式，如 `SELECT * FROM COSObject s LIMIT 10`
+    #[serde(rename = "Expression")]
+    pub expression: String,
+    #[serde(rename = "ExpressionType")]
+    expression_type: &'static str,
+    #[serde(rename = "InputSerialization")]
+    pub input: InputSerialization,
+    #[serde(rename = "OutputSerialization")]
+    pub output: OutputSerialization,
+    #[serde(rename = "RequestProgress")]
+    request_progress: RequestProgress,
+}
+
+impl SelectRequest {
+    /// 创建查询请求
+    pub fn new<S: Into<String>>(expression: S, input: InputSerialization, output: OutputSerialization) -> Self {
+        Self {
+            expression: expression.into(),
+            expression_type: "SQL",
+            input,
+            output,
+            request_progress: RequestProgress { enabled: false },
+        }
+    }
+
+    /// 是否让服务端定期返回查询进度
+    pub fn with_progress(mut self, enabled: bool) -> Self {
+        self.request_progress.enabled = enabled;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RequestProgress {
+    #[serde(rename = "Enabled", serialize_with = "serialize_upper_bool")]
+    enabled: bool,
+}
+
+/// 输入数据格式
+#[derive(Debug, Clone, Serialize)]
+pub struct InputSerialization {
+    #[serde(rename = "CompressionType")]
+    pub compression: CompressionType,
+    #[serde(rename = "CSV", skip_serializing_if = "Option::is_none")]
+    pub csv: Option<CsvInput>,
+    #[serde(rename = "JSON", skip_serializing_if = "Option::is_none")]
+    pub json: Option<JsonInput>,
+}
+
+impl InputSerialization {
+    /// CSV 格式的输入
+    pub fn csv(csv: CsvInput) -> Self {
+        Self {
+            compression: CompressionType::None,
+            csv: Some(csv),
+            json: None,
+        }
+    }
+
+    /// JSON 格式的输入
+    pub fn json(json: JsonInput) -> Self {
+        Self {
+            compression: CompressionType::None,
+            csv: None,
+            json: Some(json),
+        }
+    }
+
+    /// 指定对象的压缩格式
+    pub fn with_compression(mut self, compression: CompressionType) -> Self {
+        self.compression = compression;
+        self
+    }
+}
+
+/// 对象压缩格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompressionType {
+    #[default]
+    None,
+    Gzip,
+    Bzip2,
+}
+
+impl fmt::Display for CompressionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CompressionType::None => "NONE",
+            CompressionType::Gzip => "GZIP",
+            CompressionType::Bzip2 => "BZIP2",
+        };
+        f.write_str(s)
+    }
+}
+
+/// CSV 首行的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileHeaderInfo {
+    /// 首行不是表头
+    #[default]
+    None,
+    /// 首行是表头，但不能在 SQL 中按列名引用
+    Ignore,
+    /// 首行是表头，可以在 SQL 中按列名引用
+    Use,
+}
+
+impl fmt::Display for FileHeaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            FileHeaderInfo::None => "NONE",
+            FileHeaderInfo::Ignore => "IGNORE",
+            FileHeaderInfo::Use => "USE",
+        };
+        f.write_str(s)
+    }
+}
+
+/// CSV 输入格式
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CsvInput {
+    #[serde(rename = "FileHeaderInfo")]
+    pub file_header_info: FileHeaderInfo,
+    /// 记录分隔符，默认为 `\n`
+    #[serde(rename = "RecordDelimiter", skip_serializing_if = "Option::is_none")]
+    pub record_delimiter: Option<String>,
+    /// 字段分隔符，默认为 `,`
+    #[serde(rename = "FieldDelimiter", skip_serializing_if = "Option::is_none")]
+    pub field_delimiter: Option<String>,
+    #[serde(rename = "QuoteCharacter", skip_serializing_if = "Option::is_none")]
+    pub quote_character: Option<String>,
+    #[serde(rename = "QuoteEscapeCharacter", skip_serializing_if = "Option::is_none")]
+    pub quote_escape_character: Option<String>,
+    /// 以该字符开头的行会被忽略
+    #[serde(rename = "Comments", skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+    /// 引号内是否允许出现记录分隔符
+    #[serde(rename = "AllowQuotedRecordDelimiter", serialize_with = "serialize_upper_bool")]
+    pub allow_quoted_record_delimiter: bool,
+}
+
+/// JSON 对象的组织方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonType {
+    /// 整个对象是一个 JSON 文档
+    #[default]
+    Document,
+    /// 每行一个 JSON 对象
+    Lines,
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            JsonType::Document => "DOCUMENT",
+            JsonType::Lines => "LINES",
+        };
+        f.write_str(s)
+    }
+}
+
+/// JSON 输入格式
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JsonInput {
+    #[serde(rename = "Type")]
+    pub json_type: JsonType,
+}
+
+/// 输出数据格式
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputSerialization {
+    #[serde(rename = "CSV", skip_serializing_if = "Option::is_none")]
+    pub csv: Option<CsvOutput>,
+    #[serde(rename = "JSON", skip_serializing_if = "Option::is_none")]
+    pub json: Option<JsonOutput>,
+}
+
+impl OutputSerialization {
+    /// 以 CSV 格式输出
+    pub fn csv(csv: CsvOutput) -> Self {
+        Self { csv: Some(csv), json: None }
+    }
+
+    /// 以 JSON 格式输出
+    pub fn json(json: JsonOutput) -> Self {
+        Self { csv: None, json: Some(json) }
+    }
+}
+
+/// 输出字段的引号策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteFields {
+    /// 仅在需要时添加引号
+    #[default]
+    AsNeeded,
+    /// 所有字段都添加引号
+    Always,
+}
+
+impl fmt::Display for QuoteFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            QuoteFields::AsNeeded => "ASNEEDED",
+            QuoteFields::Always => "ALWAYS",
+        };
+        f.write_str(s)
+    }
+}
+
+/// CSV 输出格式
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CsvOutput {
+    #[serde(rename = "QuoteFields")]
+    pub quote_fields: QuoteFields,
+    #[serde(rename = "RecordDelimiter", skip_serializing_if = "Option::is_none")]
+    pub record_delimiter: Option<String>,
+    #[serde(rename = "FieldDelimiter", skip_serializing_if = "Option::is_none")]
+    pub field_delimiter: Option<String>,
+    #[serde(rename = "QuoteCharacter", skip_serializing_if = "Option::is_none")]
+    pub quote_character: Option<String>,
+    #[serde(rename = "QuoteEscapeCharacter", skip_serializing_if = "Option::is_none")]
+    pub quote_escape_character: Option<String>,
+}
+
+/// JSON 输出格式
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JsonOutput {
+    /// 记录分隔符，默认为 `\n`
+    #[serde(rename = "RecordDelimiter", skip_serializing_if = "Option::is_none")]
+    pub record_delimiter: Option<String>,
+}
+
+macro_rules! serialize_display {
+    ($($ty:ty),*) => {
+        $(impl Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        })*
+    };
+}
+
+serialize_display!(CompressionType, FileHeaderInfo, JsonType, QuoteFields);
+
+fn serialize_upper_bool<S: serde::Serializer>(value: &bool, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(if *value { "TRUE" } else { "FALSE" })
+}
+
+/// 查询统计信息，来自 Stats 或 Progress 事件
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct SelectStats {
+    #[serde(rename = "BytesScanned", default)]
+    pub bytes_scanned: u64,
+    #[serde(rename = "BytesProcessed", default)]
+    pub bytes_processed: u64,
+    #[serde(rename = "BytesReturned", default)]
+    pub bytes_returned: u64,
+}
+
+/// 查询结果数据流
+///
+/// 逐块输出 Records 事件中的数据；服务端返回错误事件或在 End 事件之前断开时输出错误。
+pub struct SelectObjectStream {
+    inner: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+    decoder: EventStreamDecoder,
+    stats: Option<SelectStats>,
+    progress: Option<SelectStats>,
+    finished: bool,
+}
+
+impl SelectObjectStream {
+    fn new(inner: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>) -> Self {
+        Self {
+            inner,
+            decoder: EventStreamDecoder::default(),
+            stats: None,
+            progress: None,
+            finished: false,
+        }
+    }
+
+    /// 查询结束时的统计信息，收到 Stats 事件之前为空
+    pub fn stats(&self) -> Option<SelectStats> {
+        self.stats
+    }
+
+    /// 最近一次 Progress 事件中的进度，需在请求中开启进度
+    pub fn progress(&self) -> Option<SelectStats> {
+        self.progress
+    }
+
+    /// 处理一条事件，返回其中的查询结果数据
+    fn handle(&mut self, message: Message) -> Result<Option<Bytes>> {
+        match message.header(":message-type") {
+            Some("event") => {}
+            Some("error") => {
+                return Err(CosError::server(
+                    message.header(":error-code").unwrap_or("SelectError"),
+                    message.header(":error-message").unwrap_or(""),
+                ));
+            }
+            other => {
+                return Err(CosError::other(format!(
+                    "Unexpected select message type: {}",
+                    other.unwrap_or("<none>")
+                )));
+            }
+        }
+
+        match message.header(":event-type") {
+            Some("Records") => return Ok(Some(message.payload)),
+            Some("Stats") => self.stats = Some(parse_stats(&message.payload)?),
+            Some("Progress") => self.progress = Some(parse_stats(&message.payload)?),
+            Some("End") => self.finished = true,
+            // Cont 为保活事件，其他未知事件直接忽略
+            _ => {}
+        }
+        Ok(None)
+    }
+}
+
+impl Stream for SelectObjectStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.finished {
+                return Poll::Ready(None);
+            }
+
+            match self.decoder.next_message() {
+                Ok(Some(message)) => match self.handle(message) {
+                    Ok(Some(records)) => return Poll::Ready(Some(Ok(records))),
+                    Ok(None) => continue,
+                    Err(e) => {
+                        self.finished = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                },
+                Ok(None) => {}
+                Err(e) => {
+                    self.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+
+            match self.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => self.decoder.push(&chunk),
+                Poll::Ready(Some(Err(e))) => {
+                    self.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => {
+                    self.finished = true;
+                    return Poll::Ready(Some(Err(CosError::other(
+                        "Select response ended before End event",
+                    ))));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SelectObjectStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectObjectStream")
+            .field("stats", &self.stats)
+            .field("progress", &self.progress)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+fn parse_stats(payload: &[u8]) -> Result<SelectStats> {
+    let text = std::str::from_utf8(payload)
+        .map_err(|e| CosError::other(format!("Invalid select stats: {}", e)))?;
+    quick_xml::de::from_str(text).map_err(|e| CosError::other(format!("Failed to parse select stats: {}", e)))
+}
+
+/// 事件流中的一条消息
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Message {
+    /// 字符串类型的消息头，其他类型的消息头会被跳过
+    headers: Vec<(String, String)>,
+    payload: Bytes,
+}
+
+impl Message {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// 12 字节的前导：总长度、消息头长度与前导 CRC32
+const PRELUDE_LEN: usize = 12;
+
+/// 消息末尾的 CRC32 长度
+const MESSAGE_CRC_LEN: usize = 4;
+
+/// 单条消息的最大长度（16MB）
+const MAX_MESSAGE_LEN: usize = 16 * 1024 * 1024;
+
+/// 事件流解码器
+///
+/// 每条消息的格式为：
+///
+/// ```text
+/// [总长度 u32][消息头长度 u32][前导 CRC32 u32][消息头][消息体][消息 CRC32 u32]
+/// ```
+///
+/// 所有整数均为大端序，CRC32 使用 IEEE 多项式。数据可以按任意边界分块输入。
+#[derive(Debug, Default)]
+struct EventStreamDecoder {
+    buf: BytesMut,
+}
+
+impl EventStreamDecoder {
+    /// 追加接收到的数据
+    fn push(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// 解码下一条完整的消息，数据不足时返回 `None`
+    fn next_message(&mut self) -> Result<Option<Message>> {
+        if self.buf.len() < PRELUDE_LEN {
+            return Ok(None);
+        }
+
+        let total_len = u32::from_be_bytes(self.buf[0..4].try_into().unwrap()) as usize;
+        let headers_len = u32::from_be_bytes(self.buf[4..8].try_into().unwrap()) as usize;
+        let prelude_crc = u32::from_be_bytes(self.buf[8..12].try_into().unwrap());
+
+        if crc32(&self.buf[0..8]) != prelude_crc {
+            return Err(CosError::other("Select event prelude checksum mismatch"));
+        }
+        if total_len < PRELUDE_LEN + MESSAGE_CRC_LEN + headers_len || total_len > MAX_MESSAGE_LEN {
+            return Err(CosError::other(format!("Invalid select event length {}", total_len)));
+        }
+        if self.buf.len() < total_len {
+            return Ok(None);
+        }
+
+        let frame = self.buf.split_to(total_len).freeze();
+        let message_crc = u32::from_be_bytes(frame[total_len - MESSAGE_CRC_LEN..].try_into().unwrap());
+        if crc32(&frame[..total_len - MESSAGE_CRC_LEN]) != message_crc {
+            return Err(CosError::other("Select event message checksum mismatch"));
+        }
+
+        let headers = parse_headers(&frame[PRELUDE_LEN..PRELUDE_LEN + headers_len])?;
+        let payload = frame.slice(PRELUDE_LEN + headers_len..total_len - MESSAGE_CRC_LEN);
+        Ok(Some(Message { headers, payload }))
+    }
+}
+
+/// 解析消息头，仅保留字符串类型的值
+fn parse_headers(mut data: &[u8]) -> Result<Vec<(String, String)>> {
+    let truncated = || CosError::other("Truncated select event header");
+    let mut headers = Vec::new();
+
+    while data.has_remaining() {
+        let name_len = data.first().copied().ok_or_else(truncated)? as usize;
+        data.advance(1);
+        if data.len() < name_len + 1 {
+            return Err(truncated());
+        }
+        let name = String::from_utf8_lossy(&data[..name_len]).into_owned();
+        data.advance(name_len);
+        let value_type = data.get_u8();
+
+        let value_len = match value_type {
+            // bool true / bool false
+            0 | 1 => 0,
+            // byte / short / int / long
+            2 => 1,
+            3 => 2,
+            4 => 4,
+            5 => 8,
+            // byte array / string
+            6 | 7 => {
+                if data.len() < 2 {
+                    return Err(truncated());
+                }
+                data.get_u16() as usize
+            }
+            // timestamp / uuid
+            8 => 8,
+            9 => 16,
+            t => return Err(CosError::other(format!("Unknown select event header type {}", t))),
+        };
+        if data.len() < value_len {
+            return Err(truncated());
+        }
+        if value_type == 7 {
+            headers.push((name, String::from_utf8_lossy(&data[..value_len]).into_owned()));
+        }
+        data.advance(value_len);
+    }
+
+    Ok(headers)
+}
+
+/// CRC32（IEEE）多项式（反射形式）
+const CRC32_POLY: u32 = 0xEDB8_8320;
+
+const CRC32_TABLE: [u32; 256] = build_crc32_table();
+
+const fn build_crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ CRC32_POLY } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::StreamExt;
+
+    /// 按事件流格式编码一条消息，消息头均为字符串类型
+    fn encode(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut header_bytes = Vec::new();
+        for (name, value) in headers {
+            header_bytes.push(name.len() as u8);
+            header_bytes.extend_from_slice(name.as_bytes());
+            header_bytes.push(7);
+            header_bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            header_bytes.extend_from_slice(value.as_bytes());
+        }
+
+        let total_len = PRELUDE_LEN + header_bytes.len() + payload.len() + MESSAGE_CRC_LEN;
+        let mut frame = Vec::with_capacity(total_len);
+        frame.extend_from_slice(&(total_len as u32).to_be_bytes());
+        frame.extend_from_slice(&(header_bytes.len() as u32).to_be_bytes());
+        let prelude_crc = crc32(&frame);
+        frame.extend_from_slice(&prelude_crc.to_be_bytes());
+        frame.extend_from_slice(&header_bytes);
+        frame.extend_from_slice(payload);
+        let message_crc = crc32(&frame);
+        frame.extend_from_slice(&message_crc.to_be_bytes());
+        frame
+    }
+
+    fn event(event_type: &str, payload: &[u8]) -> Vec<u8> {
+        encode(&[(":message-type", "event"), (":event-type", event_type)], payload)
+    }
+
+    fn stream_of(chunks: Vec<Vec<u8>>) -> SelectObjectStream {
+        let chunks = chunks.into_iter().map(|c| Ok(Bytes::from(c)));
+        SelectObjectStream::new(futures::stream::iter(chunks).boxed())
+    }
+
+    /// 服务端返回的 End 事件，只有消息头没有消息体
+    const END_FRAME: [u8; 56] = [
+        0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x28, 0xc1, 0xc6, 0x84, 0xd4, 0x0d, 0x3a, 0x6d,
+        0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2d, 0x74, 0x79, 0x70, 0x65, 0x07, 0x00, 0x05, 0x65,
+        0x76, 0x65, 0x6e, 0x74, 0x0b, 0x3a, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2d, 0x74, 0x79, 0x70,
+        0x65, 0x07, 0x00, 0x03, 0x45, 0x6e, 0x64, 0xcf, 0x97, 0xd3, 0x92,
+    ];
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_decode_captured_end_frame() {
+        assert_eq!(event("End", b""), END_FRAME);
+
+        let mut decoder = EventStreamDecoder::default();
+        decoder.push(&END_FRAME);
+        let message = decoder.next_message().unwrap().unwrap();
+        assert_eq!(message.header(":message-type"), Some("event"));
+        assert_eq!(message.header(":event-type"), Some("End"));
+        assert!(message.payload.is_empty());
+        assert!(decoder.next_message().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_split_frames() {
+        let mut data = encode(
+            &[
+                (":message-type", "event"),
+                (":event-type", "Records"),
+                (":content-type", "application/octet-stream"),
+            ],
+            b"1,Alice\n2,Bob\n",
+        );
+        data.extend_from_slice(&END_FRAME);
+
+        // 逐字节输入，消息在完整到达之前不会被解码
+        let mut decoder = EventStreamDecoder::default();
+        let mut messages = Vec::new();
+        for byte in &data {
+            decoder.push(std::slice::from_ref(byte));
+            while let Some(message) = decoder.next_message().unwrap() {
+                messages.push(message);
+            }
+        }
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].header(":content-type"), Some("application/octet-stream"));
+        assert_eq!(&messages[0].payload[..], b"1,Alice\n2,Bob\n");
+        assert_eq!(messages[1].header(":event-type"), Some("End"));
+    }
+
+    #[test]
+    fn test_decode_skips_non_string_headers() {
+        let mut headers = Vec::new();
+        headers.extend_from_slice(b"\x04flag\x00");
+        headers.extend_from_slice(b"\x02id\x04\x00\x00\x00\x2a");
+        headers.extend_from_slice(b"\x0b:event-type\x07\x00\x04Cont");
+
+        assert_eq!(
+            parse_headers(&headers).unwrap(),
+            vec![(":event-type".to_string(), "Cont".to_string())]
+        );
+        assert!(parse_headers(b"\x0b:event-type\x07\x00\x09Cont").is_err());
+        assert!(parse_headers(b"\x02id\x0a").is_err());
+    }
+
+    #[test]
+    fn test_decode_checksum_mismatch() {
+        let mut frame = END_FRAME;
+        frame[8] ^= 0xff;
+        let mut decoder = EventStreamDecoder::default();
+        decoder.push(&frame);
+        assert!(decoder.next_message().is_err());
+
+        let mut frame = END_FRAME;
+        frame[20] ^= 0xff;
+        let mut decoder = EventStreamDecoder::default();
+        decoder.push(&frame);
+        assert!(decoder.next_message().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_select_stream() {
+        let stats = b"<Stats><BytesScanned>2048</BytesScanned><BytesProcessed>2048</BytesProcessed>\
+            <BytesReturned>14</BytesReturned></Stats>";
+        let mut data = event("Records", b"1,Alice\n");
+        data.extend(event("Cont", b""));
+        data.extend(event("Records", b"2,Bob\n"));
+        data.extend(event("Stats", stats));
+        data.extend(END_FRAME);
+
+        // 在消息中间切分网络分块
+        let (a, b) = data.split_at(30);
+        let mut stream = stream_of(vec![a.to_vec(), b.to_vec()]);
+
+        let mut records = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            records.extend_from_slice(&chunk.unwrap());
+        }
+        assert_eq!(records, b"1,Alice\n2,Bob\n");
+        assert_eq!(
+            stream.stats(),
+            Some(SelectStats {
+                bytes_scanned: 2048,
+                bytes_processed: 2048,
+                bytes_returned: 14,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_stream_errors() {
+        let error = encode(
+            &[
+                (":message-type", "error"),
+                (":error-code", "InvalidTextEncoding"),
+                (":error-message", "UTF-8 encoding is required"),
+            ],
+            b"",
+        );
+        let mut stream = stream_of(vec![event("Records", b"1\n"), error]);
+        assert_eq!(&stream.next().await.unwrap().unwrap()[..], b"1\n");
+        match stream.next().await.unwrap() {
+            Err(CosError::Server { code, .. }) => assert_eq!(code, "InvalidTextEncoding"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(stream.next().await.is_none());
+
+        // 没有 End 事件的响应视为被截断
+        let mut stream = stream_of(vec![event("Records", b"1\n")]);
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_select_request_xml() {
+        let request = SelectRequest::new(
+            "SELECT s._1 FROM COSObject s LIMIT 10",
+            InputSerialization::csv(CsvInput {
+                file_header_info: FileHeaderInfo::Use,
+                field_delimiter: Some(";".to_string()),
+                ..Default::default()
+            })
+            .with_compression(CompressionType::Gzip),
+            OutputSerialization::json(JsonOutput::default()),
+        );
+
+        let xml = quick_xml::se::to_string(&request).unwrap();
+        assert_eq!(
+            xml,
+            "<SelectRequest><Expression>SELECT s._1 FROM COSObject s LIMIT 10</Expression>\
+             <ExpressionType>SQL</ExpressionType><InputSerialization><CompressionType>GZIP</CompressionType>\
+             <CSV><FileHeaderInfo>USE</FileHeaderInfo><FieldDelimiter>;</FieldDelimiter>\
+             <AllowQuotedRecordDelimiter>FALSE</AllowQuotedRecordDelimiter></CSV></InputSerialization>\
+             <OutputSerialization><JSON/></OutputSerialization>\
+             <RequestProgress><Enabled>FALSE</Enabled></RequestProgress></SelectRequest>"
+        );
+
+        let request = SelectRequest::new(
+            "SELECT * FROM COSObject",
+            InputSerialization::json(JsonInput { json_type: JsonType::Lines }),
+            OutputSerialization::csv(CsvOutput::default()),
+        )
+        .with_progress(true);
+        let xml = quick_xml::se::to_string(&request).unwrap();
+        assert!(xml.contains("<JSON><Type>LINES</Type></JSON>"));
+        assert!(xml.contains("<CSV><QuoteFields>ASNEEDED</QuoteFields></CSV>"));
+        assert!(xml.contains("<Enabled>TRUE</Enabled>"));
+    }
+}
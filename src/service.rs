@@ -0,0 +1,140 @@
+//! 服务模块
+//!
+//! 提供不针对单个存储桶的服务级操作，如列出账号下的全部存储桶
+
+use crate::acl::Owner;
+use crate::client::CosClient;
+use crate::config::Config;
+use crate::error::Result;
+use crate::object::{parse_http_date, request_id};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// 服务级操作客户端
+///
+/// 只需要配置密钥，`Config` 中的存储桶和地域不参与请求。
+#[derive(Debug, Clone)]
+pub struct ServiceClient {
+    client: CosClient,
+    endpoint: Option<String>,
+}
+
+impl ServiceClient {
+    /// 创建服务级操作客户端，只校验密钥
+    pub fn new(config: Config) -> Result<Self> {
+        Ok(Self {
+            client: CosClient::for_service(config)?,
+            endpoint: None,
+        })
+    }
+
+    /// 使用指定的服务地址，如私有化部署或代理的地址 `https://cos.example.com`，此时忽略 `region`
+    pub fn with_endpoint<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// 列出账号下的存储桶
+    ///
+    /// 指定 `region` 时访问 `cos.<region>.myqcloud.com`，只返回该地域的存储桶；
+    /// 否则访问 `service.cos.myqcloud.com` 返回全部地域的存储桶。
+    /// 设置了服务地址（本客户端或 [`Config::endpoint`]）时访问该地址。
+    pub async fn list_buckets(&self, region: Option<&str>) -> Result<ListBucketsResponse> {
+        let config = self.client.config();
+        let scheme = if config.use_https { "https" } else { "http" };
+        let endpoint = match (&self.endpoint, region) {
+            (Some(endpoint), _) => endpoint.clone(),
+            (None, _) if config.endpoint.is_some() => config.service_url(),
+            (None, Some(region)) => format!("{}://cos.{}.myqcloud.com", scheme, region),
+            (None, None) => format!("{}://service.cos.myqcloud.com", scheme),
+        };
+        let response = self.client.get_service(&endpoint, HashMap::new()).await?;
+        let request_id = request_id(response.headers());
+        let headers = response.headers().clone();
+        let mut list_response: ListBucketsResponse = CosClient::parse_xml(response).await?;
+        for bucket in &mut list_response.buckets.buckets {
+            bucket.creation_date = parse_http_date(&bucket.creation_date_raw);
+        }
+        list_response.request_id = request_id;
+        list_response.headers = headers;
+
+        Ok(list_response)
+    }
+}
+
+/// 列出存储桶响应
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ListAllMyBucketsResult")]
+pub struct ListBucketsResponse {
+    #[serde(rename = "Owner")]
+    pub owner: Owner,
+    #[serde(rename = "Buckets", default)]
+    pub buckets: BucketList,
+    /// 服务端返回的请求 ID（`x-cos-request-id`）
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// 完整的响应头
+    #[serde(skip)]
+    pub headers: HeaderMap,
+}
+
+/// 存储桶列表，对应 `<Buckets><Bucket>` 结构
+#[derive(Debug, Default, Deserialize)]
+pub struct BucketList {
+    #[serde(rename = "Bucket", default)]
+    pub buckets: Vec<Bucket>,
+}
+
+/// 存储桶信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bucket {
+    /// 存储桶名称，格式为 `<bucket-name>-<appid>`
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// 所在地域，如 `ap-beijing`
+    #[serde(rename = "Location")]
+    pub location: String,
+    /// 创建时间，由 `creation_date_raw` 解析得到
+    #[serde(skip)]
+    pub creation_date: Option<DateTime<Utc>>,
+    /// 原始的 CreationDate 字符串
+    #[serde(rename = "CreationDate", default)]
+    pub creation_date_raw: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    #[tokio::test]
+    async fn test_list_buckets() {
+        let body = "<ListAllMyBucketsResult><Owner><ID>qcs::cam::uin/100:uin/100</ID>\
+            <DisplayName>100</DisplayName></Owner><Buckets>\
+            <Bucket><Name>a-1250000000</Name><Location>ap-beijing</Location>\
+            <CreationDate>2024-05-24T11:49:50Z</CreationDate></Bucket>\
+            <Bucket><Name>b-1250000000</Name><Location>ap-guangzhou</Location>\
+            <CreationDate>2024-05-25T00:00:00Z</CreationDate></Bucket>\
+            </Buckets></ListAllMyBucketsResult>";
+        let client = client_for(vec![ServedResponse::new(200, body)]).await;
+        let endpoint = format!("http://{}", client.config().domain.clone().unwrap());
+        let client = ServiceClient { client, endpoint: Some(endpoint) };
+
+        let response = client.list_buckets(None).await.unwrap();
+        assert_eq!(response.owner.display_name, "100");
+        assert_eq!(response.buckets.buckets.len(), 2);
+        assert_eq!(response.buckets.buckets[1].location, "ap-guangzhou");
+        assert_eq!(
+            response.buckets.buckets[0].creation_date.unwrap().to_rfc3339(),
+            "2024-05-24T11:49:50+00:00"
+        );
+    }
+
+    #[test]
+    fn test_service_client_does_not_require_bucket() {
+        assert!(ServiceClient::new(Config::new("id", "key", "", "")).is_ok());
+        assert!(ServiceClient::new(Config::new("", "key", "", "")).is_err());
+    }
+}
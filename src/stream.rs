@@ -0,0 +1,480 @@
+//! 流式传输模块
+//!
+//! 以流的方式上传和下载对象，内存占用与对象大小无关
+
+use crate::error::{CosError, Result};
+use crate::response::CosResponse;
+use crate::multipart::{CompletedPart, MultipartUploadOptions, UploadPartOptions, MAX_PART_NUMBER};
+use crate::object::{
+    copy_stream_to_writer, header_str, insert_traffic_limit, md5_from_etag, parse_http_date, request_id,
+    response_override_params, version_params, GetObjectOptions, ObjectClient, PutObjectResponse,
+};
+use crate::transfer::{split_traffic_limit, UploadFileResponse, UploadOptions, DEFAULT_PART_TIMEOUT};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::{Stream, TryStreamExt};
+use reqwest::Body;
+use std::collections::HashMap;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+use tokio_util::io::{ReaderStream, StreamReader};
+
+/// 对象数据流
+///
+/// 响应头中的元数据在创建时即可读取，响应体按块逐步返回。
+pub struct ObjectStream {
+    pub content_length: u64,
+    pub content_type: String,
+    pub etag: String,
+    /// 最后修改时间，响应头无法解析时为空
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 Last-Modified 响应头
+    pub last_modified_raw: Option<String>,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    /// ETag 为内容 MD5 时的期望摘要
+    pub(crate) expected_md5: Option<String>,
+    inner: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+}
+
+impl ObjectStream {
+    /// 从响应创建数据流
+    pub(crate) fn from_response(response: CosResponse) -> Self {
+        let headers = response.headers();
+        let content_length = header_str(headers, "content-length")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        let content_type = header_str(headers, "content-type")
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let etag = header_str(headers, "etag").unwrap_or("").to_string();
+        let last_modified_raw = header_str(headers, "last-modified").map(|s| s.to_string());
+        let last_modified = last_modified_raw.as_deref().and_then(parse_http_date);
+        let crc64 = header_str(headers, "x-cos-hash-crc64ecma").map(|s| s.to_string());
+        let expected_md5 = md5_from_etag(headers);
+
+        Self {
+            content_length,
+            content_type,
+            etag,
+            last_modified,
+            last_modified_raw,
+            crc64,
+            expected_md5,
+            inner: response.bytes_stream(),
+        }
+    }
+
+    /// 由内存中的数据块创建数据流，用于测试
+    #[cfg(test)]
+    pub(crate) fn from_chunks(chunks: Vec<&'static [u8]>, etag: &str) -> Self {
+        let content_length = chunks.iter().map(|c| c.len() as u64).sum();
+        let chunks = chunks.into_iter().map(|c| Ok(Bytes::from_static(c)));
+        Self {
+            content_length,
+            content_type: "application/octet-stream".to_string(),
+            etag: etag.to_string(),
+            last_modified: None,
+            last_modified_raw: None,
+            crc64: None,
+            expected_md5: crate::object::plain_md5(etag),
+            inner: Box::pin(futures::stream::iter(chunks)),
+        }
+    }
+
+    /// 转换为 [`AsyncRead`]，可配合 `tokio::io::copy` 使用
+    pub fn into_async_read(self) -> impl AsyncRead + Send + Unpin {
+        StreamReader::new(self.inner.map_err(|e| std::io::Error::other(e.to_string())))
+    }
+}
+
+impl Stream for ObjectStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl fmt::Debug for ObjectStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObjectStream")
+            .field("content_length", &self.content_length)
+            .field("content_type", &self.content_type)
+            .field("etag", &self.etag)
+            .field("last_modified", &self.last_modified)
+            .field("crc64", &self.crc64)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 下载到 [`AsyncWrite`] 后返回的对象元数据，来自响应头
+#[derive(Debug, Clone)]
+pub struct ObjectMeta {
+    /// 写入的字节数，指定范围时为范围长度
+    pub content_length: u64,
+    pub content_type: String,
+    pub etag: String,
+    /// 最后修改时间，响应头无法解析时为空
+    pub last_modified: Option<DateTime<Utc>>,
+    /// 原始的 Last-Modified 响应头
+    pub last_modified_raw: Option<String>,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    pub version_id: Option<String>,
+    pub request_id: Option<String>,
+}
+
+impl ObjectClient {
+    /// 获取对象并写入任意 [`AsyncWrite`]，如网络连接、压缩器或哈希计算器
+    ///
+    /// 数据按块写入，不落地到本地文件；写入完成后刷新 `writer` 并返回响应头中的元数据。
+    /// 获取完整对象时按配置校验 MD5 并校验 CRC64，指定范围或图片处理时不校验。
+    pub async fn get_object_to_writer<W>(
+        &self,
+        key: &str,
+        writer: &mut W,
+        options: GetObjectOptions,
+    ) -> Result<ObjectMeta>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut params = version_params(options.version_id.as_deref());
+        response_override_params(
+            &mut params,
+            options.response_content_type.as_deref(),
+            options.response_content_disposition.as_deref(),
+        );
+        if let Some(ref image_process) = options.image_process {
+            image_process.insert_into(&mut params);
+        }
+        let response = self
+            .client
+            .with_timeout(options.timeout)
+            .get_with_headers(&self.object_path(key)?, params, options.to_headers()?)
+            .await?;
+
+        let whole_object = options.range.is_none() && options.image_process.is_none();
+        let version_id = header_str(response.headers(), "x-cos-version-id").map(|s| s.to_string());
+        let request_id = request_id(response.headers());
+        let mut stream = ObjectStream::from_response(response);
+        let verify_md5 = whole_object && self.client.config().verify_md5;
+        copy_stream_to_writer(&mut stream, writer, verify_md5, whole_object).await?;
+
+        Ok(ObjectMeta {
+            content_length: stream.content_length,
+            content_type: stream.content_type,
+            etag: stream.etag,
+            last_modified: stream.last_modified,
+            last_modified_raw: stream.last_modified_raw,
+            crc64: stream.crc64,
+            version_id,
+            request_id,
+        })
+    }
+
+    /// 以流的方式获取对象
+    pub async fn get_object_stream(&self, key: &str) -> Result<ObjectStream> {
+        self.get_object_stream_with_headers(key, HashMap::new()).await
+    }
+
+    /// 以流的方式获取对象，附带额外的请求头
+    pub(crate) async fn get_object_stream_with_headers(
+        &self,
+        key: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<ObjectStream> {
+        let response = self
+            .client
+            .get_with_headers(&self.object_path(key)?, HashMap::new(), headers)
+            .await?;
+
+        Ok(ObjectStream::from_response(response))
+    }
+
+    /// 从任意 [`AsyncRead`] 数据源流式上传对象
+    ///
+    /// 已知长度且小于分块上传阈值时以流式请求体一次性上传；长度达到阈值或未知时
+    /// 按分块大小逐块读取并使用分块上传，内存中最多只保留一个分块的数据。
+    /// 分块依次上传，`options.traffic_limit_bits_per_sec` 直接作用于每个请求。
+    pub async fn put_object_stream<R>(
+        &self,
+        key: &str,
+        reader: R,
+        content_length: Option<u64>,
+        options: UploadOptions,
+    ) -> Result<UploadFileResponse>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        let mut headers = options.headers.clone();
+        if let Some(ct) = &options.content_type {
+            headers.insert("Content-Type".to_string(), ct.clone());
+        }
+        if let Some(encryption) = &options.encryption {
+            headers.extend(encryption.to_headers());
+        }
+        let traffic_limit = split_traffic_limit(options.traffic_limit_bits_per_sec, 1)?.0;
+        let part_options = UploadPartOptions {
+            traffic_limit_bits_per_sec: traffic_limit,
+            timeout: Some(options.part_timeout.unwrap_or(DEFAULT_PART_TIMEOUT)),
+            ..Default::default()
+        };
+
+        let sizing = options.sizing(self.client.config())?;
+        let reader = Box::pin(reader);
+        match content_length {
+            Some(length) if length < sizing.threshold => {
+                insert_traffic_limit(&mut headers, traffic_limit)?;
+                headers.insert("Content-Length".to_string(), length.to_string());
+                let body = Body::wrap_stream(ReaderStream::new(reader));
+                let _permit = self.part_permit().await?;
+                let response = self
+                    .client
+                    .put_with_headers(&self.object_path(key)?, HashMap::new(), headers, Some(body))
+                    .await?;
+                Ok(PutObjectResponse::from_headers(response.headers()).into())
+            }
+            Some(length) => {
+                let part_size = sizing.fit(length).part_size;
+                self.put_object_stream_parts(key, reader, headers, part_size, &part_options)
+                    .await
+            }
+            None => {
+                self.put_object_stream_parts(key, reader, headers, sizing.part_size, &part_options)
+                    .await
+            }
+        }
+    }
+
+    /// 将数据源按 `part_size` 逐块读取并分块上传
+    async fn put_object_stream_parts(
+        &self,
+        key: &str,
+        mut reader: Pin<Box<impl AsyncRead + Send>>,
+        mut headers: HashMap<String, String>,
+        part_size: u64,
+        part_options: &UploadPartOptions,
+    ) -> Result<UploadFileResponse> {
+
+        // 第一个分块未读满说明数据量较小，直接使用简单上传
+        let first = read_part(&mut reader, part_size).await?;
+        if (first.len() as u64) < part_size {
+            insert_traffic_limit(&mut headers, part_options.traffic_limit_bits_per_sec)?;
+            let _permit = self.part_permit().await?;
+            let response = self
+                .client
+                .put_with_headers(&self.object_path(key)?, HashMap::new(), headers, Some(first))
+                .await?;
+            return Ok(PutObjectResponse::from_headers(response.headers()).into());
+        }
+
+        let upload_id = self
+            .initiate_multipart_upload(
+                key,
+                Some(MultipartUploadOptions {
+                    headers,
+                    ..Default::default()
+                }),
+            )
+            .await?
+            .upload_id;
+
+        let parts = match self
+            .upload_stream_parts(key, &upload_id, &mut reader, first, part_size, part_options)
+            .await
+        {
+            Ok(parts) => parts,
+            Err(e) => {
+                if let Err(abort_err) = self.abort_multipart_upload(key, &upload_id).await {
+                    log::warn!("Failed to abort multipart upload {}: {}", upload_id, abort_err);
+                }
+                return Err(e);
+            }
+        };
+
+        let response = self.complete_multipart_upload(key, &upload_id, parts).await?;
+        Ok(UploadFileResponse {
+            etag: response.etag,
+            crc64: response.crc64,
+            version_id: response.version_id,
+            upload_id: Some(upload_id),
+            encryption: response.encryption,
+        })
+    }
+
+    /// 依次读取并上传分块，直到数据源结束
+    async fn upload_stream_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        reader: &mut Pin<Box<impl AsyncRead + Send>>,
+        first: Vec<u8>,
+        part_size: u64,
+        part_options: &UploadPartOptions,
+    ) -> Result<Vec<CompletedPart>> {
+        let mut parts = Vec::new();
+        let mut data = first;
+        let mut part_number = 1u32;
+
+        while !data.is_empty() {
+            if part_number > MAX_PART_NUMBER {
+                return Err(CosError::config(format!(
+                    "Stream needs more than {} parts with part size {}",
+                    MAX_PART_NUMBER, part_size
+                )));
+            }
+
+            let full = data.len() as u64 == part_size;
+            let _permit = self.part_permit().await?;
+            let response = self
+                .upload_part_with_options(key, upload_id, part_number, data, part_options)
+                .await?;
+            parts.push(CompletedPart::new(part_number, response.etag));
+
+            if !full {
+                break;
+            }
+            data = read_part(reader, part_size).await?;
+            part_number += 1;
+        }
+
+        Ok(parts)
+    }
+}
+
+/// 从数据源读取至多 `part_size` 字节，仅在数据源结束时返回不足一个分块的数据
+async fn read_part<R>(reader: &mut R, part_size: u64) -> Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut data = Vec::new();
+    reader
+        .take(part_size)
+        .read_to_end(&mut data)
+        .await
+        .map_err(|e| CosError::other(format!("Failed to read stream: {}", e)))?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_into_async_read() {
+        let stream = ObjectStream::from_chunks(vec![b"hello, ", b"cos"], "");
+
+        let mut reader = stream.into_async_read();
+        let mut text = String::new();
+        reader.read_to_string(&mut text).await.unwrap();
+        assert_eq!(text, "hello, cos");
+    }
+
+    #[tokio::test]
+    async fn test_read_part() {
+        let mut reader: &[u8] = b"0123456789";
+        assert_eq!(read_part(&mut reader, 4).await.unwrap(), b"0123");
+        assert_eq!(read_part(&mut reader, 4).await.unwrap(), b"4567");
+        assert_eq!(read_part(&mut reader, 4).await.unwrap(), b"89");
+        assert!(read_part(&mut reader, 4).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_object_to_writer() {
+        use crate::range::ByteRange;
+        use crate::test_server::{client_for, ServedResponse};
+
+        let client = ObjectClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "hello")
+                    .with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\"")
+                    .with_header("Content-Type", "text/plain")
+                    .with_header("Last-Modified", "Wed, 14 Oct 2026 08:00:00 GMT"),
+                // 范围下载时 ETag 对应完整对象，不应校验 MD5
+                ServedResponse::new(206, "ell").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""),
+                ServedResponse::new(200, "hello").with_header("ETag", "\"00000000000000000000000000000000\""),
+            ])
+            .await,
+        );
+
+        let mut buf = Vec::new();
+        let meta = client.get_object_to_writer("a.txt", &mut buf, GetObjectOptions::default()).await.unwrap();
+        assert_eq!(buf, b"hello");
+        assert_eq!(meta.content_length, 5);
+        assert_eq!(meta.content_type, "text/plain");
+        assert_eq!(meta.last_modified_raw.as_deref(), Some("Wed, 14 Oct 2026 08:00:00 GMT"));
+        assert_eq!(meta.last_modified.unwrap().to_rfc3339(), "2026-10-14T08:00:00+00:00");
+
+        let mut buf = Vec::new();
+        let options = GetObjectOptions {
+            range: Some(ByteRange::from(1..=3)),
+            ..Default::default()
+        };
+        client.get_object_to_writer("a.txt", &mut buf, options).await.unwrap();
+        assert_eq!(buf, b"ell");
+
+        let mut buf = Vec::new();
+        let err = client.get_object_to_writer("a.txt", &mut buf, GetObjectOptions::default()).await.unwrap_err();
+        assert!(matches!(err, CosError::IntegrityCheckFailed { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_put_object_stream_known_length_uses_threshold() {
+        use crate::config::Config;
+        use crate::multipart::MIN_PART_SIZE;
+        use crate::testing::{MockResponse, MockTransport};
+        use std::io::Cursor;
+
+        let transport = MockTransport::new();
+        let client = ObjectClient::new(
+            transport
+                .client(Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123"))
+                .unwrap(),
+        );
+        let options = UploadOptions {
+            multipart_threshold: Some(MIN_PART_SIZE),
+            part_size: Some(MIN_PART_SIZE),
+            ..Default::default()
+        };
+
+        // 小于阈值时一次性上传
+        transport.push_response(MockResponse::new(200, "").with_header("ETag", "\"abc\""));
+        let response = client
+            .put_object_stream("a.bin", Cursor::new(vec![1u8; 10]), Some(10), options.clone())
+            .await
+            .unwrap();
+        assert!(response.upload_id.is_none());
+        assert_eq!(transport.last_request().unwrap().header("Content-Length"), Some("10"));
+
+        // 达到阈值时即使长度已知也使用分块上传
+        let data = vec![1u8; (MIN_PART_SIZE + MIN_PART_SIZE / 2) as usize];
+        let length = data.len() as u64;
+        transport
+            .push_response(MockResponse::new(
+                200,
+                "<InitiateMultipartUploadResult><Bucket>test-bucket-123</Bucket><Key>a.bin</Key>\
+                 <UploadId>upload-1</UploadId></InitiateMultipartUploadResult>",
+            ))
+            .push_response(MockResponse::new(200, "").with_header("ETag", "\"p1\""))
+            .push_response(MockResponse::new(200, "").with_header("ETag", "\"p2\""))
+            .push_response(MockResponse::new(
+                200,
+                "<CompleteMultipartUploadResult><Key>a.bin</Key><ETag>\"abc-2\"</ETag></CompleteMultipartUploadResult>",
+            ));
+        let response = client
+            .put_object_stream("a.bin", Cursor::new(data), Some(length), options)
+            .await
+            .unwrap();
+        assert_eq!(response.upload_id.as_deref(), Some("upload-1"));
+        assert_eq!(response.etag, "\"abc-2\"");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 5);
+        assert_eq!(requests[2].query_param("partNumber").as_deref(), Some("1"));
+        assert_eq!(requests[3].query_param("partNumber").as_deref(), Some("2"));
+        assert_eq!(requests[3].body.as_ref().map(|b| b.len() as u64), Some(MIN_PART_SIZE / 2));
+    }
+}
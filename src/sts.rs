@@ -7,11 +7,22 @@
 //!
 //! 参考文档：<https://cloud.tencent.com/document/product/436/14048>
 
+use crate::config::Config;
+use crate::credentials::{Credentials, CredentialsProvider};
 use crate::error::CosError;
+pub use crate::policy::{Policy, Statement};
+use crate::tc3::{self, Tc3Request};
+use crate::transport::{configure, with_tls_backend};
 use reqwest::Client;
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
 use url::form_urlencoded;
 
 /// STS 临时密钥客户端
@@ -21,6 +32,68 @@ pub struct StsClient {
     secret_key: String,
     region: String,
     client: Client,
+    endpoint: String,
+    signature_method: StsSignatureMethod,
+    cache_margin: Duration,
+    cache: Arc<CredentialsCache>,
+}
+
+/// STS 服务地址
+const STS_ENDPOINT: &str = "https://sts.tencentcloudapi.com";
+/// STS 接口版本
+const STS_VERSION: &str = "2018-08-13";
+/// 签名方法 v3 使用的服务名
+const STS_SERVICE: &str = "sts";
+
+/// STS 请求的签名方法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StsSignatureMethod {
+    /// 签名方法 v3（TC3-HMAC-SHA256），以 POST + JSON 请求体发送，策略大小不受 URL 长度限制
+    #[default]
+    Tc3HmacSha256,
+    /// 旧版签名方法 v1（HmacSHA1），以 GET + URL 参数发送，只在无法使用 v3 的环境中使用
+    HmacSha1,
+}
+
+/// 临时密钥距离过期不足该时长时由 [`StsCredentialsProvider`] 与 [`StsClient::get_credentials_cached`] 刷新
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// [`StsClient::get_credentials_cached`] 的缓存命中统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StsCacheStats {
+    /// 直接返回缓存密钥的次数
+    pub hits: u64,
+    /// 向 STS 重新获取密钥的次数，包括获取失败的次数
+    pub misses: u64,
+}
+
+/// 按请求参数的哈希缓存临时密钥，每个键一把锁，保证同一请求同时只有一次 STS 调用
+#[derive(Debug, Default)]
+struct CredentialsCache {
+    entries: std::sync::Mutex<HashMap<u64, Arc<Mutex<Option<CachedCredentials>>>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Debug, Clone)]
+struct CachedCredentials {
+    credentials: TemporaryCredentials,
+    /// 过期时间的 Unix 时间戳（秒），响应未包含时按请求的有效期推算
+    expires_at: u64,
+}
+
+impl CredentialsCache {
+    /// 取出键对应的缓存槽，同时清理已经过期且没有调用方占用的槽
+    fn slot(&self, key: u64, now: u64) -> Arc<Mutex<Option<CachedCredentials>>> {
+        let mut entries = self.entries.lock().unwrap();
+        if !entries.contains_key(&key) {
+            entries.retain(|_, slot| match slot.try_lock() {
+                Ok(cached) => cached.as_ref().is_some_and(|c| c.expires_at > now),
+                Err(_) => true,
+            });
+        }
+        entries.entry(key).or_default().clone()
+    }
 }
 
 /// 临时密钥响应
@@ -56,7 +129,12 @@ struct StsResponseData {
     #[serde(rename = "ExpiredTime")]
     expired_time: Option<u64>,
     #[serde(rename = "Expiration")]
+    #[allow(dead_code)]
     expiration: Option<String>,
+    #[serde(rename = "AssumedRoleUser")]
+    assumed_role_user: Option<AssumedRoleUser>,
+    #[serde(rename = "RequestId")]
+    request_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,29 +145,6 @@ struct StsError {
     message: String,
 }
 
-/// 权限策略
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Policy {
-    /// 策略语法版本
-    pub version: String,
-    /// 策略声明列表
-    pub statement: Vec<Statement>,
-}
-
-/// 策略声明
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Statement {
-    /// 效果：allow 或 deny
-    pub effect: String,
-    /// 允许的操作列表
-    pub action: Vec<String>,
-    /// 资源列表
-    pub resource: Vec<String>,
-    /// 条件（可选）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<HashMap<String, HashMap<String, serde_json::Value>>>,
-}
-
 /// 临时密钥请求参数
 #[derive(Debug, Clone)]
 pub struct GetCredentialsRequest {
@@ -101,6 +156,41 @@ pub struct GetCredentialsRequest {
     pub name: Option<String>,
 }
 
+/// 扮演角色请求参数（AssumeRole）
+#[derive(Debug, Clone, Default)]
+pub struct AssumeRoleRequest {
+    /// 角色的资源描述，如 `qcs::cam::uin/100000000001:roleName/ci-deployer`
+    pub role_arn: String,
+    /// 会话名称，会记录在临时身份中
+    pub role_session_name: String,
+    /// 权限策略，与角色自身的权限取交集；为空时使用角色的全部权限
+    pub policy: Option<Policy>,
+    /// 有效期（秒），为空时使用服务端默认值（7200 秒），不能超过角色的最大会话时间
+    pub duration_seconds: Option<u32>,
+    /// 角色载体条件中要求的外部 ID
+    pub external_id: Option<String>,
+}
+
+/// 扮演角色得到的临时身份
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssumedRoleUser {
+    /// 临时身份的资源描述
+    #[serde(rename = "Arn")]
+    pub arn: String,
+    /// 临时身份 ID
+    #[serde(rename = "AssumedRoleId")]
+    pub assumed_role_id: String,
+}
+
+/// 扮演角色响应
+#[derive(Debug, Clone)]
+pub struct AssumeRoleResponse {
+    /// 临时密钥
+    pub credentials: TemporaryCredentials,
+    /// 临时身份信息，响应未包含时为空
+    pub assumed_role_user: Option<AssumedRoleUser>,
+}
+
 impl StsClient {
     /// 创建 STS 客户端
     pub fn new(secret_id: String, secret_key: String, region: String) -> Self {
@@ -108,52 +198,232 @@ impl StsClient {
             secret_id,
             secret_key,
             region,
-            client: Client::new(),
+            client: with_tls_backend(Client::builder())
+                .build()
+                .expect("Failed to create HTTP client"),
+            endpoint: STS_ENDPOINT.to_string(),
+            signature_method: StsSignatureMethod::default(),
+            cache_margin: DEFAULT_REFRESH_MARGIN,
+            cache: Arc::default(),
         }
     }
 
-    /// 获取临时密钥
-    /// 使用腾讯云官方STS SDK的签名方法
+    /// 使用 COS 配置中的密钥、地域与连接设置（超时、HTTP 版本、TLS）创建 STS 客户端
+    pub fn from_config(config: &Config) -> Result<Self, CosError> {
+        let client = configure(Client::builder(), config)?
+            .build()
+            .map_err(|e| CosError::other(format!("Failed to create HTTP client: {}", e)))?;
+        Ok(Self {
+            secret_id: config.secret_id.clone(),
+            secret_key: config.secret_key.clone(),
+            region: config.region.clone(),
+            client,
+            endpoint: STS_ENDPOINT.to_string(),
+            signature_method: StsSignatureMethod::default(),
+            cache_margin: DEFAULT_REFRESH_MARGIN,
+            cache: Arc::default(),
+        })
+    }
+
+    /// 设置签名方法，默认使用签名方法 v3
+    pub fn with_signature_method(mut self, method: StsSignatureMethod) -> Self {
+        self.signature_method = method;
+        self
+    }
+
+    /// 设置 [`get_credentials_cached`](Self::get_credentials_cached) 的刷新余量，
+    /// 缓存的密钥距离过期不足该时长时重新获取，默认 5 分钟
+    pub fn with_cache_margin(mut self, margin: Duration) -> Self {
+        self.cache_margin = margin;
+        self
+    }
+
+    /// 将请求发往指定地址，用于测试
+    #[cfg(test)]
+    pub(crate) fn with_endpoint<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// 获取临时密钥（GetFederationToken）
     pub async fn get_credentials(
         &self,
         request: GetCredentialsRequest,
     ) -> Result<TemporaryCredentials, CosError> {
         let policy_json = serde_json::to_string(&request.policy)
             .map_err(|e| CosError::other(format!("Policy serialization error: {}", e)))?;
-        
-        let duration_seconds = request.duration_seconds.unwrap_or(1800);
-        let name = request.name.unwrap_or_else(|| "temp-user".to_string());
-        
-        // 使用腾讯云STS SDK的方式：GET请求 + URL参数
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        
-        let nonce = timestamp; // 使用时间戳作为随机数
-        
-        // 构建请求参数 - 先创建所有字符串变量以确保生命周期
-          let timestamp_str = timestamp.to_string();
-          let nonce_str = nonce.to_string();
-          // Policy参数需要URL编码，不是base64编码
-          let encoded_policy = urlencoding::encode(&policy_json).to_string();
-          let duration_str = duration_seconds.to_string();
-         
-         let mut params = HashMap::new();
-          params.insert("Action", "GetFederationToken");
-          params.insert("Version", "2018-08-13");
-          params.insert("Region", &self.region);
-          params.insert("SecretId", &self.secret_id);
-          params.insert("Timestamp", &timestamp_str);
-          params.insert("Nonce", &nonce_str);
-          params.insert("Name", &name);
-           params.insert("Policy", &encoded_policy);
-           params.insert("DurationSeconds", &duration_str);
-         
-         // 生成签名
-         let signature = self.generate_signature(&params)?;
-         params.insert("Signature", &signature);
-        
+
+        let mut params = serde_json::Map::new();
+        params.insert("Name".to_string(), request.name.unwrap_or_else(|| "temp-user".to_string()).into());
+        // Policy 参数需要 URL 编码，不是 base64 编码
+        params.insert("Policy".to_string(), urlencoding::encode(&policy_json).into_owned().into());
+        params.insert("DurationSeconds".to_string(), request.duration_seconds.unwrap_or(1800).into());
+
+        let response_text = self.call("GetFederationToken", params).await?;
+        parse_credentials(&response_text)
+    }
+
+    /// 获取临时密钥，相同的策略、会话名称与有效期复用缓存的密钥
+    ///
+    /// 缓存的密钥距离过期仍多于刷新余量（见 [`with_cache_margin`](Self::with_cache_margin)）时直接返回，
+    /// 否则重新获取；同一请求的并发调用只发出一次 STS 请求。缓存由客户端的所有副本共享，
+    /// 命中情况可以通过 [`cache_stats`](Self::cache_stats) 查看。
+    pub async fn get_credentials_cached(
+        &self,
+        request: GetCredentialsRequest,
+    ) -> Result<TemporaryCredentials, CosError> {
+        let policy_json = serde_json::to_string(&request.policy)
+            .map_err(|e| CosError::other(format!("Policy serialization error: {}", e)))?;
+        let mut hasher = DefaultHasher::new();
+        (&policy_json, &request.name, request.duration_seconds).hash(&mut hasher);
+        let key = hasher.finish();
+
+        let slot = self.cache.slot(key, unix_now());
+        let mut cached = slot.lock().await;
+        let refresh_at = unix_now().saturating_add(self.cache_margin.as_secs());
+        if let Some(entry) = cached.as_ref().filter(|c| c.expires_at > refresh_at) {
+            self.cache.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(entry.credentials.clone());
+        }
+
+        self.cache.misses.fetch_add(1, Ordering::Relaxed);
+        let fetched_at = unix_now();
+        let duration = request.duration_seconds.unwrap_or(1800);
+        let credentials = self.get_credentials(request).await?;
+        let expires_at = credentials
+            .expired_time
+            .unwrap_or(fetched_at + u64::from(duration));
+        *cached = Some(CachedCredentials {
+            credentials: credentials.clone(),
+            expires_at,
+        });
+        Ok(credentials)
+    }
+
+    /// [`get_credentials_cached`](Self::get_credentials_cached) 的缓存命中统计
+    pub fn cache_stats(&self) -> StsCacheStats {
+        StsCacheStats {
+            hits: self.cache.hits.load(Ordering::Relaxed),
+            misses: self.cache.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// 扮演 CAM 角色获取临时密钥（AssumeRole）
+    ///
+    /// STS 返回的错误（如角色载体不包含当前账号、有效期超过角色的最大会话时间）保留原始错误码，
+    /// 可以通过 [`CosError::code`] 获取。
+    pub async fn assume_role(&self, request: AssumeRoleRequest) -> Result<AssumeRoleResponse, CosError> {
+        let mut params = serde_json::Map::new();
+        params.insert("RoleArn".to_string(), request.role_arn.into());
+        params.insert("RoleSessionName".to_string(), request.role_session_name.into());
+        if let Some(policy) = &request.policy {
+            let policy_json = serde_json::to_string(policy)
+                .map_err(|e| CosError::other(format!("Policy serialization error: {}", e)))?;
+            params.insert("Policy".to_string(), urlencoding::encode(&policy_json).into_owned().into());
+        }
+        if let Some(duration_seconds) = request.duration_seconds {
+            params.insert("DurationSeconds".to_string(), duration_seconds.into());
+        }
+        if let Some(external_id) = request.external_id {
+            params.insert("ExternalId".to_string(), external_id.into());
+        }
+
+        let response_text = self.call("AssumeRole", params).await?;
+        let mut data = parse_response(&response_text)?;
+        let assumed_role_user = data.assumed_role_user.take();
+        Ok(AssumeRoleResponse {
+            credentials: credentials_from(data)?,
+            assumed_role_user,
+        })
+    }
+
+    /// 按客户端的签名方法调用 STS 接口，返回响应体
+    async fn call(&self, action: &str, params: serde_json::Map<String, serde_json::Value>) -> Result<String, CosError> {
+        let timestamp = unix_now();
+        let request = match self.signature_method {
+            StsSignatureMethod::Tc3HmacSha256 => self.tc3_request(action, &params, timestamp)?,
+            StsSignatureMethod::HmacSha1 => self.v1_request(action, &params, timestamp)?,
+        };
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| CosError::other(format!("Request failed: {}", e)))?;
+        response.text().await
+            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))
+    }
+
+    /// 签名方法 v3：POST + JSON 请求体，公共参数放在 `X-TC-*` 请求头中
+    fn tc3_request(
+        &self,
+        action: &str,
+        params: &serde_json::Map<String, serde_json::Value>,
+        timestamp: u64,
+    ) -> Result<reqwest::RequestBuilder, CosError> {
+        let payload = serde_json::to_string(params)
+            .map_err(|e| CosError::other(format!("Request serialization error: {}", e)))?;
+        let url = url::Url::parse(&self.endpoint)
+            .map_err(|e| CosError::config(format!("Invalid STS endpoint {:?}: {}", self.endpoint, e)))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(CosError::config(format!("STS endpoint {:?} has no host", self.endpoint))),
+        };
+        let authorization = Tc3Request {
+            service: STS_SERVICE,
+            host: &host,
+            timestamp: timestamp as i64,
+            payload: &payload,
+        }
+        .authorization(&self.secret_id, &self.secret_key)?;
+
+        Ok(self
+            .client
+            .post(format!("{}/", self.endpoint))
+            .header("Content-Type", tc3::CONTENT_TYPE)
+            .header("X-TC-Action", action)
+            .header("X-TC-Version", STS_VERSION)
+            .header("X-TC-Region", &self.region)
+            .header("X-TC-Timestamp", timestamp.to_string())
+            .header("Authorization", authorization)
+            .body(payload))
+    }
+
+    /// 旧版签名方法 v1：GET + URL 参数，参数值转换为字符串
+    fn v1_request(
+        &self,
+        action: &str,
+        params: &serde_json::Map<String, serde_json::Value>,
+        timestamp: u64,
+    ) -> Result<reqwest::RequestBuilder, CosError> {
+        let timestamp_str = timestamp.to_string();
+        let values: Vec<(&str, String)> = params
+            .iter()
+            .map(|(k, v)| {
+                let value = match v {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                (k.as_str(), value)
+            })
+            .collect();
+
+        let mut params = HashMap::new();
+        params.insert("Action", action);
+        params.insert("Version", STS_VERSION);
+        params.insert("Region", &self.region);
+        params.insert("SecretId", &self.secret_id);
+        params.insert("Timestamp", &timestamp_str);
+        // 使用时间戳作为随机数
+        params.insert("Nonce", &timestamp_str);
+        for (k, v) in &values {
+            params.insert(k, v);
+        }
+
+        // 生成签名
+        let signature = self.generate_signature(&params)?;
+        params.insert("Signature", &signature);
+
         // 构建URL
         let query_string = params.iter()
             .map(|(k, v)| {
@@ -162,90 +432,16 @@ impl StsClient {
             })
             .collect::<Vec<_>>()
             .join("&");
-        
-        let url = format!("https://sts.tencentcloudapi.com/?{}", query_string);
-        
-        // 发送GET请求
-        let response = self.client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| CosError::other(format!("Request failed: {}", e)))?;
-        
-        let response_text = response.text().await
-            .map_err(|e| CosError::other(format!("Failed to read response: {}", e)))?;
-        
 
-        // 解析响应 - 使用新版API格式
-        if response_text.contains("\"Response\"") {
-            // 新版API响应格式
-            let sts_response: StsResponse = serde_json::from_str(&response_text)
-                .map_err(|e| CosError::other(format!("Response parsing error: {}\nResponse: {}", e, response_text)))?;
-            
-            if let Some(error) = sts_response.response.error {
-                return Err(CosError::other(format!("STS API error: {} - {}", error.code, error.message)));
-            }
-            
-            let mut credentials = sts_response.response.credentials
-                .ok_or_else(|| CosError::other("No credentials in response".to_string()))?;
-            
-            // 从响应的顶层获取ExpiredTime并设置到credentials中
-            if let Some(expired_time) = sts_response.response.expired_time {
-                credentials.expired_time = Some(expired_time);
-            }
-            
-            Ok(credentials)
-        } else {
-            // 旧版API响应格式
-            #[derive(Deserialize)]
-            struct LegacyStsResponse {
-                code: i32,
-                message: String,
-                #[serde(rename = "codeDesc")]
-                data: Option<LegacyCredentialsData>,
-            }
-            
-            #[derive(Deserialize)]
-            struct LegacyCredentialsData {
-                credentials: LegacyCredentials,
-                #[serde(rename = "expiredTime")]
-                expired_time: u64,
-            }
-            
-            #[derive(Deserialize)]
-            struct LegacyCredentials {
-                #[serde(rename = "tmpSecretId")]
-                tmp_secret_id: String,
-                #[serde(rename = "tmpSecretKey")]
-                tmp_secret_key: String,
-                #[serde(rename = "sessionToken")]
-                session_token: String,
-            }
-            
-            let legacy_response: LegacyStsResponse = serde_json::from_str(&response_text)
-                .map_err(|e| CosError::other(format!("Legacy response parsing error: {}\nResponse: {}", e, response_text)))?;
-            
-            if legacy_response.code != 0 {
-                return Err(CosError::other(format!("STS API error: {} - {}", legacy_response.code, legacy_response.message)));
-            }
-            
-            let data = legacy_response.data
-                .ok_or_else(|| CosError::other("No data in legacy response".to_string()))?;
-            
-            Ok(TemporaryCredentials {
-                tmp_secret_id: data.credentials.tmp_secret_id,
-                tmp_secret_key: data.credentials.tmp_secret_key,
-                token: data.credentials.session_token,
-                expired_time: Some(data.expired_time),
-            })
-        }
+        Ok(self.client.get(format!("{}/?{}", self.endpoint, query_string)))
     }
-    
+
     /// 生成腾讯云 STS API 签名（使用官方SDK的简单签名方法）
     fn generate_signature(
         &self,
         params: &HashMap<&str, &str>,
     ) -> Result<String, CosError> {
+        use base64::Engine;
         use hmac::{Hmac, Mac};
         use sha1::Sha1;
         
@@ -273,171 +469,456 @@ impl StsClient {
              .map_err(|e| CosError::other(format!("HMAC key error: {}", e)))?;
          mac.update(string_to_sign.as_bytes());
          
-         let signature = base64::encode(mac.finalize().into_bytes());
+         let signature = base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
          Ok(signature)
     }
 }
 
-impl Policy {
-    /// 创建新的权限策略
-    pub fn new() -> Self {
-        Self {
-            version: "2.0".to_string(),
-            statement: Vec::new(),
+/// 当前的 Unix 时间戳（秒）
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// 解析 STS 响应中的临时密钥
+fn parse_credentials(response_text: &str) -> Result<TemporaryCredentials, CosError> {
+    // 解析响应 - 使用新版API格式
+    if response_text.contains("\"Response\"") {
+        // 新版API响应格式
+        credentials_from(parse_response(response_text)?)
+    } else {
+        // 旧版API响应格式
+        #[derive(Deserialize)]
+        struct LegacyStsResponse {
+            code: i32,
+            message: String,
+            #[serde(rename = "codeDesc")]
+            data: Option<LegacyCredentialsData>,
         }
-    }
-    
-    /// 添加策略声明
-    pub fn add_statement(mut self, statement: Statement) -> Self {
-        self.statement.push(statement);
-        self
-    }
-    
-    /// 创建允许上传对象的策略
-    pub fn allow_put_object(bucket: &str, prefix: Option<&str>) -> Self {
-        // 从bucket名称中提取appid (格式: bucket-appid)
-        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
-        let (bucket_name, appid) = if parts.len() == 2 {
-            (parts[1], parts[0])
-        } else {
-            (bucket, "*")
-        };
         
-        let resource = if let Some(prefix) = prefix {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
-        } else {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
-        };
+        #[derive(Deserialize)]
+        struct LegacyCredentialsData {
+            credentials: LegacyCredentials,
+            #[serde(rename = "expiredTime")]
+            expired_time: u64,
+        }
         
-        Self::new().add_statement(Statement {
-            effect: "allow".to_string(),
-            action: vec![
-                "name/cos:PutObject".to_string(),
-                "name/cos:PostObject".to_string(),
-                "name/cos:InitiateMultipartUpload".to_string(),
-                "name/cos:ListMultipartUploads".to_string(),
-                "name/cos:ListParts".to_string(),
-                "name/cos:UploadPart".to_string(),
-                "name/cos:CompleteMultipartUpload".to_string(),
-            ],
-            resource: vec![resource],
-            condition: None,
-        })
-    }
-    
-    /// 创建允许下载对象的策略
-    pub fn allow_get_object(bucket: &str, prefix: Option<&str>) -> Self {
-        // 从bucket名称中提取appid (格式: bucket-appid)
-        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
-        let (bucket_name, appid) = if parts.len() == 2 {
-            (parts[1], parts[0])
-        } else {
-            (bucket, "*")
-        };
+        #[derive(Deserialize)]
+        struct LegacyCredentials {
+            #[serde(rename = "tmpSecretId")]
+            tmp_secret_id: String,
+            #[serde(rename = "tmpSecretKey")]
+            tmp_secret_key: String,
+            #[serde(rename = "sessionToken")]
+            session_token: String,
+        }
         
-        let resource = if let Some(prefix) = prefix {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
-        } else {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
-        };
+        let legacy_response: LegacyStsResponse = serde_json::from_str(response_text)
+            .map_err(|e| CosError::other(format!("Legacy response parsing error: {}\nResponse: {}", e, response_text)))?;
         
-        Self::new().add_statement(Statement {
-            effect: "allow".to_string(),
-            action: vec![
-                "name/cos:GetObject".to_string(),
-                "name/cos:HeadObject".to_string(),
-            ],
-            resource: vec![resource],
-            condition: None,
-        })
-    }
-    
-    /// 创建允许删除对象的策略
-    pub fn allow_delete_object(bucket: &str, prefix: Option<&str>) -> Self {
-        // 从bucket名称中提取appid (格式: bucket-appid)
-        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
-        let (bucket_name, appid) = if parts.len() == 2 {
-            (parts[1], parts[0])
-        } else {
-            (bucket, "*")
-        };
+        if legacy_response.code != 0 {
+            return Err(sts_error(legacy_response.code.to_string(), legacy_response.message, None));
+        }
         
-        let resource = if let Some(prefix) = prefix {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
-        } else {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
-        };
+        let data = legacy_response.data
+            .ok_or_else(|| CosError::other("No data in legacy response".to_string()))?;
         
-        Self::new().add_statement(Statement {
-            effect: "allow".to_string(),
-            action: vec![
-                "name/cos:DeleteObject".to_string(),
-            ],
-            resource: vec![resource],
-            condition: None,
+        Ok(TemporaryCredentials {
+            tmp_secret_id: data.credentials.tmp_secret_id,
+            tmp_secret_key: data.credentials.tmp_secret_key,
+            token: data.credentials.session_token,
+            expired_time: Some(data.expired_time),
         })
     }
-    
-    /// 创建允许上传和下载对象的策略
-    pub fn allow_read_write(bucket: &str, prefix: Option<&str>) -> Self {
-        // 从bucket名称中提取appid (格式: bucket-appid)
-        let parts: Vec<&str> = bucket.rsplitn(2, '-').collect();
-        let (bucket_name, appid) = if parts.len() == 2 {
-            (parts[1], parts[0])
-        } else {
-            (bucket, "*")
-        };
-        
-        let resource = if let Some(prefix) = prefix {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/{}*", appid, appid, bucket_name, prefix)
-        } else {
-            format!("qcs::cos:*:uid/{}:prefix//{}/{}/*", appid, appid, bucket_name)
-        };
-        
-        Self::new().add_statement(Statement {
-            effect: "allow".to_string(),
-            action: vec![
-                "name/cos:PutObject".to_string(),
-                "name/cos:PostObject".to_string(),
-                "name/cos:GetObject".to_string(),
-                "name/cos:HeadObject".to_string(),
-                "name/cos:DeleteObject".to_string(),
-                "name/cos:InitiateMultipartUpload".to_string(),
-                "name/cos:ListMultipartUploads".to_string(),
-                "name/cos:ListParts".to_string(),
-                "name/cos:UploadPart".to_string(),
-                "name/cos:CompleteMultipartUpload".to_string(),
-            ],
-            resource: vec![resource],
-            condition: None,
-        })
+}
+
+/// 解析新版 API 的响应，STS 返回错误时转换为 [`CosError`]
+fn parse_response(response_text: &str) -> Result<StsResponseData, CosError> {
+    let sts_response: StsResponse = serde_json::from_str(response_text)
+        .map_err(|e| CosError::other(format!("Response parsing error: {}\nResponse: {}", e, response_text)))?;
+    let data = sts_response.response;
+    match data.error {
+        Some(error) => Err(sts_error(error.code, error.message, data.request_id)),
+        None => Ok(data),
+    }
+}
+
+/// 取出响应中的临时密钥，过期时间位于响应的顶层
+fn credentials_from(data: StsResponseData) -> Result<TemporaryCredentials, CosError> {
+    let mut credentials = data
+        .credentials
+        .ok_or_else(|| CosError::other("No credentials in response".to_string()))?;
+    if let Some(expired_time) = data.expired_time {
+        credentials.expired_time = Some(expired_time);
     }
+    Ok(credentials)
 }
 
-impl Default for Policy {
-    fn default() -> Self {
-        Self::new()
+/// STS 错误码保持原样：`InternalError` 类错误为服务端错误，其余为客户端错误
+fn sts_error(code: String, message: String, request_id: Option<String>) -> CosError {
+    if code.starts_with("InternalError") {
+        CosError::Server {
+            code,
+            message,
+            status: None,
+            request_id,
+            details: None,
+        }
+    } else {
+        CosError::Client {
+            code,
+            message,
+            status: None,
+            request_id,
+            details: None,
+        }
+    }
+}
+
+impl From<TemporaryCredentials> for Credentials {
+    fn from(credentials: TemporaryCredentials) -> Self {
+        Self {
+            secret_id: credentials.tmp_secret_id,
+            secret_key: credentials.tmp_secret_key,
+            security_token: Some(credentials.token),
+            expires_at: credentials
+                .expired_time
+                .and_then(|time| chrono::DateTime::from_timestamp(time as i64, 0)),
+        }
     }
 }
 
+/// 自动刷新临时密钥的凭证提供者
+///
+/// 按请求模板向 STS 获取临时密钥并缓存，距离过期不足刷新余量（默认 5 分钟）时重新获取，
+/// 并发的刷新会被串行化，同一时间只有一次 STS 请求。刷新失败时若缓存的密钥尚未过期则继续使用，否则返回错误。
+///
+/// ```no_run
+/// # async fn example() -> cos_rust_sdk::Result<()> {
+/// use cos_rust_sdk::sts::{GetCredentialsRequest, Policy, StsClient, StsCredentialsProvider};
+/// use cos_rust_sdk::{Config, CosClient};
+///
+/// let sts = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string());
+/// let provider = StsCredentialsProvider::new(
+///     sts,
+///     GetCredentialsRequest {
+///         name: Some("uploader".to_string()),
+///         policy: Policy::allow_put_object("bucket-1250000000", Some("uploads/")),
+///         duration_seconds: Some(1800),
+///     },
+/// );
+/// let config = Config::new("", "", "ap-beijing", "bucket-1250000000").with_credentials_provider(provider);
+/// let client = CosClient::new(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct StsCredentialsProvider {
+    client: StsClient,
+    request: GetCredentialsRequest,
+    refresh_margin: Duration,
+    cached: Mutex<Option<Credentials>>,
+}
+
+impl StsCredentialsProvider {
+    /// 创建按 `request` 获取临时密钥的提供者
+    pub fn new(client: StsClient, request: GetCredentialsRequest) -> Self {
+        Self {
+            client,
+            request,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// 设置刷新余量，距离过期不足该时长时刷新临时密钥
+    pub fn with_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    async fn fetch(&self) -> Result<Credentials, CosError> {
+        let fetched_at = chrono::Utc::now();
+        let temporary = self.client.get_credentials(self.request.clone()).await?;
+        let mut credentials = Credentials::from(temporary);
+        if credentials.expires_at.is_none() {
+            // 响应未包含过期时间时按请求的有效期推算
+            let duration = self.request.duration_seconds.unwrap_or(1800);
+            credentials.expires_at = Some(fetched_at + chrono::Duration::seconds(duration.into()));
+        }
+        Ok(credentials)
+    }
+}
+
+impl CredentialsProvider for StsCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, CosError>> {
+        Box::pin(async move {
+            let mut cached = self.cached.lock().await;
+            let margin = chrono::Duration::from_std(self.refresh_margin).unwrap_or(chrono::Duration::zero());
+            if let Some(credentials) = cached.as_ref().filter(|c| !c.expires_within(margin)) {
+                return Ok(credentials.clone());
+            }
+
+            match self.fetch().await {
+                Ok(credentials) => {
+                    *cached = Some(credentials.clone());
+                    Ok(credentials)
+                }
+                Err(e) => match cached.as_ref().filter(|c| !c.expires_within(chrono::Duration::zero())) {
+                    Some(credentials) => {
+                        log::warn!("Failed to refresh STS credentials, using the cached ones: {}", e);
+                        Ok(credentials.clone())
+                    }
+                    None => Err(e),
+                },
+            }
+        })
+    }
+
+    fn refresh_margin(&self) -> Duration {
+        self.refresh_margin
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    
-    #[test]
-    fn test_policy_creation() {
-        let policy = Policy::allow_put_object("test-bucket-1234567890", Some("uploads/"));
-        assert_eq!(policy.version, "2.0");
-        assert_eq!(policy.statement.len(), 1);
-        assert_eq!(policy.statement[0].effect, "allow");
-        assert!(policy.statement[0].action.contains(&"cos:PutObject".to_string()));
+    use crate::test_server::{serve, ServedResponse};
+    use std::sync::Arc;
+
+    fn credentials_response(id: &str, expires_in: i64) -> ServedResponse {
+        let expired_time = chrono::Utc::now().timestamp() + expires_in;
+        ServedResponse::new(
+            200,
+            &format!(
+                r#"{{"Response":{{"Credentials":{{"TmpSecretId":"{}","TmpSecretKey":"key","Token":"token-{}"}},"ExpiredTime":{},"RequestId":"r"}}}}"#,
+                id, id, expired_time
+            ),
+        )
+    }
+
+    fn provider_for(addr: &str) -> StsCredentialsProvider {
+        let client = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string())
+            .with_endpoint(format!("http://{}", addr));
+        StsCredentialsProvider::new(
+            client,
+            GetCredentialsRequest {
+                policy: Policy::allow_put_object("test-bucket-123", None),
+                duration_seconds: Some(1800),
+                name: None,
+            },
+        )
+    }
+
+    fn failure_response() -> ServedResponse {
+        ServedResponse::new(
+            200,
+            r#"{"Response":{"Error":{"Code":"InternalError","Message":"busy"},"RequestId":"r"}}"#,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_credentials_signature_methods() {
+        let request = || GetCredentialsRequest {
+            policy: Policy::allow_put_object("test-bucket-123", None),
+            duration_seconds: Some(900),
+            name: Some("uploader".to_string()),
+        };
+
+        // 默认使用签名方法 v3，参数放在 JSON 请求体中
+        let (addr, requests) = serve(vec![credentials_response("tmp-1", 900)]).await;
+        let client = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string())
+            .with_endpoint(format!("http://{}", addr));
+        assert_eq!(client.get_credentials(request()).await.unwrap().tmp_secret_id, "tmp-1");
+        let recorded = requests.lock().unwrap().remove(0);
+        assert_eq!(recorded.request_line, "POST / HTTP/1.1");
+        assert_eq!(recorded.header("x-tc-action"), Some("GetFederationToken"));
+        assert_eq!(recorded.header("x-tc-version"), Some("2018-08-13"));
+        assert_eq!(recorded.header("x-tc-region"), Some("ap-beijing"));
+        assert_eq!(recorded.header("content-type"), Some(tc3::CONTENT_TYPE));
+        let body: serde_json::Value = serde_json::from_slice(&recorded.body).unwrap();
+        assert_eq!(body["DurationSeconds"], 900);
+        assert_eq!(body["Name"], "uploader");
+        assert!(body["Policy"].as_str().unwrap().starts_with("%7B"));
+
+        let payload = String::from_utf8(recorded.body.clone()).unwrap();
+        let expected = Tc3Request {
+            service: "sts",
+            host: recorded.header("host").unwrap(),
+            timestamp: recorded.header("x-tc-timestamp").unwrap().parse().unwrap(),
+            payload: &payload,
+        }
+        .authorization("id", "key")
+        .unwrap();
+        assert_eq!(recorded.header("authorization"), Some(expected.as_str()));
+
+        // 旧版签名方法 v1 以 GET + URL 参数发送
+        let (addr, requests) = serve(vec![credentials_response("tmp-2", 900)]).await;
+        let client = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string())
+            .with_endpoint(format!("http://{}", addr))
+            .with_signature_method(StsSignatureMethod::HmacSha1);
+        assert_eq!(client.get_credentials(request()).await.unwrap().tmp_secret_id, "tmp-2");
+        let recorded = requests.lock().unwrap().remove(0);
+        assert!(recorded.request_line.starts_with("GET /?"), "{}", recorded.request_line);
+        for param in ["Action=GetFederationToken", "DurationSeconds=900", "Name=uploader", "Signature="] {
+            assert!(recorded.request_line.contains(param), "{}", recorded.request_line);
+        }
+        assert!(recorded.header("authorization").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_assume_role() {
+        let expired_time = chrono::Utc::now().timestamp() + 3600;
+        let (addr, requests) = serve(vec![
+            ServedResponse::new(
+                200,
+                &format!(
+                    r#"{{"Response":{{"Credentials":{{"TmpSecretId":"tmp-role","TmpSecretKey":"key","Token":"token"}},"ExpiredTime":{},"Expiration":"2026-10-14T12:00:00Z","AssumedRoleUser":{{"Arn":"qcs::sts:100000000001:assumed-role/4611686018427397919/ci","AssumedRoleId":"4611686018427397919:ci"}},"RequestId":"r"}}}}"#,
+                    expired_time
+                ),
+            ),
+            ServedResponse::new(
+                200,
+                r#"{"Response":{"Error":{"Code":"InvalidParameter.ParamError","Message":"DurationSeconds exceeds the max session duration"},"RequestId":"req-2"}}"#,
+            ),
+        ])
+        .await;
+        let client = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string())
+            .with_endpoint(format!("http://{}", addr));
+
+        let response = client
+            .assume_role(AssumeRoleRequest {
+                role_arn: "qcs::cam::uin/100000000001:roleName/ci".to_string(),
+                role_session_name: "ci".to_string(),
+                policy: Some(Policy::allow_put_object("test-bucket-123", None)),
+                duration_seconds: Some(3600),
+                external_id: Some("ext-1".to_string()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.credentials.tmp_secret_id, "tmp-role");
+        assert_eq!(response.credentials.expired_time, Some(expired_time as u64));
+        let user = response.assumed_role_user.unwrap();
+        assert_eq!(user.assumed_role_id, "4611686018427397919:ci");
+        assert!(user.arn.ends_with("/ci"));
+
+        let recorded = requests.lock().unwrap().remove(0);
+        assert_eq!(recorded.header("x-tc-action"), Some("AssumeRole"));
+        let body: serde_json::Value = serde_json::from_slice(&recorded.body).unwrap();
+        assert_eq!(body["RoleArn"], "qcs::cam::uin/100000000001:roleName/ci");
+        assert_eq!(body["RoleSessionName"], "ci");
+        assert_eq!(body["DurationSeconds"], 3600);
+        assert_eq!(body["ExternalId"], "ext-1");
+        assert!(body["Policy"].as_str().unwrap().starts_with("%7B"));
+
+        // 未设置的可选参数不发送，STS 错误码保持原样
+        let err = client
+            .assume_role(AssumeRoleRequest {
+                role_arn: "qcs::cam::uin/100000000001:roleName/ci".to_string(),
+                role_session_name: "ci".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some("InvalidParameter.ParamError"));
+        assert_eq!(err.request_id(), Some("req-2"));
+        assert!(matches!(err, CosError::Client { .. }), "{:?}", err);
+        let body: serde_json::Value = serde_json::from_slice(&requests.lock().unwrap()[0].body).unwrap();
+        assert_eq!(body.as_object().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_credentials_cached() {
+        let (addr, requests) = serve(vec![
+            credentials_response("tmp-1", 3600),
+            credentials_response("tmp-2", 120),
+            failure_response(),
+            credentials_response("tmp-3", 3600),
+        ])
+        .await;
+        let client = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string())
+            .with_endpoint(format!("http://{}", addr));
+        let request = |name: &str| GetCredentialsRequest {
+            policy: Policy::allow_put_object("test-bucket-123", None),
+            duration_seconds: Some(1800),
+            name: Some(name.to_string()),
+        };
+
+        // 并发调用只发出一次 STS 请求，副本共享缓存
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                let request = request("uploader");
+                tokio::spawn(async move { client.get_credentials_cached(request).await.unwrap().tmp_secret_id })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), "tmp-1");
+        }
+        assert_eq!(client.cache_stats(), StsCacheStats { hits: 3, misses: 1 });
+
+        // 不同的请求参数使用各自的缓存；剩余 2 分钟不足刷新余量，下次调用重新获取
+        assert_eq!(client.get_credentials_cached(request("reader")).await.unwrap().tmp_secret_id, "tmp-2");
+        let err = client.get_credentials_cached(request("reader")).await.unwrap_err();
+        assert_eq!(err.code(), Some("InternalError"));
+        assert_eq!(client.get_credentials_cached(request("reader")).await.unwrap().tmp_secret_id, "tmp-3");
+        assert_eq!(client.get_credentials_cached(request("uploader")).await.unwrap().tmp_secret_id, "tmp-1");
+        assert_eq!(client.cache_stats(), StsCacheStats { hits: 4, misses: 4 });
+        assert_eq!(requests.lock().unwrap().len(), 4);
+
+        // 调小刷新余量后剩余 2 分钟的密钥也可以复用
+        let (addr, requests) = serve(vec![credentials_response("tmp-4", 120)]).await;
+        let client = StsClient::new("id".to_string(), "key".to_string(), "ap-beijing".to_string())
+            .with_endpoint(format!("http://{}", addr))
+            .with_cache_margin(Duration::from_secs(30));
+        for _ in 0..2 {
+            assert_eq!(client.get_credentials_cached(request("reader")).await.unwrap().tmp_secret_id, "tmp-4");
+        }
+        assert_eq!(requests.lock().unwrap().len(), 1);
     }
-    
-    #[test]
-    fn test_policy_serialization() {
-        let policy = Policy::allow_read_write("test-bucket", None);
-        let json = serde_json::to_string(&policy).unwrap();
-        assert!(json.contains("version"));
-        assert!(json.contains("statement"));
+
+    #[tokio::test]
+    async fn test_sts_credentials_provider_refreshes() {
+        let (addr, requests) = serve(vec![
+            // 剩余 2 分钟，不足默认的 5 分钟刷新余量
+            credentials_response("tmp-1", 120),
+            failure_response(),
+            credentials_response("tmp-2", 3600),
+        ])
+        .await;
+        let provider = provider_for(&addr);
+
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.secret_id, "tmp-1");
+        assert_eq!(credentials.security_token.as_deref(), Some("token-tmp-1"));
+        assert!(credentials.expires_at.is_some());
+
+        // 刷新失败时继续使用尚未过期的密钥
+        assert_eq!(provider.credentials().await.unwrap().secret_id, "tmp-1");
+        assert_eq!(provider.credentials().await.unwrap().secret_id, "tmp-2");
+        assert_eq!(provider.credentials().await.unwrap().secret_id, "tmp-2");
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        // 并发获取只发出一次 STS 请求
+        let (addr, requests) = serve(vec![credentials_response("tmp-3", 3600)]).await;
+        let provider = Arc::new(provider_for(&addr));
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let provider = provider.clone();
+                tokio::spawn(async move { provider.credentials().await.unwrap().secret_id })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), "tmp-3");
+        }
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // 没有可用的缓存密钥时返回错误
+        let (addr, _) = serve(vec![failure_response()]).await;
+        let err = provider_for(&addr).credentials().await.unwrap_err();
+        assert!(err.to_string().contains("InternalError"), "{}", err);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,114 @@
+//! 软链接模块
+//!
+//! 提供指向同一存储桶内其他对象的软链接对象的创建与查询
+
+use crate::client::encode_key;
+use crate::error::{CosError, Result};
+use crate::object::{header_str, ObjectClient, PutObjectResponse};
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+
+/// 软链接目标请求头
+const SYMLINK_TARGET_HEADER: &str = "x-cos-symlink-target";
+
+/// 创建软链接选项
+#[derive(Debug, Clone, Default)]
+pub struct PutSymlinkOptions {
+    /// 禁止覆盖同名对象，链接已存在时返回 [`CosError::PreconditionFailed`]
+    pub forbid_overwrite: bool,
+    /// 额外的请求头
+    pub headers: HashMap<String, String>,
+}
+
+impl ObjectClient {
+    /// 创建指向 `target_key` 的软链接
+    ///
+    /// 软链接本身是一个对象，再次创建同名软链接会更新其指向，适合实现 "latest" 一类的指针。
+    pub async fn put_symlink(
+        &self,
+        link_key: &str,
+        target_key: &str,
+        options: PutSymlinkOptions,
+    ) -> Result<PutObjectResponse> {
+        let mut headers = options.headers;
+        headers.insert(
+            SYMLINK_TARGET_HEADER.to_string(),
+            encode_key(target_key.trim_start_matches('/')),
+        );
+        if options.forbid_overwrite {
+            headers.insert("x-cos-forbid-overwrite".to_string(), "true".to_string());
+        }
+
+        let response = self
+            .client
+            .put_with_headers(&self.object_path(link_key)?, symlink_params(), headers, None::<&[u8]>)
+            .await?;
+
+        Ok(PutObjectResponse::from_headers(response.headers()))
+    }
+
+    /// 获取软链接指向的目标对象键
+    pub async fn get_symlink(&self, link_key: &str) -> Result<String> {
+        let response = self
+            .client
+            .get(&self.object_path(link_key)?, symlink_params())
+            .await?;
+
+        symlink_target_from_headers(response.headers())
+            .ok_or_else(|| CosError::other(format!("Object {} is not a symlink", link_key)))
+    }
+}
+
+fn symlink_params() -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("symlink".to_string(), "".to_string());
+    params
+}
+
+/// 从响应头中解析软链接目标，目标对象键在请求头中经过 URL 编码
+pub(crate) fn symlink_target_from_headers(headers: &HeaderMap) -> Option<String> {
+    let target = header_str(headers, SYMLINK_TARGET_HEADER)?;
+    Some(
+        urlencoding::decode(target)
+            .map(|s| s.into_owned())
+            .unwrap_or_else(|_| target.to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    #[tokio::test]
+    async fn test_symlink_round_trip() {
+        let client = ObjectClient::new(
+            client_for(vec![
+                ServedResponse::new(200, "").with_header("ETag", "\"abc\""),
+                ServedResponse::new(200, "")
+                    .with_header("x-cos-symlink-target", "builds/v1.2%20rc/%E5%8C%85.tar.gz"),
+                ServedResponse::new(200, ""),
+            ])
+            .await,
+        );
+
+        let response = client
+            .put_symlink("builds/latest", "builds/v1.2 rc/包.tar.gz", PutSymlinkOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(response.etag, "\"abc\"");
+
+        assert_eq!(client.get_symlink("builds/latest").await.unwrap(), "builds/v1.2 rc/包.tar.gz");
+        assert!(client.get_symlink("builds/plain").await.is_err());
+    }
+
+    #[test]
+    fn test_symlink_target_header() {
+        assert_eq!(encode_key("dir/a b+c.txt"), "dir/a%20b%2Bc.txt");
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(symlink_target_from_headers(&headers), None);
+        headers.insert(SYMLINK_TARGET_HEADER, "dir/a%20b%2Bc.txt".parse().unwrap());
+        assert_eq!(symlink_target_from_headers(&headers).as_deref(), Some("dir/a b+c.txt"));
+    }
+}
@@ -0,0 +1,197 @@
+//! 标签模块
+//!
+//! 提供存储桶与对象共用的标签类型，以及对象标签的读写接口；存储桶标签接口见 [`BucketClient`](crate::BucketClient)
+
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use crate::object::{version_params, ObjectClient};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 单个对象的标签数量上限
+pub const MAX_OBJECT_TAGS: usize = 10;
+
+/// 单个存储桶的标签数量上限
+pub const MAX_BUCKET_TAGS: usize = 50;
+
+/// 标签键的最大长度
+pub const MAX_TAG_KEY_LENGTH: usize = 128;
+
+/// 标签值的最大长度
+pub const MAX_TAG_VALUE_LENGTH: usize = 256;
+
+/// 标签
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+impl Tag {
+    /// 创建标签
+    pub fn new<K: Into<String>, V: Into<String>>(key: K, value: V) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// 标签集合的 XML 结构
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "Tagging")]
+pub(crate) struct Tagging {
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: TagSet,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct TagSet {
+    #[serde(rename = "Tag", default)]
+    pub tags: Vec<Tag>,
+}
+
+impl ObjectClient {
+    /// 设置对象标签，会覆盖对象已有的全部标签
+    pub async fn put_object_tagging(
+        &self,
+        key: &str,
+        tags: &[(String, String)],
+        version_id: Option<&str>,
+    ) -> Result<()> {
+        let tags: Vec<Tag> = tags.iter().map(|(k, v)| Tag::new(k.as_str(), v.as_str())).collect();
+        validate_tags(&tags, MAX_OBJECT_TAGS)?;
+
+        let tagging = Tagging {
+            tag_set: TagSet { tags },
+        };
+        let xml_body = quick_xml::se::to_string(&tagging)
+            .map_err(|e| CosError::other(format!("Failed to serialize tagging: {}", e)))?;
+
+        let _response = self
+            .client
+            .put(&self.object_path(key)?, tagging_params(version_id), Some(xml_body))
+            .await?;
+        Ok(())
+    }
+
+    /// 获取对象标签
+    pub async fn get_object_tagging(&self, key: &str, version_id: Option<&str>) -> Result<Vec<Tag>> {
+        let response = self
+            .client
+            .get(&self.object_path(key)?, tagging_params(version_id))
+            .await?;
+        let tagging: Tagging = CosClient::parse_xml(response).await?;
+
+        Ok(tagging.tag_set.tags)
+    }
+
+    /// 删除对象标签
+    pub async fn delete_object_tagging(&self, key: &str, version_id: Option<&str>) -> Result<()> {
+        let _response = self
+            .client
+            .delete(&self.object_path(key)?, tagging_params(version_id))
+            .await?;
+        Ok(())
+    }
+}
+
+/// 标签子资源的查询参数
+fn tagging_params(version_id: Option<&str>) -> HashMap<String, String> {
+    let mut params = version_params(version_id);
+    params.insert("tagging".to_string(), "".to_string());
+    params
+}
+
+/// 校验标签数量、键值长度以及键是否重复
+pub(crate) fn validate_tags(tags: &[Tag], max_tags: usize) -> Result<()> {
+    if tags.len() > max_tags {
+        return Err(CosError::config(format!(
+            "Too many tags: {} (at most {})",
+            tags.len(),
+            max_tags
+        )));
+    }
+
+    let mut keys = HashSet::new();
+    for tag in tags {
+        let key_length = tag.key.chars().count();
+        if key_length == 0 || key_length > MAX_TAG_KEY_LENGTH {
+            return Err(CosError::config(format!(
+                "Tag key {:?} must be 1 to {} characters",
+                tag.key, MAX_TAG_KEY_LENGTH
+            )));
+        }
+        if tag.value.chars().count() > MAX_TAG_VALUE_LENGTH {
+            return Err(CosError::config(format!(
+                "Value of tag {:?} exceeds {} characters",
+                tag.key, MAX_TAG_VALUE_LENGTH
+            )));
+        }
+        if !keys.insert(tag.key.as_str()) {
+            return Err(CosError::config(format!("Duplicate tag key: {:?}", tag.key)));
+        }
+    }
+
+    Ok(())
+}
+
+/// 生成上传时使用的 `x-cos-tagging` 请求头的值
+pub(crate) fn tagging_header(tags: &[Tag]) -> Result<String> {
+    validate_tags(tags, MAX_OBJECT_TAGS)?;
+
+    Ok(tags
+        .iter()
+        .map(|tag| format!("{}={}", urlencoding::encode(&tag.key), urlencoding::encode(&tag.value)))
+        .collect::<Vec<_>>()
+        .join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tagging_xml_round_trip() {
+        let tagging = Tagging {
+            tag_set: TagSet {
+                tags: vec![Tag::new("team", "storage"), Tag::new("env", "prod")],
+            },
+        };
+        let xml = quick_xml::se::to_string(&tagging).unwrap();
+        assert_eq!(
+            xml,
+            "<Tagging><TagSet><Tag><Key>team</Key><Value>storage</Value></Tag>\
+             <Tag><Key>env</Key><Value>prod</Value></Tag></TagSet></Tagging>"
+        );
+
+        let parsed: Tagging = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed.tag_set.tags, tagging.tag_set.tags);
+
+        let empty: Tagging = quick_xml::de::from_str("<Tagging><TagSet></TagSet></Tagging>").unwrap();
+        assert!(empty.tag_set.tags.is_empty());
+    }
+
+    #[test]
+    fn test_tagging_header() {
+        let tags = vec![Tag::new("team", "storage ops"), Tag::new("项目", "a&b")];
+        assert_eq!(
+            tagging_header(&tags).unwrap(),
+            "team=storage%20ops&%E9%A1%B9%E7%9B%AE=a%26b"
+        );
+    }
+
+    #[test]
+    fn test_validate_tags() {
+        let too_many: Vec<Tag> = (0..11).map(|i| Tag::new(format!("k{}", i), "v")).collect();
+        assert!(validate_tags(&too_many, MAX_OBJECT_TAGS).is_err());
+
+        assert!(validate_tags(&[Tag::new("", "v")], MAX_OBJECT_TAGS).is_err());
+        assert!(validate_tags(&[Tag::new("k".repeat(129), "v")], MAX_OBJECT_TAGS).is_err());
+        assert!(validate_tags(&[Tag::new("k", "v".repeat(257))], MAX_OBJECT_TAGS).is_err());
+        assert!(validate_tags(&[Tag::new("k", "1"), Tag::new("k", "2")], MAX_OBJECT_TAGS).is_err());
+        assert!(validate_tags(&[Tag::new("k", "")], MAX_OBJECT_TAGS).is_ok());
+    }
+}
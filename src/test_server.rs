@@ -0,0 +1,195 @@
+//! 单元测试使用的本地 HTTP 服务
+//!
+//! 按顺序为每个连接返回预设的响应，用于在不访问真实 COS 的情况下测试状态码处理。
+//!
+//! 只检查 SDK 自身的请求和响应处理时优先使用 [`crate::testing::MockTransport`]。
+//! 这里的服务用于必须经过 reqwest 真实网络栈的场景，`MockTransport` 无法覆盖：
+//!
+//! - 请求超时、连接失败（[`unreachable_client`]）等只有真实连接才会产生的错误；
+//! - 经由 HTTP 代理访问真实 COS 域名（[`proxied_client_for`]），用于地域纠正等依赖 Host 的逻辑；
+//! - 默认 reqwest 传输层本身的行为，如重试时请求体的重放和实际发送的请求行、请求头
+
+use crate::client::CosClient;
+use crate::config::Config;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// 本地服务返回的预设响应，与 [`crate::testing::MockResponse`] 区分
+pub(crate) struct ServedResponse {
+    pub status: u16,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Vec<u8>,
+    /// 读取请求后等待该时长再返回响应
+    pub delay: Option<Duration>,
+}
+
+impl ServedResponse {
+    pub fn new(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.as_bytes().to_vec(),
+            delay: None,
+        }
+    }
+
+    /// 使用二进制响应体
+    pub fn bytes(status: u16, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+            delay: None,
+        }
+    }
+
+    pub fn with_header(mut self, name: &'static str, value: &str) -> Self {
+        self.headers.push((name, value.to_string()));
+        self
+    }
+
+    /// 延迟返回响应，用于模拟慢速服务；延迟期间服务继续接受后续连接
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+/// 服务收到的请求
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedRequest {
+    /// 请求行，如 `PUT /a.txt HTTP/1.1`
+    pub request_line: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// 按名称（不区分大小写）获取请求头
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// 按顺序记录服务收到的请求
+pub(crate) type RecordedRequests = Arc<Mutex<Vec<RecordedRequest>>>;
+
+/// 启动本地服务，返回指向该服务的客户端
+pub(crate) async fn client_for(responses: Vec<ServedResponse>) -> CosClient {
+    recording_client_for(responses).await.0
+}
+
+/// 启动本地服务，返回指向该服务的客户端以及服务收到的请求记录
+pub(crate) async fn recording_client_for(responses: Vec<ServedResponse>) -> (CosClient, RecordedRequests) {
+    let (addr, requests) = serve(responses).await;
+    (client_for_addr(&addr), requests)
+}
+
+/// 启动本地服务并将其作为 HTTP 代理，客户端按 `config` 访问 COS 域名，请求实际发往本地服务
+///
+/// 用于测试依赖真实域名（如地域）的逻辑，记录的请求行为代理形式，如 `GET http://<host>/a.txt HTTP/1.1`
+pub(crate) async fn proxied_client_for(config: Config, responses: Vec<ServedResponse>) -> (CosClient, RecordedRequests) {
+    let (addr, requests) = serve(responses).await;
+    let proxy = reqwest::Proxy::http(format!("http://{}", addr)).unwrap();
+    let client = CosClient::build_with(config.with_https(false), reqwest::Client::builder().proxy(proxy)).unwrap();
+    (client, requests)
+}
+
+/// 启动按顺序返回预设响应的本地服务，返回服务地址
+pub(crate) async fn serve(responses: Vec<ServedResponse>) -> (String, RecordedRequests) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = RecordedRequests::default();
+    let recorded = requests.clone();
+
+    tokio::spawn(async move {
+        for response in responses {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            if let Some(request) = read_request(&mut socket).await {
+                recorded.lock().unwrap().push(request);
+            }
+
+            let mut raw = format!("HTTP/1.1 {} Mock\r\n", response.status);
+            for (name, value) in &response.headers {
+                raw.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            raw.push_str(&format!(
+                "Content-Length: {}\r\nConnection: close\r\n\r\n",
+                response.body.len()
+            ));
+            let mut raw = raw.into_bytes();
+            raw.extend_from_slice(&response.body);
+            let delay = response.delay;
+            let reply = async move {
+                if let Some(delay) = delay {
+                    tokio::time::sleep(delay).await;
+                }
+                let _ = socket.write_all(&raw).await;
+                let _ = socket.shutdown().await;
+            };
+            match delay {
+                Some(_) => drop(tokio::spawn(reply)),
+                None => reply.await,
+            }
+        }
+    });
+
+    (addr.to_string(), requests)
+}
+
+/// 返回指向一个未监听端口的客户端，用于模拟网络故障
+pub(crate) async fn unreachable_client() -> CosClient {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    client_for_addr(&addr.to_string())
+}
+
+fn client_for_addr(addr: &str) -> CosClient {
+    let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123")
+        .with_domain(addr)
+        .with_https(false);
+    CosClient::new(config).unwrap()
+}
+
+/// 读取完整的请求（请求头及 Content-Length 指定的请求体）
+async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<RecordedRequest> {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = socket.read(&mut buf).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        data.extend_from_slice(&buf[..n]);
+
+        if let Some(end) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&data[..end]).to_string();
+            let mut lines = head.split("\r\n");
+            let request_line = lines.next().unwrap_or_default().to_string();
+            let headers: Vec<(String, String)> = lines
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .collect();
+            let content_length: usize = headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, v)| v.parse().ok())
+                .unwrap_or(0);
+            if data.len() >= end + 4 + content_length {
+                return Some(RecordedRequest {
+                    request_line,
+                    headers,
+                    body: data[end + 4..end + 4 + content_length].to_vec(),
+                });
+            }
+        }
+    }
+}
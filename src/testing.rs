@@ -0,0 +1,220 @@
+//! 测试工具
+//!
+//! 开启 `test-util` feature 后可用。[`MockTransport`] 代替真实的网络请求，按顺序返回预设的响应并记录收到的请求，
+//! 用于在不访问 COS 的情况下对使用 SDK 的代码做单元测试。请求在记录前已完成签名，可以检查签名相关的请求头。
+//!
+//! ```
+//! use cos_rust_sdk::testing::{MockResponse, MockTransport};
+//! use cos_rust_sdk::{Config, ObjectClient};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> cos_rust_sdk::Result<()> {
+//! let transport = MockTransport::new();
+//! transport.push_response(MockResponse::new(200, "hello").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""));
+//!
+//! let config = Config::new("id", "key", "ap-beijing", "bucket-1250000000");
+//! let client = ObjectClient::new(transport.client(config)?);
+//! let response = client.get_object("a.txt").await?;
+//! assert_eq!(&response.data[..], b"hello");
+//!
+//! let request = transport.last_request().unwrap();
+//! assert_eq!(request.method, "GET");
+//! assert_eq!(request.url.path(), "/a.txt");
+//! assert!(request.signed_headers().contains(&"host".to_string()));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::CosClient;
+use crate::config::Config;
+use crate::error::{CosError, Result};
+use crate::transport::HttpTransport;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Request, Response};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// 返回预设响应的传输层
+///
+/// 克隆得到的副本共享响应队列与请求记录，可以在交给客户端后继续添加响应和检查请求。
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: VecDeque<MockResponse>,
+    requests: Vec<CapturedRequest>,
+}
+
+impl MockTransport {
+    /// 创建没有预设响应的传输层
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按配置创建使用该传输层的客户端
+    pub fn client(&self, config: Config) -> Result<CosClient> {
+        Ok(CosClient::new(config)?.with_transport(Arc::new(self.clone())))
+    }
+
+    /// 添加一个响应，请求按添加的顺序依次得到响应
+    pub fn push_response(&self, response: MockResponse) -> &Self {
+        self.lock().responses.push_back(response);
+        self
+    }
+
+    /// 收到的所有请求
+    pub fn requests(&self) -> Vec<CapturedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// 最近收到的请求
+    pub fn last_request(&self) -> Option<CapturedRequest> {
+        self.lock().requests.last().cloned()
+    }
+
+    /// 尚未使用的响应数量
+    pub fn pending_responses(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        let captured = CapturedRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request.body().and_then(|body| body.as_bytes()).map(Bytes::copy_from_slice),
+        };
+        let response = {
+            let mut state = self.lock();
+            state.requests.push(captured);
+            state.responses.pop_front()
+        };
+
+        Box::pin(async move {
+            match response {
+                Some(response) => response.into_response(),
+                None => Err(CosError::other(format!(
+                    "No mock response queued for {} {}",
+                    request.method(),
+                    request.url()
+                ))),
+            }
+        })
+    }
+}
+
+/// 预设响应
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Bytes,
+}
+
+impl MockResponse {
+    /// 创建响应，未设置 `Content-Length` 时按响应体长度填写
+    pub fn new<B: Into<Bytes>>(status: u16, body: B) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// 创建 COS 格式的错误响应
+    pub fn error(status: u16, code: &str, message: &str) -> Self {
+        Self::new(
+            status,
+            format!(
+                "<?xml version='1.0' encoding='utf-8' ?><Error><Code>{}</Code><Message>{}</Message></Error>",
+                code, message
+            ),
+        )
+    }
+
+    /// 添加响应头
+    pub fn with_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    fn into_response(self) -> Result<Response> {
+        let mut builder = http::Response::builder().status(self.status);
+        let has_length = self
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("content-length"));
+        for (name, value) in &self.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        if !has_length {
+            builder = builder.header("Content-Length", self.body.len());
+        }
+        let response = builder
+            .body(self.body)
+            .map_err(|e| CosError::other(format!("Invalid mock response: {}", e)))?;
+        Ok(Response::from(response))
+    }
+}
+
+/// 传输层收到的请求
+#[derive(Debug, Clone)]
+pub struct CapturedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// 请求体，流式请求体无法记录，为空
+    pub body: Option<Bytes>,
+}
+
+impl CapturedRequest {
+    /// 按名称（不区分大小写）获取请求头
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// 获取解码后的查询参数
+    pub fn query_param(&self, name: &str) -> Option<String> {
+        self.url
+            .query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    }
+
+    /// 参与签名的请求头名称（小写），取自 Authorization 中的 `q-header-list`
+    pub fn signed_headers(&self) -> Vec<String> {
+        self.header("authorization")
+            .and_then(|auth| auth.split('&').find_map(|pair| pair.strip_prefix("q-header-list=")))
+            .map(|list| list.split(';').filter(|name| !name.is_empty()).map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::ObjectClient;
+
+    #[tokio::test]
+    async fn test_mock_transport_without_response() {
+        let transport = MockTransport::new();
+        let config = Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123");
+        let client = ObjectClient::new(transport.client(config).unwrap());
+
+        let err = client.delete_object("a.txt").await.unwrap_err();
+        assert!(err.to_string().contains("No mock response queued for DELETE"), "{}", err);
+        assert_eq!(transport.requests().len(), 1);
+    }
+}
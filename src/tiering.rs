@@ -0,0 +1,118 @@
+//! 智能分层模块
+//!
+//! 提供存储桶智能分层存储配置的读写接口
+//!
+//! 参考文档：<https://cloud.tencent.com/document/product/436/54007>
+
+use crate::bucket::BucketClient;
+use crate::client::CosClient;
+use crate::error::{CosError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 对象沉降到低频层允许的连续未访问天数
+pub const INTELLIGENT_TIERING_DAYS: [u32; 3] = [30, 60, 90];
+
+/// 智能分层配置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "IntelligentTieringConfiguration")]
+pub struct IntelligentTieringConfiguration {
+    /// 开启后不能关闭
+    #[serde(rename = "Status")]
+    pub status: IntelligentTieringStatus,
+    #[serde(rename = "Transition", skip_serializing_if = "Option::is_none")]
+    pub transition: Option<IntelligentTieringTransition>,
+}
+
+impl IntelligentTieringConfiguration {
+    /// 开启智能分层，对象连续 `days` 天未被访问后沉降到低频层
+    pub fn enabled(days: u32) -> Self {
+        Self {
+            status: IntelligentTieringStatus::Enabled,
+            transition: Some(IntelligentTieringTransition {
+                days,
+                request_frequent: 1,
+            }),
+        }
+    }
+}
+
+/// 智能分层状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntelligentTieringStatus {
+    Enabled,
+    Suspended,
+}
+
+/// 沉降到低频层的条件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntelligentTieringTransition {
+    /// 连续未访问的天数，取值为 30、60 或 90
+    #[serde(rename = "Days")]
+    pub days: u32,
+    /// 统计周期内的访问次数阈值，目前固定为 1
+    #[serde(rename = "RequestFrequent", default = "default_request_frequent")]
+    pub request_frequent: u32,
+}
+
+fn default_request_frequent() -> u32 {
+    1
+}
+
+impl BucketClient {
+    /// 设置存储桶智能分层配置
+    pub async fn put_bucket_intelligent_tiering(&self, config: &IntelligentTieringConfiguration) -> Result<()> {
+        if let Some(ref transition) = config.transition {
+            if !INTELLIGENT_TIERING_DAYS.contains(&transition.days) {
+                return Err(CosError::config(format!(
+                    "Intelligent tiering days must be one of {:?}, got {}",
+                    INTELLIGENT_TIERING_DAYS, transition.days
+                )));
+            }
+        }
+        let xml_body = quick_xml::se::to_string(config)
+            .map_err(|e| CosError::other(format!("Failed to serialize intelligent tiering configuration: {}", e)))?;
+
+        self.client.put("/", tiering_params(), Some(xml_body)).await?;
+        Ok(())
+    }
+
+    /// 获取存储桶智能分层配置
+    pub async fn get_bucket_intelligent_tiering(&self) -> Result<IntelligentTieringConfiguration> {
+        let response = self.client.get("/", tiering_params()).await?;
+        CosClient::parse_xml(response).await
+    }
+}
+
+/// 智能分层子资源的查询参数
+fn tiering_params() -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("intelligenttiering".to_string(), "".to_string());
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intelligent_tiering_xml_round_trip() {
+        let config = IntelligentTieringConfiguration::enabled(30);
+        let xml = quick_xml::se::to_string(&config).unwrap();
+        assert_eq!(
+            xml,
+            "<IntelligentTieringConfiguration><Status>Enabled</Status>\
+             <Transition><Days>30</Days><RequestFrequent>1</RequestFrequent></Transition>\
+             </IntelligentTieringConfiguration>"
+        );
+        let parsed: IntelligentTieringConfiguration = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, config);
+
+        let parsed: IntelligentTieringConfiguration = quick_xml::de::from_str(
+            "<IntelligentTieringConfiguration><Status>Suspended</Status></IntelligentTieringConfiguration>",
+        )
+        .unwrap();
+        assert_eq!(parsed.status, IntelligentTieringStatus::Suspended);
+        assert!(parsed.transition.is_none());
+    }
+}
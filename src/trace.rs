@@ -0,0 +1,243 @@
+//! 请求追踪
+//!
+//! 开启 `tracing` feature 后，每个请求都在名为 `cos.request` 的 span 中执行，span 字段包括
+//! 操作名、方法、主机、路径，以及完成后记录的状态码、请求 ID、尝试次数和耗时。
+//! 请求需要重试时，在退避等待前记录一条 warn 事件，包含尝试次数与状态码或错误。
+//! 请求头（包括 Authorization 与临时密钥）不会被记录。未开启 feature 时这里的函数不做任何事。
+
+use crate::error::Result;
+use crate::response::CosResponse;
+use reqwest::Method;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+/// 单个请求的追踪上下文
+pub(crate) struct RequestTrace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    started: std::time::Instant,
+}
+
+impl RequestTrace {
+    /// 开始追踪一个请求
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn start(method: &Method, host: &str, path: &str, params: &HashMap<String, String>, attempt: u32) -> Self {
+        #[cfg(feature = "tracing")]
+        let span = {
+            let span = tracing::debug_span!(
+                "cos.request",
+                operation = %operation_name(method, path, params),
+                method = %method,
+                host = %host,
+                path = %path,
+                attempt,
+                status = tracing::field::Empty,
+                request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+            );
+            span.in_scope(|| tracing::debug!("request started"));
+            span
+        };
+
+        Self {
+            #[cfg(feature = "tracing")]
+            span,
+            #[cfg(feature = "tracing")]
+            started: std::time::Instant::now(),
+        }
+    }
+
+    /// 在请求的 span 中执行
+    pub(crate) async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+            future.instrument(self.span.clone()).await
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            future.await
+        }
+    }
+
+    /// 记录请求结果
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn finish(self, result: &Result<CosResponse>) {
+        #[cfg(feature = "tracing")]
+        {
+            let latency_ms = self.started.elapsed().as_millis() as u64;
+            let span = &self.span;
+            span.record("latency_ms", latency_ms);
+            span.in_scope(|| match result {
+                Ok(response) => {
+                    let status = response.status();
+                    span.record("status", status.as_u16());
+                    if let Some(id) = crate::object::request_id(response.headers()) {
+                        span.record("request_id", id.as_str());
+                    }
+                    if status.is_server_error() {
+                        tracing::warn!(status = status.as_u16(), latency_ms, "request failed");
+                    } else {
+                        tracing::debug!(status = status.as_u16(), latency_ms, "request finished");
+                    }
+                }
+                Err(error) => tracing::warn!(error = %error, latency_ms, "request failed"),
+            });
+        }
+    }
+}
+
+/// 记录一次失败的尝试以及随后的重试，在退避等待之前调用
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn retrying(
+    method: &Method,
+    path: &str,
+    attempt: u32,
+    max_attempts: u32,
+    result: &Result<CosResponse>,
+    backoff: Duration,
+) {
+    #[cfg(feature = "tracing")]
+    {
+        let backoff_ms = backoff.as_millis() as u64;
+        match result {
+            Ok(response) => tracing::warn!(
+                method = %method,
+                path = %path,
+                attempt,
+                max_attempts,
+                status = response.status().as_u16(),
+                backoff_ms,
+                "request will be retried"
+            ),
+            Err(error) => tracing::warn!(
+                method = %method,
+                path = %path,
+                attempt,
+                max_attempts,
+                status = error.status(),
+                error = %error,
+                backoff_ms,
+                "request will be retried"
+            ),
+        }
+    }
+}
+
+/// 由方法、路径与子资源参数生成操作名，如 `GetObject`、`PutBucketAcl`
+///
+/// 子资源为取值为空的查询参数，如 `?acl`、`?uploads`。
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub(crate) fn operation_name(method: &Method, path: &str, params: &HashMap<String, String>) -> String {
+    let method = method.as_str();
+    let mut name = String::with_capacity(32);
+    name.push_str(&capitalize(&method.to_ascii_lowercase()));
+    name.push_str(if path == "/" { "Bucket" } else { "Object" });
+
+    let mut sub_resources: Vec<&str> = params
+        .iter()
+        .filter(|(_, value)| value.is_empty())
+        .map(|(key, _)| key.as_str())
+        .collect();
+    sub_resources.sort_unstable();
+    if let Some(sub_resource) = sub_resources.first() {
+        name.push_str(&capitalize(sub_resource));
+    }
+    name
+}
+
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_name() {
+        let mut params = HashMap::new();
+        assert_eq!(operation_name(&Method::GET, "/a.txt", &params), "GetObject");
+        assert_eq!(operation_name(&Method::HEAD, "/", &params), "HeadBucket");
+
+        params.insert("acl".to_string(), String::new());
+        assert_eq!(operation_name(&Method::PUT, "/", &params), "PutBucketAcl");
+
+        params.clear();
+        params.insert("partNumber".to_string(), "1".to_string());
+        params.insert("uploadId".to_string(), "u1".to_string());
+        assert_eq!(operation_name(&Method::PUT, "/a.bin", &params), "PutObject");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_retrying_emits_warn() {
+        use crate::error::CosError;
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Level, Metadata};
+
+        /// 事件的级别与字段
+        type Recorded = (Level, Vec<(String, String)>);
+
+        /// 只收集事件的订阅者
+        #[derive(Default)]
+        struct Collector(Arc<Mutex<Vec<Recorded>>>);
+
+        struct Fields(Vec<(String, String)>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push((field.name().to_string(), format!("{:?}", value)));
+            }
+        }
+
+        impl tracing::Subscriber for Collector {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields(Vec::new());
+                event.record(&mut fields);
+                self.0.lock().unwrap().push((*event.metadata().level(), fields.0));
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let collector = Collector::default();
+        let events = collector.0.clone();
+        let error = CosError::Server {
+            code: "SlowDown".to_string(),
+            message: "Please reduce your request rate.".to_string(),
+            status: Some(503),
+            request_id: None,
+            details: None,
+        };
+        tracing::subscriber::with_default(collector, || {
+            retrying(&Method::PUT, "/a.txt", 2, 3, &Err(error), Duration::from_millis(200));
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, Level::WARN);
+        let field = |name: &str| fields.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+        assert_eq!(field("attempt"), Some("2"));
+        assert_eq!(field("status"), Some("503"));
+        assert_eq!(field("backoff_ms"), Some("200"));
+    }
+}
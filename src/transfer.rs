@@ -0,0 +1,515 @@
+//! 高级传输模块
+//!
+//! 在底层对象接口之上提供自动选择简单上传或并发分块上传的文件传输功能
+
+use crate::config::Config;
+use crate::crc64;
+use crate::encryption::ServerSideEncryption;
+use crate::error::{CosError, Result};
+use crate::mime;
+use crate::retry::RetryConfig;
+use crate::multipart::{CompletedPart, MultipartUploadOptions, UploadPartOptions, MAX_PART_NUMBER, MIN_PART_SIZE};
+use crate::object::{insert_traffic_limit, ObjectClient, PutObjectResponse, MAX_TRAFFIC_LIMIT, MIN_TRAFFIC_LIMIT};
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::time::Duration;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::task::JoinSet;
+
+/// 默认的分块上传阈值（32MB）
+pub const DEFAULT_MULTIPART_THRESHOLD: u64 = 32 * 1024 * 1024;
+
+/// 默认的分块大小（16MB）
+pub const DEFAULT_PART_SIZE: u64 = 16 * 1024 * 1024;
+
+/// 默认的分块上传并发数
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// 高级传输接口中单个分块请求的默认超时（10 分钟），与 [`Config::timeout`] 无关
+pub const DEFAULT_PART_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// 文件上传选项
+#[derive(Debug, Clone, Default)]
+pub struct UploadOptions {
+    /// 文件大小达到该值时使用分块上传，未指定时使用 [`Config::multipart_threshold`]
+    pub multipart_threshold: Option<u64>,
+    /// 分块大小，不小于 1MB，未指定时使用 [`Config::part_size`]；分块数超过 10000 时会自动增大
+    pub part_size: Option<u64>,
+    /// 同时上传的分块数量，未指定时使用 [`Config::max_upload_concurrency`]
+    pub concurrency: Option<usize>,
+    /// 未指定时根据文件扩展名推断，配置启用时再按文件内容检测
+    pub content_type: Option<String>,
+    /// 额外的请求头
+    pub headers: HashMap<String, String>,
+    /// 服务端加密方式
+    pub encryption: Option<ServerSideEncryption>,
+    /// 上传完成后校验本地与服务端的 CRC64
+    pub verify_crc64: bool,
+    /// 所有并发分块请求的总限速（bit/s）
+    ///
+    /// COS 的限速作用于单个请求，N 个并发分块会占用 N 倍的带宽，因此这里的总限速会平均分配给
+    /// 每个分块请求；平均后低于 [`MIN_TRAFFIC_LIMIT`] 时会相应降低并发数。
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 单个分块请求的超时，未指定时为 [`DEFAULT_PART_TIMEOUT`]
+    pub part_timeout: Option<Duration>,
+    /// 本次上传所有请求的重试策略，未指定时使用 [`Config::retry`]
+    pub retry: Option<RetryConfig>,
+}
+
+impl UploadOptions {
+    /// 合并本次调用与客户端配置中的分块参数
+    pub(crate) fn sizing(&self, config: &Config) -> Result<PartSizing> {
+        let sizing = PartSizing {
+            threshold: self.multipart_threshold.unwrap_or(config.multipart_threshold),
+            part_size: self.part_size.unwrap_or(config.part_size),
+            concurrency: self.concurrency.unwrap_or(config.max_upload_concurrency),
+        };
+        if sizing.part_size < MIN_PART_SIZE {
+            return Err(CosError::config(format!(
+                "Part size {} is below the minimum of {} bytes",
+                sizing.part_size, MIN_PART_SIZE
+            )));
+        }
+        if sizing.concurrency == 0 {
+            return Err(CosError::config("Concurrency must be greater than zero"));
+        }
+        Ok(sizing)
+    }
+}
+
+/// 生效的分块参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PartSizing {
+    pub threshold: u64,
+    pub part_size: u64,
+    pub concurrency: usize,
+}
+
+impl PartSizing {
+    /// 分块数超过上限时增大分块大小（按 1MB 取整），使 `size` 字节的数据不超过 10000 个分块
+    pub(crate) fn fit(mut self, size: u64) -> Self {
+        if size.div_ceil(self.part_size) > MAX_PART_NUMBER as u64 {
+            let part_size = size.div_ceil(MAX_PART_NUMBER as u64).div_ceil(MIN_PART_SIZE) * MIN_PART_SIZE;
+            log::debug!(
+                "Increasing part size from {} to {} bytes to fit {} bytes in {} parts",
+                self.part_size, part_size, size, MAX_PART_NUMBER
+            );
+            self.part_size = part_size;
+        }
+        self
+    }
+}
+
+/// 文件下载选项
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    /// 下载完成后校验本地与服务端的 CRC64
+    pub verify_crc64: bool,
+    /// 下载请求的限速（bit/s），范围为 [`MIN_TRAFFIC_LIMIT`] 到 [`MAX_TRAFFIC_LIMIT`]
+    pub traffic_limit_bits_per_sec: Option<u64>,
+    /// 下载请求的重试策略，未指定时使用 [`Config::retry`]
+    pub retry: Option<RetryConfig>,
+}
+
+/// 文件上传结果
+#[derive(Debug, Clone)]
+pub struct UploadFileResponse {
+    pub etag: String,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+    pub version_id: Option<String>,
+    /// 使用分块上传时的 UploadId
+    pub upload_id: Option<String>,
+    /// 服务端加密方式
+    pub encryption: Option<ServerSideEncryption>,
+}
+
+impl From<PutObjectResponse> for UploadFileResponse {
+    fn from(response: PutObjectResponse) -> Self {
+        Self {
+            etag: response.etag,
+            crc64: response.crc64,
+            version_id: response.version_id,
+            upload_id: None,
+            encryption: response.encryption,
+        }
+    }
+}
+
+impl ObjectClient {
+    /// 上传本地文件
+    ///
+    /// 小于阈值的文件使用简单上传，否则使用并发分块上传；
+    /// 任一分块失败时会终止本次分块上传，避免残留分块产生存储费用。
+    pub async fn upload_file(
+        &self,
+        key: &str,
+        file_path: &Path,
+        options: UploadOptions,
+    ) -> Result<UploadFileResponse> {
+        self.with_retry(options.retry.as_ref())?
+            .upload_file_with_retry(key, file_path, options)
+            .await
+    }
+
+    /// 上传本地文件，请求使用客户端当前的重试策略
+    async fn upload_file_with_retry(
+        &self,
+        key: &str,
+        file_path: &Path,
+        options: UploadOptions,
+    ) -> Result<UploadFileResponse> {
+        let file_size = tokio::fs::metadata(file_path)
+            .await
+            .map_err(|e| CosError::other(format!("Failed to read file metadata: {}", e)))?
+            .len();
+
+        let content_type = match options.content_type.clone() {
+            Some(content_type) => Some(content_type),
+            None => mime::from_file(file_path, self.client.config().sniff_content_type)
+                .await
+                .map(|s| s.to_string()),
+        };
+
+        let mut headers = options.headers.clone();
+        if let Some(ct) = content_type {
+            headers.insert("Content-Type".to_string(), ct);
+        }
+        if let Some(ref encryption) = options.encryption {
+            headers.extend(encryption.to_headers());
+        }
+
+        let sizing = options.sizing(self.client.config())?.fit(file_size);
+        if file_size < sizing.threshold {
+            let traffic_limit = split_traffic_limit(options.traffic_limit_bits_per_sec, 1)?.0;
+            insert_traffic_limit(&mut headers, traffic_limit)?;
+            let data = tokio::fs::read(file_path)
+                .await
+                .map_err(|e| CosError::other(format!("Failed to read file: {}", e)))?;
+            let local_crc = options.verify_crc64.then(|| crc64::checksum(&data));
+            let _permit = self.part_permit().await?;
+            let response = self
+                .client
+                .put_with_headers(&self.object_path(key)?, HashMap::new(), headers, Some(data))
+                .await?;
+            let response = PutObjectResponse::from_headers(response.headers());
+            if let Some(local_crc) = local_crc {
+                crc64::verify(local_crc, response.crc64.as_deref())?;
+            }
+            return Ok(response.into());
+        }
+
+        let upload_id = self
+            .initiate_multipart_upload(
+                key,
+                Some(MultipartUploadOptions {
+                    headers,
+                    ..Default::default()
+                }),
+            )
+            .await?
+            .upload_id;
+
+        let (parts, local_crc) = match self
+            .upload_file_parts(key, &upload_id, file_path, file_size, sizing, &options)
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                if let Err(abort_err) = self.abort_multipart_upload(key, &upload_id).await {
+                    log::warn!("Failed to abort multipart upload {}: {}", upload_id, abort_err);
+                }
+                return Err(e);
+            }
+        };
+
+        let response = match self.complete_multipart_upload(key, &upload_id, parts).await {
+            Ok(response) => response,
+            Err(e) => {
+                if let Err(abort_err) = self.abort_multipart_upload(key, &upload_id).await {
+                    log::warn!("Failed to abort multipart upload {}: {}", upload_id, abort_err);
+                }
+                return Err(e);
+            }
+        };
+        if options.verify_crc64 {
+            crc64::verify(local_crc, response.crc64.as_deref())?;
+        }
+        Ok(UploadFileResponse {
+            etag: response.etag,
+            crc64: response.crc64,
+            version_id: response.version_id,
+            upload_id: Some(upload_id),
+            encryption: response.encryption,
+        })
+    }
+
+    /// 并发上传文件的所有分块，同时返回按分块合并得到的整个文件的 CRC64
+    async fn upload_file_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        file_path: &Path,
+        file_size: u64,
+        sizing: PartSizing,
+        options: &UploadOptions,
+    ) -> Result<(Vec<CompletedPart>, u64)> {
+        let part_count = file_size.div_ceil(sizing.part_size) as u32;
+        let (traffic_limit, concurrency) =
+            split_traffic_limit(options.traffic_limit_bits_per_sec, sizing.concurrency)?;
+        let part_options = UploadPartOptions {
+            traffic_limit_bits_per_sec: traffic_limit,
+            timeout: Some(options.part_timeout.unwrap_or(DEFAULT_PART_TIMEOUT)),
+            ..Default::default()
+        };
+        let mut tasks = JoinSet::new();
+        let mut parts = Vec::with_capacity(part_count as usize);
+        let verify_crc64 = options.verify_crc64;
+
+        for part_number in 1..=part_count {
+            if tasks.len() >= concurrency {
+                parts.push(join_part(&mut tasks).await?);
+            }
+
+            let offset = (part_number as u64 - 1) * sizing.part_size;
+            let length = sizing.part_size.min(file_size - offset);
+            let client = self.clone();
+            let key = key.to_string();
+            let upload_id = upload_id.to_string();
+            let path = file_path.to_path_buf();
+            let part_options = part_options.clone();
+            tasks.spawn(async move {
+                let data = read_file_range(&path, offset, length).await?;
+                let crc = if verify_crc64 { crc64::checksum(&data) } else { 0 };
+                let _permit = client.part_permit().await?;
+                let response = client
+                    .upload_part_with_options(&key, &upload_id, part_number, data, &part_options)
+                    .await?;
+                Ok((CompletedPart::new(part_number, response.etag), crc, length))
+            });
+        }
+
+        while !tasks.is_empty() {
+            parts.push(join_part(&mut tasks).await?);
+        }
+
+        parts.sort_by_key(|(part, _, _)| part.part_number);
+        let crc = parts
+            .iter()
+            .fold(0, |crc, (_, part_crc, length)| crc64::combine(crc, *part_crc, *length));
+        let parts = parts.into_iter().map(|(part, _, _)| part).collect();
+
+        Ok((parts, crc))
+    }
+}
+
+/// 将总限速平均分配给并发请求，返回单个请求的限速与实际并发数
+///
+/// 平均后低于 [`MIN_TRAFFIC_LIMIT`] 时降低并发数，使每个请求的限速不低于下限；
+/// 单个请求的限速不超过 [`MAX_TRAFFIC_LIMIT`]。
+pub(crate) fn split_traffic_limit(total: Option<u64>, concurrency: usize) -> Result<(Option<u64>, usize)> {
+    let Some(total) = total else {
+        return Ok((None, concurrency));
+    };
+    if total < MIN_TRAFFIC_LIMIT {
+        return Err(CosError::config(format!(
+            "Traffic limit {} bit/s is below the minimum of {}",
+            total, MIN_TRAFFIC_LIMIT
+        )));
+    }
+
+    let concurrency = concurrency.min((total / MIN_TRAFFIC_LIMIT) as usize).max(1);
+    let per_request = (total / concurrency as u64).min(MAX_TRAFFIC_LIMIT);
+    Ok((Some(per_request), concurrency))
+}
+
+/// 等待任意一个分块任务完成，失败时取消其余任务
+pub(crate) async fn join_part<T: 'static>(tasks: &mut JoinSet<Result<T>>) -> Result<T> {
+    let result = match tasks.join_next().await {
+        Some(Ok(result)) => result,
+        Some(Err(e)) => Err(CosError::other(format!("Part task failed: {}", e))),
+        None => Err(CosError::other("No part task to wait for")),
+    };
+    if result.is_err() {
+        tasks.abort_all();
+    }
+    result
+}
+
+/// 读取文件中指定范围的数据
+async fn read_file_range(path: &Path, offset: u64, length: u64) -> Result<Vec<u8>> {
+    let mut file = File::open(path)
+        .await
+        .map_err(|e| CosError::other(format!("Failed to open file: {}", e)))?;
+    file.seek(SeekFrom::Start(offset))
+        .await
+        .map_err(|e| CosError::other(format!("Failed to seek file: {}", e)))?;
+
+    let mut data = vec![0u8; length as usize];
+    file.read_exact(&mut data)
+        .await
+        .map_err(|e| CosError::other(format!("Failed to read file: {}", e)))?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[tokio::test]
+    async fn test_read_file_range() {
+        let path = std::env::temp_dir().join("cos-rust-sdk-read-file-range.bin");
+        let mut file = std::fs::File::create(&path).unwrap();
+        file.write_all(b"0123456789").unwrap();
+
+        assert_eq!(read_file_range(&path, 3, 4).await.unwrap(), b"3456");
+        assert!(read_file_range(&path, 8, 4).await.is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_retry_override() {
+        use crate::retry::RetryConfig;
+        use crate::testing::{MockResponse, MockTransport};
+
+        let path = std::env::temp_dir().join("cos-rust-sdk-upload-retry.txt");
+        std::fs::write(&path, b"hello").unwrap();
+        let transport = MockTransport::new();
+        let client = ObjectClient::new(
+            transport
+                .client(Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123"))
+                .unwrap(),
+        );
+
+        transport
+            .push_response(MockResponse::error(503, "SlowDown", "Please reduce your request rate."))
+            .push_response(
+                MockResponse::new(200, "").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""),
+            );
+        let options = UploadOptions {
+            retry: Some(RetryConfig::default().with_backoff(Duration::ZERO, Duration::ZERO)),
+            ..Default::default()
+        };
+        let response = client.upload_file("a.txt", &path, options).await.unwrap();
+        assert_eq!(response.etag, "\"5d41402abc4b2a76b9719d911017c592\"");
+        assert_eq!(transport.requests().len(), 2);
+
+        // 未覆盖时使用配置中的策略，默认不重试
+        transport.push_response(MockResponse::error(503, "SlowDown", "Please reduce your request rate."));
+        assert!(client.upload_file("a.txt", &path, UploadOptions::default()).await.is_err());
+        assert_eq!(transport.requests().len(), 3);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_aborts_when_complete_fails() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let path = std::env::temp_dir().join("cos-rust-sdk-upload-complete.txt");
+        std::fs::write(&path, b"hello").unwrap();
+        let transport = MockTransport::new();
+        let client = ObjectClient::new(
+            transport
+                .client(Config::new("test_id", "test_key", "ap-beijing", "test-bucket-123"))
+                .unwrap(),
+        );
+
+        transport
+            .push_response(MockResponse::new(
+                200,
+                "<InitiateMultipartUploadResult><Bucket>test-bucket-123</Bucket><Key>a.txt</Key>\
+                 <UploadId>upload-1</UploadId></InitiateMultipartUploadResult>",
+            ))
+            .push_response(MockResponse::new(200, "").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""))
+            .push_response(MockResponse::error(400, "InvalidPart", "One or more of the specified parts could not be found."))
+            .push_response(MockResponse::new(204, ""));
+        let options = UploadOptions {
+            multipart_threshold: Some(1),
+            ..Default::default()
+        };
+        let err = client.upload_file("a.txt", &path, options.clone()).await.unwrap_err();
+        assert_eq!(err.code(), Some("InvalidPart"));
+
+        // 合并失败后取消分块上传，不留下未完成的上传
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[2].method, reqwest::Method::POST);
+        assert_eq!(requests[3].method, reqwest::Method::DELETE);
+        assert_eq!(requests[3].query_param("uploadId").as_deref(), Some("upload-1"));
+
+        // 合并成功时返回响应头中的版本 ID
+        transport
+            .push_response(MockResponse::new(
+                200,
+                "<InitiateMultipartUploadResult><Bucket>test-bucket-123</Bucket><Key>a.txt</Key>\
+                 <UploadId>upload-2</UploadId></InitiateMultipartUploadResult>",
+            ))
+            .push_response(MockResponse::new(200, "").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""))
+            .push_response(
+                MockResponse::new(
+                    200,
+                    "<CompleteMultipartUploadResult><Key>a.txt</Key><ETag>\"abc-1\"</ETag></CompleteMultipartUploadResult>",
+                )
+                .with_header("x-cos-version-id", "MTg0NDUxNTc1NjIzMTQ1MDAwODg"),
+            );
+        let response = client.upload_file("a.txt", &path, options).await.unwrap();
+        assert_eq!(response.upload_id.as_deref(), Some("upload-2"));
+        assert_eq!(response.version_id.as_deref(), Some("MTg0NDUxNTc1NjIzMTQ1MDAwODg"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_upload_sizing() {
+        let config = Config::new("id", "key", "ap-beijing", "bucket-123").with_part_size(8 * MIN_PART_SIZE);
+        let sizing = UploadOptions::default().sizing(&config).unwrap();
+        assert_eq!(sizing.threshold, DEFAULT_MULTIPART_THRESHOLD);
+        assert_eq!(sizing.part_size, 8 * MIN_PART_SIZE);
+        assert_eq!(sizing.concurrency, DEFAULT_CONCURRENCY);
+
+        let options = UploadOptions {
+            part_size: Some(2 * MIN_PART_SIZE),
+            concurrency: Some(1),
+            ..Default::default()
+        };
+        let sizing = options.sizing(&config).unwrap();
+        assert_eq!((sizing.part_size, sizing.concurrency), (2 * MIN_PART_SIZE, 1));
+
+        let options = UploadOptions {
+            part_size: Some(1024),
+            ..Default::default()
+        };
+        assert!(options.sizing(&config).is_err());
+    }
+
+    #[test]
+    fn test_part_sizing_fit() {
+        let sizing = PartSizing {
+            threshold: DEFAULT_MULTIPART_THRESHOLD,
+            part_size: DEFAULT_PART_SIZE,
+            concurrency: 1,
+        };
+        assert_eq!(sizing.fit(DEFAULT_PART_SIZE * 10000).part_size, DEFAULT_PART_SIZE);
+
+        // 200GB 需要至少 20.48MB 的分块，按 1MB 取整为 21MB
+        let size = 200 * 1024 * MIN_PART_SIZE;
+        let fitted = sizing.fit(size);
+        assert_eq!(fitted.part_size, 21 * MIN_PART_SIZE);
+        assert!(size.div_ceil(fitted.part_size) <= MAX_PART_NUMBER as u64);
+    }
+
+    #[test]
+    fn test_split_traffic_limit() {
+        assert_eq!(split_traffic_limit(None, 4).unwrap(), (None, 4));
+        assert_eq!(split_traffic_limit(Some(MIN_TRAFFIC_LIMIT * 8), 4).unwrap(), (Some(MIN_TRAFFIC_LIMIT * 2), 4));
+        // 平均后低于下限时降低并发数
+        assert_eq!(split_traffic_limit(Some(MIN_TRAFFIC_LIMIT * 2), 4).unwrap(), (Some(MIN_TRAFFIC_LIMIT), 2));
+        assert_eq!(split_traffic_limit(Some(u64::MAX), 1).unwrap(), (Some(MAX_TRAFFIC_LIMIT), 1));
+        assert!(split_traffic_limit(Some(MIN_TRAFFIC_LIMIT - 1), 1).is_err());
+    }
+}
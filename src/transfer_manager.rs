@@ -0,0 +1,412 @@
+//! 传输管理模块
+//!
+//! 以统一的入口管理上传、下载和复制，并限制所有传输同时发出的分块请求总数
+
+use crate::copy::{CopyMultipartOptions, CopySource, MAX_SIMPLE_COPY_SIZE};
+use crate::error::{CosError, Result};
+use crate::object::{header_str, GetObjectOptions, ObjectClient};
+use crate::resumable::ResumableDownloadOptions;
+use crate::transfer::{split_traffic_limit, UploadFileResponse, UploadOptions, DEFAULT_CONCURRENCY};
+use bytes::Bytes;
+use std::fmt;
+use std::future::Future;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+
+/// 默认的最大并发请求数
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4 * DEFAULT_CONCURRENCY;
+
+/// 传输管理器配置
+#[derive(Debug, Clone)]
+pub struct TransferConfig {
+    /// 所有传输共享的最大并发请求数，单个传输的分块并发数仍受各自选项限制
+    pub max_concurrent_requests: usize,
+    /// 上传选项
+    pub upload_options: UploadOptions,
+    /// 下载到文件时使用的断点续传选项
+    pub download_options: ResumableDownloadOptions,
+    /// 复制选项，源对象大于 5GB 时使用分块复制
+    pub copy_options: CopyMultipartOptions,
+}
+
+impl Default for TransferConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            upload_options: UploadOptions::default(),
+            download_options: ResumableDownloadOptions::default(),
+            copy_options: CopyMultipartOptions::default(),
+        }
+    }
+}
+
+/// 上传的数据来源
+pub enum UploadSource {
+    /// 本地文件，按大小自动选择简单上传或并发分块上传
+    Path(PathBuf),
+    /// 内存中的数据，达到分块上传阈值时按分块依次上传
+    Bytes(Bytes),
+    /// 任意数据流，长度未知或达到分块上传阈值时按分块依次上传
+    Reader {
+        reader: Pin<Box<dyn AsyncRead + Send>>,
+        content_length: Option<u64>,
+    },
+}
+
+impl UploadSource {
+    /// 由数据流创建上传来源
+    pub fn reader<R: AsyncRead + Send + 'static>(reader: R, content_length: Option<u64>) -> Self {
+        Self::Reader {
+            reader: Box::pin(reader),
+            content_length,
+        }
+    }
+}
+
+impl fmt::Debug for UploadSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            Self::Bytes(data) => f.debug_tuple("Bytes").field(&data.len()).finish(),
+            Self::Reader { content_length, .. } => f
+                .debug_struct("Reader")
+                .field("content_length", content_length)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl From<PathBuf> for UploadSource {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&Path> for UploadSource {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.to_path_buf())
+    }
+}
+
+impl From<Bytes> for UploadSource {
+    fn from(data: Bytes) -> Self {
+        Self::Bytes(data)
+    }
+}
+
+impl From<Vec<u8>> for UploadSource {
+    fn from(data: Vec<u8>) -> Self {
+        Self::Bytes(data.into())
+    }
+}
+
+/// 下载的目标
+pub enum DownloadDest {
+    /// 本地文件，使用并发的断点续传下载
+    Path(PathBuf),
+    /// 下载到内存，数据在 [`DownloadResult::data`] 中返回
+    Memory,
+    /// 写入任意数据流
+    Writer(Pin<Box<dyn AsyncWrite + Send>>),
+}
+
+impl DownloadDest {
+    /// 由数据流创建下载目标
+    pub fn writer<W: AsyncWrite + Send + 'static>(writer: W) -> Self {
+        Self::Writer(Box::pin(writer))
+    }
+}
+
+impl fmt::Debug for DownloadDest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            Self::Memory => f.write_str("Memory"),
+            Self::Writer(_) => f.write_str("Writer"),
+        }
+    }
+}
+
+impl From<PathBuf> for DownloadDest {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&Path> for DownloadDest {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.to_path_buf())
+    }
+}
+
+/// 下载结果
+#[derive(Debug, Clone)]
+pub struct DownloadResult {
+    pub etag: String,
+    /// 下载的字节数
+    pub size: u64,
+    /// 下载到内存时的数据
+    pub data: Option<Bytes>,
+}
+
+/// 复制结果
+#[derive(Debug, Clone)]
+pub struct CopyResult {
+    pub etag: String,
+    /// 服务端计算的 CRC64-ECMA 校验值
+    pub crc64: Option<String>,
+}
+
+/// 进行中的传输
+///
+/// 可直接 `.await` 获取结果；调用 [`TransferHandle::abort`] 会取消传输及其所有分块请求，
+/// 已上传的分块不会自动清理，可使用 [`BucketClient::abort_multipart_uploads_older_than`](crate::BucketClient::abort_multipart_uploads_older_than) 回收。
+pub struct TransferHandle<T> {
+    inner: JoinHandle<Result<T>>,
+}
+
+impl<T> TransferHandle<T> {
+    /// 取消传输
+    pub fn abort(&self) {
+        self.inner.abort();
+    }
+
+    /// 传输是否已结束
+    pub fn is_finished(&self) -> bool {
+        self.inner.is_finished()
+    }
+}
+
+impl<T> Future for TransferHandle<T> {
+    type Output = Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner).poll(cx).map(|result| match result {
+            Ok(result) => result,
+            Err(e) if e.is_cancelled() => Err(CosError::other("Transfer was aborted")),
+            Err(e) => Err(CosError::other(format!("Transfer task failed: {}", e))),
+        })
+    }
+}
+
+impl<T> fmt::Debug for TransferHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransferHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// 传输管理器
+///
+/// 同一个管理器发起的所有传输共享一个并发请求限制，同时进行大量传输时
+/// 也不会超过 [`TransferConfig::max_concurrent_requests`] 个请求。
+#[derive(Debug, Clone)]
+pub struct TransferManager {
+    client: ObjectClient,
+    config: Arc<TransferConfig>,
+    limiter: Arc<Semaphore>,
+}
+
+impl TransferManager {
+    /// 创建传输管理器
+    pub fn new(client: ObjectClient, config: TransferConfig) -> Result<Self> {
+        if config.max_concurrent_requests == 0 {
+            return Err(CosError::config("Max concurrent requests must be greater than zero"));
+        }
+        let limiter = Arc::new(Semaphore::new(config.max_concurrent_requests));
+        Ok(Self {
+            client: client.with_part_limiter(limiter.clone()),
+            config: Arc::new(config),
+            limiter,
+        })
+    }
+
+    /// 当前可立即发出的请求数
+    pub fn available_requests(&self) -> usize {
+        self.limiter.available_permits()
+    }
+
+    /// 上传对象
+    pub fn upload(&self, key: &str, source: impl Into<UploadSource>) -> TransferHandle<UploadFileResponse> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        let key = key.to_string();
+        let source = source.into();
+        spawn(async move {
+            let options = config.upload_options.clone();
+            match source {
+                UploadSource::Path(path) => client.upload_file(&key, &path, options).await,
+                UploadSource::Bytes(data) => {
+                    let length = data.len() as u64;
+                    client.put_object_stream(&key, Cursor::new(data), Some(length), options).await
+                }
+                UploadSource::Reader { reader, content_length } => {
+                    client.put_object_stream(&key, reader, content_length, options).await
+                }
+            }
+        })
+    }
+
+    /// 下载对象
+    pub fn download(&self, key: &str, dest: impl Into<DownloadDest>) -> TransferHandle<DownloadResult> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        let key = key.to_string();
+        let dest = dest.into();
+        spawn(async move {
+            match dest {
+                DownloadDest::Path(path) => {
+                    let response = client
+                        .download_file_resumable(&key, &path, config.download_options.clone())
+                        .await?;
+                    Ok(DownloadResult {
+                        etag: response.etag,
+                        size: response.size,
+                        data: None,
+                    })
+                }
+                DownloadDest::Memory => {
+                    let _permit = client.part_permit().await?;
+                    let response = client.get_object(&key).await?;
+                    Ok(DownloadResult {
+                        etag: response.etag,
+                        size: response.data.len() as u64,
+                        data: Some(response.data),
+                    })
+                }
+                DownloadDest::Writer(mut writer) => {
+                    let options = GetObjectOptions {
+                        traffic_limit_bits_per_sec: config.download_options.traffic_limit_bits_per_sec,
+                        ..Default::default()
+                    };
+                    let _permit = client.part_permit().await?;
+                    let meta = client.get_object_to_writer(&key, &mut writer, options).await?;
+                    Ok(DownloadResult {
+                        etag: meta.etag,
+                        size: meta.content_length,
+                        data: None,
+                    })
+                }
+            }
+        })
+    }
+
+    /// 复制对象，源对象大于 5GB 时自动使用分块复制
+    pub fn copy(&self, dest_key: &str, source: CopySource) -> TransferHandle<CopyResult> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        let dest_key = dest_key.to_string();
+        spawn(async move {
+            let source_headers = client.head_copy_source(&source).await?;
+            let source_size: u64 = header_str(&source_headers, "content-length")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+
+            if source_size > MAX_SIMPLE_COPY_SIZE {
+                let response = client
+                    .copy_object_multipart(&dest_key, source, config.copy_options.clone())
+                    .await?;
+                return Ok(CopyResult {
+                    etag: response.etag,
+                    crc64: response.crc64,
+                });
+            }
+
+            let mut copy_options = config.copy_options.copy_options.clone();
+            copy_options.traffic_limit_bits_per_sec =
+                split_traffic_limit(config.copy_options.traffic_limit_bits_per_sec, 1)?.0;
+            let _permit = client.part_permit().await?;
+            let response = client.copy_object(&dest_key, source, Some(copy_options)).await?;
+            Ok(CopyResult {
+                etag: response.etag,
+                crc64: response.crc64,
+            })
+        })
+    }
+}
+
+fn spawn<T, F>(future: F) -> TransferHandle<T>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T>> + Send + 'static,
+{
+    TransferHandle {
+        inner: tokio::spawn(future),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{client_for, ServedResponse};
+
+    async fn manager_for(responses: Vec<ServedResponse>, max_concurrent_requests: usize) -> TransferManager {
+        let config = TransferConfig {
+            max_concurrent_requests,
+            ..Default::default()
+        };
+        TransferManager::new(ObjectClient::new(client_for(responses).await), config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_download_bytes() {
+        let manager = manager_for(
+            vec![
+                ServedResponse::new(200, "").with_header("ETag", "\"abc\""),
+                ServedResponse::new(200, "hello").with_header("ETag", "\"5d41402abc4b2a76b9719d911017c592\""),
+                ServedResponse::new(200, "hello"),
+            ],
+            2,
+        )
+        .await;
+
+        let response = manager.upload("a.txt", b"hello".to_vec()).await.unwrap();
+        assert_eq!(response.etag, "\"abc\"");
+
+        let result = manager.download("a.txt", DownloadDest::Memory).await.unwrap();
+        assert_eq!(result.data.unwrap(), Bytes::from_static(b"hello"));
+
+        let mut buf = Vec::new();
+        let (writer, mut reader) = tokio::io::duplex(64);
+        let handle = manager.download("a.txt", DownloadDest::writer(writer));
+        tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut buf).await.unwrap();
+        assert_eq!(handle.await.unwrap().size, 5);
+        assert_eq!(buf, b"hello");
+        assert_eq!(manager.available_requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_requests_share_limit_and_abort() {
+        let manager = manager_for(vec![ServedResponse::new(200, "hello")], 1).await;
+
+        // 占用唯一的许可，新的传输只能等待
+        let permit = manager.limiter.clone().acquire_owned().await.unwrap();
+        let handle = manager.download("a.txt", DownloadDest::Memory);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!handle.is_finished());
+
+        handle.abort();
+        assert!(handle.await.unwrap_err().to_string().contains("aborted"));
+        drop(permit);
+        assert_eq!(manager.available_requests(), 1);
+    }
+
+    #[test]
+    fn test_zero_concurrency_rejected() {
+        let client = ObjectClient::new(
+            crate::client::CosClient::new(crate::config::Config::new("id", "key", "ap-beijing", "bucket-123")).unwrap(),
+        );
+        let config = TransferConfig {
+            max_concurrent_requests: 0,
+            ..Default::default()
+        };
+        assert!(TransferManager::new(client, config).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! HTTP 传输层
+//!
+//! [`CosClient`](crate::CosClient) 通过 [`HttpTransport`] 发送已签名的请求，默认使用 reqwest；
+//! 测试时可以替换为 [`MockTransport`](crate::testing::MockTransport)。
+//!
+//! TLS 后端由 `native-tls`（默认）与 `rustls` feature 选择，两者都开启时使用 rustls。
+
+use crate::config::Config;
+use crate::error::{CosError, Result};
+use futures::future::BoxFuture;
+use reqwest::{Client, ClientBuilder, Request, Response};
+use std::fmt;
+
+/// 发送单个 HTTP 请求
+pub(crate) trait HttpTransport: Send + Sync + fmt::Debug {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+/// 选择 feature 指定的 TLS 后端
+pub(crate) fn with_tls_backend(builder: ClientBuilder) -> ClientBuilder {
+    #[cfg(feature = "rustls")]
+    let builder = builder.use_rustls_tls();
+    builder
+}
+
+/// 按配置设置请求与连接超时、HTTP 版本和 TLS
+pub(crate) fn configure(builder: ClientBuilder, config: &Config) -> Result<ClientBuilder> {
+    let mut builder = builder.timeout(config.timeout);
+    if let Some(timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if config.http1_only {
+        builder = builder.http1_only();
+    }
+    with_tls_config(builder, config)
+}
+
+/// 按配置设置 TLS 后端、额外的根证书与证书校验
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn with_tls_config(builder: ClientBuilder, config: &Config) -> Result<ClientBuilder> {
+    let mut builder = with_tls_backend(builder);
+    for pem in &config.root_certificates {
+        // rustls 后端在创建证书时不解析内容，先检查是否为 PEM 格式的证书
+        if !String::from_utf8_lossy(pem).contains("-----BEGIN CERTIFICATE-----") {
+            return Err(CosError::config("Invalid root certificate: expected a PEM encoded certificate"));
+        }
+        let certificate = reqwest::Certificate::from_pem(pem)
+            .map_err(|e| CosError::config(format!("Invalid root certificate: {}", e)))?;
+        builder = builder.add_root_certificate(certificate);
+    }
+    if config.danger_accept_invalid_certs {
+        log::warn!("TLS certificate verification is disabled");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+    Ok(builder)
+}
+
+/// 未开启 TLS 后端时只能使用 HTTP，TLS 相关的配置无效
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+fn with_tls_config(builder: ClientBuilder, config: &Config) -> Result<ClientBuilder> {
+    if !config.root_certificates.is_empty() || config.danger_accept_invalid_certs {
+        return Err(CosError::config(
+            "TLS options require the `native-tls` or `rustls` feature",
+        ));
+    }
+    Ok(builder)
+}
+
+/// 基于 reqwest 的默认传输层
+#[derive(Debug, Clone)]
+pub(crate) struct ReqwestTransport(pub(crate) Client);
+
+impl HttpTransport for ReqwestTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        // 保留 reqwest 错误，重试策略据此区分连接失败与超时
+        Box::pin(async move { Ok(self.0.execute(request).await?) })
+    }
+}
@@ -0,0 +1,44 @@
+//! 条件写入集成测试
+//!
+//! 需要真实的 COS 存储桶，默认忽略。运行方式：
+//! ```bash
+//! cargo test --test conditional -- --ignored
+//! ```
+//!
+//! 运行前请设置环境变量 COS_SECRET_ID、COS_SECRET_KEY、COS_REGION、COS_BUCKET。
+
+use cos_rust_sdk::{Config, CosClient, CosError, ObjectClient, PutObjectOptions};
+use std::env;
+
+fn object_client() -> ObjectClient {
+    let config = Config::new(
+        env::var("COS_SECRET_ID").expect("COS_SECRET_ID"),
+        env::var("COS_SECRET_KEY").expect("COS_SECRET_KEY"),
+        env::var("COS_REGION").expect("COS_REGION"),
+        env::var("COS_BUCKET").expect("COS_BUCKET"),
+    );
+    ObjectClient::new(CosClient::new(config).unwrap())
+}
+
+#[tokio::test]
+#[ignore = "requires COS credentials"]
+async fn test_forbid_overwrite_only_one_writer_wins() {
+    let client = object_client();
+    let key = "cos-rust-sdk-test/forbid-overwrite.txt";
+    let _ = client.delete_object(key).await;
+
+    let options = PutObjectOptions::new().with_forbid_overwrite(true);
+    let (first, second) = tokio::join!(
+        client.put_object_with_options(key, b"writer-1".to_vec(), options.clone()),
+        client.put_object_with_options(key, b"writer-2".to_vec(), options.clone()),
+    );
+
+    let results = [first, second];
+    let winners = results.iter().filter(|r| r.is_ok()).count();
+    assert_eq!(winners, 1, "results: {:?}", results);
+    assert!(results
+        .iter()
+        .any(|r| matches!(r, Err(CosError::PreconditionFailed { .. }))));
+
+    client.delete_object(key).await.unwrap();
+}
@@ -0,0 +1,40 @@
+//! 服务端加密集成测试
+//!
+//! 需要真实的 COS 存储桶，默认忽略。运行方式：
+//! ```bash
+//! cargo test --test encryption -- --ignored
+//! ```
+//!
+//! 运行前请设置环境变量 COS_SECRET_ID、COS_SECRET_KEY、COS_REGION、COS_BUCKET。
+
+use cos_rust_sdk::{Config, CosClient, ObjectClient, PutObjectOptions, ServerSideEncryption};
+use std::env;
+
+fn object_client() -> ObjectClient {
+    let config = Config::new(
+        env::var("COS_SECRET_ID").expect("COS_SECRET_ID"),
+        env::var("COS_SECRET_KEY").expect("COS_SECRET_KEY"),
+        env::var("COS_REGION").expect("COS_REGION"),
+        env::var("COS_BUCKET").expect("COS_BUCKET"),
+    );
+    ObjectClient::new(CosClient::new(config).unwrap())
+}
+
+#[tokio::test]
+#[ignore = "requires COS credentials"]
+async fn test_sse_cos_upload_and_head() {
+    let client = object_client();
+    let key = "cos-rust-sdk-test/sse-cos.txt";
+
+    let options = PutObjectOptions::new().with_encryption(ServerSideEncryption::Cos);
+    let response = client
+        .put_object_with_options(key, b"encrypted".to_vec(), options)
+        .await
+        .unwrap();
+    assert_eq!(response.encryption, Some(ServerSideEncryption::Cos));
+
+    let head = client.head_object(key).await.unwrap();
+    assert_eq!(head.encryption, Some(ServerSideEncryption::Cos));
+
+    client.delete_object(key).await.unwrap();
+}
@@ -0,0 +1,65 @@
+//! 图片处理集成测试
+//!
+//! 需要真实的 COS 存储桶并开通数据万象，默认忽略。运行方式：
+//! ```bash
+//! cargo test --test image -- --ignored
+//! ```
+//!
+//! 运行前请设置环境变量 COS_SECRET_ID、COS_SECRET_KEY、COS_REGION、COS_BUCKET。
+
+use cos_rust_sdk::{
+    Config, CosClient, GetObjectOptions, ImageMogr2, ObjectClient, PresignOptions, PutObjectOptions,
+};
+use std::env;
+use std::time::Duration;
+
+/// 1x1 像素的 PNG 图片
+const PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0xc0, 0xf0,
+    0x1f, 0x00, 0x05, 0x00, 0x01, 0xff, 0x89, 0x99, 0x3d, 0x1d, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45,
+    0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+fn object_client() -> ObjectClient {
+    let config = Config::new(
+        env::var("COS_SECRET_ID").expect("COS_SECRET_ID"),
+        env::var("COS_SECRET_KEY").expect("COS_SECRET_KEY"),
+        env::var("COS_REGION").expect("COS_REGION"),
+        env::var("COS_BUCKET").expect("COS_BUCKET"),
+    );
+    ObjectClient::new(CosClient::new(config).unwrap())
+}
+
+#[tokio::test]
+#[ignore = "requires COS credentials"]
+async fn test_get_resized_image() {
+    let client = object_client();
+    let key = "cos-rust-sdk-test/photo.png";
+    client
+        .put_object_with_options(key, PNG.to_vec(), PutObjectOptions::new().with_content_type("image/png"))
+        .await
+        .unwrap();
+
+    let mogr2 = || ImageMogr2::new().thumbnail_width(200).format("jpg");
+    let options = GetObjectOptions {
+        image_process: Some(mogr2().into()),
+        ..Default::default()
+    };
+    let response = client.get_object_with_options(key, options).await.unwrap();
+    assert_eq!(response.content_type, "image/jpeg");
+
+    let options = PresignOptions {
+        image_process: Some(mogr2().into()),
+        ..Default::default()
+    };
+    let url = client
+        .presigned_get_url(key, Duration::from_secs(600), Some(options))
+        .unwrap();
+    let response = reqwest::get(&url).await.unwrap();
+    assert!(response.status().is_success());
+    assert_eq!(response.headers()["content-type"], "image/jpeg");
+
+    client.delete_object(key).await.unwrap();
+}
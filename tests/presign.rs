@@ -0,0 +1,80 @@
+//! 预签名 URL 集成测试
+//!
+//! 需要真实的 COS 存储桶，默认忽略。运行方式：
+//! ```bash
+//! cargo test --test presign -- --ignored
+//! ```
+//!
+//! 运行前请设置环境变量 COS_SECRET_ID、COS_SECRET_KEY、COS_REGION、COS_BUCKET。
+
+use cos_rust_sdk::{Config, CosClient, ObjectClient, PresignOptions};
+use reqwest::Method;
+use std::env;
+use std::time::Duration;
+
+fn object_client() -> ObjectClient {
+    let config = Config::new(
+        env::var("COS_SECRET_ID").expect("COS_SECRET_ID"),
+        env::var("COS_SECRET_KEY").expect("COS_SECRET_KEY"),
+        env::var("COS_REGION").expect("COS_REGION"),
+        env::var("COS_BUCKET").expect("COS_BUCKET"),
+    );
+    ObjectClient::new(CosClient::new(config).unwrap())
+}
+
+#[tokio::test]
+#[ignore = "requires COS credentials"]
+async fn test_presigned_get_url_downloads_object() {
+    let client = object_client();
+    let key = "cos-rust-sdk-test/presigned-get.txt";
+    client
+        .put_object(key, b"presigned".to_vec(), Some("text/plain"))
+        .await
+        .unwrap();
+
+    let options = PresignOptions {
+        response_content_disposition: Some("attachment; filename=\"a.txt\"".to_string()),
+        ..Default::default()
+    };
+    let url = client
+        .presigned_get_url(key, Duration::from_secs(300), Some(options))
+        .unwrap();
+
+    let response = reqwest::get(&url).await.unwrap();
+    assert!(response.status().is_success(), "status: {}", response.status());
+    assert_eq!(
+        response.headers()["content-disposition"],
+        "attachment; filename=\"a.txt\""
+    );
+    assert_eq!(response.bytes().await.unwrap().as_ref(), b"presigned");
+
+    client.delete_object(key).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires COS credentials"]
+async fn test_presigned_put_uploads_object() {
+    let client = object_client();
+    let key = "cos-rust-sdk-test/presigned-put.txt";
+
+    let mut options = PresignOptions::default();
+    options
+        .headers
+        .insert("Content-Type".to_string(), "text/plain".to_string());
+    let request = client
+        .presigned_url(Method::PUT, key, Duration::from_secs(300), options)
+        .unwrap();
+
+    let mut builder = reqwest::Client::new().request(request.method, &request.url);
+    for (name, value) in &request.headers {
+        builder = builder.header(name, value);
+    }
+    let response = builder.body("uploaded").send().await.unwrap();
+    assert!(response.status().is_success(), "status: {}", response.status());
+
+    let object = client.get_object(key).await.unwrap();
+    assert_eq!(&object.data[..], b"uploaded");
+    assert_eq!(object.content_type, "text/plain");
+
+    client.delete_object(key).await.unwrap();
+}